@@ -0,0 +1,113 @@
+// Project: clap-version-flag
+// File: attr/src/lib.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: The #[clap_version] attribute on main
+// License: MIT
+
+//! The `#[clap_version]` attribute.
+//!
+//! Re-exported by `clap-version-flag` as `clap_version_flag::clap_version`
+//! with its `attribute` feature; not meant to be used on its own.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{ItemFn, LitStr, parse_macro_input};
+
+/// Themes the `theme` argument accepts, the names of `Theme::built_in()`
+const THEMES: [&str; 5] = [
+    "classic",
+    "high-contrast",
+    "solarized",
+    "dracula",
+    "monochrome",
+];
+
+/// Prints the colorful version before the body of `main` runs
+///
+/// Handles `-V`/`--version` without touching the `Command` or `Parser`
+/// struct: the attribute puts a call to `exit_on_version_flag` with
+/// `colorful_version!()` at the top of the function. `theme = "..."` picks
+/// a built-in theme by the name `Theme::named` takes: `"classic"`
+/// (default), `"high-contrast"`, `"solarized"`, `"dracula"` or
+/// `"monochrome"`; other names fail to compile.
+///
+/// # Examples
+/// ```no_run
+/// use clap_version_flag::clap_version;
+///
+/// #[clap_version(theme = "high-contrast")]
+/// fn main() {
+///     // `myapp --version` never gets here
+/// }
+/// ```
+///
+/// An unknown theme is rejected:
+/// ```compile_fail
+/// use clap_version_flag::clap_version;
+///
+/// #[clap_version(theme = "rainbow")]
+/// fn main() {}
+/// ```
+///
+/// So are a theme that is not a string, other arguments and items that
+/// are not functions:
+/// ```compile_fail
+/// use clap_version_flag::clap_version;
+///
+/// #[clap_version(theme = classic)]
+/// fn main() {}
+/// ```
+///
+/// ```compile_fail
+/// use clap_version_flag::clap_version;
+///
+/// #[clap_version(color = "always")]
+/// fn main() {}
+/// ```
+///
+/// ```compile_fail
+/// use clap_version_flag::clap_version;
+///
+/// #[clap_version]
+/// struct App;
+/// ```
+#[proc_macro_attribute]
+pub fn clap_version(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut theme = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("theme") {
+            let name: LitStr = meta.value()?.parse()?;
+            if !THEMES.contains(&name.value().as_str()) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("unknown theme, expected one of: {}", THEMES.join(", ")),
+                ));
+            }
+            theme = Some(name.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported argument, expected `theme`"))
+        }
+    });
+    parse_macro_input!(args with parser);
+
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = parse_macro_input!(item as ItemFn);
+    let theme =
+        theme.map(|name| quote!(.with_theme(::clap_version_flag::Theme::named(#name).unwrap())));
+    quote! {
+        #(#attrs)*
+        #vis #sig {
+            ::clap_version_flag::exit_on_version_flag(
+                &::clap_version_flag::colorful_version!() #theme,
+            );
+            #block
+        }
+    }
+    .into()
+}
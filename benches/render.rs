@@ -0,0 +1,131 @@
+// Project: clap-version-flag
+// File: benches/render.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Colored rendering with and without a reused buffer
+// License: MIT
+
+//! Compares `to_colored_string` with `render_into` on a reused buffer, the
+//! pattern of a version header written before every log line.
+//!
+//! Run with `cargo bench --bench render`. Each case is timed in samples and
+//! reported as the median time per call, next to the change against the
+//! previous run, which is kept as the baseline in the target directory.
+//! The run fails when the two paths render different text, when
+//! `render_into` allocates or when it is not faster.
+//!
+//! Uses only the standard library, so it builds without network access.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use clap::ColorChoice;
+use clap_version_flag::{ColorDepth, ColorfulVersion};
+
+/// Counts every allocation of the process
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Calls per sample
+const ITERATIONS: u32 = 20_000;
+/// Samples per case; the median is reported
+const SAMPLES: usize = 15;
+
+/// Returns the median time per call of `f`, after a warm-up sample
+fn measure(mut f: impl FnMut()) -> Duration {
+    let mut sample = || {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            f();
+        }
+        start.elapsed() / ITERATIONS
+    };
+    sample();
+    let mut samples: Vec<Duration> = (0..SAMPLES).map(|_| sample()).collect();
+    samples.sort();
+    samples[SAMPLES / 2]
+}
+
+/// Where the medians of the previous run are kept
+fn baseline_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("render-baseline.txt")
+}
+
+/// Returns the median of `case` in the previous run, in nanoseconds
+fn baseline(saved: &str, case: &str) -> Option<f64> {
+    saved.lines().find_map(|line| {
+        let (name, nanos) = line.split_once(' ')?;
+        (name == case).then(|| nanos.parse().ok())?
+    })
+}
+
+fn main() {
+    let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe")
+        .with_color_choice(ColorChoice::Always)
+        .with_color_depth(ColorDepth::TrueColor);
+
+    let mut buffer = String::with_capacity(256);
+    version.render_into(&mut buffer);
+    assert_eq!(buffer, version.to_colored_string(), "the paths differ");
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    buffer.clear();
+    version.render_into(&mut buffer);
+    assert_eq!(
+        ALLOCATIONS.load(Ordering::Relaxed),
+        before,
+        "render_into allocated"
+    );
+
+    let allocating = measure(|| {
+        black_box(black_box(&version).to_colored_string());
+    });
+    let direct = measure(|| {
+        buffer.clear();
+        black_box(&version).render_into(&mut buffer);
+        black_box(&buffer);
+    });
+
+    let saved = fs::read_to_string(baseline_path()).unwrap_or_default();
+    let mut next = String::new();
+    for (case, median) in [("to_colored_string", allocating), ("render_into", direct)] {
+        let nanos = median.as_secs_f64() * 1e9;
+        let change = match baseline(&saved, case) {
+            Some(previous) => format!("{:+.1}% vs baseline", (nanos / previous - 1.0) * 100.0),
+            None => "no baseline".to_string(),
+        };
+        println!("{case:<18} {median:>10.2?}/iter  ({change})");
+        next.push_str(&format!("{case} {nanos}\n"));
+    }
+    println!(
+        "speedup            {:>9.2}x",
+        allocating.as_secs_f64() / direct.as_secs_f64()
+    );
+    if let Err(error) = fs::write(baseline_path(), next) {
+        eprintln!("baseline not saved: {error}");
+    }
+    assert!(direct < allocating, "render_into is not faster");
+}
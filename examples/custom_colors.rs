@@ -41,21 +41,28 @@ fn main() {
 
     println!("=== High Contrast (Accessibility) ===");
     let high_contrast = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
-        .with_hex_colors("#FFFFFF", "#000000", "#FFFF00", "#00FF00")
+        .with_colors("#FFFFFF", "#000000", "#FFFF00", "#00FF00")
         .unwrap();
     high_contrast.print();
     println!();
 
     println!("=== Dark Theme ===");
     let dark = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
-        .with_hex_colors("#E0E0E0", "#1A1A1A", "#FFA500", "#87CEEB")
+        .with_colors("#E0E0E0", "#1A1A1A", "#FFA500", "#87CEEB")
         .unwrap();
     dark.print();
     println!();
 
     println!("=== Brand Colors Example (GitHub-like) ===");
     let github = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
-        .with_hex_colors("#FFFFFF", "#24292E", "#0366D6", "#6F42C1")
+        .with_colors("#FFFFFF", "#24292E", "#0366D6", "#6F42C1")
         .unwrap();
     github.print();
+    println!();
+
+    println!("=== Custom Logo ===");
+    let logo = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+        .with_logo(include_str!("logo.txt"))
+        .with_logo_colors([(255, 95, 95), (255, 175, 95), (255, 255, 95), (95, 255, 95)]);
+    logo.print();
 }
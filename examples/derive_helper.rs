@@ -3,7 +3,7 @@
 //! Run with: cargo run --example derive_helper -- --version
 
 use clap::{CommandFactory, FromArgMatches, Parser};
-use clap_version_flag::{ColorfulVersionExt, colorful_version};
+use clap_version_flag::{ArgMatchesExt, ColorfulVersionExt, colorful_version};
 
 #[derive(Parser)]
 #[command(name = "my-tool")]
@@ -31,7 +31,7 @@ fn main() {
     let matches = cmd.get_matches();
 
     // Check for version flag
-    if matches.get_flag("clap_version_flag_version") {
+    if matches.version_requested() {
         version.print_and_exit();
     }
 
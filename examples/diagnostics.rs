@@ -0,0 +1,24 @@
+// Example: Diagnostics for support requests
+// Run with: cargo run --example diagnostics --features examples-extra -- -VVV
+// Run with: cargo run --example diagnostics --features examples-extra -- --bug-report
+
+use clap::Command;
+use clap_version_flag::{ColorfulVersionExt, colorful_version};
+
+fn main() {
+    let version = colorful_version!().with_config_snapshot(|| {
+        vec![
+            ("new_ui".to_string(), "on".to_string()),
+            ("api_token".to_string(), "abc123".to_string()),
+        ]
+    });
+    let matches = Command::new("diagnostics")
+        .with_verbose_colorful_version(&version)
+        .with_bug_report_flag()
+        .get_matches();
+
+    // `-V`, `-VV` and `-VVV` add detail; `--bug-report` prints Markdown
+    version.check_and_exit(&matches);
+
+    println!("run with -VVV or --bug-report");
+}
@@ -0,0 +1,18 @@
+// Example: Machine-readable version output
+// Run with: cargo run --example json_output --features examples-extra -- --version --version-format json
+
+use clap::Command;
+use clap_version_flag::{ColorfulVersionExt, colorful_version};
+
+fn main() {
+    let version = colorful_version!();
+    let matches = Command::new("json-output")
+        .with_colorful_version(&version)
+        .with_version_format_flag()
+        .get_matches();
+
+    // `--version --version-format json` prints one JSON object, ready for jq
+    version.check_and_exit(&matches);
+
+    println!("{}", version.to_json());
+}
@@ -0,0 +1,37 @@
+// Example: Dry run of an upgrade tailored to the install method
+// Run with: cargo run --example self_update_dry_run --features examples-extra -- 2.0.0
+// Run with: cargo run --example self_update_dry_run --features examples-extra -- 2.0.0 --via homebrew
+
+use clap::{Arg, Command};
+use clap_version_flag::{ColorfulVersionExt, colorful_version};
+
+fn main() {
+    let cmd = Command::new("self-update-dry-run")
+        .arg(
+            Arg::new("latest")
+                .required(true)
+                .help("Release to upgrade to"),
+        )
+        .arg(
+            Arg::new("via")
+                .long("via")
+                .value_parser(["homebrew", "apt", "cargo"])
+                .help("Install method instead of detecting it"),
+        );
+    let matches = cmd.clone().ignore_errors(true).get_matches();
+    let version = colorful_version!()
+        .with_install_method_hint(matches.get_one::<String>("via").map(String::as_str));
+
+    let matches = cmd.with_colorful_version(&version).get_matches();
+    version.check_and_exit(&matches);
+
+    let latest = matches.get_one::<String>("latest").expect("required");
+    let method = version.install_method();
+    println!("{}", version.update_message(latest, method));
+
+    // Nothing is executed: print what a self-update would do instead
+    match method.upgrade_command(version.package_name()) {
+        Some(command) => println!("dry run: would run `{command}`"),
+        None => println!("dry run: unknown install method, nothing to run"),
+    }
+}
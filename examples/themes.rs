@@ -0,0 +1,50 @@
+// Example: Themes
+// Run with: cargo run --example themes --features examples-extra -- --version
+// Run with: cargo run --example themes --features examples-extra -- --theme ocean --version
+
+use clap::{Arg, Command};
+use clap_version_flag::{ColorfulVersionExt, Role, Theme, colorful_version};
+
+/// Builds one of the example themes
+fn theme(name: &str) -> Theme {
+    match name {
+        "ocean" => Theme::new()
+            .primary((0, 180, 216))
+            .on_primary((3, 4, 94))
+            .accent((144, 224, 239))
+            .secondary((202, 240, 248)),
+        "mono" => Theme::new()
+            .primary((255, 255, 255))
+            .on_primary((0, 0, 0))
+            .accent((200, 200, 200))
+            .secondary((150, 150, 150)),
+        _ => Theme::default(),
+    }
+}
+
+fn main() {
+    let cmd = Command::new("themes").arg(
+        Arg::new("theme")
+            .long("theme")
+            .value_parser(["default", "ocean", "mono"])
+            .default_value("default")
+            .help("Theme of the version output"),
+    );
+
+    // The theme has to be known before the version flag is answered, so
+    // peek at it with a first, lenient parse
+    let name = cmd
+        .clone()
+        .ignore_errors(true)
+        .get_matches()
+        .get_one::<String>("theme")
+        .cloned()
+        .unwrap_or_default();
+    let version = colorful_version!().with_theme(theme(&name));
+
+    let matches = cmd.with_colorful_version(&version).get_matches();
+    version.check_and_exit(&matches);
+
+    let (r, g, b) = version.theme().role(Role::Primary);
+    println!("theme {name}: primary #{r:02X}{g:02X}{b:02X}");
+}
@@ -0,0 +1,57 @@
+// Example: Update check against a local stub registry
+// Run with: cargo run --example update_check --features examples-extra -- --version
+
+use clap::Command;
+use clap_version_flag::{ColorfulVersionExt, UpdateSource, colorful_version};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::time::Duration;
+
+/// Latest release announced by the stub registry
+const LATEST: &str = "99.0.0";
+
+/// Serves a crates.io-style response for every request on a background thread
+fn spawn_stub_registry() -> std::io::Result<String> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                line.clear();
+            }
+            let body = format!(r#"{{"crate":{{"max_stable_version":"{LATEST}"}}}}"#);
+            let _ = write!(
+                &stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+    Ok(format!("http://{address}/api/v1/crates"))
+}
+
+fn main() -> std::io::Result<()> {
+    let registry = spawn_stub_registry()?;
+    let cache = std::env::temp_dir().join(format!("update-check-example-{}", std::process::id()));
+
+    let version = colorful_version!()
+        .with_update_check(UpdateSource::Registry(registry))
+        .with_update_timeout(Duration::from_secs(5))
+        .with_update_cache(&cache);
+    let matches = Command::new("update-check")
+        .with_colorful_version(&version)
+        .get_matches();
+
+    // `--version` appends "(update available: 99.0.0)"
+    let printed = version.check(&matches);
+    let _ = std::fs::remove_file(&cache);
+    if !printed {
+        println!(
+            "latest release: {}",
+            version.latest_version().unwrap_or_default()
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+/*
+ * Project: clap-version-flag
+ * File: include/clap_version_flag.h
+ * Author: Hadi Cahyadi <cumulus13@gmail.com>
+ * Date: 2026-10-14
+ * Description: C interface of the `ffi` feature
+ * License: MIT
+ */
+
+#ifndef CLAP_VERSION_FLAG_H
+#define CLAP_VERSION_FLAG_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Every string is NUL-terminated UTF-8 owned by the library: it stays valid
+ * until the library is unloaded and must not be freed. Functions return
+ * NULL until the Rust side has called `clap_version_flag::ffi::register`.
+ */
+
+/* "{name} v{version} by {author}" without escape codes */
+const char *cvf_version_plain(void);
+
+/* The single-line JSON object of `ColorfulVersion::to_json` */
+const char *cvf_version_json(void);
+
+/* The `schema_version` of the JSON object */
+uint32_t cvf_schema_version(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* CLAP_VERSION_FLAG_H */
@@ -0,0 +1,269 @@
+// Project: clap-version-flag
+// File: src/accessibility.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: OS accessibility settings (high contrast, reduced motion)
+// License: MIT
+
+//! OS accessibility settings.
+//!
+//! On Windows and macOS the high-contrast and reduced-motion settings of the
+//! operating system are detected once per process, when a version is first
+//! printed. Renderings into strings, such as a help header, never run the
+//! lookup themselves but use its result once known. High contrast switches the
+//! output to [`Theme::high_contrast`]; reduced motion is reported through
+//! [`ColorfulVersion::animations_enabled`] for animated output to honor.
+//! [`ColorfulVersion::with_accessibility`] overrides the detection.
+
+use crate::{ColorfulVersion, Colors, Theme};
+use std::sync::OnceLock;
+
+/// The operating system's settings, once detected
+static DETECTED: OnceLock<Accessibility> = OnceLock::new();
+
+/// Accessibility preferences that affect the version output
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{Accessibility, ColorfulVersion, Role, Theme};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_accessibility(Accessibility::new().high_contrast(true).reduced_motion(true));
+///
+/// assert_eq!(version.theme(), Theme::high_contrast());
+/// assert!(!version.animations_enabled());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Accessibility {
+    high_contrast: bool,
+    reduced_motion: bool,
+}
+
+impl Accessibility {
+    /// Creates preferences with every accommodation turned off
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether the high-contrast theme is used
+    #[must_use]
+    pub fn high_contrast(mut self, enabled: bool) -> Self {
+        self.high_contrast = enabled;
+        self
+    }
+
+    /// Sets whether animations are disabled
+    #[must_use]
+    pub fn reduced_motion(mut self, enabled: bool) -> Self {
+        self.reduced_motion = enabled;
+        self
+    }
+
+    /// Returns whether the high-contrast theme is used
+    #[must_use]
+    pub fn is_high_contrast(&self) -> bool {
+        self.high_contrast
+    }
+
+    /// Returns whether animations are disabled
+    #[must_use]
+    pub fn is_reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Returns the operating system's settings, detected once per process
+    ///
+    /// Reads the Windows registry or the macOS `universalaccess` defaults;
+    /// other platforms (and failed lookups) report no accommodations.
+    #[must_use]
+    pub fn detect() -> Self {
+        *DETECTED.get_or_init(detect_os)
+    }
+
+    /// Returns the operating system's settings if already detected
+    fn detected() -> Option<Self> {
+        DETECTED.get().copied()
+    }
+}
+
+#[cfg(windows)]
+fn detect_os() -> Accessibility {
+    let high_contrast = query(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Control Panel\Accessibility\HighContrast",
+            "/v",
+            "Flags",
+        ],
+    )
+    .and_then(|output| registry_value(&output, "Flags"))
+    .and_then(|flags| flags.parse::<u32>().ok())
+    // HCF_HIGHCONTRASTON
+    .is_some_and(|flags| flags & 1 == 1);
+    let reduced_motion = query(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Control Panel\Desktop\WindowMetrics",
+            "/v",
+            "MinAnimate",
+        ],
+    )
+    .and_then(|output| registry_value(&output, "MinAnimate"))
+    .is_some_and(|value| value == "0");
+
+    Accessibility {
+        high_contrast,
+        reduced_motion,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_os() -> Accessibility {
+    let enabled = |key: &str| {
+        query("defaults", &["read", "com.apple.universalaccess", key])
+            .is_some_and(|output| output.trim() == "1")
+    };
+    Accessibility {
+        high_contrast: enabled("increaseContrast"),
+        reduced_motion: enabled("reduceMotion"),
+    }
+}
+
+#[cfg(not(any(windows, target_os = "macos")))]
+fn detect_os() -> Accessibility {
+    Accessibility::default()
+}
+
+/// Runs a settings lookup command and returns its stdout on success
+#[cfg(any(windows, target_os = "macos"))]
+fn query(program: &str, args: &[&str]) -> Option<String> {
+    let output = std::process::Command::new(program)
+        .args(args)
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Extracts a value from `reg query` output (`    Name    REG_SZ    value`)
+#[cfg_attr(not(windows), allow(dead_code))]
+fn registry_value(output: &str, name: &str) -> Option<String> {
+    output.lines().find_map(|line| {
+        let mut parts = line.split_whitespace();
+        (parts.next()? == name).then_some(())?;
+        let _kind = parts.next()?;
+        Some(parts.collect::<Vec<_>>().join(" "))
+    })
+}
+
+impl Theme {
+    /// A maximum-contrast theme: white on black with pure status colors
+    #[must_use]
+    pub fn high_contrast() -> Self {
+        Self::new()
+            .primary((0, 0, 0))
+            .on_primary((255, 255, 255))
+            .secondary((255, 255, 255))
+            .accent((255, 255, 0))
+            .success((0, 255, 0))
+            .warning((255, 255, 0))
+            .error((255, 0, 0))
+    }
+}
+
+impl ColorfulVersion {
+    /// Overrides the detected OS accessibility settings
+    #[must_use]
+    pub fn with_accessibility(mut self, accessibility: Accessibility) -> Self {
+        self.accessibility = Some(accessibility);
+        self
+    }
+
+    /// Returns the accessibility settings in effect
+    ///
+    /// Detects the OS settings with [`Accessibility::detect`] unless
+    /// overridden.
+    #[must_use]
+    pub fn accessibility(&self) -> Accessibility {
+        self.accessibility.unwrap_or_else(Accessibility::detect)
+    }
+
+    /// Returns whether animated output may animate (no reduced motion)
+    #[must_use]
+    pub fn animations_enabled(&self) -> bool {
+        !self.accessibility().reduced_motion
+    }
+
+    /// Detects the OS settings ahead of printing, unless overridden
+    ///
+    /// Called by the methods that print, so the lookup stays out of the
+    /// rendering itself.
+    pub(crate) fn detect_accessibility(&self) {
+        if self.accessibility.is_none() {
+            let _ = Accessibility::detect();
+        }
+    }
+
+    /// Returns the settings rendering uses, without running the detection
+    fn rendered_accessibility(&self) -> Accessibility {
+        self.accessibility
+            .or_else(Accessibility::detected)
+            .unwrap_or_default()
+    }
+
+    /// Returns the colors to render with: high contrast when requested,
+    /// then the environment overrides, then the minimum contrast
+    pub(crate) fn palette(&self) -> Colors {
+        self.apply_min_contrast(self.apply_env_overrides(self.selected_colors()))
+    }
+
+    /// Returns the high-contrast colors when requested, else the configured ones
+    pub(crate) fn selected_colors(&self) -> Colors {
+        if self.rendered_accessibility().high_contrast {
+            Colors::from(Theme::high_contrast()).with_unthemed_of(&self.colors)
+        } else {
+            self.base_colors()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_value() {
+        let output = "\r\nHKEY_CURRENT_USER\\Control Panel\\Accessibility\\HighContrast\r\n    Flags    REG_SZ    127\r\n";
+        assert_eq!(registry_value(output, "Flags").as_deref(), Some("127"));
+        assert_eq!(registry_value(output, "MinAnimate"), None);
+    }
+
+    #[test]
+    fn test_override_controls_palette() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane").with_rgb_colors(
+            (1, 1, 1),
+            (2, 2, 2),
+            (3, 3, 3),
+            (4, 4, 4),
+        );
+        let plain = version.clone().with_accessibility(Accessibility::new());
+        assert_eq!(plain.palette().name_bg, (2, 2, 2));
+        assert!(plain.animations_enabled());
+
+        let contrast = version.with_accessibility(Accessibility::new().high_contrast(true));
+        assert_eq!(contrast.palette().name_bg, (0, 0, 0));
+        assert_eq!(
+            contrast.colored_line(crate::ColorDepth::TrueColor),
+            "\x1b[38;2;255;255;255;48;2;0;0;0mapp\x1b[0m\x1b[38;2;255;255;0m v1.0\x1b[0m\x1b[38;2;255;255;255m by Jane\x1b[0m"
+        );
+        // the configured colors survive for when the override is lifted
+        assert_eq!(contrast.colors.name_bg, (2, 2, 2));
+    }
+}
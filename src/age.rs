@@ -0,0 +1,283 @@
+// Project: clap-version-flag
+// File: src/age.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Build age rendering with escalating colors
+// License: MIT
+
+//! Build age.
+//!
+//! Verbose output can show how old the running build is ("built: 42 days
+//! ago"), colored green, yellow or red once the age passes the configured
+//! [`AgeThresholds`], nudging users of stale builds to update.
+
+use crate::{ColorfulVersion, Field, Role, Verbosity};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const DAY: u64 = 24 * 60 * 60;
+
+/// Ages at which the build age turns yellow and red
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::AgeThresholds;
+/// use std::time::Duration;
+///
+/// const WEEK: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// let thresholds = AgeThresholds::new(WEEK, 4 * WEEK);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AgeThresholds {
+    warn_after: Duration,
+    stale_after: Duration,
+}
+
+impl Default for AgeThresholds {
+    /// Yellow after 30 days, red after 90 days
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl AgeThresholds {
+    /// The default thresholds, usable in `const` contexts
+    pub(crate) const DEFAULT: Self =
+        Self::new(Duration::from_secs(30 * DAY), Duration::from_secs(90 * DAY));
+
+    /// Creates thresholds turning yellow after `warn_after` and red after `stale_after`
+    #[must_use]
+    pub const fn new(warn_after: Duration, stale_after: Duration) -> Self {
+        Self {
+            warn_after,
+            stale_after,
+        }
+    }
+
+    /// Returns the color role for a build of the given age
+    fn role(&self, age: Duration) -> Role {
+        if age >= self.stale_after {
+            Role::Error
+        } else if age >= self.warn_after {
+            Role::Warning
+        } else {
+            Role::Success
+        }
+    }
+}
+
+impl ColorfulVersion {
+    /// Sets when the binary was built, enabling the "built: N days ago" line
+    ///
+    /// [`ColorfulVersion::from_build_info`] sets it from the embedded build
+    /// date when that parses as Unix seconds or an RFC 3339 / ISO 8601 date.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// let built = SystemTime::now() - Duration::from_secs(3 * 24 * 60 * 60);
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_build_time(built);
+    /// assert!(version.as_verbose_plain_string().contains("built: 3 days ago"));
+    /// ```
+    #[must_use]
+    pub fn with_build_time(mut self, built: SystemTime) -> Self {
+        self.build_time = Some(built);
+        self
+    }
+
+    /// Sets the ages at which the build age turns yellow and red
+    #[must_use]
+    pub fn with_age_thresholds(mut self, thresholds: AgeThresholds) -> Self {
+        self.age_thresholds = thresholds;
+        self
+    }
+
+    /// Returns how long ago the binary was built, if the build time is known
+    #[must_use]
+    pub fn build_age(&self) -> Option<Duration> {
+        let built = self.build_time?;
+        // A build time in the future (clock skew) counts as brand new
+        Some(SystemTime::now().duration_since(built).unwrap_or_default())
+    }
+
+    /// Returns the "built" detail line for a build of the given age
+    pub(crate) fn age_field(&self, age: Duration) -> Field {
+        Field {
+            key: "built".into(),
+            value: describe_age(age).into(),
+            color: Some(self.role(self.age_thresholds.role(age))),
+            level: Verbosity::Verbose,
+        }
+    }
+}
+
+/// Describes an age as "just now", "5 minutes ago", "1 day ago", ...
+fn describe_age(age: Duration) -> String {
+    let seconds = age.as_secs();
+    let (count, unit) = match seconds {
+        0..60 => return "just now".to_string(),
+        60..3600 => (seconds / 60, "minute"),
+        3600..DAY => (seconds / 3600, "hour"),
+        _ => (seconds / DAY, "day"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{count} {unit}{plural} ago")
+}
+
+/// Parses Unix seconds or an RFC 3339 / ISO 8601 date or date-time
+///
+/// Accepted: `1791936000`, `2026-10-14`, `2026-10-14T10:00:00Z`,
+/// `2026-10-14 12:00:00+02:00`. A date-time without offset is taken as UTC.
+pub(crate) fn parse_timestamp(value: &str) -> Option<SystemTime> {
+    let value = value.trim();
+    if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+        return Some(UNIX_EPOCH + Duration::from_secs(value.parse().ok()?));
+    }
+
+    let (date, time) = match value.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut seconds = days_from_civil(year, month, day) * DAY as i64;
+    if let Some(time) = time {
+        let (clock, offset) = split_offset(time)?;
+        let mut fields = clock.split(':');
+        let hours: i64 = fields.next()?.parse().ok()?;
+        let minutes: i64 = fields.next()?.parse().ok()?;
+        let secs: i64 = match fields.next() {
+            Some(secs) => secs.split('.').next()?.parse().ok()?,
+            None => 0,
+        };
+        seconds += hours * 3600 + minutes * 60 + secs - offset;
+    }
+    u64::try_from(seconds)
+        .ok()
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+/// Splits a `Z` or `±HH:MM` suffix off a time, returning the offset in seconds
+fn split_offset(time: &str) -> Option<(&str, i64)> {
+    if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        return Some((clock, 0));
+    }
+    let Some(index) = time.rfind(['+', '-']) else {
+        return Some((time, 0));
+    };
+    let (clock, offset) = time.split_at(index);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let digits: String = offset[1..].chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 {
+        return None;
+    }
+    let hours: i64 = digits[..2].parse().ok()?;
+    let minutes: i64 = digits[2..].parse().ok()?;
+    Some((clock, sign * (hours * 3600 + minutes * 60)))
+}
+
+/// Formats the UTC date of `time` as `YYYY-MM-DD`
+pub(crate) fn format_date(time: SystemTime) -> String {
+    let days = match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => (since.as_secs() / DAY) as i64,
+        Err(before) => -(before.duration().as_secs().div_ceil(DAY) as i64),
+    };
+    let (year, month, day) = civil_from_days(days);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unix(seconds: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(seconds)
+    }
+
+    #[test]
+    fn test_parse_timestamp() {
+        assert_eq!(parse_timestamp("1791936000"), Some(unix(1_791_936_000)));
+        assert_eq!(parse_timestamp("2026-10-14"), Some(unix(1_791_936_000)));
+        assert_eq!(parse_timestamp("2000-02-29"), Some(unix(951_782_400)));
+        assert_eq!(
+            parse_timestamp("2026-10-14T10:00:00Z"),
+            Some(unix(1_791_972_000))
+        );
+        assert_eq!(
+            parse_timestamp("2026-10-14 12:00:00.123+02:00"),
+            Some(unix(1_791_972_000))
+        );
+        assert_eq!(parse_timestamp("yesterday"), None);
+        assert_eq!(parse_timestamp("2026-13-01"), None);
+    }
+
+    #[test]
+    fn test_format_date_round_trip() {
+        for date in ["1970-01-01", "2000-02-29", "2026-10-14", "2100-12-31"] {
+            assert_eq!(format_date(parse_timestamp(date).unwrap()), date);
+        }
+        assert_eq!(format_date(unix(1_791_972_000)), "2026-10-14");
+    }
+
+    #[test]
+    fn test_describe_age() {
+        assert_eq!(describe_age(Duration::from_secs(5)), "just now");
+        assert_eq!(describe_age(Duration::from_secs(60)), "1 minute ago");
+        assert_eq!(describe_age(Duration::from_secs(7200)), "2 hours ago");
+        assert_eq!(describe_age(Duration::from_secs(42 * DAY)), "42 days ago");
+    }
+
+    #[test]
+    fn test_threshold_colors() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_age_thresholds(
+            AgeThresholds::new(Duration::from_secs(DAY), Duration::from_secs(7 * DAY)),
+        );
+        assert_eq!(version.age_field(Duration::ZERO).color, Some((0, 255, 0)));
+        assert_eq!(
+            version.age_field(Duration::from_secs(2 * DAY)).color,
+            Some((255, 255, 0))
+        );
+        assert_eq!(
+            version.age_field(Duration::from_secs(7 * DAY)).color,
+            Some((255, 0, 0))
+        );
+    }
+}
@@ -0,0 +1,200 @@
+// Project: clap-version-flag
+// File: src/alignment.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Centering or right-aligning the output in the terminal
+// License: MIT
+
+//! Output alignment.
+//!
+//! [`ColorfulVersion::with_alignment`] centers or right-aligns every line
+//! of the printed output within the terminal. Lines are measured in
+//! terminal columns with the escape codes stripped, so colors, hyperlinks
+//! and wide characters do not shift them. The width is the one set with
+//! [`ColorfulVersion::with_terminal_width`]. Output printed to stdout
+//! without one uses the width of the terminal stdout writes to (queried
+//! with `ioctl(TIOCGWINSZ)` on Unix), else `COLUMNS`. Shells such as bash
+//! keep `COLUMNS` to themselves rather than exporting it, so it only
+//! matters where it is set explicitly. Output written elsewhere, e.g. with
+//! [`ColorfulVersion::write_to`], knows no terminal and is aligned only in
+//! a width that was set. Without a width the output is left as is, which
+//! keeps pipes and log files free of padding.
+
+use std::borrow::Cow;
+
+use crate::{Align, ColorfulVersion, color, grapheme};
+
+impl ColorfulVersion {
+    /// Aligns the printed output within the terminal width
+    ///
+    /// Applies to [`print`](Self::print), [`check`](Self::check) and the
+    /// other output printed to stdout, in the width of its terminal, and to
+    /// [`write_to`](Self::write_to) and the other full renderings in a width
+    /// set with [`with_terminal_width`](Self::with_terminal_width) only.
+    /// [`as_plain_string`](Self::as_plain_string) and
+    /// [`to_colored_string`](Self::to_colored_string) stay unpadded so they
+    /// can be embedded. Lines wider than the terminal are left alone.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{Align, ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("app", "1.0", "Jo")
+    ///     .with_alignment(Align::Center)
+    ///     .with_terminal_width(20);
+    /// assert_eq!(version.render_plain(Verbosity::Normal), "   app v1.0 by Jo");
+    /// ```
+    #[must_use]
+    pub fn with_alignment(mut self, align: Align) -> Self {
+        self.alignment = align;
+        self
+    }
+
+    /// Sets the terminal width to align in, instead of querying the terminal
+    #[must_use]
+    pub fn with_terminal_width(mut self, columns: usize) -> Self {
+        self.terminal_width = Some(columns);
+        self
+    }
+
+    /// Returns the alignment set with [`with_alignment`](Self::with_alignment)
+    #[must_use]
+    pub fn alignment(&self) -> Align {
+        self.alignment
+    }
+
+    /// Returns the version to print to stdout, aligned in its terminal
+    ///
+    /// Without a width of its own, the version takes that of the terminal
+    /// stdout writes to, else `COLUMNS`.
+    pub(crate) fn for_stdout(&self) -> Cow<'_, Self> {
+        if self.alignment == Align::Left || self.terminal_width.is_some() {
+            return Cow::Borrowed(self);
+        }
+        let columns = stdout_columns().or_else(|| {
+            std::env::var("COLUMNS")
+                .ok()
+                .and_then(|columns| columns.trim().parse().ok())
+                .filter(|&columns| columns > 0)
+        });
+        match columns {
+            Some(columns) => Cow::Owned(self.clone().with_terminal_width(columns)),
+            None => Cow::Borrowed(self),
+        }
+    }
+
+    /// Pads each line of `text` on the left to apply the alignment
+    pub(crate) fn aligned(&self, text: String) -> String {
+        if self.alignment == Align::Left {
+            return text;
+        }
+        let Some(columns) = self.terminal_width else {
+            return text;
+        };
+        text.split('\n')
+            .map(|line| {
+                let free = columns.saturating_sub(grapheme::width(&color::strip_escapes(line)));
+                let padding = match self.alignment {
+                    Align::Center => free / 2,
+                    _ => free,
+                };
+                format!("{}{line}", " ".repeat(padding))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Returns the width of the terminal stdout writes to, if it is one
+#[cfg(unix)]
+fn stdout_columns() -> Option<usize> {
+    terminal_columns_of(libc::STDOUT_FILENO)
+}
+
+/// Returns the width of the terminal `fd` refers to, if it is one
+#[cfg(unix)]
+fn terminal_columns_of(fd: libc::c_int) -> Option<usize> {
+    let mut size = libc::winsize {
+        ws_row: 0,
+        ws_col: 0,
+        ws_xpixel: 0,
+        ws_ypixel: 0,
+    };
+    // SAFETY: TIOCGWINSZ fills the `winsize` behind the pointer, which
+    // outlives the call; on a pipe or file the call fails and writes nothing
+    let result = unsafe { libc::ioctl(fd, libc::TIOCGWINSZ, &mut size) };
+    (result == 0 && size.ws_col > 0).then_some(usize::from(size.ws_col))
+}
+
+#[cfg(not(unix))]
+fn stdout_columns() -> Option<usize> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorDepth, Verbosity};
+
+    #[test]
+    fn test_measures_visible_width() {
+        let version = ColorfulVersion::new("应用", "1.0", "Jo")
+            .with_alignment(Align::Right)
+            .with_terminal_width(20);
+        let colored = version.colored_line(ColorDepth::TrueColor);
+        let aligned = version.aligned(colored.clone());
+        assert_eq!(aligned, format!("{}{colored}", " ".repeat(5)));
+        assert_eq!(
+            version.aligned("a\nbb".to_string()),
+            format!("{}a\n{}bb", " ".repeat(19), " ".repeat(18))
+        );
+        let narrow = version.clone().with_terminal_width(4);
+        assert_eq!(
+            narrow.render_plain(Verbosity::Normal),
+            version.as_plain_string()
+        );
+        assert_eq!(
+            version.with_alignment(Align::Left).aligned("a".to_string()),
+            "a"
+        );
+    }
+
+    #[test]
+    fn test_written_output_needs_a_width() {
+        // only the stdout paths look up the terminal
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_alignment(Align::Center);
+        let mut out = Vec::new();
+        version.write_to(&mut out).unwrap();
+        assert_eq!(out, b"app v1.0 by Jo\n");
+
+        let sized = version.with_terminal_width(20);
+        assert!(matches!(sized.for_stdout(), Cow::Borrowed(_)));
+        let mut out = Vec::new();
+        sized.write_to(&mut out).unwrap();
+        assert_eq!(out, b"   app v1.0 by Jo\n");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_width_queried_from_tty() {
+        use std::os::fd::AsRawFd;
+
+        let (reader, _writer) = std::io::pipe().unwrap();
+        assert_eq!(terminal_columns_of(reader.as_raw_fd()), None);
+
+        // SAFETY: a pseudo-terminal pair opened, sized and closed here
+        unsafe {
+            let primary = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+            assert!(primary >= 0);
+            let size = libc::winsize {
+                ws_row: 24,
+                ws_col: 132,
+                ws_xpixel: 0,
+                ws_ypixel: 0,
+            };
+            assert_eq!(libc::ioctl(primary, libc::TIOCSWINSZ, &size), 0);
+            assert_eq!(terminal_columns_of(primary), Some(132));
+            libc::close(primary);
+        }
+    }
+}
@@ -0,0 +1,363 @@
+// Project: clap-version-flag
+// File: src/authors.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Multiple authors parsed from CARGO_PKG_AUTHORS
+// License: MIT
+
+//! Multiple authors.
+//!
+//! Cargo joins the `authors` of a manifest with colons, so
+//! `CARGO_PKG_AUTHORS` reads `Jane Doe <jane@example.com>:John Roe`.
+//! [`colorful_version!`](crate::colorful_version) splits that list into
+//! [`Author`]s, and [`AuthorDisplay`] picks how they appear after "by":
+//!
+//! ```text
+//! myapp v1.0.0 by Jane Doe <jane@example.com>, John Roe   (Joined)
+//! myapp v1.0.0 by Jane Doe <jane@example.com>             (First)
+//! myapp v1.0.0 by Jane Doe <jane@example.com>             (PerLine)
+//!                 John Roe
+//! ```
+//!
+//! [`ColorfulVersion::with_author_emails`] strips the email addresses.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{ColorDepth, ColorfulVersion, grapheme};
+
+/// One package author, as written in `Cargo.toml`
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::Author;
+///
+/// let author = Author::parse("Jane Doe <jane@example.com>");
+/// assert_eq!(author.name(), "Jane Doe");
+/// assert_eq!(author.email(), Some("jane@example.com"));
+/// assert_eq!(author.to_string(), "Jane Doe <jane@example.com>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Author {
+    name: Cow<'static, str>,
+    email: Option<Cow<'static, str>>,
+}
+
+impl Author {
+    /// Creates an author without an email address
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: Cow::Owned(name.into()),
+            email: None,
+        }
+    }
+
+    /// Sets the email address
+    #[must_use]
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(Cow::Owned(email.into()));
+        self
+    }
+
+    /// Parses `Name <email>`; text without a trailing `<...>` is all name
+    #[must_use]
+    pub fn parse(text: &str) -> Self {
+        let (name, email) = split(text);
+        Self {
+            name: Cow::Owned(name.to_owned()),
+            email: email.map(|email| Cow::Owned(email.to_owned())),
+        }
+    }
+
+    /// Parses like [`parse`](Self::parse), borrowing from `text` instead of copying
+    #[must_use]
+    pub fn parse_static(text: &'static str) -> Self {
+        let (name, email) = split(text);
+        Self {
+            name: Cow::Borrowed(name),
+            email: email.map(Cow::Borrowed),
+        }
+    }
+
+    /// Parses a colon-separated list such as `CARGO_PKG_AUTHORS`
+    ///
+    /// Empty entries are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Author;
+    ///
+    /// let authors = Author::parse_list("Jane <jane@example.com>:John");
+    /// assert_eq!(authors, [Author::new("Jane").with_email("jane@example.com"), Author::new("John")]);
+    /// ```
+    #[must_use]
+    pub fn parse_list(authors: &str) -> Vec<Self> {
+        authors
+            .split(':')
+            .filter(|author| !author.trim().is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+
+    /// Parses like [`parse_list`](Self::parse_list), borrowing from `authors`
+    ///
+    /// The macros use this for `env!("CARGO_PKG_AUTHORS")`, so the names are
+    /// not copied.
+    #[must_use]
+    pub fn parse_static_list(authors: &'static str) -> Vec<Self> {
+        authors
+            .split(':')
+            .filter(|author| !author.trim().is_empty())
+            .map(Self::parse_static)
+            .collect()
+    }
+
+    /// Returns the author's name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the author's email address, if any
+    #[must_use]
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+}
+
+/// Splits `Name <email>` into the trimmed name and the email, if any
+fn split(text: &str) -> (&str, Option<&str>) {
+    let text = text.trim();
+    if let Some(rest) = text.strip_suffix('>')
+        && let Some((name, email)) = rest.rsplit_once('<')
+    {
+        let email = email.trim();
+        return (name.trim(), (!email.is_empty()).then_some(email));
+    }
+    (text, None)
+}
+
+impl fmt::Display for Author {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.email {
+            Some(email) => write!(f, "{} <{email}>", self.name),
+            None => f.write_str(&self.name),
+        }
+    }
+}
+
+impl From<&str> for Author {
+    fn from(text: &str) -> Self {
+        Self::parse(text)
+    }
+}
+
+impl From<String> for Author {
+    fn from(text: String) -> Self {
+        Self::parse(&text)
+    }
+}
+
+/// How several authors appear after "by"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AuthorDisplay {
+    /// Only the first author
+    First,
+    /// All authors joined with ", " (default)
+    #[default]
+    Joined,
+    /// The first author on the version line, the rest on lines below it
+    PerLine,
+}
+
+impl ColorfulVersion {
+    /// Sets the authors, replacing the one given to [`ColorfulVersion::new`]
+    ///
+    /// Strings are parsed with [`Author::parse`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "")
+    ///     .with_authors(["Jane <jane@example.com>", "John"]);
+    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0 by Jane <jane@example.com>, John");
+    /// ```
+    #[must_use]
+    pub fn with_authors(mut self, authors: impl IntoIterator<Item = impl Into<Author>>) -> Self {
+        self.authors = authors.into_iter().map(Into::into).collect();
+        self.refresh_author();
+        self
+    }
+
+    /// Sets how several authors are shown
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{AuthorDisplay, ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "")
+    ///     .with_authors(["Jane", "John"])
+    ///     .with_author_display(AuthorDisplay::PerLine);
+    /// assert_eq!(
+    ///     version.render_plain(Verbosity::Normal),
+    ///     "myapp v1.0.0 by Jane\n                John"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_author_display(mut self, display: AuthorDisplay) -> Self {
+        self.author_display = display;
+        self.refresh_author();
+        self
+    }
+
+    /// Shows (`true`, the default) or strips the authors' email addresses
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "Jane <jane@example.com>")
+    ///     .with_author_emails(false);
+    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0 by Jane");
+    /// ```
+    #[must_use]
+    pub fn with_author_emails(mut self, show: bool) -> Self {
+        self.author_emails = show;
+        self.refresh_author();
+        self
+    }
+
+    /// Returns the parsed authors
+    #[must_use]
+    pub fn authors(&self) -> &[Author] {
+        &self.authors
+    }
+
+    /// Renders one author with or without the email address
+    fn author_label(&self, author: &Author) -> String {
+        if self.author_emails {
+            author.to_string()
+        } else {
+            author.name.to_string()
+        }
+    }
+
+    /// Returns whether `text` is the label of `author`, without building it
+    fn shows(&self, author: &Author, text: &str) -> bool {
+        match (&author.email, self.author_emails) {
+            (Some(email), true) => text
+                .strip_prefix(author.name.as_ref())
+                .and_then(|rest| rest.strip_prefix(" <"))
+                .and_then(|rest| rest.strip_suffix('>'))
+                .is_some_and(|rest| rest == email),
+            _ => text == author.name,
+        }
+    }
+
+    /// Recomputes the author segment shown on the version line
+    fn refresh_author(&mut self) {
+        if let [only] = self.authors.as_slice()
+            && self.shows(only, &self.author)
+        {
+            return;
+        }
+        self.author = Cow::Owned(match self.author_display {
+            AuthorDisplay::Joined => self
+                .authors
+                .iter()
+                .map(|author| self.author_label(author))
+                .collect::<Vec<_>>()
+                .join(", "),
+            AuthorDisplay::First | AuthorDisplay::PerLine => self
+                .authors
+                .first()
+                .map(|author| self.author_label(author))
+                .unwrap_or_default(),
+        });
+    }
+
+    /// Appends the remaining authors below the version line in
+    /// [`AuthorDisplay::PerLine`] mode, aligned under the first one
+    pub(crate) fn push_author_lines(&self, out: &mut String, depth: Option<ColorDepth>) {
+        if self.hide_author
+            || self.author_display != AuthorDisplay::PerLine
+            || self.authors.len() < 2
+        {
+            return;
+        }
+        let headline = self.as_plain_string();
+        let indent = match headline.trim_end().strip_suffix(self.author.as_ref()) {
+            Some(prefix) => grapheme::width(prefix),
+            None => 0,
+        };
+        let padding = " ".repeat(indent);
+        for author in &self.authors[1..] {
+            let label = self.author_label(author);
+            out.push('\n');
+            out.push_str(&padding);
+            match depth {
+                Some(depth) => out.push_str(&self.paint_segment(
+                    &label,
+                    &self.styles.author,
+                    self.palette().author_color,
+                    self.author_background(),
+                    depth,
+                )),
+                None => out.push_str(&label),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildInfo;
+
+    #[test]
+    fn test_parse_variants() {
+        assert_eq!(Author::parse("Jo"), Author::new("Jo"));
+        assert_eq!(Author::parse(" Jo <> "), Author::new("Jo"));
+        assert_eq!(
+            Author::parse("Jo <jo@x.io>"),
+            Author::new("Jo").with_email("jo@x.io")
+        );
+        assert!(Author::parse_list("").is_empty());
+        assert_eq!(Author::parse_list("a::b").len(), 2);
+    }
+
+    #[test]
+    fn test_new_keeps_single_author() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo <jo@x.io>");
+        assert_eq!(version.authors(), [Author::new("Jo").with_email("jo@x.io")]);
+        assert_eq!(version.as_plain_string(), "app v1.0 by Jo <jo@x.io>");
+    }
+
+    #[test]
+    fn test_first_and_stripped() {
+        let version = ColorfulVersion::new("app", "1.0", "")
+            .with_authors(["A <a@x.io>", "B <b@x.io>"])
+            .with_author_display(AuthorDisplay::First)
+            .with_author_emails(false);
+        assert_eq!(version.as_plain_string(), "app v1.0 by A");
+    }
+
+    #[test]
+    fn test_per_line_verbose_keeps_fields_below() {
+        let version = ColorfulVersion::new("app", "1.0", "")
+            .with_authors(["A", "B"])
+            .with_author_display(AuthorDisplay::PerLine);
+        let verbose = version.as_verbose_plain_string();
+        assert!(verbose.starts_with("app v1.0 by A\n            B\n"));
+    }
+
+    #[test]
+    fn test_build_info_authors_split() {
+        const INFO: BuildInfo = BuildInfo::new("app", "1.0", "A <a@x.io>:B");
+        let version = ColorfulVersion::from_build_info(&INFO);
+        assert_eq!(version.authors().len(), 2);
+        assert_eq!(version.as_plain_string(), "app v1.0 by A <a@x.io>, B");
+    }
+}
@@ -0,0 +1,311 @@
+// Project: clap-version-flag
+// File: src/background.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Light/dark terminal background detection and adaptive themes
+// License: MIT
+
+//! Terminal background detection.
+//!
+//! The default colors assume a dark terminal. [`ColorfulVersion::with_adaptive_theme`]
+//! picks one of two themes instead, by the background the terminal reports:
+//!
+//! 1. `COLORFGBG` (set by rxvt, Konsole and others), e.g. `15;0` for white
+//!    on black;
+//! 2. otherwise, when stdin and stdout are both terminals on Unix, an OSC 11
+//!    query (`ESC ] 11 ; ? BEL`) answered with the background color, waiting
+//!    at most 100 ms. The terminal is in raw mode only for the query and is
+//!    restored even if it panics.
+//!
+//! Detection runs once per process. Unknown backgrounds count as dark, so the
+//! dark theme is the fallback.
+
+use std::sync::OnceLock;
+
+use crate::color::Rgb;
+use crate::{ColorfulVersion, Colors, Theme};
+
+/// Brightness of the terminal background
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Background {
+    /// Light background, e.g. black on white
+    Light,
+    /// Dark background, e.g. white on black
+    #[default]
+    Dark,
+}
+
+impl Background {
+    /// Detects the background of the current terminal, once per process
+    ///
+    /// Returns `None` when neither `COLORFGBG` nor an OSC 11 query answers.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Background;
+    ///
+    /// let background = Background::detect().unwrap_or_default();
+    /// println!("{background:?}");
+    /// ```
+    #[must_use]
+    pub fn detect() -> Option<Self> {
+        static DETECTED: OnceLock<Option<Background>> = OnceLock::new();
+        *DETECTED.get_or_init(|| {
+            std::env::var("COLORFGBG")
+                .ok()
+                .and_then(|value| Self::from_colorfgbg(&value))
+                .or_else(query_terminal)
+        })
+    }
+
+    /// Reads the background from a `COLORFGBG` value (`fg;bg` or `fg;x;bg`)
+    ///
+    /// The background is the last entry, an ANSI color index: 7 (light gray)
+    /// and the bright colors 9 to 15 are light, the others dark.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Background;
+    ///
+    /// assert_eq!(Background::from_colorfgbg("15;0"), Some(Background::Dark));
+    /// assert_eq!(Background::from_colorfgbg("0;default;15"), Some(Background::Light));
+    /// assert_eq!(Background::from_colorfgbg("default"), None);
+    /// ```
+    #[must_use]
+    pub fn from_colorfgbg(value: &str) -> Option<Self> {
+        let index: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+        Some(if index == 7 || (9..=15).contains(&index) {
+            Self::Light
+        } else {
+            Self::Dark
+        })
+    }
+
+    /// Classifies a background color by its relative luminance
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Background;
+    ///
+    /// assert_eq!(Background::from_rgb((250, 250, 245)), Background::Light);
+    /// assert_eq!(Background::from_rgb((40, 44, 52)), Background::Dark);
+    /// ```
+    #[must_use]
+    pub fn from_rgb(rgb: Rgb) -> Self {
+        if crate::contrast::luminance(rgb) > 0.18 {
+            Self::Light
+        } else {
+            Self::Dark
+        }
+    }
+
+    /// Reads the background color from an OSC 11 reply
+    ///
+    /// Accepts `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` with 1 to 4 hex digits per
+    /// channel, terminated by BEL or ST.
+    fn from_osc11_reply(reply: &str) -> Option<Self> {
+        let spec = reply.split_once("rgb:")?.1;
+        let spec = spec
+            .trim_end_matches(['\x07', '\\'])
+            .trim_end_matches('\x1b');
+        let mut channels = spec.split('/').map(|channel| {
+            let digits = channel.get(..channel.len().min(4))?;
+            let value = u32::from_str_radix(digits, 16).ok()?;
+            let max = (1u32 << (4 * digits.len())) - 1;
+            u8::try_from(value * 255 / max).ok()
+        });
+        let rgb = (channels.next()??, channels.next()??, channels.next()??);
+        Some(Self::from_rgb(rgb))
+    }
+}
+
+/// Runs `stty` on the terminal `control`, returning its trimmed stdout
+#[cfg(unix)]
+fn stty(control: &std::fs::File, args: &[&str]) -> Option<String> {
+    use std::process::{Command, Stdio};
+
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::from(control.try_clone().ok()?))
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The terminal in raw mode, restored to its saved mode when dropped
+///
+/// Dropping also runs while a panic unwinds, so the user's terminal is never
+/// left raw. Raw mode turns off signal generation, so Ctrl-C during the
+/// query is read as input rather than killing the process mid-query.
+#[cfg(unix)]
+struct RawMode {
+    control: std::fs::File,
+    saved: String,
+}
+
+#[cfg(unix)]
+impl RawMode {
+    /// Switches `control` to raw mode without echo, where reads return after
+    /// at most 100 ms of silence
+    fn enter(control: std::fs::File) -> Option<Self> {
+        let saved = stty(&control, &["-g"])?;
+        let guard = Self { control, saved };
+        stty(&guard.control, &["raw", "-echo", "min", "0", "time", "1"])?;
+        Some(guard)
+    }
+}
+
+#[cfg(unix)]
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        stty(&self.control, &[self.saved.as_str()]);
+    }
+}
+
+/// Asks the terminal for its background color with OSC 11
+#[cfg(unix)]
+fn query_terminal() -> Option<Background> {
+    use std::fs::File;
+    use std::io::{IsTerminal, Read, Write};
+
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        return None;
+    }
+    let mut tty = File::options()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    let raw_mode = RawMode::enter(tty.try_clone().ok()?)?;
+
+    let mut reply = Vec::new();
+    if tty
+        .write_all(b"\x1b]11;?\x07")
+        .and_then(|()| tty.flush())
+        .is_ok()
+    {
+        let mut buffer = [0u8; 64];
+        while let Ok(read @ 1..) = tty.read(&mut buffer) {
+            reply.extend_from_slice(&buffer[..read]);
+            if reply.ends_with(b"\x07") || reply.ends_with(b"\x1b\\") || reply.len() > 256 {
+                break;
+            }
+        }
+    }
+    drop(raw_mode);
+    Background::from_osc11_reply(&String::from_utf8_lossy(&reply))
+}
+
+#[cfg(not(unix))]
+fn query_terminal() -> Option<Background> {
+    None
+}
+
+impl ColorfulVersion {
+    /// Uses `light` on light terminal backgrounds and `dark` on all others
+    ///
+    /// Replaces the colors set with [`with_theme`](Self::with_theme) and
+    /// friends; high contrast and the environment overrides still apply on
+    /// top. The background comes from [`Background::detect`] unless set with
+    /// [`with_background`](Self::with_background).
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{Background, ColorfulVersion, Role, Theme};
+    ///
+    /// let light = Theme::new().primary((0, 90, 181));
+    /// let dark = Theme::new().primary((120, 190, 255));
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_adaptive_theme(light, dark)
+    ///     .with_background(Background::Light);
+    /// assert_eq!(version.theme().role(Role::Primary), (0, 90, 181));
+    /// ```
+    #[must_use]
+    pub fn with_adaptive_theme(mut self, light: Theme, dark: Theme) -> Self {
+        self.adaptive_theme = Some((light, dark));
+        self
+    }
+
+    /// Overrides the detected terminal background
+    #[must_use]
+    pub fn with_background(mut self, background: Background) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Returns the terminal background in effect (dark when unknown)
+    #[must_use]
+    pub fn background(&self) -> Background {
+        self.background
+            .or_else(Background::detect)
+            .unwrap_or_default()
+    }
+
+    /// Returns the configured colors, resolving an adaptive theme
+    pub(crate) fn base_colors(&self) -> Colors {
+        match self.adaptive_theme {
+            Some((light, dark)) => Colors::from(match self.background() {
+                Background::Light => light,
+                Background::Dark => dark,
+            })
+            .with_unthemed_of(&self.colors),
+            None => self.colors,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Role;
+
+    #[test]
+    fn test_osc11_reply() {
+        let reply = |spec: &str| Background::from_osc11_reply(spec);
+        assert_eq!(
+            reply("\x1b]11;rgb:ffff/ffff/ffff\x07"),
+            Some(Background::Light)
+        );
+        assert_eq!(
+            reply("\x1b]11;rgb:1e1e/1e1e/2e2e\x1b\\"),
+            Some(Background::Dark)
+        );
+        assert_eq!(reply("\x1b]11;rgb:f/f/e\x07"), Some(Background::Light));
+        assert_eq!(reply(""), None);
+        assert_eq!(reply("\x1b]11;rgb:zz/00/00\x07"), None);
+    }
+
+    #[test]
+    fn test_colorfgbg() {
+        assert_eq!(Background::from_colorfgbg("0;7"), Some(Background::Light));
+        assert_eq!(Background::from_colorfgbg("7;8"), Some(Background::Dark));
+        assert_eq!(Background::from_colorfgbg(""), None);
+    }
+
+    #[test]
+    fn test_adaptive_theme_follows_background() {
+        let light = Theme::new().primary((1, 1, 1));
+        let dark = Theme::new().primary((2, 2, 2));
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_adaptive_theme(light, dark);
+        let primary = |background| {
+            version
+                .clone()
+                .with_background(background)
+                .theme()
+                .role(Role::Primary)
+        };
+        assert_eq!(primary(Background::Light), (1, 1, 1));
+        assert_eq!(primary(Background::Dark), (2, 2, 2));
+    }
+
+    #[test]
+    fn test_without_adaptive_theme_background_is_ignored() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        let light = version.clone().with_background(Background::Light);
+        assert_eq!(light.theme(), version.theme());
+    }
+}
@@ -0,0 +1,248 @@
+// Project: clap-version-flag
+// File: src/banner.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Multi-line banner output inside a box
+// License: MIT
+
+//! Banner mode.
+//!
+//! [`ColorfulVersion::with_banner`] renders the version output as centered
+//! lines inside a box drawn with Unicode box-drawing characters: the name,
+//! the version, the author and then the detail lines of the current
+//! verbosity. Widths are measured in terminal columns, so wide CJK
+//! characters and emoji keep the right border straight, and the border is
+//! painted in the [`Role::Primary`] color.
+
+use crate::{ColorDepth, ColorfulVersion, Field, Role, color, grapheme};
+
+/// Line style of a banner box
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BannerStyle {
+    /// `┌─┐` thin lines with square corners
+    Single,
+    /// `╔═╗` double lines
+    Double,
+    /// `╭─╮` thin lines with rounded corners
+    #[default]
+    Rounded,
+    /// `┏━┓` thick lines
+    Heavy,
+}
+
+/// The characters drawing one box style
+struct Border {
+    top_left: char,
+    top_right: char,
+    bottom_left: char,
+    bottom_right: char,
+    horizontal: char,
+    vertical: char,
+}
+
+impl BannerStyle {
+    fn border(self) -> Border {
+        let [
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+        ] = match self {
+            Self::Single => ['┌', '┐', '└', '┘', '─', '│'],
+            Self::Double => ['╔', '╗', '╚', '╝', '═', '║'],
+            Self::Rounded => ['╭', '╮', '╰', '╯', '─', '│'],
+            Self::Heavy => ['┏', '┓', '┗', '┛', '━', '┃'],
+        };
+        Border {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+impl ColorfulVersion {
+    /// Renders the version output as a banner inside a box
+    ///
+    /// Applies to the text output of the version flag and
+    /// [`print`](Self::print); [`as_plain_string`](Self::as_plain_string)
+    /// keeps the single line clap shows.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{BannerStyle, ColorfulVersion, VersionFormat};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_banner(BannerStyle::Rounded);
+    /// assert_eq!(
+    ///     version.render(VersionFormat::Plain),
+    ///     "╭─────────────╮\n\
+    ///      │    myapp    │\n\
+    ///      │   v1.0.0    │\n\
+    ///      │ by John Doe │\n\
+    ///      ╰─────────────╯"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_banner(mut self, style: BannerStyle) -> Self {
+        self.banner = Some(style);
+        self
+    }
+
+    /// Renders the banner; `colored` paints it regardless of terminal support
+    pub(crate) fn render_banner(
+        &self,
+        style: BannerStyle,
+        fields: &[Field],
+        colored: bool,
+    ) -> String {
+        let depth = self.depth();
+        let lines = self.banner_lines(fields, colored.then_some(depth));
+        let inner = lines
+            .iter()
+            .map(|(plain, _)| grapheme::width(plain))
+            .max()
+            .unwrap_or(0)
+            + 2;
+
+        let border = style.border();
+        let paint_border = |text: String| {
+            if colored {
+                color::paint(&text, self.role(Role::Primary), None, depth)
+            } else {
+                text
+            }
+        };
+        let rule = |left: char, right: char| {
+            let line: String = std::iter::once(left)
+                .chain(std::iter::repeat_n(border.horizontal, inner))
+                .chain(std::iter::once(right))
+                .collect();
+            paint_border(line)
+        };
+
+        let mut out = vec![rule(border.top_left, border.top_right)];
+        for (plain, shown) in &lines {
+            let free = inner - grapheme::width(plain);
+            let left = free / 2;
+            out.push(format!(
+                "{}{}{shown}{}{}",
+                paint_border(border.vertical.to_string()),
+                " ".repeat(left),
+                " ".repeat(free - left),
+                paint_border(border.vertical.to_string()),
+            ));
+        }
+        out.push(rule(border.bottom_left, border.bottom_right));
+        out.join("\n")
+    }
+
+    /// Returns each banner line as its plain text (for measuring) and the
+    /// text to show, painted at `depth` when set
+    fn banner_lines(&self, fields: &[Field], depth: Option<ColorDepth>) -> Vec<(String, String)> {
+        let [name, version, author] = self.fitted_segments();
+        let author = self.attributed(&author.padded());
+        let mut lines = vec![
+            (name.padded(), name.padded()),
+            (version.padded(), version.padded()),
+            (author.clone(), author),
+        ];
+        lines.extend(fields.iter().map(|field| {
+            let line = self.detail_line(field, None);
+            (line, self.detail_line(field, depth))
+        }));
+        if let Some(depth) = depth {
+            self.paint_banner(&mut lines, depth);
+        }
+        if self.hide_author {
+            lines.remove(2);
+        }
+        if self.hide_name {
+            lines.remove(0);
+        }
+        lines
+    }
+
+    /// Paints the name, version and author lines of the banner
+    fn paint_banner(&self, lines: &mut [(String, String)], depth: ColorDepth) {
+        lines[0].1 = self.paint_name(&lines[0].0, depth);
+        lines[1].1 = self.colored_version("", depth);
+        let author = self.paint_segment(
+            &lines[2].0,
+            &self.styles.author,
+            self.palette().author_color,
+            self.author_background(),
+            depth,
+        );
+        lines[2].1 = match self.link_target(self.author_link.as_deref()) {
+            Some(url) => color::hyperlink(&author, url),
+            None => author,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VersionFormat;
+
+    #[test]
+    fn test_styles() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        let corners = |style| {
+            let banner = version
+                .clone()
+                .with_banner(style)
+                .render(VersionFormat::Plain);
+            let first = banner.lines().next().unwrap().to_string();
+            let last = banner.lines().last().unwrap().to_string();
+            (first, last)
+        };
+        assert_eq!(
+            corners(BannerStyle::Single),
+            ("┌───────┐".to_string(), "└───────┘".to_string())
+        );
+        assert_eq!(corners(BannerStyle::Double).0, "╔═══════╗");
+        assert_eq!(corners(BannerStyle::Heavy).1, "┗━━━━━━━┛");
+    }
+
+    #[test]
+    fn test_wide_characters_and_details() {
+        let version = ColorfulVersion::builder("日本", "1.0", "Jo")
+            .license("MIT")
+            .build()
+            .with_banner(BannerStyle::Single);
+        assert_eq!(
+            version.render(VersionFormat::Plain),
+            "┌──────────────┐\n\
+             │     日本     │\n\
+             │     v1.0     │\n\
+             │    by Jo     │\n\
+             │ license: MIT │\n\
+             └──────────────┘"
+        );
+    }
+
+    #[test]
+    fn test_colored_border() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_color_depth(ColorDepth::TrueColor)
+            .with_banner(BannerStyle::Rounded);
+        let banner = version.render_banner(BannerStyle::Rounded, &[], true);
+        let mut lines = banner.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            "\x1b[38;2;170;0;255m╭───────╮\x1b[0m"
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            "\x1b[38;2;170;0;255m│\x1b[0m  \x1b[38;2;255;255;255;48;2;170;0;255mapp\x1b[0m  \x1b[38;2;170;0;255m│\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,173 @@
+// Project: clap-version-flag
+// File: src/bare.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Bare version output for shell scripts
+// License: MIT
+
+//! Bare version output.
+//!
+//! Scripts reading the version want `1.2.3`, not a colored line to take
+//! apart. [`PrintMode::Bare`] prints the version string alone, with no
+//! prefix, author, detail lines or escape codes, e.g. for
+//! `VER=$(myapp --version=short)`. Choose it for every version request with
+//! [`ColorfulVersion::with_print_mode`], or let users pick it per call with
+//! [`VersionFlagConfig::mode_value`](crate::VersionFlagConfig::mode_value):
+//!
+//! ```
+//! use clap::Command;
+//! use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionFlagConfig};
+//!
+//! let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe")
+//!     .with_flag_config(VersionFlagConfig::new().mode_value(true));
+//! let cmd = Command::new("myapp").with_colorful_version(&version);
+//!
+//! let matches = cmd.try_get_matches_from(["myapp", "--version=short"]).unwrap();
+//! assert_eq!(version.requested_print_mode(&matches), Some(clap_version_flag::PrintMode::Bare));
+//! ```
+
+use clap::builder::PossibleValue;
+use clap::{ArgMatches, ValueEnum};
+
+use crate::ColorfulVersion;
+
+/// How much of the version information a version request prints
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PrintMode {
+    /// The regular output, as chosen by the verbosity and format
+    #[default]
+    Full,
+    /// Only the version string, e.g. `1.2.3`, without color
+    Bare,
+}
+
+impl ValueEnum for PrintMode {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Full, Self::Bare]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Full => PossibleValue::new("full").help("The full version output (default)"),
+            Self::Bare => PossibleValue::new("short")
+                .alias("bare")
+                .help("Only the version number"),
+        })
+    }
+}
+
+impl ColorfulVersion {
+    /// Sets how much a version request prints
+    ///
+    /// With [`PrintMode::Bare`], [`print`](Self::print) and the version flag
+    /// print only the version string; `--version-format json` still prints JSON.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, PrintMode};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe")
+    ///     .with_print_mode(PrintMode::Bare);
+    /// assert_eq!(version.print_mode(), PrintMode::Bare);
+    /// version.print(); // prints "1.2.3"
+    /// ```
+    #[must_use]
+    pub fn with_print_mode(mut self, mode: PrintMode) -> Self {
+        self.print_mode = mode;
+        self
+    }
+
+    /// Returns the print mode set with [`with_print_mode`](Self::with_print_mode)
+    #[must_use]
+    pub fn print_mode(&self) -> PrintMode {
+        self.print_mode
+    }
+
+    /// Returns the print mode of a version request, if the flag was given
+    ///
+    /// A mode given as the flag's value (`--version=short`) wins over the
+    /// configured [`print_mode`](Self::print_mode).
+    #[must_use]
+    pub fn requested_print_mode(&self, matches: &ArgMatches) -> Option<PrintMode> {
+        self.flag.requested_verbosity(matches)?;
+        Some(self.flag.requested_mode(matches).unwrap_or(self.print_mode))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorfulVersionExt, VersionFlagConfig, VersionFormat};
+    use clap::Command;
+
+    fn command(version: &ColorfulVersion) -> Command {
+        Command::new("app")
+            .with_colorful_version(version)
+            .with_version_format_flag()
+    }
+
+    fn output(version: &ColorfulVersion, args: &[&str]) -> Option<String> {
+        let matches = command(version).try_get_matches_from(args).unwrap();
+        version.requested_output(&matches)
+    }
+
+    #[test]
+    fn test_flag_value_selects_mode() {
+        let version = ColorfulVersion::new("app", "1.2.3", "Jo")
+            .with_color_depth(crate::ColorDepth::TrueColor)
+            .with_flag_config(VersionFlagConfig::new().mode_value(true));
+        assert_eq!(
+            output(&version, &["app", "--version=short"]).unwrap(),
+            "1.2.3"
+        );
+        assert_eq!(output(&version, &["app", "-V=bare"]).unwrap(), "1.2.3");
+        assert!(
+            output(&version, &["app", "--version"])
+                .unwrap()
+                .contains("by Jo")
+        );
+        assert_eq!(output(&version, &["app"]), None);
+        assert!(
+            command(&version)
+                .try_get_matches_from(["app", "--version=tiny"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_configured_mode() {
+        let version = ColorfulVersion::new("app", "1.2.3", "Jo").with_print_mode(PrintMode::Bare);
+        assert_eq!(output(&version, &["app", "-V"]).unwrap(), "1.2.3");
+        assert!(
+            output(&version, &["app", "-V", "--version-format", "json"])
+                .unwrap()
+                .starts_with('{')
+        );
+        // Without mode_value the flag takes no value
+        assert!(
+            command(&version)
+                .try_get_matches_from(["app", "--version=full"])
+                .is_err()
+        );
+        assert_eq!(
+            version.render(VersionFormat::Plain),
+            "app v1.2.3 by Jo",
+            "rendering is unaffected"
+        );
+    }
+
+    #[test]
+    fn test_full_value_overrides_configured_bare() {
+        let version = ColorfulVersion::new("app", "1.2.3", "Jo")
+            .with_print_mode(PrintMode::Bare)
+            .with_flag_config(VersionFlagConfig::new().mode_value(true));
+        let matches = command(&version)
+            .try_get_matches_from(["app", "--version=full"])
+            .unwrap();
+        assert_eq!(
+            version.requested_print_mode(&matches),
+            Some(PrintMode::Full)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// Project: clap-version-flag
+// File: src/bidi.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Directional isolation of right-to-left names
+// License: MIT
+
+//! Bidirectional text handling.
+//!
+//! A terminal lays out a line with the Unicode bidirectional algorithm, so an
+//! Arabic or Hebrew package name followed by " v1.2.0 by" pulls the Latin
+//! version number into its right-to-left run and the line reads out of
+//! order. Names, authors and detail values that contain right-to-left
+//! characters are therefore wrapped in a first-strong isolate (U+2068 …
+//! U+2069), which confines their direction to themselves. Text without
+//! right-to-left characters is left untouched.
+
+use crate::ColorfulVersion;
+use std::borrow::Cow;
+
+/// First strong isolate: the enclosed text takes its own direction
+const FSI: char = '\u{2068}';
+/// Pop directional isolate, closing [`FSI`]
+const PDI: char = '\u{2069}';
+
+/// Returns whether `c` is a strong right-to-left character
+fn is_rtl(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0590}'..='\u{08FF}'     // Hebrew, Arabic, Syriac, Thaana, NKo, ...
+        | '\u{FB1D}'..='\u{FDFF}'   // Hebrew and Arabic presentation forms A
+        | '\u{FE70}'..='\u{FEFF}'   // Arabic presentation forms B
+        | '\u{10800}'..='\u{10FFF}' // historic right-to-left scripts
+        | '\u{1E800}'..='\u{1EFFF}' // Adlam, Arabic mathematical symbols
+    )
+}
+
+/// Wraps `text` in a directional isolate when it contains right-to-left text
+///
+/// Text that is already one isolate is left as is.
+pub(crate) fn isolate(text: &str) -> Cow<'_, str> {
+    let isolated = text.starts_with(FSI) && text.ends_with(PDI);
+    if !isolated && text.chars().any(is_rtl) {
+        Cow::Owned(format!("{FSI}{text}{PDI}"))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+impl ColorfulVersion {
+    /// Sets whether right-to-left text is wrapped in directional isolates
+    ///
+    /// Enabled by default. Isolation only changes text that contains
+    /// right-to-left characters; disable it when the output is parsed by a
+    /// tool that does not expect the invisible U+2068/U+2069 marks.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("שלום", "1.0.0", "John Doe");
+    /// assert_eq!(version.as_plain_string(), "\u{2068}שלום\u{2069} v1.0.0 by John Doe");
+    ///
+    /// let version = version.with_bidi_isolation(false);
+    /// assert_eq!(version.as_plain_string(), "שלום v1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn with_bidi_isolation(mut self, enabled: bool) -> Self {
+        self.bidi_isolation = enabled;
+        self
+    }
+
+    /// Isolates `text` if bidirectional isolation is enabled
+    pub(crate) fn bidi<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.bidi_isolation {
+            isolate(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolate() {
+        assert_eq!(isolate("myapp"), "myapp");
+        assert_eq!(isolate("محمد"), "\u{2068}محمد\u{2069}");
+        assert_eq!(isolate("app-עברית"), "\u{2068}app-עברית\u{2069}");
+        assert_eq!(isolate("\u{2068}محمد\u{2069}"), "\u{2068}محمد\u{2069}");
+    }
+
+    #[test]
+    fn test_isolates_stay_inside_escape_codes() {
+        let version = ColorfulVersion::new("app", "1.0", "محمد");
+        assert_eq!(
+            version.colored_line(crate::ColorDepth::TrueColor),
+            "\x1b[38;2;255;255;255;48;2;170;0;255mapp\x1b[0m\x1b[38;2;255;255;0m v1.0\x1b[0m\x1b[38;2;0;255;255m by \u{2068}محمد\u{2069}\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_padding_surrounds_isolate() {
+        let version = ColorfulVersion::new("שלום", "1.0", "Jane")
+            .with_layout(crate::Layout::new().name(crate::FieldWidth::new().min(6)));
+        assert_eq!(
+            version.as_plain_string(),
+            "\u{2068}שלום\u{2069}   v1.0 by Jane"
+        );
+    }
+}
@@ -0,0 +1,167 @@
+// Project: clap-version-flag
+// File: src/bug_report.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Markdown diagnostic block for bug reports
+// License: MIT
+
+//! Bug reports.
+//!
+//! [`ColorfulVersion::bug_report`] renders everything a maintainer asks for
+//! first as Markdown, ready to paste into a GitHub issue, and
+//! [`ColorfulVersionExt::with_bug_report_flag`](crate::ColorfulVersionExt::with_bug_report_flag)
+//! prints it for `myapp --bug-report`:
+//!
+//! ````markdown
+//! ### Version information
+//!
+//! ```text
+//! name:          myapp
+//! version:       1.4.2
+//! commit:        4f2a9c1
+//! os:            linux
+//! arch:          x86_64
+//! rustc:         rustc 1.90.0
+//! features:      json, tls
+//! installed via: cargo install
+//! ```
+//!
+//! ### Environment
+//!
+//! ```text
+//! TERM=xterm-256color
+//! LANG=en_US.UTF-8
+//! ```
+//! ````
+//!
+//! Fields without a value are left out. Only the environment variables
+//! that affect the output are listed (terminal, color and locale settings,
+//! plus the [color overrides](ColorfulVersion::with_env_overrides)), so the
+//! block never leaks secrets.
+
+use crate::{ColorfulVersion, env_overrides};
+
+/// Environment variables that affect the output
+const ENV_VARS: [&str; 12] = [
+    "TERM",
+    "COLORTERM",
+    "TERM_PROGRAM",
+    "WT_SESSION",
+    "NO_COLOR",
+    "CLICOLOR",
+    "CLICOLOR_FORCE",
+    "LANG",
+    "LC_ALL",
+    "LC_CTYPE",
+    "SHELL",
+    "CI",
+];
+
+impl ColorfulVersion {
+    /// Returns the Markdown diagnostic block for bug reports
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.0.0", "John Doe")
+    ///     .extra("commit", "4f2a9c1")
+    ///     .build();
+    /// let report = version.bug_report();
+    /// assert!(report.starts_with("### Version information\n\n```text\nname:          myapp\n"));
+    /// assert!(report.contains("\ncommit:        4f2a9c1\n"));
+    /// ```
+    #[must_use]
+    pub fn bug_report(&self) -> String {
+        self.render_bug_report(|name| std::env::var(name).ok())
+    }
+
+    /// Renders the bug report, reading the environment through `lookup`
+    fn render_bug_report(&self, lookup: impl Fn(&str) -> Option<String>) -> String {
+        let features = self.features().join(", ");
+        let install_method = self.install_method().to_string();
+        let fields = [
+            ("name", Some(self.package_name.as_ref())),
+            ("version", Some(self.version.as_ref())),
+            ("commit", self.metadata("commit")),
+            ("os", Some(std::env::consts::OS)),
+            ("arch", Some(std::env::consts::ARCH)),
+            ("target", self.metadata("target")),
+            ("rustc", self.metadata("rustc")),
+            ("profile", self.metadata("profile")),
+            (
+                "features",
+                Some(features.as_str()).filter(|list| !list.is_empty()),
+            ),
+            ("installed via", Some(install_method.as_str())),
+        ];
+        let width = fields.iter().map(|(key, _)| key.len()).max().unwrap_or(0) + 2;
+
+        let mut out = String::from("### Version information\n\n```text\n");
+        for (key, value) in fields {
+            if let Some(value) = value {
+                out.push_str(&format!("{:width$}{value}\n", format!("{key}:")));
+            }
+        }
+        out.push_str("```\n\n### Environment\n\n```text\n");
+
+        let overrides = self
+            .env_prefix
+            .iter()
+            .flat_map(|prefix| env_overrides::variables(prefix));
+        let mut any = false;
+        for name in ENV_VARS.map(String::from).into_iter().chain(overrides) {
+            if let Some(value) = lookup(&name) {
+                out.push_str(&format!("{name}={value}\n"));
+                any = true;
+            }
+        }
+        if !any {
+            out.push_str("(none set)\n");
+        }
+        out.push_str("```");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BuildInfo, InstallMethod};
+
+    #[test]
+    fn test_full_report() {
+        const INFO: BuildInfo = BuildInfo::new("app", "1.0", "Jo")
+            .with_commit(Some("abc1234"))
+            .with_rustc(Some("rustc 1.90.0"))
+            .with_features(Some("tls"));
+        let version = ColorfulVersion::from_build_info(&INFO)
+            .with_install_method(InstallMethod::Cargo)
+            .with_env_overrides("APP");
+        let lookup = |name: &str| match name {
+            "TERM" => Some("xterm".to_string()),
+            "APP_VERSION_NAME_BG" => Some("teal".to_string()),
+            "APP_VERSION_ERROR" => Some("red".to_string()),
+            _ => None,
+        };
+        let report = version.render_bug_report(lookup);
+        let expected_os = format!("os:            {}\n", std::env::consts::OS);
+        assert!(report.contains(&expected_os));
+        assert!(report.contains("commit:        abc1234\nos:"));
+        assert!(report.contains("rustc:         rustc 1.90.0\n"));
+        assert!(report.contains("features:      tls\ninstalled via: cargo install\n```"));
+        assert!(
+            report
+                .ends_with("### Environment\n\n```text\nTERM=xterm\nAPP_VERSION_NAME_BG=teal\nAPP_VERSION_ERROR=red\n```")
+        );
+    }
+
+    #[test]
+    fn test_missing_values_left_out() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        let report = version.render_bug_report(|_| None);
+        assert!(!report.contains("commit:"));
+        assert!(!report.contains("features:"));
+        assert!(report.ends_with("```text\n(none set)\n```"));
+    }
+}
@@ -0,0 +1,461 @@
+// Project: clap-version-flag
+// File: src/build.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Build script helpers capturing toolchain information
+// License: MIT
+
+//! Build script helpers.
+//!
+//! Some build details are only known to Cargo build scripts. Add this crate
+//! to `[build-dependencies]` and call the helpers from `build.rs`; they pass
+//! the details on as `CLAP_VERSION_FLAG_*` compile-time variables, which
+//! [`generate_build_info!`](crate::generate_build_info) picks up and the
+//! verbose version output shows, like `cargo --version --verbose` does:
+//!
+//! ```no_run
+//! // in build.rs, inside `fn main`
+//! clap_version_flag::build::emit_rustc_info();
+//! clap_version_flag::build::emit_features();
+//! clap_version_flag::build::emit_dependencies();
+//! ```
+//!
+//! The helpers declare their inputs with `cargo:rerun-if-changed` and
+//! `cargo:rerun-if-env-changed`: `build.rs` itself and `RUSTC`, plus the
+//! manifest and lock file for [`emit_dependencies`]. Like any such
+//! instruction they replace Cargo's default of rerunning the script on
+//! every change to the package, so a build script that reads further files
+//! must declare those too.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Emits the compiler version, target triple, opt-level and profile
+///
+/// Sets `CLAP_VERSION_FLAG_RUSTC` (the `rustc --version` line),
+/// `CLAP_VERSION_FLAG_TARGET`, `CLAP_VERSION_FLAG_OPT_LEVEL` and
+/// `CLAP_VERSION_FLAG_PROFILE` for the crate being built. Details Cargo does
+/// not provide are skipped. Only call it from a build script.
+pub fn emit_rustc_info() {
+    let lookup = |name: &str| std::env::var(name).ok();
+    for line in rustc_info_lines(lookup, rustc_version) {
+        println!("{line}");
+    }
+}
+
+/// Returns the build script instructions of [`emit_rustc_info`]
+fn rustc_info_lines(
+    env: impl Fn(&str) -> Option<String>,
+    rustc_version: impl Fn(&str) -> Option<String>,
+) -> Vec<String> {
+    let rustc = env("RUSTC").unwrap_or_else(|| "rustc".into());
+    let values = [
+        ("CLAP_VERSION_FLAG_RUSTC", rustc_version(&rustc)),
+        ("CLAP_VERSION_FLAG_TARGET", env("TARGET")),
+        ("CLAP_VERSION_FLAG_OPT_LEVEL", env("OPT_LEVEL")),
+        ("CLAP_VERSION_FLAG_PROFILE", env("PROFILE")),
+    ];
+
+    let mut lines = vec![
+        "cargo:rerun-if-changed=build.rs".to_string(),
+        "cargo:rerun-if-env-changed=RUSTC".to_string(),
+    ];
+    for (name, value) in values {
+        // a newline would end the instruction early
+        if let Some(value) = value.filter(|value| !value.contains('\n')) {
+            lines.push(format!("cargo:rustc-env={name}={value}"));
+        }
+    }
+    lines
+}
+
+/// Emits the enabled Cargo features of the crate being built
+///
+/// Sets `CLAP_VERSION_FLAG_FEATURES` to the comma-separated feature names,
+/// which [`BuildInfo::features`](crate::BuildInfo::features) picks up. Only
+/// call it from a build script.
+pub fn emit_features() {
+    let vars: Vec<(String, String)> = std::env::vars().collect();
+    println!("{}", features_line(&vars));
+}
+
+/// Returns the build script instruction of [`emit_features`]
+///
+/// Prefers `CARGO_CFG_FEATURE`, which holds the exact names; otherwise the
+/// names are recovered from the `CARGO_FEATURE_*` variables, which Cargo
+/// uppercases with `-` turned into `_`, so they come back lowercase with `-`.
+fn features_line(vars: &[(String, String)]) -> String {
+    let exact = vars
+        .iter()
+        .find(|(name, _)| name == "CARGO_CFG_FEATURE")
+        .map(|(_, value)| value.split(',').map(str::to_string).collect());
+    let mut features: Vec<String> = exact.unwrap_or_else(|| {
+        vars.iter()
+            .filter_map(|(name, _)| name.strip_prefix("CARGO_FEATURE_"))
+            .map(|feature| feature.to_lowercase().replace('_', "-"))
+            .collect()
+    });
+    features.retain(|feature| !feature.is_empty());
+    features.sort_unstable();
+    format!(
+        "cargo:rustc-env=CLAP_VERSION_FLAG_FEATURES={}",
+        features.join(",")
+    )
+}
+
+/// Emits the resolved versions of the direct dependencies
+///
+/// Sets `CLAP_VERSION_FLAG_DEPENDENCIES` to the comma-separated "name
+/// version" pairs of the `[dependencies]` (including target-specific ones)
+/// of the crate being built, read from its `Cargo.toml` and the `Cargo.lock`
+/// of the package or workspace. Dev- and build-dependencies are left out;
+/// optional dependencies are listed whether or not they are enabled, as the
+/// lock file does not tell. Only call it from a build script.
+pub fn emit_dependencies() {
+    let Ok(manifest_dir) = std::env::var("CARGO_MANIFEST_DIR") else {
+        return;
+    };
+    let manifest_dir = Path::new(&manifest_dir);
+    let Ok(manifest) = fs::read_to_string(manifest_dir.join("Cargo.toml")) else {
+        return;
+    };
+    let Some(lock_path) = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.is_file())
+    else {
+        return;
+    };
+    println!(
+        "cargo:rerun-if-changed={}",
+        manifest_dir.join("Cargo.toml").display()
+    );
+    println!("cargo:rerun-if-changed={}", lock_path.display());
+    let Ok(lock) = fs::read_to_string(&lock_path) else {
+        return;
+    };
+
+    let package = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
+    let version = std::env::var("CARGO_PKG_VERSION").unwrap_or_default();
+    let pairs: Vec<String> = resolve_dependencies(&manifest, &lock, &package, &version)
+        .into_iter()
+        .map(|(name, version)| format!("{name} {version}"))
+        .collect();
+    println!(
+        "cargo:rustc-env=CLAP_VERSION_FLAG_DEPENDENCIES={}",
+        pairs.join(",")
+    );
+}
+
+/// A `[[package]]` entry of `Cargo.lock`
+#[derive(Debug, Default)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    dependencies: Vec<String>,
+}
+
+/// Returns the locked `(name, version)` of each normal direct dependency
+///
+/// Dependencies renamed with `package = "..."` are reported under the name
+/// of the package.
+fn resolve_dependencies(
+    manifest: &str,
+    lock: &str,
+    package: &str,
+    version: &str,
+) -> Vec<(String, String)> {
+    let names = manifest_dependencies(manifest);
+    let packages = locked_packages(lock);
+    let Some(root) = packages
+        .iter()
+        .find(|locked| locked.name == package && locked.version == version)
+    else {
+        return Vec::new();
+    };
+
+    let mut resolved: Vec<(String, String)> = root
+        .dependencies
+        .iter()
+        .filter_map(|dependency| {
+            // "name", "name version" or "name version (source)"
+            let mut parts = dependency.split(' ');
+            let name = parts.next()?;
+            if !names.iter().any(|candidate| candidate == name) {
+                return None;
+            }
+            let version = match parts.next() {
+                Some(version) => version.to_string(),
+                None => packages
+                    .iter()
+                    .find(|locked| locked.name == name)?
+                    .version
+                    .clone(),
+            };
+            Some((name.to_string(), version))
+        })
+        .collect();
+    resolved.sort();
+    resolved.dedup();
+    resolved
+}
+
+/// Returns the package names of the normal dependencies in `manifest`
+fn manifest_dependencies(manifest: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut in_table = false;
+    // the entry of the current `[dependencies.name]` table, if any
+    let mut current: Option<usize> = None;
+    for line in manifest.lines().map(str::trim) {
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.trim_end_matches(']').trim();
+            let is_target = header.starts_with("target.");
+            in_table = header == "dependencies" || (is_target && header.ends_with(".dependencies"));
+            let key = match header.strip_prefix("dependencies.") {
+                Some(key) => Some(key),
+                None if is_target => header.rsplit_once(".dependencies.").map(|(_, key)| key),
+                None => None,
+            };
+            current = key.map(|key| {
+                names.push(key.trim_matches('"').to_string());
+                names.len() - 1
+            });
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let key = key.trim().trim_matches('"');
+        if in_table {
+            let renamed = inline_value(value, "package");
+            names.push(renamed.unwrap_or_else(|| key.to_string()));
+        } else if let Some(index) = current.filter(|_| key == "package") {
+            names[index] = value.trim().trim_matches('"').to_string();
+        }
+    }
+    names
+}
+
+/// Returns the string value of `key` in an inline table such as
+/// `{ version = "1", package = "real-name" }`
+fn inline_value(value: &str, key: &str) -> Option<String> {
+    let inner = value
+        .trim()
+        .strip_prefix('{')?
+        .trim_end()
+        .strip_suffix('}')?;
+    inner.split(',').find_map(|entry| {
+        let (entry_key, entry_value) = entry.split_once('=')?;
+        (entry_key.trim() == key).then(|| entry_value.trim().trim_matches('"').to_string())
+    })
+}
+
+/// Parses the `[[package]]` entries of a `Cargo.lock`
+fn locked_packages(lock: &str) -> Vec<LockedPackage> {
+    let mut packages: Vec<LockedPackage> = Vec::new();
+    let mut in_dependencies = false;
+    for line in lock.lines().map(str::trim) {
+        if line == "[[package]]" {
+            packages.push(LockedPackage::default());
+            in_dependencies = false;
+            continue;
+        }
+        let Some(package) = packages.last_mut() else {
+            continue;
+        };
+        if in_dependencies {
+            if line.starts_with(']') {
+                in_dependencies = false;
+            } else {
+                package
+                    .dependencies
+                    .push(line.trim_end_matches(',').trim_matches('"').to_string());
+            }
+            continue;
+        }
+        match line.split_once(" = ") {
+            Some(("name", value)) => package.name = value.trim_matches('"').to_string(),
+            Some(("version", value)) => package.version = value.trim_matches('"').to_string(),
+            Some(("dependencies", "[")) => in_dependencies = true,
+            Some(("dependencies", value)) => {
+                // an empty or single-line list
+                package.dependencies = value
+                    .trim_matches(['[', ']'])
+                    .split(',')
+                    .map(|item| item.trim().trim_matches('"').to_string())
+                    .filter(|item| !item.is_empty())
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+    packages
+}
+
+/// Runs `{rustc} --version`, returning its one-line output
+fn rustc_version(rustc: &str) -> Option<String> {
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let version = String::from_utf8(output.stdout).ok()?;
+    let version = version.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rustc_info_lines() {
+        let env = |name: &str| match name {
+            "RUSTC" => Some("/opt/rustc".to_string()),
+            "TARGET" => Some("x86_64-unknown-linux-gnu".to_string()),
+            "OPT_LEVEL" => Some("3".to_string()),
+            _ => None,
+        };
+        let lines = rustc_info_lines(env, |rustc| {
+            assert_eq!(rustc, "/opt/rustc");
+            Some("rustc 1.85.0 (4d91de4e4 2025-02-17)".to_string())
+        });
+        assert_eq!(
+            lines,
+            [
+                "cargo:rerun-if-changed=build.rs",
+                "cargo:rerun-if-env-changed=RUSTC",
+                "cargo:rustc-env=CLAP_VERSION_FLAG_RUSTC=rustc 1.85.0 (4d91de4e4 2025-02-17)",
+                "cargo:rustc-env=CLAP_VERSION_FLAG_TARGET=x86_64-unknown-linux-gnu",
+                "cargo:rustc-env=CLAP_VERSION_FLAG_OPT_LEVEL=3",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_features_line() {
+        let vars = |pairs: &[(&str, &str)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect()
+        };
+        assert_eq!(
+            features_line(&vars(&[
+                ("CARGO_FEATURE_JSON_OUTPUT", "1"),
+                ("CARGO_FEATURE_TLS", "1"),
+                ("PATH", "/bin"),
+            ])),
+            "cargo:rustc-env=CLAP_VERSION_FLAG_FEATURES=json-output,tls"
+        );
+        assert_eq!(
+            features_line(&vars(&[
+                ("CARGO_FEATURE_JSON_OUTPUT", "1"),
+                ("CARGO_CFG_FEATURE", "tls,json_output"),
+            ])),
+            "cargo:rustc-env=CLAP_VERSION_FLAG_FEATURES=json_output,tls"
+        );
+        assert_eq!(
+            features_line(&[]),
+            "cargo:rustc-env=CLAP_VERSION_FLAG_FEATURES="
+        );
+    }
+
+    #[test]
+    fn test_resolve_dependencies() {
+        let manifest = r#"
+[package]
+name = "app"
+version = "0.1.0"
+
+[dependencies]
+clap = { version = "4", features = ["derive"] }
+json = { package = "serde_json", version = "1" }
+"colored" = "2"
+
+[dependencies.toml_edit]
+version = "0.22"
+optional = true
+
+[target.'cfg(unix)'.dependencies]
+libc = "0.2"
+
+[dev-dependencies]
+tempfile = "3"
+"#;
+        let lock = r#"
+version = 4
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "clap",
+ "colored",
+ "libc",
+ "serde_json",
+ "tempfile",
+ "toml_edit 0.22.27",
+]
+
+[[package]]
+name = "clap"
+version = "4.5.53"
+dependencies = []
+
+[[package]]
+name = "colored"
+version = "2.1.0"
+
+[[package]]
+name = "libc"
+version = "0.2.177"
+
+[[package]]
+name = "serde_json"
+version = "1.0.145"
+
+[[package]]
+name = "tempfile"
+version = "3.23.0"
+"#;
+        let pairs = resolve_dependencies(manifest, lock, "app", "0.1.0");
+        let pairs: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(name, version)| (name.as_str(), version.as_str()))
+            .collect();
+        assert_eq!(
+            pairs,
+            [
+                ("clap", "4.5.53"),
+                ("colored", "2.1.0"),
+                ("libc", "0.2.177"),
+                ("serde_json", "1.0.145"),
+                ("toml_edit", "0.22.27"),
+            ]
+        );
+        assert!(resolve_dependencies(manifest, lock, "app", "9.9.9").is_empty());
+    }
+
+    #[test]
+    fn test_own_manifest() {
+        let manifest = include_str!("../Cargo.toml");
+        let lock = include_str!("../Cargo.lock");
+        let pairs = resolve_dependencies(
+            manifest,
+            lock,
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+        );
+        let names: Vec<&str> = pairs.iter().map(|(name, _)| name.as_str()).collect();
+        assert!(names.contains(&"clap") && names.contains(&"thiserror"));
+        assert!(!names.contains(&"trycmd"));
+    }
+
+    #[test]
+    fn test_rustc_version() {
+        assert!(rustc_version("rustc").unwrap().starts_with("rustc "));
+        assert_eq!(rustc_version("/nonexistent/rustc"), None);
+    }
+}
@@ -0,0 +1,544 @@
+// Project: clap-version-flag
+// File: src/build_info.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Typed build metadata embedded at compile time
+// License: MIT
+
+//! Typed build metadata embedded at compile time.
+//!
+//! [`generate_build_info!`](crate::generate_build_info) expands to a
+//! `BUILD_INFO` constant in the calling crate holding everything Cargo (and
+//! optionally a build script or packager) embeds into the binary. Hand it to
+//! [`ColorfulVersion::from_build_info`](crate::ColorfulVersion::from_build_info)
+//! to render it.
+//!
+//! # JSON schema
+//!
+//! [`BuildInfo::to_json`] (and the `Serialize` impl behind the `serde`
+//! feature) produce one canonical shape shared by every integration, e.g.
+//! metrics labels, health endpoints or SBOM generators:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "name": "myapp",
+//!   "version": "1.0.0",
+//!   "authors": ["John Doe <john@example.com>"],
+//!   "description": "Does useful things",
+//!   "homepage": null,
+//!   "repository": "https://github.com/john/myapp",
+//!   "license": "MIT",
+//!   "rust_version": "1.85",
+//!   "commit": "4f2a9c1",
+//!   "build_date": null,
+//!   "install_method": null,
+//!   "target": "x86_64-unknown-linux-gnu",
+//!   "features": ["json", "tls"],
+//!   "build_id": "7f3a21c",
+//!   "rustc": "rustc 1.85.0 (4d91de4e4 2025-02-17)",
+//!   "opt_level": "3",
+//!   "profile": "release",
+//!   "dependencies": {"clap": "4.5.53", "colored": "2.1.0"}
+//! }
+//! ```
+//!
+//! Every key is always present; unknown values are `null`. Within one
+//! `schema_version`, keys are only ever added, never renamed, removed or
+//! retyped, so consumers should ignore keys they do not know. Any breaking
+//! change bumps [`BuildInfo::SCHEMA_VERSION`].
+//!
+//! # Build id
+//!
+//! Two binaries can share a version yet differ in commit, target or enabled
+//! features. [`BuildInfo::build_id`] condenses those three into a short,
+//! stable hash, so support can tell such binaries apart at a glance.
+
+use crate::json::{self, JsonObject};
+
+/// Build metadata embedded at compile time
+///
+/// Usually created with [`generate_build_info!`](crate::generate_build_info).
+/// Optional values come from environment variables read at build time:
+///
+/// | Field            | Source                                  |
+/// |------------------|-----------------------------------------|
+/// | `commit`         | `CLAP_VERSION_FLAG_COMMIT`              |
+/// | `build_date`     | `CLAP_VERSION_FLAG_BUILD_DATE`          |
+/// | `install_method` | `CLAP_VERSION_FLAG_INSTALL_METHOD`      |
+/// | `target`         | `CLAP_VERSION_FLAG_TARGET`              |
+/// | `features`       | `CLAP_VERSION_FLAG_FEATURES`            |
+/// | `rustc`          | `CLAP_VERSION_FLAG_RUSTC`               |
+/// | `opt_level`      | `CLAP_VERSION_FLAG_OPT_LEVEL`           |
+/// | `profile`        | `CLAP_VERSION_FLAG_PROFILE`             |
+/// | `dependencies`   | `CLAP_VERSION_FLAG_DEPENDENCIES`        |
+///
+/// [`build::emit_rustc_info`](crate::build::emit_rustc_info) sets the target
+/// and the last three from a build script,
+/// [`build::emit_features`](crate::build::emit_features) the features and
+/// [`build::emit_dependencies`](crate::build::emit_dependencies) the
+/// dependencies.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::BuildInfo;
+///
+/// const INFO: BuildInfo = BuildInfo::new("myapp", "1.0.0", "John Doe")
+///     .with_license("MIT")
+///     .with_commit(Some("4f2a9c1"));
+///
+/// assert_eq!(INFO.license, Some("MIT"));
+/// assert_eq!(INFO.description, None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct BuildInfo {
+    /// Package name (`CARGO_PKG_NAME`)
+    pub name: &'static str,
+    /// Package version (`CARGO_PKG_VERSION`)
+    pub version: &'static str,
+    /// Package authors, colon-separated (`CARGO_PKG_AUTHORS`)
+    pub authors: &'static str,
+    /// Package description (`CARGO_PKG_DESCRIPTION`)
+    pub description: Option<&'static str>,
+    /// Homepage URL (`CARGO_PKG_HOMEPAGE`)
+    pub homepage: Option<&'static str>,
+    /// Repository URL (`CARGO_PKG_REPOSITORY`)
+    pub repository: Option<&'static str>,
+    /// License expression (`CARGO_PKG_LICENSE`)
+    pub license: Option<&'static str>,
+    /// Minimum supported Rust version (`CARGO_PKG_RUST_VERSION`)
+    pub rust_version: Option<&'static str>,
+    /// Source control commit the binary was built from
+    pub commit: Option<&'static str>,
+    /// Date or timestamp of the build
+    pub build_date: Option<&'static str>,
+    /// Packager-provided install method hint
+    pub install_method: Option<&'static str>,
+    /// Target triple the binary was compiled for
+    pub target: Option<&'static str>,
+    /// Enabled Cargo features, comma-separated
+    pub features: Option<&'static str>,
+    /// `rustc --version` of the compiler that built the binary
+    pub rustc: Option<&'static str>,
+    /// Optimization level (`0`–`3`, `s` or `z`)
+    pub opt_level: Option<&'static str>,
+    /// Cargo profile, `debug` or `release`
+    pub profile: Option<&'static str>,
+    /// Direct dependencies as comma-separated "name version" pairs
+    pub dependencies: Option<&'static str>,
+}
+
+/// Number of hex digits of the build id
+const BUILD_ID_LEN: usize = 7;
+
+/// Treats Cargo's empty-string "unset" values as `None`
+const fn non_empty(value: &'static str) -> Option<&'static str> {
+    if value.is_empty() { None } else { Some(value) }
+}
+
+/// Flattens an `option_env!` value, treating an empty value as unset
+const fn non_empty_opt(value: Option<&'static str>) -> Option<&'static str> {
+    match value {
+        Some(value) => non_empty(value),
+        None => None,
+    }
+}
+
+impl BuildInfo {
+    /// Version of the JSON schema produced by [`BuildInfo::to_json`]
+    pub const SCHEMA_VERSION: u32 = json::SCHEMA_VERSION;
+
+    /// Creates build info with the required fields and everything else unset
+    #[must_use]
+    pub const fn new(name: &'static str, version: &'static str, authors: &'static str) -> Self {
+        Self {
+            name,
+            version,
+            authors,
+            description: None,
+            homepage: None,
+            repository: None,
+            license: None,
+            rust_version: None,
+            commit: None,
+            build_date: None,
+            install_method: None,
+            target: None,
+            features: None,
+            rustc: None,
+            opt_level: None,
+            profile: None,
+            dependencies: None,
+        }
+    }
+
+    /// Sets the description; an empty string leaves it unset
+    #[must_use]
+    pub const fn with_description(mut self, description: &'static str) -> Self {
+        self.description = non_empty(description);
+        self
+    }
+
+    /// Sets the homepage; an empty string leaves it unset
+    #[must_use]
+    pub const fn with_homepage(mut self, homepage: &'static str) -> Self {
+        self.homepage = non_empty(homepage);
+        self
+    }
+
+    /// Sets the repository; an empty string leaves it unset
+    #[must_use]
+    pub const fn with_repository(mut self, repository: &'static str) -> Self {
+        self.repository = non_empty(repository);
+        self
+    }
+
+    /// Sets the license; an empty string leaves it unset
+    #[must_use]
+    pub const fn with_license(mut self, license: &'static str) -> Self {
+        self.license = non_empty(license);
+        self
+    }
+
+    /// Sets the minimum supported Rust version; an empty string leaves it unset
+    #[must_use]
+    pub const fn with_rust_version(mut self, rust_version: &'static str) -> Self {
+        self.rust_version = non_empty(rust_version);
+        self
+    }
+
+    /// Sets the source control commit
+    #[must_use]
+    pub const fn with_commit(mut self, commit: Option<&'static str>) -> Self {
+        self.commit = non_empty_opt(commit);
+        self
+    }
+
+    /// Sets the build date or timestamp
+    #[must_use]
+    pub const fn with_build_date(mut self, build_date: Option<&'static str>) -> Self {
+        self.build_date = non_empty_opt(build_date);
+        self
+    }
+
+    /// Sets the packager-provided install method hint
+    #[must_use]
+    pub const fn with_install_method(mut self, install_method: Option<&'static str>) -> Self {
+        self.install_method = non_empty_opt(install_method);
+        self
+    }
+
+    /// Sets the target triple
+    #[must_use]
+    pub const fn with_target(mut self, target: Option<&'static str>) -> Self {
+        self.target = non_empty_opt(target);
+        self
+    }
+
+    /// Sets the enabled features, comma-separated
+    #[must_use]
+    pub const fn with_features(mut self, features: Option<&'static str>) -> Self {
+        self.features = non_empty_opt(features);
+        self
+    }
+
+    /// Sets the `rustc --version` line
+    #[must_use]
+    pub const fn with_rustc(mut self, rustc: Option<&'static str>) -> Self {
+        self.rustc = non_empty_opt(rustc);
+        self
+    }
+
+    /// Sets the optimization level
+    #[must_use]
+    pub const fn with_opt_level(mut self, opt_level: Option<&'static str>) -> Self {
+        self.opt_level = non_empty_opt(opt_level);
+        self
+    }
+
+    /// Sets the Cargo profile
+    #[must_use]
+    pub const fn with_profile(mut self, profile: Option<&'static str>) -> Self {
+        self.profile = non_empty_opt(profile);
+        self
+    }
+
+    /// Sets the direct dependencies, as comma-separated "name version" pairs
+    #[must_use]
+    pub const fn with_dependencies(mut self, dependencies: Option<&'static str>) -> Self {
+        self.dependencies = non_empty_opt(dependencies);
+        self
+    }
+
+    /// Returns the individual authors from Cargo's colon-separated list
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::BuildInfo;
+    ///
+    /// let info = BuildInfo::new("myapp", "1.0.0", "Jane:John Doe <john@example.com>");
+    /// assert_eq!(
+    ///     info.author_list().collect::<Vec<_>>(),
+    ///     ["Jane", "John Doe <john@example.com>"]
+    /// );
+    /// ```
+    pub fn author_list(&self) -> impl Iterator<Item = &'static str> {
+        self.authors
+            .split(':')
+            .map(str::trim)
+            .filter(|author| !author.is_empty())
+    }
+
+    /// Returns the enabled features from the comma-separated list, sorted
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::BuildInfo;
+    ///
+    /// let info = BuildInfo::new("myapp", "1.0.0", "Jane").with_features(Some("tls, json"));
+    /// assert_eq!(info.feature_list(), ["json", "tls"]);
+    /// ```
+    #[must_use]
+    pub fn feature_list(&self) -> Vec<&'static str> {
+        let mut features: Vec<_> = self
+            .features
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|feature| !feature.is_empty())
+            .collect();
+        features.sort_unstable();
+        features.dedup();
+        features
+    }
+
+    /// Returns the direct dependencies as `(name, version)` pairs, sorted
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::BuildInfo;
+    ///
+    /// let info = BuildInfo::new("myapp", "1.0.0", "Jane")
+    ///     .with_dependencies(Some("serde 1.0.228,clap 4.5.53"));
+    /// assert_eq!(info.dependency_list(), [("clap", "4.5.53"), ("serde", "1.0.228")]);
+    /// ```
+    #[must_use]
+    pub fn dependency_list(&self) -> Vec<(&'static str, &'static str)> {
+        let mut dependencies: Vec<_> = self
+            .dependencies
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|pair| pair.trim().split_once(' '))
+            .map(|(name, version)| (name, version.trim()))
+            .collect();
+        dependencies.sort_unstable();
+        dependencies
+    }
+
+    /// Returns a short hash identifying the commit, target and features
+    ///
+    /// The hash is stable across runs, platforms and compiler versions. An
+    /// unset target falls back to the architecture and operating system
+    /// this crate was compiled for, and the feature order does not matter.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::BuildInfo;
+    ///
+    /// let info = BuildInfo::new("myapp", "1.0.0", "Jane")
+    ///     .with_commit(Some("4f2a9c1"))
+    ///     .with_target(Some("x86_64-unknown-linux-gnu"));
+    /// assert_eq!(info.build_id().len(), 7);
+    /// assert_ne!(info.build_id(), info.with_features(Some("tls")).build_id());
+    /// ```
+    #[must_use]
+    pub fn build_id(&self) -> String {
+        let fallback = format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS);
+        let target = self.target.unwrap_or(&fallback);
+        let features = self.feature_list().join(",");
+        let mut hash = Fnv1a::new();
+        for part in [self.commit.unwrap_or_default(), target, &features] {
+            hash.write(part.as_bytes());
+            // separate the parts, so ("ab", "c") and ("a", "bc") differ
+            hash.write(&[0]);
+        }
+        let mut id = format!("{:016x}", hash.finish());
+        id.truncate(BUILD_ID_LEN);
+        id
+    }
+
+    /// Returns the build info in the canonical JSON schema
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::BuildInfo;
+    ///
+    /// let info = BuildInfo::new("myapp", "1.0.0", "Jane");
+    /// assert!(info.to_json().starts_with(r#"{"schema_version":1,"name":"myapp""#));
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let dependencies = self
+            .dependency_list()
+            .into_iter()
+            .fold(JsonObject::new(), |object, (name, version)| {
+                object.string(name, version)
+            });
+        JsonObject::new()
+            .schema_version()
+            .string("name", self.name)
+            .string("version", self.version)
+            .string_array("authors", self.author_list())
+            .optional_string("description", self.description)
+            .optional_string("homepage", self.homepage)
+            .optional_string("repository", self.repository)
+            .optional_string("license", self.license)
+            .optional_string("rust_version", self.rust_version)
+            .optional_string("commit", self.commit)
+            .optional_string("build_date", self.build_date)
+            .optional_string("install_method", self.install_method)
+            .optional_string("target", self.target)
+            .string_array("features", self.feature_list())
+            .string("build_id", &self.build_id())
+            .optional_string("rustc", self.rustc)
+            .optional_string("opt_level", self.opt_level)
+            .optional_string("profile", self.profile)
+            .raw("dependencies", &dependencies.finish())
+            .finish()
+    }
+}
+
+/// 64-bit FNV-1a, a hash whose output never changes between releases
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for BuildInfo {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("BuildInfo", 19)?;
+        state.serialize_field("schema_version", &Self::SCHEMA_VERSION)?;
+        state.serialize_field("name", self.name)?;
+        state.serialize_field("version", self.version)?;
+        state.serialize_field("authors", &self.author_list().collect::<Vec<_>>())?;
+        state.serialize_field("description", &self.description)?;
+        state.serialize_field("homepage", &self.homepage)?;
+        state.serialize_field("repository", &self.repository)?;
+        state.serialize_field("license", &self.license)?;
+        state.serialize_field("rust_version", &self.rust_version)?;
+        state.serialize_field("commit", &self.commit)?;
+        state.serialize_field("build_date", &self.build_date)?;
+        state.serialize_field("install_method", &self.install_method)?;
+        state.serialize_field("target", &self.target)?;
+        state.serialize_field("features", &self.feature_list())?;
+        state.serialize_field("build_id", &self.build_id())?;
+        state.serialize_field("rustc", &self.rustc)?;
+        state.serialize_field("opt_level", &self.opt_level)?;
+        state.serialize_field("profile", &self.profile)?;
+        let dependencies: std::collections::BTreeMap<_, _> =
+            self.dependency_list().into_iter().collect();
+        state.serialize_field("dependencies", &dependencies)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_values_are_unset() {
+        const INFO: BuildInfo = BuildInfo::new("app", "1.0.0", "Jane")
+            .with_description("")
+            .with_homepage("https://example.com")
+            .with_commit(Some(""))
+            .with_build_date(None);
+
+        assert_eq!(INFO.description, None);
+        assert_eq!(INFO.homepage, Some("https://example.com"));
+        assert_eq!(INFO.commit, None);
+        assert_eq!(INFO.build_date, None);
+    }
+
+    #[test]
+    fn test_json_schema() {
+        const INFO: BuildInfo = BuildInfo::new("app", "1.0.0", "Jane:John")
+            .with_license("MIT")
+            .with_commit(Some("abc1234"))
+            .with_target(Some("x86_64-unknown-linux-gnu"));
+
+        assert_eq!(
+            INFO.to_json(),
+            r#"{"schema_version":1,"name":"app","version":"1.0.0","authors":["Jane","John"],"description":null,"homepage":null,"repository":null,"license":"MIT","rust_version":null,"commit":"abc1234","build_date":null,"install_method":null,"target":"x86_64-unknown-linux-gnu","features":[],"build_id":"539e66e","rustc":null,"opt_level":null,"profile":null,"dependencies":{}}"#
+        );
+        let parsed: serde_json::Value = serde_json::from_str(&INFO.to_json()).unwrap();
+        assert_eq!(parsed["schema_version"], BuildInfo::SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_build_id() {
+        // FNV-1a reference vectors, so the ids never change between releases
+        let fnv = |bytes: &[u8]| {
+            let mut hash = Fnv1a::new();
+            hash.write(bytes);
+            hash.finish()
+        };
+        assert_eq!(fnv(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv(b"a"), 0xaf63_dc4c_8601_ec8c);
+
+        let info = BuildInfo::new("app", "1.0.0", "Jane")
+            .with_commit(Some("abc1234"))
+            .with_target(Some("x86_64-unknown-linux-gnu"));
+        let with_features = info.with_features(Some("tls,json"));
+        assert_eq!(
+            with_features.build_id(),
+            info.with_features(Some("json, tls,")).build_id()
+        );
+        assert_ne!(with_features.build_id(), info.build_id());
+        assert_ne!(
+            info.with_commit(Some("abc1235")).build_id(),
+            info.build_id()
+        );
+        assert!(info.build_id().chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_matches_to_json() {
+        let info = BuildInfo::new("app", "1.0.0", "Jane").with_homepage("https://example.com");
+        assert_eq!(serde_json::to_string(&info).unwrap(), info.to_json());
+    }
+
+    #[test]
+    fn test_generated_constant() {
+        crate::generate_build_info!(pub(crate) GENERATED);
+
+        assert_eq!(GENERATED.name, env!("CARGO_PKG_NAME"));
+        assert_eq!(GENERATED.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(GENERATED.license, Some("MIT OR Apache-2.0"));
+        assert_eq!(
+            GENERATED.repository,
+            Some("https://github.com/cumulus13/clap-version-flag")
+        );
+    }
+}
@@ -0,0 +1,150 @@
+// Project: clap-version-flag
+// File: src/builder.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Builder for ColorfulVersion with optional metadata fields
+// License: MIT
+
+use std::borrow::Cow;
+
+use crate::{ColorSpec, ColorfulVersion, Field, Verbosity};
+
+/// Builder for a [`ColorfulVersion`] carrying optional metadata
+///
+/// Every field is rendered as a "key: value" line below the version line, in
+/// the order it was added. Values use the author color unless a color was
+/// given with [`ColorfulVersionBuilder::field_color`].
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::ColorfulVersionBuilder;
+///
+/// let version = ColorfulVersionBuilder::new("myapp", "1.0.0", "John Doe")
+///     .description("Does useful things")
+///     .homepage("https://example.com")
+///     .copyright_year(2025)
+///     .extra("channel", "beta")
+///     .field_color("channel", (255, 128, 0))
+///     .build();
+///
+/// assert_eq!(version.homepage(), Some("https://example.com"));
+/// assert_eq!(version.metadata("channel"), Some("beta"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ColorfulVersionBuilder {
+    version: ColorfulVersion,
+}
+
+impl ColorfulVersionBuilder {
+    /// Creates a builder with the required name, version and author
+    #[must_use]
+    pub fn new(
+        package_name: impl Into<String>,
+        version: impl Into<String>,
+        author: impl Into<String>,
+    ) -> Self {
+        Self {
+            version: ColorfulVersion::new(package_name, version, author),
+        }
+    }
+
+    /// Sets a one-line description of the application
+    #[must_use]
+    pub fn description(self, description: impl Into<String>) -> Self {
+        self.extra("description", description)
+    }
+
+    /// Sets the homepage URL
+    #[must_use]
+    pub fn homepage(self, homepage: impl Into<String>) -> Self {
+        self.extra("homepage", homepage)
+    }
+
+    /// Sets the source repository URL
+    #[must_use]
+    pub fn repository(self, repository: impl Into<String>) -> Self {
+        self.extra("repository", repository)
+    }
+
+    /// Sets the license expression (e.g. "MIT OR Apache-2.0")
+    #[must_use]
+    pub fn license(self, license: impl Into<String>) -> Self {
+        self.extra("license", license)
+    }
+
+    /// Sets the copyright year, rendered as "© {year} {author}"
+    #[must_use]
+    pub fn copyright_year(self, year: u16) -> Self {
+        let copyright = format!("© {year} {}", self.version.author());
+        self.extra("copyright", copyright)
+    }
+
+    /// Adds an arbitrary key/value field
+    ///
+    /// Setting a key that already exists replaces its value in place.
+    #[must_use]
+    pub fn extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        let key = key.into();
+        let value = Cow::Owned(value.into());
+        match self.version.metadata.iter_mut().find(|f| f.key == key) {
+            Some(field) => field.value = value,
+            None => self.version.metadata.push(Field {
+                key: key.into(),
+                value,
+                color: None,
+                level: Verbosity::Normal,
+            }),
+        }
+        self
+    }
+
+    /// Sets the color of a field's value
+    ///
+    /// Has no effect if the field has not been added yet.
+    #[must_use]
+    pub fn field_color(mut self, key: &str, color: impl Into<ColorSpec>) -> Self {
+        if let Some(field) = self.version.metadata.iter_mut().find(|f| f.key == key) {
+            field.color = Some(color.into().rgb());
+        }
+        self
+    }
+
+    /// Finishes the builder
+    #[must_use]
+    pub fn build(self) -> ColorfulVersion {
+        self.version
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_fields() {
+        let version = ColorfulVersionBuilder::new("myapp", "1.0.0", "Jane")
+            .repository("https://github.com/jane/myapp")
+            .license("MIT")
+            .copyright_year(2025)
+            .build();
+
+        assert_eq!(version.repository(), Some("https://github.com/jane/myapp"));
+        assert_eq!(version.license(), Some("MIT"));
+        assert_eq!(version.metadata("copyright"), Some("© 2025 Jane"));
+        assert_eq!(version.description(), None);
+    }
+
+    #[test]
+    fn test_extra_replaces_and_colors() {
+        let version = ColorfulVersionBuilder::new("myapp", "1.0.0", "Jane")
+            .extra("channel", "beta")
+            .field_color("channel", (1, 2, 3))
+            .field_color("missing", (4, 5, 6))
+            .extra("channel", "stable")
+            .build();
+
+        assert_eq!(version.metadata.len(), 1);
+        assert_eq!(version.metadata[0].value, "stable");
+        assert_eq!(version.metadata[0].color, Some((1, 2, 3)));
+    }
+}
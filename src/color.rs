@@ -0,0 +1,1039 @@
+// Project: clap-version-flag
+// File: src/color.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Terminal color depth detection and RGB quantization
+// License: MIT
+
+//! Color parsing, depth detection and quantization.
+//!
+//! [`parse_color`] accepts hex codes, the CSS named colors and the CSS
+//! `rgb()`/`hsl()` functional notations; [`ColorSpec`] wraps the result for
+//! the builder methods.
+//!
+//! Not every terminal understands 24-bit truecolor escape sequences. This
+//! module detects what the terminal supports from `COLORTERM`/`TERM` and maps
+//! RGB values to the nearest xterm-256 or ANSI-16 color when needed.
+
+use crate::VersionError;
+use std::env;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+/// An RGB color as `(red, green, blue)`
+pub type Rgb = (u8, u8, u8);
+
+/// The number of colors the terminal can display
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorDepth {
+    /// 24-bit RGB (`ESC[38;2;R;G;Bm`)
+    TrueColor,
+    /// xterm 256-color palette (`ESC[38;5;Nm`)
+    Ansi256,
+    /// The 16 basic ANSI colors (`ESC[31m`, `ESC[91m`, ...)
+    Ansi16,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the current terminal from the environment
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorDepth;
+    ///
+    /// let depth = ColorDepth::detect();
+    /// println!("{depth:?}");
+    /// ```
+    #[must_use]
+    pub fn detect() -> Self {
+        if env::var_os("WT_SESSION").is_some() {
+            // Windows Terminal supports truecolor but does not set COLORTERM
+            return Self::TrueColor;
+        }
+        Self::from_env(
+            env::var("COLORTERM").ok().as_deref(),
+            env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    /// Determines the color depth from `COLORTERM` and `TERM` values
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorDepth;
+    ///
+    /// assert_eq!(ColorDepth::from_env(Some("truecolor"), None), ColorDepth::TrueColor);
+    /// assert_eq!(ColorDepth::from_env(None, Some("xterm-256color")), ColorDepth::Ansi256);
+    /// assert_eq!(ColorDepth::from_env(None, Some("xterm")), ColorDepth::Ansi16);
+    /// ```
+    #[must_use]
+    pub fn from_env(colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return Self::TrueColor;
+        }
+        match term {
+            Some(term) if term.ends_with("-direct") || term == "xterm-kitty" => Self::TrueColor,
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            _ => Self::Ansi16,
+        }
+    }
+}
+
+/// The xterm default values of the 16 ANSI colors
+const ANSI16_PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// The CSS Color Module Level 4 named colors, sorted by name
+const CSS_COLORS: [(&str, Rgb); 148] = [
+    ("aliceblue", (240, 248, 255)),
+    ("antiquewhite", (250, 235, 215)),
+    ("aqua", (0, 255, 255)),
+    ("aquamarine", (127, 255, 212)),
+    ("azure", (240, 255, 255)),
+    ("beige", (245, 245, 220)),
+    ("bisque", (255, 228, 196)),
+    ("black", (0, 0, 0)),
+    ("blanchedalmond", (255, 235, 205)),
+    ("blue", (0, 0, 255)),
+    ("blueviolet", (138, 43, 226)),
+    ("brown", (165, 42, 42)),
+    ("burlywood", (222, 184, 135)),
+    ("cadetblue", (95, 158, 160)),
+    ("chartreuse", (127, 255, 0)),
+    ("chocolate", (210, 105, 30)),
+    ("coral", (255, 127, 80)),
+    ("cornflowerblue", (100, 149, 237)),
+    ("cornsilk", (255, 248, 220)),
+    ("crimson", (220, 20, 60)),
+    ("cyan", (0, 255, 255)),
+    ("darkblue", (0, 0, 139)),
+    ("darkcyan", (0, 139, 139)),
+    ("darkgoldenrod", (184, 134, 11)),
+    ("darkgray", (169, 169, 169)),
+    ("darkgreen", (0, 100, 0)),
+    ("darkgrey", (169, 169, 169)),
+    ("darkkhaki", (189, 183, 107)),
+    ("darkmagenta", (139, 0, 139)),
+    ("darkolivegreen", (85, 107, 47)),
+    ("darkorange", (255, 140, 0)),
+    ("darkorchid", (153, 50, 204)),
+    ("darkred", (139, 0, 0)),
+    ("darksalmon", (233, 150, 122)),
+    ("darkseagreen", (143, 188, 143)),
+    ("darkslateblue", (72, 61, 139)),
+    ("darkslategray", (47, 79, 79)),
+    ("darkslategrey", (47, 79, 79)),
+    ("darkturquoise", (0, 206, 209)),
+    ("darkviolet", (148, 0, 211)),
+    ("deeppink", (255, 20, 147)),
+    ("deepskyblue", (0, 191, 255)),
+    ("dimgray", (105, 105, 105)),
+    ("dimgrey", (105, 105, 105)),
+    ("dodgerblue", (30, 144, 255)),
+    ("firebrick", (178, 34, 34)),
+    ("floralwhite", (255, 250, 240)),
+    ("forestgreen", (34, 139, 34)),
+    ("fuchsia", (255, 0, 255)),
+    ("gainsboro", (220, 220, 220)),
+    ("ghostwhite", (248, 248, 255)),
+    ("gold", (255, 215, 0)),
+    ("goldenrod", (218, 165, 32)),
+    ("gray", (128, 128, 128)),
+    ("green", (0, 128, 0)),
+    ("greenyellow", (173, 255, 47)),
+    ("grey", (128, 128, 128)),
+    ("honeydew", (240, 255, 240)),
+    ("hotpink", (255, 105, 180)),
+    ("indianred", (205, 92, 92)),
+    ("indigo", (75, 0, 130)),
+    ("ivory", (255, 255, 240)),
+    ("khaki", (240, 230, 140)),
+    ("lavender", (230, 230, 250)),
+    ("lavenderblush", (255, 240, 245)),
+    ("lawngreen", (124, 252, 0)),
+    ("lemonchiffon", (255, 250, 205)),
+    ("lightblue", (173, 216, 230)),
+    ("lightcoral", (240, 128, 128)),
+    ("lightcyan", (224, 255, 255)),
+    ("lightgoldenrodyellow", (250, 250, 210)),
+    ("lightgray", (211, 211, 211)),
+    ("lightgreen", (144, 238, 144)),
+    ("lightgrey", (211, 211, 211)),
+    ("lightpink", (255, 182, 193)),
+    ("lightsalmon", (255, 160, 122)),
+    ("lightseagreen", (32, 178, 170)),
+    ("lightskyblue", (135, 206, 250)),
+    ("lightslategray", (119, 136, 153)),
+    ("lightslategrey", (119, 136, 153)),
+    ("lightsteelblue", (176, 196, 222)),
+    ("lightyellow", (255, 255, 224)),
+    ("lime", (0, 255, 0)),
+    ("limegreen", (50, 205, 50)),
+    ("linen", (250, 240, 230)),
+    ("magenta", (255, 0, 255)),
+    ("maroon", (128, 0, 0)),
+    ("mediumaquamarine", (102, 205, 170)),
+    ("mediumblue", (0, 0, 205)),
+    ("mediumorchid", (186, 85, 211)),
+    ("mediumpurple", (147, 112, 219)),
+    ("mediumseagreen", (60, 179, 113)),
+    ("mediumslateblue", (123, 104, 238)),
+    ("mediumspringgreen", (0, 250, 154)),
+    ("mediumturquoise", (72, 209, 204)),
+    ("mediumvioletred", (199, 21, 133)),
+    ("midnightblue", (25, 25, 112)),
+    ("mintcream", (245, 255, 250)),
+    ("mistyrose", (255, 228, 225)),
+    ("moccasin", (255, 228, 181)),
+    ("navajowhite", (255, 222, 173)),
+    ("navy", (0, 0, 128)),
+    ("oldlace", (253, 245, 230)),
+    ("olive", (128, 128, 0)),
+    ("olivedrab", (107, 142, 35)),
+    ("orange", (255, 165, 0)),
+    ("orangered", (255, 69, 0)),
+    ("orchid", (218, 112, 214)),
+    ("palegoldenrod", (238, 232, 170)),
+    ("palegreen", (152, 251, 152)),
+    ("paleturquoise", (175, 238, 238)),
+    ("palevioletred", (219, 112, 147)),
+    ("papayawhip", (255, 239, 213)),
+    ("peachpuff", (255, 218, 185)),
+    ("peru", (205, 133, 63)),
+    ("pink", (255, 192, 203)),
+    ("plum", (221, 160, 221)),
+    ("powderblue", (176, 224, 230)),
+    ("purple", (128, 0, 128)),
+    ("rebeccapurple", (102, 51, 153)),
+    ("red", (255, 0, 0)),
+    ("rosybrown", (188, 143, 143)),
+    ("royalblue", (65, 105, 225)),
+    ("saddlebrown", (139, 69, 19)),
+    ("salmon", (250, 128, 114)),
+    ("sandybrown", (244, 164, 96)),
+    ("seagreen", (46, 139, 87)),
+    ("seashell", (255, 245, 238)),
+    ("sienna", (160, 82, 45)),
+    ("silver", (192, 192, 192)),
+    ("skyblue", (135, 206, 235)),
+    ("slateblue", (106, 90, 205)),
+    ("slategray", (112, 128, 144)),
+    ("slategrey", (112, 128, 144)),
+    ("snow", (255, 250, 250)),
+    ("springgreen", (0, 255, 127)),
+    ("steelblue", (70, 130, 180)),
+    ("tan", (210, 180, 140)),
+    ("teal", (0, 128, 128)),
+    ("thistle", (216, 191, 216)),
+    ("tomato", (255, 99, 71)),
+    ("turquoise", (64, 224, 208)),
+    ("violet", (238, 130, 238)),
+    ("wheat", (245, 222, 179)),
+    ("white", (255, 255, 255)),
+    ("whitesmoke", (245, 245, 245)),
+    ("yellow", (255, 255, 0)),
+    ("yellowgreen", (154, 205, 50)),
+];
+
+/// Parses a color given as hex (`#RRGGBB`, `#RGB`, `#` optional), a CSS/X11
+/// color name, `rgb(R, G, B)` or `hsl(H, S%, L%)`
+///
+/// Names are matched case-insensitively against the 148 CSS named colors.
+/// The functional forms take commas or spaces between components, as copied
+/// from design tools: `rgb()` channels are `0`-`255` or percentages, the
+/// `hsl()` hue is in degrees (an optional `deg` suffix is allowed).
+///
+/// # Errors
+/// Returns [`VersionError::InvalidHexColor`] for malformed hex colors and
+/// [`VersionError::InvalidColor`] for unknown names.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::color::parse_color;
+///
+/// assert_eq!(parse_color("rebeccapurple").unwrap(), (102, 51, 153));
+/// assert_eq!(parse_color("SlateGray").unwrap(), (112, 128, 144));
+/// assert_eq!(parse_color("#0ff").unwrap(), (0, 255, 255));
+/// assert_eq!(parse_color("rgb(170, 0, 255)").unwrap(), (170, 0, 255));
+/// assert_eq!(parse_color("hsl(280, 100%, 50%)").unwrap(), (170, 0, 255));
+/// assert!(parse_color("notacolor").is_err());
+/// ```
+pub fn parse_color(value: &str) -> Result<Rgb, VersionError> {
+    let value = value.trim();
+    if value.starts_with('#') {
+        return crate::parse_hex(value);
+    }
+    let name = value.to_ascii_lowercase();
+    if let Some((function, args)) = name.strip_suffix(')').and_then(|rest| rest.split_once('(')) {
+        return parse_functional(function.trim(), args)
+            .ok_or_else(|| VersionError::InvalidColor(value.to_string()));
+    }
+    match CSS_COLORS.binary_search_by(|(candidate, _)| (*candidate).cmp(name.as_str())) {
+        Ok(index) => Ok(CSS_COLORS[index].1),
+        // Hex without the leading '#', as the deprecated `with_hex_colors` accepted
+        Err(_) if matches!(value.len(), 3 | 6) && value.chars().all(|c| c.is_ascii_hexdigit()) => {
+            crate::parse_hex(value)
+        }
+        Err(_) => Err(VersionError::InvalidColor(value.to_string())),
+    }
+}
+
+/// Evaluates `rgb(...)` or `hsl(...)` arguments
+fn parse_functional(function: &str, args: &str) -> Option<Rgb> {
+    let args: Vec<&str> = args
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    let [a, b, c] = args[..] else {
+        return None;
+    };
+
+    match function {
+        "rgb" => Some((channel(a)?, channel(b)?, channel(c)?)),
+        "hsl" => {
+            let hue = a.strip_suffix("deg").unwrap_or(a).parse::<f64>().ok()?;
+            Some(hsl_to_rgb(hue, percentage(b)?, percentage(c)?))
+        }
+        _ => None,
+    }
+}
+
+/// Parses an `rgb()` channel, either `0`-`255` or a percentage
+fn channel(value: &str) -> Option<u8> {
+    if value.ends_with('%') {
+        return Some(unit_to_u8(percentage(value)?));
+    }
+    value.parse().ok()
+}
+
+/// Parses `0%`-`100%` into `0.0`-`1.0`
+fn percentage(value: &str) -> Option<f64> {
+    let percent = value.strip_suffix('%')?.parse::<f64>().ok()?;
+    (0.0..=100.0).contains(&percent).then_some(percent / 100.0)
+}
+
+/// Maps `0.0`-`1.0` onto `0`-`255`
+fn unit_to_u8(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Converts hue (degrees), saturation and lightness (`0.0`-`1.0`) to RGB
+pub(crate) fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> Rgb {
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let (r, g, b) = match hue {
+        h if h < 1.0 => (chroma, x, 0.0),
+        h if h < 2.0 => (x, chroma, 0.0),
+        h if h < 3.0 => (0.0, chroma, x),
+        h if h < 4.0 => (0.0, x, chroma),
+        h if h < 5.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    (unit_to_u8(r + m), unit_to_u8(g + m), unit_to_u8(b + m))
+}
+
+/// A color accepted by the builder methods
+///
+/// Converts from RGB tuples and, fallibly, from every string form
+/// [`parse_color`] understands, so values can be copied straight from design
+/// tools.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::ColorSpec;
+///
+/// let spec: ColorSpec = "hsl(280, 100%, 50%)".parse().unwrap();
+/// assert_eq!(spec, ColorSpec::from((170, 0, 255)));
+/// assert_eq!(spec.to_string(), "#AA00FF");
+/// assert!(ColorSpec::try_from("rgb(300, 0, 0)").is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ColorSpec(Rgb);
+
+impl ColorSpec {
+    /// Creates a color from its channels
+    #[must_use]
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self((red, green, blue))
+    }
+
+    /// Returns the color as an RGB tuple
+    #[must_use]
+    pub const fn rgb(self) -> Rgb {
+        self.0
+    }
+
+    /// Parses `#RRGGBB` or `#RGB` (the `#` is optional) in a const context
+    ///
+    /// Accepts exactly the hex forms [`parse_color`] does, but can be
+    /// evaluated at compile time, where an invalid color becomes a
+    /// compile error. The [`hex!`](crate::hex) macro forces that evaluation.
+    ///
+    /// # Panics
+    /// Panics if `hex` is not a valid hex color; at compile time when used in
+    /// a constant.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorSpec;
+    ///
+    /// const PURPLE: ColorSpec = ColorSpec::from_hex_const("#AA00FF");
+    /// assert_eq!(PURPLE.rgb(), (170, 0, 255));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use clap_version_flag::ColorSpec;
+    ///
+    /// const BROKEN: ColorSpec = ColorSpec::from_hex_const("#GGG");
+    /// ```
+    #[must_use]
+    pub const fn from_hex_const(hex: &str) -> Self {
+        let mut digits = hex.as_bytes();
+        while let [b'#', rest @ ..] = digits {
+            digits = rest;
+        }
+        match digits {
+            [r1, r2, g1, g2, b1, b2] => {
+                Self((hex_pair(*r1, *r2), hex_pair(*g1, *g2), hex_pair(*b1, *b2)))
+            }
+            [r, g, b] => Self((hex_pair(*r, *r), hex_pair(*g, *g), hex_pair(*b, *b))),
+            _ => panic!("invalid hex color: expected #RRGGBB or #RGB"),
+        }
+    }
+}
+
+/// Combines two hex digits into a byte, panicking on anything else
+const fn hex_pair(high: u8, low: u8) -> u8 {
+    hex_digit(high) << 4 | hex_digit(low)
+}
+
+const fn hex_digit(digit: u8) -> u8 {
+    match digit {
+        b'0'..=b'9' => digit - b'0',
+        b'a'..=b'f' => digit - b'a' + 10,
+        b'A'..=b'F' => digit - b'A' + 10,
+        _ => panic!("invalid hex color: not a hex digit"),
+    }
+}
+
+impl From<Rgb> for ColorSpec {
+    fn from(rgb: Rgb) -> Self {
+        Self(rgb)
+    }
+}
+
+impl FromStr for ColorSpec {
+    type Err = VersionError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_color(value).map(Self)
+    }
+}
+
+impl TryFrom<&str> for ColorSpec {
+    type Error = VersionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&String> for ColorSpec {
+    type Error = VersionError;
+
+    fn try_from(value: &String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for ColorSpec {
+    type Error = VersionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl fmt::Display for ColorSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (r, g, b) = self.0;
+        write!(f, "#{r:02X}{g:02X}{b:02X}")
+    }
+}
+
+/// Channel levels of the 6x6x6 color cube in the xterm 256-color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = u32::from(a.0.abs_diff(b.0));
+    let dg = u32::from(a.1.abs_diff(b.1));
+    let db = u32::from(a.2.abs_diff(b.2));
+    dr * dr + dg * dg + db * db
+}
+
+fn nearest_cube_level(channel: u8) -> usize {
+    CUBE_LEVELS
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, level)| level.abs_diff(channel))
+        .map_or(0, |(index, _)| index)
+}
+
+/// Maps an RGB color to the nearest xterm-256 palette index
+///
+/// Both the 6x6x6 color cube and the 24-step grayscale ramp are considered.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::color::to_ansi256;
+///
+/// assert_eq!(to_ansi256((255, 0, 0)), 196);
+/// assert_eq!(to_ansi256((128, 128, 128)), 244);
+/// ```
+#[must_use]
+pub fn to_ansi256(rgb: (u8, u8, u8)) -> u8 {
+    let (r, g, b) = (
+        nearest_cube_level(rgb.0),
+        nearest_cube_level(rgb.1),
+        nearest_cube_level(rgb.2),
+    );
+    let cube_rgb = (CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+    let cube_index = 16 + 36 * r + 6 * g + b;
+
+    let average = (u32::from(rgb.0) + u32::from(rgb.1) + u32::from(rgb.2)) / 3;
+    let gray_step = (average.saturating_sub(3) / 10).min(23);
+    let gray_value = (8 + gray_step * 10) as u8;
+    let gray_index = 232 + gray_step as usize;
+
+    if distance(rgb, (gray_value, gray_value, gray_value)) < distance(rgb, cube_rgb) {
+        gray_index as u8
+    } else {
+        cube_index as u8
+    }
+}
+
+/// Returns the color of an entry of the 256-color palette
+pub(crate) fn ansi256_rgb(index: u8) -> Rgb {
+    match index {
+        0..=15 => ANSI16_PALETTE[usize::from(index)],
+        16..=231 => {
+            let cube = usize::from(index - 16);
+            (
+                CUBE_LEVELS[cube / 36],
+                CUBE_LEVELS[cube / 6 % 6],
+                CUBE_LEVELS[cube % 6],
+            )
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            (gray, gray, gray)
+        }
+    }
+}
+
+/// Maps an RGB color to the nearest of the 16 ANSI colors (0-15)
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::color::to_ansi16;
+///
+/// assert_eq!(to_ansi16((255, 255, 0)), 11); // bright yellow
+/// assert_eq!(to_ansi16((10, 10, 10)), 0); // black
+/// ```
+#[must_use]
+pub fn to_ansi16(rgb: (u8, u8, u8)) -> u8 {
+    ANSI16_PALETTE
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, color)| distance(rgb, **color))
+        .map_or(0, |(index, _)| index as u8)
+}
+
+/// Returns whether the terminal renders OSC 8 hyperlinks
+///
+/// `FORCE_HYPERLINK=1` or `FORCE_HYPERLINK=0` overrides the detection.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::color::supports_hyperlinks;
+///
+/// println!("hyperlinks: {}", supports_hyperlinks());
+/// ```
+#[must_use]
+pub fn supports_hyperlinks() -> bool {
+    if let Ok(force) = env::var("FORCE_HYPERLINK") {
+        return !matches!(force.as_str(), "0" | "false" | "");
+    }
+    if env::var_os("WT_SESSION").is_some() {
+        return true;
+    }
+    supports_hyperlinks_from_env(
+        env::var("TERM_PROGRAM").ok().as_deref(),
+        env::var("TERM").ok().as_deref(),
+        env::var("VTE_VERSION").ok().as_deref(),
+    )
+}
+
+/// Determines OSC 8 hyperlink support from `TERM_PROGRAM`, `TERM` and `VTE_VERSION`
+///
+/// Recognizes iTerm2, WezTerm, kitty, VS Code, Ghostty and VTE-based
+/// terminals such as recent gnome-terminal (VTE 0.50 and later).
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::color::supports_hyperlinks_from_env;
+///
+/// assert!(supports_hyperlinks_from_env(Some("iTerm.app"), None, None));
+/// assert!(supports_hyperlinks_from_env(None, Some("xterm-kitty"), None));
+/// assert!(supports_hyperlinks_from_env(None, None, Some("6003")));
+/// assert!(!supports_hyperlinks_from_env(None, Some("xterm-256color"), None));
+/// ```
+#[must_use]
+pub fn supports_hyperlinks_from_env(
+    term_program: Option<&str>,
+    term: Option<&str>,
+    vte_version: Option<&str>,
+) -> bool {
+    matches!(
+        term_program,
+        Some("iTerm.app" | "WezTerm" | "vscode" | "ghostty")
+    ) || matches!(term, Some("xterm-kitty" | "wezterm" | "xterm-ghostty"))
+        || vte_version
+            .and_then(|version| version.parse::<u32>().ok())
+            .is_some_and(|version| version >= 5000)
+}
+
+/// Wraps `text` in an OSC 8 hyperlink to `url`
+pub(crate) fn hyperlink(text: &str, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// Returns whether colored output should be produced at all
+///
+/// Follows the same rules as the `colored` crate (`NO_COLOR`, `CLICOLOR`,
+/// `CLICOLOR_FORCE`, tty detection and manual overrides).
+pub(crate) fn should_colorize() -> bool {
+    #[cfg(feature = "no-color")]
+    {
+        false
+    }
+    #[cfg(not(feature = "no-color"))]
+    {
+        colored::control::SHOULD_COLORIZE.should_colorize()
+    }
+}
+
+/// Forces colors on or off, for this crate and the `colored` crate alike
+///
+/// Passes through to `colored::control::set_override`, so an application
+/// that manages color globally, e.g. from a `--color` option, gets the same
+/// decision for its own output and the version line. Overrides set directly
+/// on `colored::control` are honored the same way, including by the outputs
+/// written to stderr, such as the
+/// [panic hook](crate::ColorfulVersion::install_panic_hook). Has no effect
+/// with the `no-color` feature.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::color;
+///
+/// color::set_override(false);
+/// assert!(!color::colors_enabled());
+/// color::unset_override();
+/// ```
+pub fn set_override(enabled: bool) {
+    colored::control::set_override(enabled);
+}
+
+/// Removes the override, returning to `NO_COLOR`/`CLICOLOR`/tty detection
+pub fn unset_override() {
+    colored::control::unset_override();
+}
+
+/// Returns the override in effect, if it changes the decision
+///
+/// `colored` keeps the only record of the override, so it is recovered by
+/// comparing its decision with what the environment alone decides. An
+/// override that agrees with the environment changes nothing and reports
+/// `None`.
+#[must_use]
+pub fn color_override() -> Option<bool> {
+    let decision = colored::control::SHOULD_COLORIZE.should_colorize();
+    (decision != colored::control::ShouldColorize::from_env().should_colorize()).then_some(decision)
+}
+
+/// Returns whether this crate currently emits colors
+///
+/// The same decision `print()` and the version flag make.
+#[must_use]
+pub fn colors_enabled() -> bool {
+    should_colorize()
+}
+
+fn push_sgr_color(
+    out: &mut impl Write,
+    rgb: (u8, u8, u8),
+    depth: ColorDepth,
+    background: bool,
+) -> fmt::Result {
+    match depth {
+        ColorDepth::TrueColor => write!(
+            out,
+            "{};2;{};{};{}",
+            if background { 48 } else { 38 },
+            rgb.0,
+            rgb.1,
+            rgb.2
+        ),
+        ColorDepth::Ansi256 => write!(
+            out,
+            "{};5;{}",
+            if background { 48 } else { 38 },
+            to_ansi256(rgb)
+        ),
+        ColorDepth::Ansi16 => {
+            let index = to_ansi16(rgb);
+            let base = match (background, index < 8) {
+                (false, true) => 30,
+                (false, false) => 90 - 8,
+                (true, true) => 40,
+                (true, false) => 100 - 8,
+            };
+            write!(out, "{}", base + index)
+        }
+    }
+}
+
+/// Wraps `text` in SGR escape codes for the given colors and depth
+pub(crate) fn paint(
+    text: &str,
+    fg: (u8, u8, u8),
+    bg: Option<(u8, u8, u8)>,
+    depth: ColorDepth,
+) -> String {
+    paint_with(text, Some(fg), bg, &[], depth)
+}
+
+/// Wraps `text` in SGR escape codes for attributes (bold, ...) and colors
+///
+/// Returns `text` unchanged when there is nothing to apply.
+pub(crate) fn paint_with(
+    text: &str,
+    fg: Option<(u8, u8, u8)>,
+    bg: Option<(u8, u8, u8)>,
+    attributes: &[u8],
+    depth: ColorDepth,
+) -> String {
+    let mut out = String::with_capacity(text.len() + 32);
+    let _ = write_painted(&mut out, text, fg, bg, attributes, depth);
+    out
+}
+
+/// Writes `text` wrapped in SGR escape codes straight to `out`
+///
+/// The allocation-free core of [`paint_with`]: `text` can be any
+/// [`Display`](fmt::Display), e.g. `format_args!`, and the codes are
+/// written piece by piece.
+pub(crate) fn write_painted(
+    out: &mut impl Write,
+    text: impl fmt::Display,
+    fg: Option<(u8, u8, u8)>,
+    bg: Option<(u8, u8, u8)>,
+    attributes: &[u8],
+    depth: ColorDepth,
+) -> fmt::Result {
+    if fg.is_none() && bg.is_none() && attributes.is_empty() {
+        return write!(out, "{text}");
+    }
+    out.write_str("\x1b[")?;
+    let mut separator = "";
+    for attribute in attributes {
+        write!(out, "{separator}{attribute}")?;
+        separator = ";";
+    }
+    if let Some(fg) = fg {
+        out.write_str(separator)?;
+        push_sgr_color(out, fg, depth, false)?;
+        separator = ";";
+    }
+    if let Some(bg) = bg {
+        out.write_str(separator)?;
+        push_sgr_color(out, bg, depth, true)?;
+    }
+    write!(out, "m{text}\x1b[0m")
+}
+
+/// Returns `steps` colors blending evenly from `from` to `to`
+#[cfg_attr(not(feature = "figlet"), allow(dead_code))]
+pub(crate) fn gradient(from: Rgb, to: Rgb, steps: usize) -> Vec<Rgb> {
+    let last = steps.saturating_sub(1).max(1);
+    let blend = |a: u8, b: u8, step: usize| {
+        let (a, b) = (usize::from(a), usize::from(b));
+        let value = if b >= a {
+            a + (b - a) * step / last
+        } else {
+            a - (a - b) * step / last
+        };
+        u8::try_from(value).unwrap_or(u8::MAX)
+    };
+    (0..steps)
+        .map(|step| {
+            (
+                blend(from.0, to.0, step),
+                blend(from.1, to.1, step),
+                blend(from.2, to.2, step),
+            )
+        })
+        .collect()
+}
+
+/// A piece of rendered output, as split by [`pieces`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Piece<'a> {
+    /// Text without escape sequences
+    Text(&'a str),
+    /// The parameters of an SGR sequence (`ESC [ ... m`)
+    Sgr(&'a str),
+    /// Any other escape sequence, such as a hyperlink
+    Control,
+}
+
+/// Splits `line` into text and ANSI escape sequences (CSI and OSC)
+pub(crate) fn pieces(line: &str) -> impl Iterator<Item = Piece<'_>> {
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let start = rest.find('\x1b').unwrap_or(rest.len());
+        if start > 0 {
+            let (text, tail) = rest.split_at(start);
+            rest = tail;
+            return Some(Piece::Text(text));
+        }
+        let body = &rest[1..];
+        let (piece, len) = match body.chars().next() {
+            // CSI: parameters up to a final byte in '@'..='~'
+            Some('[') => {
+                let params = &body[1..];
+                match params.find(|c| ('@'..='~').contains(&c)) {
+                    Some(end) if params[end..].starts_with('m') => {
+                        (Piece::Sgr(&params[..end]), end + 3)
+                    }
+                    Some(end) => (Piece::Control, end + 3),
+                    None => (Piece::Control, rest.len()),
+                }
+            }
+            // OSC: up to BEL or ST (ESC \)
+            Some(']') => {
+                let osc = &body[1..];
+                let end = osc
+                    .char_indices()
+                    .find_map(|(index, c)| match c {
+                        '\x07' => Some(index + 1),
+                        '\x1b' if osc[index + 1..].starts_with('\\') => Some(index + 2),
+                        _ => None,
+                    })
+                    .unwrap_or(osc.len());
+                (Piece::Control, end + 2)
+            }
+            Some(c) => (Piece::Control, c.len_utf8() + 1),
+            None => (Piece::Control, 1),
+        };
+        rest = &rest[len..];
+        Some(piece)
+    })
+}
+
+/// Removes ANSI escape sequences (CSI and OSC) from `line`
+pub(crate) fn strip_escapes(line: &str) -> String {
+    pieces(line)
+        .filter_map(|piece| match piece {
+            Piece::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pieces() {
+        let line = "\x1b[1;38;2;1;2;3mapp\x1b[0m \x1b]8;;https://x\x1b\\link\x1b]8;;\x07\x1b[2K!";
+        assert_eq!(
+            pieces(line).collect::<Vec<_>>(),
+            [
+                Piece::Sgr("1;38;2;1;2;3"),
+                Piece::Text("app"),
+                Piece::Sgr("0"),
+                Piece::Text(" "),
+                Piece::Control,
+                Piece::Text("link"),
+                Piece::Control,
+                Piece::Control,
+                Piece::Text("!"),
+            ]
+        );
+        assert_eq!(strip_escapes(line), "app link!");
+        assert_eq!(strip_escapes("cut\x1b[38;2"), "cut");
+    }
+
+    #[test]
+    fn test_ansi256_rgb() {
+        assert_eq!(ansi256_rgb(9), ANSI16_PALETTE[9]);
+        assert_eq!(ansi256_rgb(196), (255, 0, 0));
+        assert_eq!(ansi256_rgb(244), (128, 128, 128));
+        for index in [16, 100, 196, 231, 232, 255] {
+            assert_eq!(to_ansi256(ansi256_rgb(index)), index);
+        }
+    }
+
+    #[test]
+    fn test_gradient() {
+        assert_eq!(
+            gradient((0, 0, 255), (255, 0, 0), 3),
+            [(0, 0, 255), (127, 0, 128), (255, 0, 0)]
+        );
+        assert_eq!(gradient((1, 2, 3), (9, 9, 9), 1), [(1, 2, 3)]);
+        assert!(gradient((1, 2, 3), (9, 9, 9), 0).is_empty());
+    }
+
+    #[test]
+    fn test_from_hex_const_matches_runtime_parser() {
+        for hex in ["#AA00FF", "#fff", "00ff7f", "##123456", "#AbC"] {
+            assert_eq!(
+                ColorSpec::from_hex_const(hex).rgb(),
+                crate::parse_hex(hex).unwrap(),
+                "{hex}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex color")]
+    fn test_from_hex_const_panics_at_runtime() {
+        let hex = String::from("#12345");
+        let _ = ColorSpec::from_hex_const(&hex);
+    }
+
+    #[test]
+    fn test_functional_notation() {
+        assert_eq!(parse_color("rgb(170 0 255)").unwrap(), (170, 0, 255));
+        assert_eq!(parse_color("RGB(100%, 50%, 0%)").unwrap(), (255, 128, 0));
+        assert_eq!(parse_color("hsl(0, 100%, 50%)").unwrap(), (255, 0, 0));
+        assert_eq!(parse_color("hsl(120deg 100% 25%)").unwrap(), (0, 128, 0));
+        assert_eq!(parse_color("hsl(-120, 100%, 50%)").unwrap(), (0, 0, 255));
+        assert_eq!(parse_color("hsl(0, 0%, 100%)").unwrap(), (255, 255, 255));
+
+        for invalid in [
+            "rgb(1, 2)",
+            "rgb(256, 0, 0)",
+            "hsl(0, 50, 50%)",
+            "hwb(0, 0%, 0%)",
+        ] {
+            assert!(
+                matches!(parse_color(invalid), Err(VersionError::InvalidColor(_))),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_env() {
+        assert_eq!(
+            ColorDepth::from_env(Some("24bit"), Some("xterm")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("screen-256color")),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            ColorDepth::from_env(None, Some("xterm-direct")),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(ColorDepth::from_env(None, None), ColorDepth::Ansi16);
+    }
+
+    #[test]
+    fn test_css_color_table() {
+        assert!(CSS_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(parse_color("cyan").unwrap(), (0, 255, 255));
+        assert_eq!(parse_color(" Gray ").unwrap(), (128, 128, 128));
+        assert_eq!(parse_color("green").unwrap(), (0, 128, 0));
+        assert_eq!(parse_color("FF8000").unwrap(), (255, 128, 0));
+        assert!(matches!(
+            parse_color("#12"),
+            Err(VersionError::InvalidHexColor(_))
+        ));
+        assert!(matches!(
+            parse_color("blurple"),
+            Err(VersionError::InvalidColor(name)) if name == "blurple"
+        ));
+    }
+
+    #[test]
+    fn test_ansi256_quantization() {
+        assert_eq!(to_ansi256((0, 0, 0)), 16);
+        assert_eq!(to_ansi256((255, 255, 255)), 231);
+        assert_eq!(to_ansi256((170, 0, 255)), 129);
+        assert_eq!(to_ansi256((0, 255, 255)), 51);
+        assert_eq!(to_ansi256((30, 30, 30)), 234);
+    }
+
+    #[test]
+    fn test_ansi16_quantization() {
+        assert_eq!(to_ansi16((255, 255, 255)), 15);
+        assert_eq!(to_ansi16((0, 255, 255)), 14);
+        assert_eq!(to_ansi16((200, 0, 0)), 1);
+    }
+
+    #[test]
+    fn test_paint_depths() {
+        assert_eq!(
+            paint("x", (255, 0, 0), None, ColorDepth::TrueColor),
+            "\x1b[38;2;255;0;0mx\x1b[0m"
+        );
+        assert_eq!(
+            paint("x", (255, 0, 0), Some((0, 0, 0)), ColorDepth::Ansi256),
+            "\x1b[38;5;196;48;5;16mx\x1b[0m"
+        );
+        assert_eq!(
+            paint("x", (255, 0, 0), Some((0, 0, 0)), ColorDepth::Ansi16),
+            "\x1b[91;40mx\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_paint_with_attributes() {
+        assert_eq!(
+            paint_with("x", Some((255, 0, 0)), None, &[1, 4], ColorDepth::TrueColor),
+            "\x1b[1;4;38;2;255;0;0mx\x1b[0m"
+        );
+        assert_eq!(
+            paint_with("x", None, None, &[3], ColorDepth::TrueColor),
+            "\x1b[3mx\x1b[0m"
+        );
+        assert_eq!(paint_with("x", None, None, &[], ColorDepth::Ansi16), "x");
+    }
+}
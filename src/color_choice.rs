@@ -0,0 +1,303 @@
+// Project: clap-version-flag
+// File: src/color_choice.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Following clap's ColorChoice, optionally through anstream
+// License: MIT
+
+//! clap color choice.
+//!
+//! By default colors follow `NO_COLOR`, `CLICOLOR`, `CLICOLOR_FORCE`, the
+//! terminal check and [`color::set_override`](crate::color::set_override).
+//! An app that already decides color through clap can hand that decision
+//! over with [`ColorfulVersion::with_color_choice`], or per call with
+//! [`ColorfulVersion::print_with_choice`]. [`parse_with_version`] and
+//! [`try_parse_with_version`] pick up `Command::color` themselves:
+//!
+//! ```
+//! use clap::{ColorChoice, CommandFactory, Parser};
+//! use clap_version_flag::{ColorfulVersion, VersionOutcome, try_parse_with_version_from};
+//!
+//! #[derive(Parser)]
+//! struct Cli {}
+//!
+//! let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+//! let cmd = Cli::command().color(ColorChoice::Never);
+//! let outcome: VersionOutcome<Cli> =
+//!     try_parse_with_version_from(cmd, &version, ["myapp", "-V"]).unwrap();
+//! assert!(matches!(outcome, VersionOutcome::VersionRequested(out) if !out.contains('\x1b')));
+//! ```
+//!
+//! [`check`](ColorfulVersion::check) and
+//! [`check_and_exit`](ColorfulVersion::check_and_exit) follow it too: the
+//! version flag comes with a hidden `--version-color <WHEN>` option whose
+//! default resolves to the `Command::color` of the parsed (sub)command, so
+//! the choice travels in the matches. Passing the option overrides it for
+//! one run.
+//!
+//! With the `anstream` feature, printing goes through
+//! `anstream::AutoStream`, which translates the escape codes for legacy
+//! Windows consoles; whether to color is still decided here.
+//!
+//! [`parse_with_version`]: crate::parse_with_version
+//! [`try_parse_with_version`]: crate::try_parse_with_version
+
+use std::borrow::Cow;
+
+use std::ffi::OsStr;
+
+use clap::builder::{EnumValueParser, PossibleValue, TypedValueParser};
+use clap::{Arg, ArgMatches, ColorChoice, Command, ValueEnum};
+
+use crate::{COLOR_ARG_ID, ColorfulVersion, PrintMode, Verbosity, color};
+
+impl ColorfulVersion {
+    /// Colors output according to clap's `ColorChoice`
+    ///
+    /// `Always` and `Never` replace the environment and terminal checks;
+    /// `Auto` keeps them. Applies to every output that picks colors by
+    /// itself, such as [`print`](Self::print) and the version flag. Has no
+    /// effect with the `no-color` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::ColorChoice;
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_color_choice(ColorChoice::Never);
+    /// assert_eq!(version.to_colored_string(), "myapp v1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn with_color_choice(mut self, choice: ColorChoice) -> Self {
+        self.color_choice = Some(choice);
+        self
+    }
+
+    /// Returns the color choice set with [`with_color_choice`](Self::with_color_choice)
+    #[must_use]
+    pub fn color_choice(&self) -> Option<ColorChoice> {
+        self.color_choice
+    }
+
+    /// Prints the colorful version to stdout, colored as `choice` says
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::ColorChoice;
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// version.print_with_choice(ColorChoice::Never);
+    /// ```
+    pub fn print_with_choice(&self, choice: ColorChoice) {
+        self.print_text(Verbosity::Normal, choice);
+    }
+
+    /// Returns whether output picking its own colors is colored
+    pub(crate) fn colorize(&self) -> bool {
+        colorize(self.color_choice.unwrap_or_default())
+    }
+
+    /// Prints `verbosity` output to stdout, colored as `choice` says
+    pub(crate) fn print_text(&self, verbosity: Verbosity, choice: ColorChoice) {
+        self.detect_accessibility();
+        if self.print_mode == PrintMode::Bare {
+            print_line(&self.version);
+            return;
+        }
+        print_line(&self.for_stdout().render_text(verbosity, colorize(choice)));
+    }
+
+    /// Adopts the color choice of `cmd` unless one is set already
+    pub(crate) fn following(&self, cmd: &Command) -> Cow<'_, Self> {
+        self.following_choice(cmd.get_color())
+    }
+
+    /// Adopts `choice` unless it is `Auto` or a choice is set already
+    pub(crate) fn following_choice(&self, choice: ColorChoice) -> Cow<'_, Self> {
+        match choice {
+            ColorChoice::Auto => Cow::Borrowed(self),
+            _ if self.color_choice.is_some() => Cow::Borrowed(self),
+            choice => Cow::Owned(self.clone().with_color_choice(choice)),
+        }
+    }
+}
+
+/// Default of `--version-color`, standing for the command's own choice
+const COMMAND_COLOR: &str = "command";
+
+/// Parses `--version-color`, resolving the default to `Command::color`
+///
+/// clap runs the parser on default values too, with the command being
+/// parsed, so the matches carry the color choice of the (sub)command.
+#[derive(Clone, Copy, Debug)]
+struct ColorParser;
+
+impl TypedValueParser for ColorParser {
+    type Value = ColorChoice;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<ColorChoice, clap::Error> {
+        if value == COMMAND_COLOR {
+            return Ok(cmd.get_color());
+        }
+        EnumValueParser::<ColorChoice>::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            ColorChoice::value_variants()
+                .iter()
+                .filter_map(ValueEnum::to_possible_value),
+        ))
+    }
+}
+
+/// Returns the hidden `--version-color` option registered with the flag
+pub(crate) fn color_arg() -> Arg {
+    Arg::new(COLOR_ARG_ID)
+        .long("version-color")
+        .value_name("WHEN")
+        .value_parser(ColorParser)
+        .default_value(COMMAND_COLOR)
+        .hide(true)
+        .global(true)
+}
+
+/// Returns the color choice the matches were parsed with
+///
+/// `Auto` when the command was built without the version flag.
+pub(crate) fn requested_color(matches: &ArgMatches) -> ColorChoice {
+    matches
+        .try_get_one::<ColorChoice>(COLOR_ARG_ID)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Writes rendered output and a newline to stdout
+///
+/// Every method printing to stdout goes through here: the color decision is
+/// already made, so with the `windows-console` feature legacy consoles get
+/// the escape codes as console colors, and with `anstream` they are adapted
+/// to the console.
+pub(crate) fn print_line(text: &str) {
+    #[cfg(all(windows, feature = "windows-console"))]
+    if crate::windows_console::print_line(text) {
+        return;
+    }
+    #[cfg(feature = "anstream")]
+    {
+        use std::io::Write;
+
+        let stdout = std::io::stdout().lock();
+        let mut stdout = anstream::AutoStream::new(stdout, anstream::ColorChoice::Always);
+        let _ = writeln!(stdout, "{text}");
+    }
+    #[cfg(not(feature = "anstream"))]
+    println!("{text}");
+}
+
+/// Returns whether `choice` colors output, checking the environment for `Auto`
+fn colorize(choice: ColorChoice) -> bool {
+    match choice {
+        ColorChoice::Auto => color::should_colorize(),
+        ColorChoice::Always => cfg!(not(feature = "no-color")),
+        ColorChoice::Never => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorDepth, ColorfulVersionExt};
+
+    fn version() -> ColorfulVersion {
+        ColorfulVersion::new("app", "1.0", "Jo").with_color_depth(ColorDepth::TrueColor)
+    }
+
+    #[test]
+    fn test_choice_overrides_detection() {
+        let never = version().with_color_choice(ColorChoice::Never);
+        assert!(!never.colorize());
+        assert_eq!(never.to_colored_string(), "app v1.0 by Jo");
+        let always = version().with_color_choice(ColorChoice::Always);
+        assert_eq!(always.colorize(), cfg!(not(feature = "no-color")));
+    }
+
+    #[test]
+    fn test_following_command() {
+        let cmd = Command::new("app").color(ColorChoice::Never);
+        assert_eq!(
+            version().following(&cmd).color_choice(),
+            Some(ColorChoice::Never)
+        );
+        let always = version().with_color_choice(ColorChoice::Always);
+        assert_eq!(
+            always.following(&cmd).color_choice(),
+            Some(ColorChoice::Always)
+        );
+        assert_eq!(
+            version().following(&Command::new("app")).color_choice(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_check_follows_command_color() {
+        let output = |cmd: Command, args: &[&str]| {
+            let version = version();
+            let matches = cmd
+                .with_colorful_version(&version)
+                .try_get_matches_from(args)
+                .unwrap();
+            version.requested_output(&matches).unwrap()
+        };
+        let sub = || Command::new("app").subcommand(Command::new("serve"));
+        assert_eq!(
+            output(sub().color(ColorChoice::Never), &["app", "-V"]),
+            "app v1.0 by Jo"
+        );
+        assert_eq!(
+            output(sub().color(ColorChoice::Never), &["app", "serve", "-V"]),
+            "app v1.0 by Jo"
+        );
+        let always = output(sub().color(ColorChoice::Always), &["app", "serve", "-V"]);
+        assert_eq!(always.contains('\x1b'), cfg!(not(feature = "no-color")));
+        // the option overrides the command for one run
+        let forced = output(
+            sub().color(ColorChoice::Always),
+            &["app", "-V", "--version-color", "never"],
+        );
+        assert_eq!(forced, "app v1.0 by Jo");
+
+        // the color set on the command after the flag still counts
+        let version = version();
+        let matches = Command::new("app")
+            .with_colorful_version(&version)
+            .color(ColorChoice::Never)
+            .try_get_matches_from(["app", "-V"])
+            .unwrap();
+        assert_eq!(requested_color(&matches), ColorChoice::Never);
+    }
+
+    #[test]
+    fn test_version_flag_follows_command() {
+        #[derive(clap::Parser)]
+        struct Cli {}
+
+        let cmd = <Cli as clap::CommandFactory>::command().color(ColorChoice::Always);
+        let outcome: crate::VersionOutcome<Cli> =
+            crate::try_parse_with_version_from(cmd, &version(), ["app", "-V"]).unwrap();
+        let crate::VersionOutcome::VersionRequested(output) = outcome else {
+            panic!("version not requested");
+        };
+        assert_eq!(output.contains('\x1b'), cfg!(not(feature = "no-color")));
+    }
+}
@@ -0,0 +1,155 @@
+// Project: clap-version-flag
+// File: src/completions.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Shell completion metadata for the injected flags
+// License: MIT
+
+//! Shell completion metadata.
+//!
+//! `clap_complete` builds bash, zsh and fish completions from the
+//! [`Command`] alone, so the injected flags complete like any other once the
+//! command carries the right metadata. Most of it is there already: `-V` /
+//! `--version` and the companion flags are visible, and `--version-format` offers
+//! `text`, `plain` and `json` with descriptions. [`augment_completions`]
+//! adds what parsing cannot express on its own, the field names of the
+//! lenient `--version-fields` option:
+//!
+//! ```ignore
+//! let mut cmd = augment_completions(cmd.with_colorful_version(&version).with_version_fields_flag());
+//! clap_complete::generate(Shell::Zsh, &mut cmd, "myapp", &mut std::io::stdout());
+//! ```
+
+use clap::builder::{PossibleValue, TypedValueParser};
+use clap::{Arg, Command, error::Error};
+use std::ffi::OsStr;
+
+use crate::FIELDS_ARG_ID;
+
+/// Field names offered for `--version-fields`
+const FIELD_NAMES: [(&str, &str); 12] = [
+    ("name", "Package name"),
+    ("version", "Version number"),
+    ("author", "Author"),
+    ("commit", "Git commit"),
+    ("license", "License"),
+    ("copyright", "Copyright notice"),
+    ("description", "Description"),
+    ("repository", "Repository URL"),
+    ("target", "Target triple"),
+    ("rustc", "Compiler version"),
+    ("profile", "Build profile"),
+    ("installed-via", "Install method"),
+];
+
+/// Accepts any field name while advertising the common ones to completions
+#[derive(Clone, Copy, Debug)]
+struct FieldNameParser;
+
+impl TypedValueParser for FieldNameParser {
+    type Value = String;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<String, Error> {
+        clap::builder::StringValueParser::new().parse_ref(cmd, arg, value)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        Some(Box::new(
+            FIELD_NAMES
+                .iter()
+                .map(|(name, help)| PossibleValue::new(*name).help(*help)),
+        ))
+    }
+}
+
+/// Prepares a command with the injected flags for `clap_complete`
+///
+/// Call it on the finished command, right before generating completions.
+/// The `--version-fields` option then completes the common field names;
+/// names outside the list keep working, and `--help` is unchanged.
+/// Commands without the option are returned as is.
+///
+/// # Examples
+/// ```
+/// use clap::Command;
+/// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, augment_completions};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// let cmd = augment_completions(
+///     Command::new("myapp")
+///         .with_colorful_version(&version)
+///         .with_version_fields_flag(),
+/// );
+///
+/// let fields = cmd
+///     .get_arguments()
+///     .find(|arg| arg.get_long() == Some("version-fields"))
+///     .unwrap();
+/// assert!(fields.get_possible_values().iter().any(|value| value.get_name() == "commit"));
+/// ```
+#[must_use]
+pub fn augment_completions(cmd: Command) -> Command {
+    let has_fields = cmd.get_arguments().any(|arg| arg.get_id() == FIELDS_ARG_ID);
+    if !has_fields {
+        return cmd;
+    }
+    cmd.mut_arg(FIELDS_ARG_ID, |arg| {
+        arg.value_parser(FieldNameParser).hide_possible_values(true)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorfulVersion, ColorfulVersionExt};
+
+    fn command() -> Command {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag()
+            .with_version_fields_flag()
+    }
+
+    fn possible_values(cmd: &Command, long: &str) -> Vec<String> {
+        cmd.get_arguments()
+            .find(|arg| arg.get_long() == Some(long))
+            .unwrap()
+            .get_possible_values()
+            .iter()
+            .map(|value| value.get_name().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_field_names_completed() {
+        let cmd = augment_completions(command());
+        assert_eq!(
+            possible_values(&cmd, "version-fields").len(),
+            FIELD_NAMES.len()
+        );
+        assert_eq!(
+            possible_values(&cmd, "version-format"),
+            ["text", "plain", "json"]
+        );
+        assert!(possible_values(&command(), "version-fields").is_empty());
+    }
+
+    #[test]
+    fn test_parsing_and_help_unchanged() {
+        let mut cmd = augment_completions(command());
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["app", "-V", "--version-fields", "name,my_key"])
+            .unwrap();
+        let fields: Vec<&String> = matches.get_many(FIELDS_ARG_ID).unwrap().collect();
+        assert_eq!(fields, ["name", "my_key"]);
+        assert!(!cmd.render_help().to_string().contains("copyright"));
+    }
+
+    #[test]
+    fn test_without_fields_option() {
+        let cmd = augment_completions(Command::new("app"));
+        assert_eq!(cmd.get_arguments().count(), 0);
+    }
+}
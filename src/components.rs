@@ -0,0 +1,325 @@
+// Project: clap-version-flag
+// File: src/components.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Versions of the components making up an app
+// License: MIT
+
+//! Component versions.
+//!
+//! Apps built from separately versioned parts, a client and a server or a
+//! core and its engines, can list each part below the version line, the way
+//! `docker version` shows client and server:
+//!
+//! ```text
+//! myapp v1.0.0 by John Doe
+//! components:
+//!   core     1.2.0
+//!   server   unavailable
+//! ```
+//!
+//! Versions are fixed strings or closures called every time the version is
+//! rendered, e.g. to ask a running server. A closure returning `None` shows
+//! the component as unavailable.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::color::{self, ColorSpec, Rgb};
+use crate::{ColorDepth, ColorfulVersion, Role, grapheme};
+
+/// Columns between the longest name and the versions
+pub(crate) const GAP: usize = 3;
+
+type Lookup = dyn Fn() -> Option<String> + Send + Sync;
+
+/// Where a component's version comes from
+#[derive(Clone)]
+enum Source {
+    Fixed(String),
+    Lazy(Arc<Lookup>),
+}
+
+/// One named component
+#[derive(Clone)]
+struct Component {
+    name: String,
+    source: Source,
+    color: Option<Rgb>,
+}
+
+/// The components listed below the version line
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, ComponentVersions};
+///
+/// let components = ComponentVersions::new()
+///     .add_component("core", "1.2.0")
+///     .add_component_fn("server", || None);
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_components(components);
+/// assert_eq!(
+///     version.as_plain_string_with_components(),
+///     "myapp v1.0.0 by John Doe\ncomponents:\n  core     1.2.0\n  server   unavailable"
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct ComponentVersions {
+    components: Vec<Component>,
+}
+
+impl fmt::Debug for ComponentVersions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.components.iter().map(|component| &component.name))
+            .finish()
+    }
+}
+
+impl ComponentVersions {
+    /// Creates an empty list
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            components: Vec::new(),
+        }
+    }
+
+    /// Adds a component with a fixed version
+    #[must_use]
+    pub fn add_component(mut self, name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.components.push(Component {
+            name: name.into(),
+            source: Source::Fixed(version.into()),
+            color: None,
+        });
+        self
+    }
+
+    /// Adds a component whose version `lookup` returns when rendering
+    ///
+    /// `lookup` returns `None` when the version cannot be determined, e.g.
+    /// because the server is not running.
+    #[must_use]
+    pub fn add_component_fn<F>(mut self, name: impl Into<String>, lookup: F) -> Self
+    where
+        F: Fn() -> Option<String> + Send + Sync + 'static,
+    {
+        self.components.push(Component {
+            name: name.into(),
+            source: Source::Lazy(Arc::new(lookup)),
+            color: None,
+        });
+        self
+    }
+
+    /// Paints the version of the component `name` in `color`
+    ///
+    /// Versions default to the [`Role::Accent`] color. Names without a
+    /// component are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ComponentVersions, hex};
+    ///
+    /// let components = ComponentVersions::new()
+    ///     .add_component("client", "1.0.0")
+    ///     .add_component("server", "1.1.0")
+    ///     .component_color("server", hex!("#FF8800"));
+    /// ```
+    #[must_use]
+    pub fn component_color(mut self, name: &str, color: impl Into<ColorSpec>) -> Self {
+        let color = color.into().rgb();
+        for component in self.components.iter_mut().filter(|c| c.name == name) {
+            component.color = Some(color);
+        }
+        self
+    }
+
+    /// Returns the number of components
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns `true` if there are no components
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Returns each component's name and version, calling the lookups
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ComponentVersions;
+    ///
+    /// let components = ComponentVersions::new()
+    ///     .add_component("core", "1.2.0")
+    ///     .add_component_fn("engine", || Some("0.9.1".into()));
+    /// assert_eq!(
+    ///     components.versions(),
+    ///     [("core".into(), Some("1.2.0".into())), ("engine".into(), Some("0.9.1".into()))]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn versions(&self) -> Vec<(String, Option<String>)> {
+        self.components
+            .iter()
+            .map(|component| (component.name.clone(), component.version()))
+            .collect()
+    }
+}
+
+impl ComponentVersions {
+    /// Returns components with the given versions, keeping the colors of
+    /// the components of the same name
+    pub(crate) fn presenting(&self, versions: &[(String, Option<String>)]) -> Self {
+        let components = versions
+            .iter()
+            .map(|(name, version)| Component {
+                name: name.clone(),
+                source: match version {
+                    Some(version) => Source::Fixed(version.clone()),
+                    None => Source::Lazy(Arc::new(|| None)),
+                },
+                color: self
+                    .components
+                    .iter()
+                    .find(|component| component.name == *name)
+                    .and_then(|component| component.color),
+            })
+            .collect();
+        Self { components }
+    }
+}
+
+impl Component {
+    fn version(&self) -> Option<String> {
+        match &self.source {
+            Source::Fixed(version) => Some(version.clone()),
+            Source::Lazy(lookup) => lookup(),
+        }
+    }
+}
+
+impl ColorfulVersion {
+    /// Lists `components` below the version line
+    ///
+    /// The block follows the version output and its detail lines at every
+    /// verbosity, and is left out when a field selection is in effect.
+    #[must_use]
+    pub fn with_components(mut self, components: ComponentVersions) -> Self {
+        self.components = components;
+        self
+    }
+
+    /// Returns the components listed below the version line
+    #[must_use]
+    pub fn components(&self) -> &ComponentVersions {
+        &self.components
+    }
+
+    /// Returns the plain version line followed by the components block
+    #[must_use]
+    pub fn as_plain_string_with_components(&self) -> String {
+        let mut out = self.as_plain_string();
+        if let Some(block) = self.render_components(None) {
+            out.push('\n');
+            out.push_str(&block);
+        }
+        out
+    }
+
+    /// Renders the components block, painted when `depth` is set
+    ///
+    /// Names are painted in the [`Role::Secondary`] color, versions in their
+    /// own color and unavailable ones in the [`Role::Warning`] color.
+    pub(crate) fn render_components(&self, depth: Option<ColorDepth>) -> Option<String> {
+        let components = &self.components.components;
+        let width = components
+            .iter()
+            .map(|component| grapheme::width(&component.name))
+            .max()?
+            + GAP;
+
+        let paint = |text: &str, rgb: Rgb| match depth {
+            Some(depth) => color::paint(text, rgb, None, depth),
+            None => text.to_string(),
+        };
+        let mut lines = vec![paint("components:", self.palette().version_color)];
+        for component in components {
+            let padding = " ".repeat(width - grapheme::width(&component.name));
+            let version = match component.version() {
+                Some(version) => paint(
+                    &version,
+                    component.color.unwrap_or_else(|| self.role(Role::Accent)),
+                ),
+                None => paint("unavailable", self.role(Role::Warning)),
+            };
+            lines.push(format!(
+                "  {}{padding}{version}",
+                paint(&component.name, self.role(Role::Secondary))
+            ));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_lookup_called_per_render() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        let components = ComponentVersions::new().add_component_fn("server", || {
+            Some(format!("1.{}", CALLS.fetch_add(1, Ordering::Relaxed)))
+        });
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_components(components);
+        assert!(
+            version
+                .render_plain(crate::Verbosity::Normal)
+                .ends_with("server   1.0")
+        );
+        assert!(
+            version
+                .render_plain(crate::Verbosity::Normal)
+                .ends_with("server   1.1")
+        );
+    }
+
+    #[test]
+    fn test_colored_block() {
+        let components = ComponentVersions::new()
+            .add_component("a", "1")
+            .add_component("b", "2")
+            .component_color("b", (1, 2, 3));
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_components(components);
+        let block = version
+            .render_components(Some(ColorDepth::TrueColor))
+            .unwrap();
+        assert!(block.contains("   \x1b[38;2;255;255;0m1\x1b[0m"));
+        assert!(block.contains("   \x1b[38;2;1;2;3m2\x1b[0m"));
+    }
+
+    #[test]
+    fn test_without_components() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        assert!(version.render_components(None).is_none());
+        assert_eq!(version.as_plain_string_with_components(), "app v1.0 by Jo");
+        assert_eq!(version.components().len(), 0);
+    }
+
+    #[test]
+    fn test_block_follows_detail_lines() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_license("MIT")
+            .with_components(ComponentVersions::new().add_component("core", "2"));
+        assert_eq!(
+            version.render_plain(crate::Verbosity::Normal),
+            "app v1.0 by Jo\nlicense: MIT\ncomponents:\n  core   2"
+        );
+    }
+}
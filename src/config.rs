@@ -0,0 +1,311 @@
+// Project: clap-version-flag
+// File: src/config.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Theme configuration files in TOML
+// License: MIT
+
+//! Theme configuration files.
+//!
+//! [`ColorfulVersion::with_config_file`] lets end users restyle the version
+//! banner of a CLI without a rebuild:
+//!
+//! ```toml
+//! # ~/.config/myapp/version-theme.toml
+//! format = "{name} {version} ({author})"
+//!
+//! [theme]
+//! primary = "rebeccapurple"
+//! on_primary = "#FFFFFF"
+//! accent = "hsl(60, 100%, 50%)"
+//! secondary = "rgb(0, 255, 255)"
+//! warning = "orange"
+//!
+//! [styles]
+//! name = ["bold"]
+//! author = ["italic", "dimmed"]
+//! ```
+//!
+//! Colors accept everything [`parse_color`](crate::color::parse_color)
+//! does; the theme keys are the [`Role`](crate::Role)s in snake case. Style
+//! segments are `name`, `prefix`, `version` and `author`, plus the semantic
+//! version components `major`, `minor`, `patch`, `pre_release` and `build`
+//! (see [`Styles::major`](crate::Styles::major)), with the flags
+//! `bold`, `dimmed`, `italic`, `underline` and `strikethrough`. Every key is
+//! optional.
+//!
+//! Requires the `config` feature.
+
+use crate::{ColorSpec, ColorfulVersion, Role, Theme, VersionError};
+use std::io;
+use std::path::{Path, PathBuf};
+use toml_edit::{DocumentMut, Item};
+
+use crate::suggest::with_suggestion;
+use crate::theme::ROLE_KEYS;
+
+/// Top-level configuration keys
+const KEYS: [&str; 3] = ["format", "theme", "styles"];
+
+/// Style segment keys
+const SEGMENTS: [&str; 9] = [
+    "name",
+    "prefix",
+    "version",
+    "author",
+    "major",
+    "minor",
+    "patch",
+    "pre_release",
+    "build",
+];
+
+/// Style flags
+const FLAGS: [&str; 5] = ["bold", "dimmed", "italic", "underline", "strikethrough"];
+
+impl ColorfulVersion {
+    /// Applies the theme configuration file at `path`, if it exists
+    ///
+    /// A leading `~` expands to the home directory. A missing file leaves the
+    /// version unchanged, so the call can be made unconditionally.
+    ///
+    /// # Errors
+    /// Returns [`VersionError::IoError`] if the file cannot be read and
+    /// [`VersionError::InvalidConfig`] (or a color error) if it is malformed.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_config_file("~/.config/myapp/version-theme.toml")
+    ///     .expect("invalid version theme");
+    /// ```
+    pub fn with_config_file(self, path: impl AsRef<Path>) -> Result<Self, VersionError> {
+        match std::fs::read_to_string(expand_home(path.as_ref())) {
+            Ok(text) => self.with_config_toml(&text),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(self),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Applies a theme configuration given as TOML text
+    ///
+    /// # Errors
+    /// Returns [`VersionError::InvalidConfig`] (or a color error) if the
+    /// configuration is malformed.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Role};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_config_toml("format = \"{name}@{version}\"\n[theme]\nprimary = \"navy\"")
+    ///     .unwrap();
+    /// assert_eq!(version.as_plain_string(), "myapp@1.0.0");
+    /// assert_eq!(version.theme().role(Role::Primary), (0, 0, 128));
+    /// ```
+    pub fn with_config_toml(mut self, text: &str) -> Result<Self, VersionError> {
+        let document: DocumentMut = text
+            .parse()
+            .map_err(|e: toml_edit::TomlError| invalid(e.message()))?;
+
+        for (key, item) in document.iter() {
+            match key {
+                "format" => self = self.with_template(string(key, item)?),
+                "theme" => self = self.apply_theme(item)?,
+                "styles" => self.apply_styles(item)?,
+                _ => return Err(unknown(format!("unknown key '{key}'"), key, KEYS)),
+            }
+        }
+        Ok(self)
+    }
+
+    fn apply_theme(self, item: &Item) -> Result<Self, VersionError> {
+        let mut theme = Theme::from(self.colors);
+        for (key, value) in table("theme", item)? {
+            let role = Role::from_key(key)
+                .ok_or_else(|| unknown(format!("unknown theme role '{key}'"), key, ROLE_KEYS))?;
+            let color: ColorSpec = string(key, value)?.parse()?;
+            theme = theme.with_role(role, color);
+        }
+        Ok(self.with_theme(theme))
+    }
+
+    fn apply_styles(&mut self, item: &Item) -> Result<(), VersionError> {
+        for (key, value) in table("styles", item)? {
+            let segment = match key {
+                "name" => &mut self.styles.name,
+                "prefix" => &mut self.styles.prefix,
+                "version" => &mut self.styles.version,
+                "author" => &mut self.styles.author,
+                "major" => self.styles.major.get_or_insert_default(),
+                "minor" => self.styles.minor.get_or_insert_default(),
+                "patch" => self.styles.patch.get_or_insert_default(),
+                "pre_release" => self.styles.pre_release.get_or_insert_default(),
+                "build" => self.styles.build.get_or_insert_default(),
+                _ => {
+                    return Err(unknown(
+                        format!("unknown style segment '{key}'"),
+                        key,
+                        SEGMENTS,
+                    ));
+                }
+            };
+            let flags = value
+                .as_array()
+                .ok_or_else(|| invalid(&format!("'{key}' must be an array of style flags")))?;
+            for flag in flags {
+                *segment = match flag.as_str() {
+                    Some("bold") => segment.bold(),
+                    Some("dimmed") => segment.dimmed(),
+                    Some("italic") => segment.italic(),
+                    Some("underline") => segment.underline(),
+                    Some("strikethrough") => segment.strikethrough(),
+                    other => {
+                        let flag = other.unwrap_or("(not a string)");
+                        let message = format!("unknown style flag '{flag}' in '{key}'");
+                        return Err(unknown(message, flag, FLAGS));
+                    }
+                };
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid(message: &str) -> VersionError {
+    VersionError::InvalidConfig(message.trim().to_string())
+}
+
+/// Reports an unknown `name`, suggesting the closest of `known`
+fn unknown(
+    message: String,
+    name: &str,
+    known: impl IntoIterator<Item = &'static str>,
+) -> VersionError {
+    VersionError::InvalidConfig(with_suggestion(message, name, known))
+}
+
+fn string<'a>(key: &str, item: &'a Item) -> Result<&'a str, VersionError> {
+    item.as_str()
+        .ok_or_else(|| invalid(&format!("'{key}' must be a string")))
+}
+
+fn table<'a>(
+    key: &str,
+    item: &'a Item,
+) -> Result<impl Iterator<Item = (&'a str, &'a Item)>, VersionError> {
+    item.as_table_like()
+        .map(|table| table.iter())
+        .ok_or_else(|| invalid(&format!("'{key}' must be a table")))
+}
+
+/// Expands a leading `~` to the user's home directory
+fn expand_home(path: &Path) -> PathBuf {
+    let Ok(rest) = path.strip_prefix("~") else {
+        return path.to_path_buf();
+    };
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map_or_else(|| path.to_path_buf(), |home| PathBuf::from(home).join(rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Style;
+
+    fn version() -> ColorfulVersion {
+        ColorfulVersion::new("app", "1.0", "Jane")
+    }
+
+    #[test]
+    fn test_full_config() {
+        let version = version()
+            .with_config_toml(
+                r##"
+                format = "{name} {version}"
+
+                [theme]
+                primary = "#000080"
+                accent = "rgb(1, 2, 3)"
+                error = "hsl(0, 100%, 50%)"
+
+                [styles]
+                name = ["bold", "underline"]
+                "##,
+            )
+            .unwrap();
+
+        assert_eq!(version.as_plain_string(), "app 1.0");
+        assert_eq!(version.colors.name_bg, (0, 0, 128));
+        assert_eq!(version.colors.version_color, (1, 2, 3));
+        assert_eq!(version.theme().role(Role::Error), (255, 0, 0));
+        assert_eq!(version.colors.name_fg, (255, 255, 255));
+        assert_eq!(version.styles.name, Style::new().bold().underline());
+    }
+
+    #[test]
+    fn test_invalid_configs() {
+        for text in [
+            "colour = 1",
+            "format = 1",
+            "[theme]\nbrand = \"red\"",
+            "[styles]\nname = [\"blink\"]",
+            "[styles]\nname = \"bold\"",
+            "[theme",
+        ] {
+            assert!(
+                matches!(
+                    version().with_config_toml(text),
+                    Err(VersionError::InvalidConfig(_))
+                ),
+                "{text}"
+            );
+        }
+        assert!(matches!(
+            version().with_config_toml("[theme]\nprimary = \"nope\""),
+            Err(VersionError::InvalidColor(_))
+        ));
+    }
+
+    #[test]
+    fn test_unknown_names_suggest_closest() {
+        let message = |text: &str| version().with_config_toml(text).unwrap_err().to_string();
+        assert_eq!(
+            message("[theme]\nprimery = \"red\""),
+            "Invalid config: unknown theme role 'primery'; did you mean 'primary'?"
+        );
+        assert_eq!(
+            message("[styles]\nauthor = [\"italics\"]"),
+            "Invalid config: unknown style flag 'italics' in 'author'; did you mean 'italic'?"
+        );
+        assert_eq!(
+            message("[theme]\nbrand = \"red\""),
+            "Invalid config: unknown theme role 'brand'"
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_ignored() {
+        let version = version()
+            .with_config_file("/surely/no/such/dir/version-theme.toml")
+            .unwrap();
+        assert_eq!(version.as_plain_string(), "app v1.0 by Jane");
+    }
+
+    #[test]
+    fn test_expand_home() {
+        assert_eq!(
+            expand_home(Path::new("/etc/x.toml")),
+            Path::new("/etc/x.toml")
+        );
+        if let Some(home) = std::env::var_os("HOME") {
+            assert_eq!(
+                expand_home(Path::new("~/.config/x.toml")),
+                PathBuf::from(home).join(".config/x.toml")
+            );
+        }
+    }
+}
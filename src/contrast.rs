@@ -0,0 +1,205 @@
+// Project: clap-version-flag
+// File: src/contrast.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: WCAG contrast checking and automatic adjustment
+// License: MIT
+
+//! Contrast.
+//!
+//! [`contrast_ratio`] computes the WCAG 2 contrast ratio of two colors. The
+//! colored output is checked against it: a name badge below 3:1 (WCAG's
+//! minimum for large text) is reported as [`Warning::LowContrast`]. With
+//! [`ColorfulVersion::with_min_contrast`] the name color is instead lightened
+//! or darkened until it meets the requested ratio, and the change is
+//! reported as [`Warning::ContrastAdjusted`]:
+//!
+//! ```
+//! use clap_version_flag::{ColorfulVersion, Warning, contrast_ratio};
+//!
+//! let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+//!     .with_rgb_colors((200, 200, 255), (170, 0, 255), (255, 255, 0), (0, 255, 255))
+//!     .with_min_contrast(4.5);
+//! version.write_colored_to(&mut Vec::new()).unwrap();
+//! assert!(matches!(
+//!     version.warnings().take()[..],
+//!     [Warning::ContrastAdjusted { ratio, .. }] if ratio >= 4.5
+//! ));
+//! ```
+
+use crate::color::Rgb;
+use crate::{ColorfulVersion, Colors, Warning};
+
+/// Contrast ratio below which the name badge is reported, WCAG's minimum
+/// for large text
+const MIN_CONTRAST: f64 = 3.0;
+
+/// Blend steps tried in each direction when adjusting a color
+const STEPS: u8 = 64;
+
+/// Returns the WCAG 2 contrast ratio of two colors, from 1 to 21
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::contrast_ratio;
+///
+/// assert_eq!(contrast_ratio((0, 0, 0), (255, 255, 255)), 21.0);
+/// assert!(contrast_ratio((255, 255, 255), (170, 0, 255)) > 4.5);
+/// ```
+#[must_use]
+pub fn contrast_ratio(a: Rgb, b: Rgb) -> f64 {
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Returns the WCAG relative luminance of an sRGB color
+pub(crate) fn luminance((r, g, b): Rgb) -> f64 {
+    let linear = |channel: u8| {
+        let c = f64::from(channel) / 255.0;
+        if c <= 0.040_45 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// Returns the color closest to `fg` with at least `target` contrast on `bg`
+///
+/// Blends `fg` toward white and toward black and takes the smaller change
+/// that reaches the target. When neither does, the target is out of reach
+/// and the better of white and black is returned.
+fn adjust(fg: Rgb, bg: Rgb, target: f64) -> Rgb {
+    if contrast_ratio(fg, bg) >= target {
+        return fg;
+    }
+    let blend = |to: u8, step: u8| {
+        let mix = |channel: u8| {
+            let t = f64::from(step) / f64::from(STEPS);
+            (f64::from(channel) + (f64::from(to) - f64::from(channel)) * t).round() as u8
+        };
+        (mix(fg.0), mix(fg.1), mix(fg.2))
+    };
+    let reach = |to: u8| (1..=STEPS).find(|&step| contrast_ratio(blend(to, step), bg) >= target);
+    match (reach(255), reach(0)) {
+        (Some(lighter), Some(darker)) if darker < lighter => blend(0, darker),
+        (Some(lighter), _) => blend(255, lighter),
+        (None, Some(darker)) => blend(0, darker),
+        (None, None) if contrast_ratio((255, 255, 255), bg) >= contrast_ratio((0, 0, 0), bg) => {
+            (255, 255, 255)
+        }
+        (None, None) => (0, 0, 0),
+    }
+}
+
+impl ColorfulVersion {
+    /// Adjusts the name color until it has `ratio` contrast on its badge
+    ///
+    /// `ratio` is a WCAG contrast ratio from 1 to 21, e.g. 4.5 for WCAG AA
+    /// body text. The adjustment applies to every output, after high
+    /// contrast and the environment overrides; the other colors are kept.
+    /// Each adjustment of a colored output is recorded as
+    /// [`Warning::ContrastAdjusted`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Role, contrast_ratio};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_rgb_colors((90, 90, 90), (40, 40, 40), (255, 255, 0), (0, 255, 255))
+    ///     .with_min_contrast(4.5);
+    /// let theme = version.theme();
+    /// assert!(contrast_ratio(theme.role(Role::OnPrimary), theme.role(Role::Primary)) >= 4.5);
+    /// ```
+    #[must_use]
+    pub fn with_min_contrast(mut self, ratio: f64) -> Self {
+        self.min_contrast = Some(ratio.clamp(1.0, 21.0));
+        self
+    }
+
+    /// Applies the minimum contrast to `colors`
+    pub(crate) fn apply_min_contrast(&self, mut colors: Colors) -> Colors {
+        if let Some(target) = self.min_contrast {
+            colors.name_fg = adjust(colors.name_fg, colors.name_bg, target);
+        }
+        colors
+    }
+
+    /// Records contrast warnings for the name badge, if any apply
+    pub(crate) fn check_contrast(&self) {
+        let palette = self.palette();
+        if self.min_contrast.is_some() {
+            let before = self.apply_env_overrides(self.selected_colors());
+            if before.name_fg != palette.name_fg {
+                self.warnings.push(Warning::ContrastAdjusted {
+                    from: before.name_fg,
+                    to: palette.name_fg,
+                    ratio: contrast_ratio(palette.name_fg, palette.name_bg),
+                });
+            }
+        }
+        let ratio = contrast_ratio(palette.name_fg, palette.name_bg);
+        if ratio < MIN_CONTRAST {
+            self.warnings.push(Warning::LowContrast {
+                foreground: palette.name_fg,
+                background: palette.name_bg,
+                ratio,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio((0, 0, 0), (255, 255, 255)) - 21.0).abs() < 1e-9);
+        assert!((contrast_ratio((9, 9, 9), (9, 9, 9)) - 1.0).abs() < 1e-9);
+        assert!(contrast_ratio((255, 255, 255), (170, 0, 255)) > MIN_CONTRAST);
+    }
+
+    #[test]
+    fn test_adjust_picks_the_nearer_direction() {
+        // Dark gray on black gets lighter, light gray on white darker
+        let lighter = adjust((60, 60, 60), (0, 0, 0), 4.5);
+        assert!(lighter.0 > 60 && contrast_ratio(lighter, (0, 0, 0)) >= 4.5);
+        let darker = adjust((200, 200, 200), (255, 255, 255), 4.5);
+        assert!(darker.0 < 200 && contrast_ratio(darker, (255, 255, 255)) >= 4.5);
+        // Already readable colors are kept
+        assert_eq!(adjust((255, 255, 255), (0, 0, 0), 7.0), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_unreachable_target() {
+        assert_eq!(adjust((128, 128, 128), (120, 120, 120), 21.0), (0, 0, 0));
+        assert_eq!(adjust((20, 20, 20), (10, 10, 10), 21.0), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_adjustment_warned_once_when_colored() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_rgb_colors((90, 90, 90), (40, 40, 40), (0, 0, 0), (0, 0, 0))
+            .with_min_contrast(4.5);
+        let _ = version.render_plain(crate::Verbosity::Normal);
+        assert!(version.warnings().is_empty());
+        version.write_colored_to(&mut Vec::new()).unwrap();
+        version.write_colored_to(&mut Vec::new()).unwrap();
+        let warnings = version.warnings().take();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0]
+                .to_string()
+                .starts_with("adjusted the name color #5A5A5A to #")
+        );
+    }
+
+    #[test]
+    fn test_readable_colors_not_adjusted() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_min_contrast(4.5);
+        version.write_colored_to(&mut Vec::new()).unwrap();
+        assert!(version.warnings().is_empty());
+    }
+}
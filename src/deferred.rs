@@ -0,0 +1,100 @@
+// Project: clap-version-flag
+// File: src/deferred.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Lists parsed from a static string on first use
+// License: MIT
+
+//! Lists parsed on first use.
+//!
+//! A version built in a `const` or `static` context cannot allocate, so
+//! [`colorful_version!`](crate::colorful_version) keeps the author list and
+//! the license as the `&'static str` Cargo provides. [`Deferred`] parses
+//! them into their lists the first time they are read, and behaves as the
+//! parsed `Vec` from then on.
+
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::OnceLock;
+
+/// A `Vec` built from a `&'static str` when first dereferenced
+#[derive(Clone)]
+pub(crate) struct Deferred<T> {
+    source: &'static str,
+    parse: fn(&'static str) -> Vec<T>,
+    list: OnceLock<Vec<T>>,
+}
+
+impl<T> Deferred<T> {
+    /// Creates a list parsed from `source` with `parse` on first use
+    pub(crate) const fn new(source: &'static str, parse: fn(&'static str) -> Vec<T>) -> Self {
+        Self {
+            source,
+            parse,
+            list: OnceLock::new(),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Deferred<T> {
+    fn from(list: Vec<T>) -> Self {
+        Self {
+            source: "",
+            parse: |_| Vec::new(),
+            list: OnceLock::from(list),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Deferred<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T> Deref for Deferred<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Vec<T> {
+        self.list.get_or_init(|| (self.parse)(self.source))
+    }
+}
+
+impl<T> DerefMut for Deferred<T> {
+    fn deref_mut(&mut self) -> &mut Vec<T> {
+        if self.list.get().is_none() {
+            self.list = OnceLock::from((self.parse)(self.source));
+        }
+        self.list.get_mut().expect("the list was parsed above")
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Deferred<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &'static str) -> Vec<&'static str> {
+        text.split_whitespace().collect()
+    }
+
+    #[test]
+    fn test_parsed_on_first_use() {
+        static LIST: Deferred<&str> = Deferred::new("a b", words);
+        assert_eq!(*LIST, ["a", "b"]);
+
+        let mut list = LIST.clone();
+        list.push("c");
+        assert_eq!(*list, ["a", "b", "c"]);
+
+        let mut fresh = Deferred::new("x", words);
+        fresh.clear();
+        assert!(fresh.is_empty());
+        assert_eq!(*Deferred::from(vec!["y"]), ["y"]);
+    }
+}
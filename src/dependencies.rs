@@ -0,0 +1,159 @@
+// Project: clap-version-flag
+// File: src/dependencies.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Direct dependency versions report
+// License: MIT
+
+//! Dependency versions report.
+//!
+//! Bug reports often hinge on the version of a dependency, e.g. which TLS or
+//! SQLite library a binary was linked against. [`build::emit_dependencies`]
+//! embeds the resolved versions of the direct dependencies at build time,
+//! [`BuildInfo`](crate::BuildInfo) and
+//! [`ColorfulVersion::from_build_info`] carry them over, and
+//! [`ColorfulVersion::print_dependencies`] shows them as an aligned table:
+//!
+//! ```text
+//! dependencies:
+//!   clap      4.5.53
+//!   colored   2.1.0
+//! ```
+//!
+//! [`ColorfulVersionExt::with_dependencies_flag`](crate::ColorfulVersionExt::with_dependencies_flag)
+//! adds a `--version-deps` companion flag printing the table below `--version`.
+//!
+//! [`build::emit_dependencies`]: crate::build::emit_dependencies
+
+use crate::{ColorDepth, ColorfulVersion, Role, color, grapheme};
+
+/// Columns between the longest name and the versions
+const GAP: usize = 3;
+
+impl ColorfulVersion {
+    /// Sets the direct dependencies as `(name, version)` pairs
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_dependencies([("clap", "4.5.53"), ("colored", "2.1.0")]);
+    /// assert_eq!(
+    ///     version.as_dependencies_plain_string(),
+    ///     "dependencies:\n  clap      4.5.53\n  colored   2.1.0"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_dependencies(
+        mut self,
+        dependencies: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.dependencies = dependencies
+            .into_iter()
+            .map(|(name, version)| (name.into(), version.into()))
+            .collect();
+        self
+    }
+
+    /// Returns the direct dependencies as `(name, version)` pairs
+    #[must_use]
+    pub fn dependencies(&self) -> &[(String, String)] {
+        &self.dependencies
+    }
+
+    /// Prints the dependency table to stdout, colored when supported
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_dependencies([("clap", "4.5.53")]);
+    /// version.print_dependencies();
+    /// ```
+    pub fn print_dependencies(&self) {
+        self.detect_accessibility();
+        crate::color_choice::print_line(
+            &self.render_dependencies(self.colorize().then(|| self.depth())),
+        );
+    }
+
+    /// Returns the plain dependency table
+    #[must_use]
+    pub fn as_dependencies_plain_string(&self) -> String {
+        self.render_dependencies(None)
+    }
+
+    /// Renders the dependency table, painted when `depth` is set
+    ///
+    /// Names are painted in the [`Role::Secondary`] color and versions in
+    /// the [`Role::Accent`] color.
+    pub(crate) fn render_dependencies(&self, depth: Option<ColorDepth>) -> String {
+        if self.dependencies.is_empty() {
+            return "dependencies: none recorded".into();
+        }
+        let width = self
+            .dependencies
+            .iter()
+            .map(|(name, _)| grapheme::width(name))
+            .max()
+            .unwrap_or(0)
+            + GAP;
+
+        let mut lines = vec![match depth {
+            Some(depth) => color::paint("dependencies:", self.palette().version_color, None, depth),
+            None => "dependencies:".to_string(),
+        }];
+        for (name, version) in &self.dependencies {
+            let padding = " ".repeat(width - grapheme::width(name));
+            lines.push(match depth {
+                Some(depth) => format!(
+                    "  {}{padding}{}",
+                    color::paint(name, self.role(Role::Secondary), None, depth),
+                    color::paint(version, self.role(Role::Accent), None, depth)
+                ),
+                None => format!("  {name}{padding}{version}"),
+            });
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildInfo;
+
+    #[test]
+    fn test_empty_table() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        assert_eq!(
+            version.as_dependencies_plain_string(),
+            "dependencies: none recorded"
+        );
+    }
+
+    #[test]
+    fn test_colored_table() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_dependencies([("a", "1")]);
+        assert_eq!(
+            version.render_dependencies(Some(ColorDepth::TrueColor)),
+            "\x1b[38;2;255;255;0mdependencies:\x1b[0m\n  \x1b[38;2;0;255;255ma\x1b[0m   \x1b[38;2;255;255;0m1\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_dependencies_from_build_info() {
+        const INFO: BuildInfo =
+            BuildInfo::new("app", "1.0", "Jo").with_dependencies(Some("clap 4.5.53,colored 2.1.0"));
+        let version = ColorfulVersion::from_build_info(&INFO);
+        assert_eq!(
+            version.dependencies(),
+            [
+                ("clap".to_string(), "4.5.53".to_string()),
+                ("colored".to_string(), "2.1.0".to_string())
+            ]
+        );
+    }
+}
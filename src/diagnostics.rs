@@ -0,0 +1,173 @@
+// Project: clap-version-flag
+// File: src/diagnostics.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Feature-flag / remote-config snapshot for -VVV diagnostics
+// License: MIT
+
+//! Configuration snapshot in the diagnostics output.
+//!
+//! `-VVV` ([`Verbosity::Diagnostic`]) adds what a support engineer needs on
+//! top of `-VV`: a key/value snapshot of the active feature flags or remote
+//! configuration, supplied by the app through
+//! [`ColorfulVersion::with_config_snapshot`]. Values of sensitive keys are
+//! redacted before they are printed.
+
+use crate::{ColorfulVersion, Field, Verbosity};
+use std::fmt;
+use std::sync::Arc;
+
+/// Replacement text for redacted values
+const REDACTED: &str = "[redacted]";
+
+/// Key patterns redacted by default
+pub(crate) const DEFAULT_REDACTIONS: [&str; 4] = ["*token*", "*secret*", "*password*", "*key"];
+
+type Snapshot = dyn Fn() -> Vec<(String, String)> + Send + Sync;
+
+/// Shared handle to a configuration snapshot provider
+#[derive(Clone)]
+pub(crate) struct SnapshotProvider(Arc<Snapshot>);
+
+impl fmt::Debug for SnapshotProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SnapshotProvider")
+    }
+}
+
+impl ColorfulVersion {
+    /// Adds a snapshot of active feature flags or remote configuration to `-VVV`
+    ///
+    /// The provider is called when the diagnostics output is rendered. Each
+    /// entry is printed as `config.<key>: <value>`; values of keys matching a
+    /// redaction pattern are replaced by `[redacted]` (see
+    /// [`with_redacted_key`](Self::with_redacted_key)).
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_config_snapshot(|| {
+    ///     vec![
+    ///         ("new_ui".to_string(), "on".to_string()),
+    ///         ("api_token".to_string(), "abc123".to_string()),
+    ///     ]
+    /// });
+    ///
+    /// let output = version.render_plain(Verbosity::Diagnostic);
+    /// assert!(output.contains("config.new_ui: on"));
+    /// assert!(output.contains("config.api_token: [redacted]"));
+    /// ```
+    #[must_use]
+    pub fn with_config_snapshot<F>(mut self, provider: F) -> Self
+    where
+        F: Fn() -> Vec<(String, String)> + Send + Sync + 'static,
+    {
+        self.config_snapshot = Some(SnapshotProvider(Arc::new(provider)));
+        self
+    }
+
+    /// Redacts the values of snapshot keys matching `pattern`
+    ///
+    /// Patterns are matched case-insensitively against the whole key, `*`
+    /// matching any run of characters. `*token*`, `*secret*`, `*password*`
+    /// and `*key` are redacted by default.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_config_snapshot(|| vec![("db.url".to_string(), "postgres://...".to_string())])
+    ///     .with_redacted_key("db.*");
+    /// assert!(version.render_plain(Verbosity::Diagnostic).contains("config.db.url: [redacted]"));
+    /// ```
+    #[must_use]
+    pub fn with_redacted_key(mut self, pattern: impl Into<String>) -> Self {
+        self.redactions.push(pattern.into());
+        self
+    }
+
+    /// Returns the redacted snapshot lines shown at [`Verbosity::Diagnostic`]
+    pub(crate) fn snapshot_fields(&self) -> Vec<Field> {
+        let Some(provider) = &self.config_snapshot else {
+            return Vec::new();
+        };
+        (provider.0)()
+            .into_iter()
+            .map(|(key, value)| {
+                let redacted = DEFAULT_REDACTIONS
+                    .iter()
+                    .copied()
+                    .chain(self.redactions.iter().map(String::as_str))
+                    .any(|pattern| wildcard_match(pattern, &key));
+                Field {
+                    key: format!("config.{key}").into(),
+                    value: if redacted {
+                        REDACTED.into()
+                    } else {
+                        value.into()
+                    },
+                    color: None,
+                    level: Verbosity::Diagnostic,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Case-insensitive whole-string match where `*` matches any run of characters
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*token*", "GITHUB_TOKEN"));
+        assert!(wildcard_match("*key", "license.key"));
+        assert!(!wildcard_match("*key", "keyboard_layout"));
+        assert!(wildcard_match("db.*", "db.url"));
+        assert!(wildcard_match("exact", "Exact"));
+        assert!(!wildcard_match("exact", "exactly"));
+        assert!(wildcard_match("*", ""));
+    }
+
+    #[test]
+    fn test_snapshot_only_in_diagnostics() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_config_snapshot(|| {
+            vec![
+                ("beta".to_string(), "true".to_string()),
+                ("client_secret".to_string(), "s3cr3t".to_string()),
+            ]
+        });
+
+        assert!(!version.render_plain(Verbosity::Verbose).contains("config."));
+        assert_eq!(
+            version.render_plain(Verbosity::Diagnostic),
+            "app v1.0.0 by Jane\ninstalled via: unknown\nconfig.beta: true\nconfig.client_secret: [redacted]"
+        );
+    }
+}
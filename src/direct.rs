@@ -0,0 +1,128 @@
+// Project: clap-version-flag
+// File: src/direct.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Rendering the version line into a caller's buffer
+// License: MIT
+
+//! Direct rendering.
+//!
+//! [`ColorfulVersion::to_colored_string`] builds a `String` per segment and
+//! one more for the line. Hot paths, a version header on every log file or a
+//! line per request, can instead write into a buffer they reuse:
+//! [`ColorfulVersion::render_into`] appends to a `String` and
+//! [`ColorfulVersion::write_colored`] writes to any [`fmt::Write`], with the
+//! escape codes written piece by piece and no intermediate strings.
+//!
+//! Both write through the formatter of the regular output, which
+//! [`to_colored_string`](ColorfulVersion::to_colored_string) collects into a
+//! new `String`. The standard `name v1.0.0 by author` line, in any colors
+//! and labels, is written without allocating; text attributes, padding,
+//! templates, field selections, rainbows, right-to-left isolation,
+//! hyperlinks and per-component version styles still build pieces of it as
+//! strings.
+//! `benches/render.rs` compares the two.
+
+use std::fmt::{self, Write};
+
+use crate::ColorfulVersion;
+
+impl ColorfulVersion {
+    /// Appends the output of [`to_colored_string`](Self::to_colored_string) to `out`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let mut header = String::with_capacity(128);
+    /// for _ in 0..3 {
+    ///     header.clear();
+    ///     version.render_into(&mut header);
+    ///     assert_eq!(header, version.to_colored_string());
+    /// }
+    /// ```
+    pub fn render_into(&self, out: &mut String) {
+        // writing to a String cannot fail
+        let _ = self.write_colored(out);
+    }
+
+    /// Writes the output of [`to_colored_string`](Self::to_colored_string) to `out`
+    ///
+    /// # Errors
+    /// Returns the error of `out`, if any.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::fmt::Write;
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let mut log = String::new();
+    /// version.write_colored(&mut log).unwrap();
+    /// writeln!(log, " started").unwrap();
+    /// ```
+    pub fn write_colored(&self, out: &mut impl Write) -> fmt::Result {
+        if !self.colorize() {
+            return self.write_line(out, None);
+        }
+        self.check_contrast();
+        self.write_line(out, Some(self.depth()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorDepth, FieldWidth, Layout, Locale, Style, Styles};
+    use clap::ColorChoice;
+
+    fn rendered(version: &ColorfulVersion) -> String {
+        let mut out = String::new();
+        version.render_into(&mut out);
+        out
+    }
+
+    #[test]
+    fn test_matches_regular_renderer() {
+        let base = ColorfulVersion::new("app", "1.0.0", "Jo").with_color_depth(ColorDepth::Ansi256);
+        let versions = [
+            base.clone(),
+            base.clone().with_color_choice(ColorChoice::Always),
+            base.clone().with_color_choice(ColorChoice::Never),
+            base.clone()
+                .with_color_choice(ColorChoice::Always)
+                .with_styles(
+                    Styles::new()
+                        .name(Style::new().bold())
+                        .author(Style::new().italic()),
+                ),
+            base.clone()
+                .with_color_choice(ColorChoice::Always)
+                .with_locale(Locale::Pl),
+            base.clone()
+                .with_color_choice(ColorChoice::Always)
+                .with_styles(Styles::new().major(Style::new().bold())),
+            base.with_color_choice(ColorChoice::Always)
+                .with_layout(Layout::new().name(FieldWidth::new().min(8).max(10))),
+        ];
+        for version in &versions {
+            let colored = version.colorize();
+            assert_eq!(
+                rendered(version),
+                version.render_text(crate::Verbosity::Normal, colored)
+            );
+        }
+        let rtl = ColorfulVersion::new("app", "1.0", "محمد").with_color_choice(ColorChoice::Never);
+        assert_eq!(rendered(&rtl), "app v1.0 by \u{2068}محمد\u{2069}");
+    }
+
+    #[test]
+    fn test_appends() {
+        let version =
+            ColorfulVersion::new("app", "1.0", "Jo").with_color_choice(ColorChoice::Never);
+        let mut out = String::from("> ");
+        version.render_into(&mut out);
+        assert_eq!(out, "> app v1.0 by Jo");
+    }
+}
@@ -0,0 +1,264 @@
+// Project: clap-version-flag
+// File: src/early_exit.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Handling the version flag before any parser runs
+// License: MIT
+
+//! Early version handling.
+//!
+//! [`exit_on_version_flag`] looks for `-V`/`--version` in the raw command
+//! line and prints the version before the app's own parser runs, so the
+//! `Command` or `Parser` struct needs no changes at all. The
+//! `#[clap_version]` attribute of the `attribute` feature calls it at the
+//! top of `main`.
+//!
+//! Without the app's [`Command`] every other option is taken for a flag
+//! and every positional argument is searched past, so `myapp --verbose -V`
+//! and `myapp input.txt --version` print the version. With it,
+//! [`exit_on_version_flag_for`] searches only the arguments of the app
+//! itself: the token after an option that takes a value is that value
+//! (`myapp -e -V` passes `-V` to `-e`), and everything from a subcommand
+//! on belongs to the subcommand.
+
+use std::borrow::Cow;
+use std::ffi::OsString;
+use std::process;
+
+use clap::{Command, ValueEnum};
+
+use crate::{ColorfulVersion, PrintMode, Verbosity};
+
+/// Prints `version` and exits if the command line asks for the version
+///
+/// Recognizes `--version` and `-V`, repeated (`-VV`, `-V -V`) for more
+/// detail, up to a `--`, and a print mode given as their value
+/// (`--version=short`). A [`VersionFlagConfig`](crate::VersionFlagConfig)
+/// does not apply here; apps with a remapped flag register it on their
+/// `Command` instead. Every other option is taken for a flag, so an
+/// option value of `-V` (`myapp -e -V`) prints the version too;
+/// [`exit_on_version_flag_for`] knows the real arguments.
+///
+/// # Examples
+/// ```no_run
+/// use clap_version_flag::{colorful_version, exit_on_version_flag};
+///
+/// fn main() {
+///     exit_on_version_flag(&colorful_version!());
+///     // the app's own argument parsing follows
+/// }
+/// ```
+pub fn exit_on_version_flag(version: &ColorfulVersion) {
+    exit_on(version, version_request(std::env::args_os().skip(1), None));
+}
+
+/// Like [`exit_on_version_flag`], with the arguments of `cmd` known
+///
+/// Options of `cmd` that take a value skip their value, flags do not, and
+/// only subcommands of `cmd` end the search, so `myapp --verbose -V` and
+/// `myapp input.txt -V` print the version too.
+///
+/// # Examples
+/// ```no_run
+/// use clap::{Arg, Command};
+/// use clap_version_flag::{colorful_version, exit_on_version_flag_for};
+///
+/// fn main() {
+///     let cmd = Command::new("myapp").arg(Arg::new("expr").short('e'));
+///     exit_on_version_flag_for(&colorful_version!(), &cmd);
+///     let matches = cmd.get_matches();
+/// }
+/// ```
+pub fn exit_on_version_flag_for(version: &ColorfulVersion, cmd: &Command) {
+    exit_on(
+        version,
+        version_request(std::env::args_os().skip(1), Some(cmd)),
+    );
+}
+
+/// Prints `version` as requested and exits, if it was requested
+fn exit_on(version: &ColorfulVersion, request: Option<(Verbosity, Option<PrintMode>)>) {
+    let Some((verbosity, mode)) = request else {
+        return;
+    };
+    let version = match mode {
+        Some(mode) => Cow::Owned(version.clone().with_print_mode(mode)),
+        None => Cow::Borrowed(version),
+    };
+    version.print_with_verbosity(verbosity);
+    version.exit(0);
+    process::exit(0);
+}
+
+/// An option as written on the command line
+#[derive(Clone, Copy)]
+enum Opt<'a> {
+    Long(&'a str),
+    Short(char),
+}
+
+/// Returns whether `option` takes a value, never when `cmd` is unknown
+fn takes_value(cmd: Option<&Command>, option: Opt<'_>) -> bool {
+    let Some(cmd) = cmd else {
+        return false;
+    };
+    cmd.get_arguments()
+        .find(|arg| match option {
+            Opt::Long(name) => {
+                arg.get_long() == Some(name)
+                    || arg
+                        .get_all_aliases()
+                        .is_some_and(|aliases| aliases.contains(&name))
+            }
+            Opt::Short(c) => {
+                arg.get_short() == Some(c)
+                    || arg
+                        .get_all_short_aliases()
+                        .is_some_and(|aliases| aliases.contains(&c))
+            }
+        })
+        .is_some_and(|arg| arg.get_action().takes_values())
+}
+
+/// Returns whether the positional `arg` starts a subcommand, never when
+/// `cmd` is unknown
+fn starts_subcommand(cmd: Option<&Command>, arg: &str) -> bool {
+    cmd.is_some_and(|cmd| cmd.find_subcommand(arg).is_some())
+}
+
+/// Returns the verbosity and print mode the arguments ask for, `None`
+/// without a version flag
+fn version_request(
+    args: impl IntoIterator<Item = OsString>,
+    cmd: Option<&Command>,
+) -> Option<(Verbosity, Option<PrintMode>)> {
+    let mut count = 0u8;
+    let mut mode = None;
+    let mut value_next = false;
+    for arg in args {
+        if std::mem::take(&mut value_next) {
+            continue;
+        }
+        let Some(arg) = arg.to_str() else {
+            continue;
+        };
+        if arg == "--" {
+            break;
+        }
+        if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+            if name != "version" {
+                value_next = value.is_none() && takes_value(cmd, Opt::Long(name));
+                continue;
+            }
+            match value.map(|value| PrintMode::from_str(value, true)) {
+                None => {}
+                Some(Ok(requested)) => mode = Some(requested),
+                Some(Err(_)) => continue,
+            }
+            count = count.saturating_add(1);
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|shorts| !shorts.is_empty()) {
+            for (index, c) in shorts.char_indices() {
+                let rest = &shorts[index + c.len_utf8()..];
+                if c == 'V' {
+                    if let Some(value) = rest.strip_prefix('=') {
+                        if let Ok(requested) = PrintMode::from_str(value, true) {
+                            mode = Some(requested);
+                            count = count.saturating_add(1);
+                        }
+                        break;
+                    }
+                    count = count.saturating_add(1);
+                } else if takes_value(cmd, Opt::Short(c)) {
+                    value_next = rest.is_empty();
+                    break;
+                }
+            }
+        } else if starts_subcommand(cmd, arg) {
+            break;
+        }
+    }
+    Some((Verbosity::from_occurrences(count)?, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{Arg, ArgAction};
+
+    fn request(args: &[&str]) -> Option<Verbosity> {
+        request_for(None, args)
+    }
+
+    fn request_for(cmd: Option<&Command>, args: &[&str]) -> Option<Verbosity> {
+        version_request(args.iter().map(OsString::from), cmd).map(|(verbosity, _)| verbosity)
+    }
+
+    fn mode(args: &[&str]) -> Option<PrintMode> {
+        version_request(args.iter().map(OsString::from), None)?.1
+    }
+
+    #[test]
+    fn test_version_request() {
+        assert_eq!(request(&["input.txt"]), None);
+        assert_eq!(request(&["-", "--verbose"]), None);
+        assert_eq!(request(&["--version"]), Some(Verbosity::Normal));
+        assert_eq!(request(&["-VV"]), Some(Verbosity::Verbose));
+        assert_eq!(request(&["-V", "--version"]), Some(Verbosity::Verbose));
+        assert_eq!(request(&["--", "-V"]), None);
+    }
+
+    #[test]
+    fn test_unknown_options_are_flags() {
+        assert_eq!(
+            request(&["--verbose", "--version"]),
+            Some(Verbosity::Normal)
+        );
+        assert_eq!(request(&["-v", "-V"]), Some(Verbosity::Normal));
+        assert_eq!(request(&["-vV"]), Some(Verbosity::Normal));
+        assert_eq!(request(&["file", "--version"]), Some(Verbosity::Normal));
+        assert_eq!(request(&["--expr=x", "-V"]), Some(Verbosity::Normal));
+    }
+
+    #[test]
+    fn test_option_values_and_subcommands_skipped() {
+        let cmd = Command::new("app")
+            .arg(Arg::new("expr").short('e').long("expr"))
+            .arg(
+                Arg::new("verbose")
+                    .short('v')
+                    .long("verbose")
+                    .action(ArgAction::SetTrue),
+            )
+            .arg(Arg::new("input"))
+            .subcommand(Command::new("exec").alias("x"));
+        let cmd = Some(&cmd);
+        assert_eq!(request_for(cmd, &["-e", "-V"]), None);
+        assert_eq!(request_for(cmd, &["--expr", "-V"]), None);
+        assert_eq!(request_for(cmd, &["-e-V"]), None);
+        assert_eq!(request_for(cmd, &["exec", "ls", "-V"]), None);
+        assert_eq!(request_for(cmd, &["-ve", "-V"]), None);
+        assert_eq!(request_for(cmd, &["x", "-V"]), None);
+        assert_eq!(
+            request_for(cmd, &["--verbose", "-V"]),
+            Some(Verbosity::Normal)
+        );
+        assert_eq!(request_for(cmd, &["-vV"]), Some(Verbosity::Normal));
+        assert_eq!(
+            request_for(cmd, &["-V", "input.txt", "--version"]),
+            Some(Verbosity::Verbose)
+        );
+    }
+
+    #[test]
+    fn test_print_mode_value() {
+        assert_eq!(mode(&["--version"]), None);
+        assert_eq!(mode(&["--version=short"]), Some(PrintMode::Bare));
+        assert_eq!(mode(&["-V=bare"]), Some(PrintMode::Bare));
+        assert_eq!(mode(&["--version=full"]), Some(PrintMode::Full));
+        assert_eq!(request(&["--version=tiny"]), None);
+    }
+}
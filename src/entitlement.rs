@@ -0,0 +1,138 @@
+// Project: clap-version-flag
+// File: src/entitlement.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: License-key / entitlement line in the version output
+// License: MIT
+
+//! Entitlement line.
+//!
+//! Commercial tools often show the active edition or subscription next to
+//! the version ("Pro, expires 2026-01-01"). The app supplies that through a
+//! provider closure set with [`ColorfulVersion::with_entitlement`], which is
+//! only called when the version is rendered.
+
+use crate::{ColorfulVersion, Field, Role, Verbosity};
+use std::fmt;
+use std::sync::Arc;
+
+/// State of the user's entitlement, deciding the color of the line
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EntitlementStatus {
+    /// Valid (green)
+    Active,
+    /// Valid, but about to run out (yellow)
+    Expiring,
+    /// No longer valid (red)
+    Expired,
+}
+
+impl EntitlementStatus {
+    fn role(self) -> Role {
+        match self {
+            Self::Active => Role::Success,
+            Self::Expiring => Role::Warning,
+            Self::Expired => Role::Error,
+        }
+    }
+}
+
+/// Subscription or edition information shown in the version output
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entitlement {
+    /// Text of the line, e.g. "Pro, expires 2026-01-01"
+    pub label: String,
+    /// Status deciding the color of the line
+    pub status: EntitlementStatus,
+}
+
+impl Entitlement {
+    /// Creates an entitlement line
+    #[must_use]
+    pub fn new(label: impl Into<String>, status: EntitlementStatus) -> Self {
+        Self {
+            label: label.into(),
+            status,
+        }
+    }
+}
+
+type Provider = dyn Fn() -> Option<Entitlement> + Send + Sync;
+
+/// Shared handle to an entitlement provider
+#[derive(Clone)]
+pub(crate) struct EntitlementProvider(Arc<Provider>);
+
+impl fmt::Debug for EntitlementProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("EntitlementProvider")
+    }
+}
+
+impl ColorfulVersion {
+    /// Adds an entitlement line supplied by `provider`
+    ///
+    /// The provider is called every time the version is rendered as text; it
+    /// returns `None` to omit the line, e.g. for the free edition.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Entitlement, EntitlementStatus};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_entitlement(|| {
+    ///     Some(Entitlement::new("Pro, expires 2026-01-01", EntitlementStatus::Active))
+    /// });
+    /// assert_eq!(
+    ///     version.render(clap_version_flag::VersionFormat::Plain),
+    ///     "myapp v1.0.0 by John Doe\nsubscription: Pro, expires 2026-01-01"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_entitlement<F>(mut self, provider: F) -> Self
+    where
+        F: Fn() -> Option<Entitlement> + Send + Sync + 'static,
+    {
+        self.entitlement = Some(EntitlementProvider(Arc::new(provider)));
+        self
+    }
+
+    /// Returns the entitlement detail line, if a provider supplies one
+    pub(crate) fn entitlement_field(&self) -> Option<Field> {
+        let entitlement = (self.entitlement.as_ref()?.0)()?;
+        Some(Field {
+            key: "subscription".into(),
+            value: entitlement.label.into(),
+            color: Some(self.role(entitlement.status.role())),
+            level: Verbosity::Normal,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_colors() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_entitlement(|| Some(Entitlement::new("Trial", EntitlementStatus::Expired)));
+        let field = version.entitlement_field().unwrap();
+        assert_eq!(field.value, "Trial");
+        assert_eq!(field.color, Some((255, 0, 0)));
+    }
+
+    #[test]
+    fn test_provider_without_entitlement() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_entitlement(|| None);
+        assert_eq!(version.entitlement_field(), None);
+        assert_eq!(
+            version.as_verbose_plain_string(),
+            "app v1.0.0 by Jane\ninstalled via: unknown"
+        );
+        assert_eq!(
+            format!("{:?}", version.entitlement),
+            "Some(EntitlementProvider)"
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// Project: clap-version-flag
+// File: src/env_overrides.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Color overrides read from environment variables
+// License: MIT
+
+//! Color overrides from the environment.
+//!
+//! [`ColorfulVersion::with_env_overrides`] lets users theme the output from
+//! their shell profile, e.g. `export MYAPP_VERSION_NAME_BG=teal`.
+
+use crate::{ColorfulVersion, Colors, Warning, color};
+
+/// Selects the color a variable overrides
+type Slot = fn(&mut Colors) -> &mut (u8, u8, u8);
+
+/// Variable suffixes and the colors they override
+const OVERRIDES: [(&str, Slot); 8] = [
+    ("NAME_FG", |colors| &mut colors.name_fg),
+    ("NAME_BG", |colors| &mut colors.name_bg),
+    ("VERSION_FG", |colors| &mut colors.version_color),
+    ("AUTHOR_FG", |colors| &mut colors.author_color),
+    ("LICENSE_FG", |colors| &mut colors.license_color),
+    ("SUCCESS", |colors| &mut colors.success),
+    ("WARNING", |colors| &mut colors.warning),
+    ("ERROR", |colors| &mut colors.error),
+];
+
+/// Returns the override variables read for `prefix`
+pub(crate) fn variables(prefix: &str) -> impl Iterator<Item = String> + '_ {
+    OVERRIDES
+        .iter()
+        .map(move |(suffix, _)| format!("{prefix}_VERSION_{suffix}"))
+}
+
+impl ColorfulVersion {
+    /// Lets environment variables override the configured colors
+    ///
+    /// Each time the version is rendered, the following variables are read
+    /// and, when they hold a color [`parse_color`](color::parse_color)
+    /// understands, replace the corresponding color (invalid values are
+    /// ignored and recorded as a [`Warning::InvalidColorOverride`]):
+    ///
+    /// | Variable                      | Overrides                      |
+    /// |-------------------------------|--------------------------------|
+    /// | `{PREFIX}_VERSION_NAME_FG`    | name foreground                |
+    /// | `{PREFIX}_VERSION_NAME_BG`    | name background                |
+    /// | `{PREFIX}_VERSION_VERSION_FG` | version                        |
+    /// | `{PREFIX}_VERSION_AUTHOR_FG`  | author                         |
+    /// | `{PREFIX}_VERSION_LICENSE_FG` | license and copyright          |
+    /// | `{PREFIX}_VERSION_SUCCESS`    | [`Role::Success`](crate::Role) |
+    /// | `{PREFIX}_VERSION_WARNING`    | [`Role::Warning`](crate::Role) |
+    /// | `{PREFIX}_VERSION_ERROR`      | [`Role::Error`](crate::Role)   |
+    ///
+    /// Overrides apply on top of every other color setting, including the
+    /// high-contrast theme.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// // honors e.g. `MYAPP_VERSION_NAME_BG="rgb(0, 128, 128)"`
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_env_overrides("MYAPP");
+    /// ```
+    #[must_use]
+    pub fn with_env_overrides(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Applies the environment overrides, if enabled, to `colors`
+    pub(crate) fn apply_env_overrides(&self, colors: Colors) -> Colors {
+        match &self.env_prefix {
+            Some(prefix) => overridden(
+                colors,
+                prefix,
+                |name| std::env::var(name).ok(),
+                |variable, value| {
+                    self.warnings
+                        .push(Warning::InvalidColorOverride { variable, value });
+                },
+            ),
+            None => colors,
+        }
+    }
+}
+
+/// Overrides `colors` with the values `lookup` finds for `prefix`, passing
+/// each variable that is not a color to `ignored`
+fn overridden(
+    mut colors: Colors,
+    prefix: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+    mut ignored: impl FnMut(String, String),
+) -> Colors {
+    for (suffix, slot) in OVERRIDES {
+        let variable = format!("{prefix}_VERSION_{suffix}");
+        let Some(value) = lookup(&variable) else {
+            continue;
+        };
+        match color::parse_color(&value) {
+            Ok(rgb) => *slot(&mut colors) = rgb,
+            Err(_) => ignored(variable, value),
+        }
+    }
+    colors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overridden() {
+        let lookup = |name: &str| match name {
+            "APP_VERSION_NAME_BG" => Some("teal".to_string()),
+            "APP_VERSION_AUTHOR_FG" => Some("rgb(1, 2, 3)".to_string()),
+            "APP_VERSION_ERROR" => Some("not a color".to_string()),
+            _ => None,
+        };
+        let mut ignored = Vec::new();
+        let colors = overridden(Colors::default(), "APP", lookup, |variable, value| {
+            ignored.push((variable, value));
+        });
+
+        assert_eq!(colors.name_bg, (0, 128, 128));
+        assert_eq!(colors.author_color, (1, 2, 3));
+        assert_eq!(colors.name_fg, Colors::default().name_fg);
+        assert_eq!(colors.error, Colors::default().error);
+        assert_eq!(
+            ignored,
+            [("APP_VERSION_ERROR".to_string(), "not a color".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_disabled_without_prefix() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane");
+        let colors = version.apply_env_overrides(version.colors);
+        assert_eq!(colors.name_bg, version.colors.name_bg);
+    }
+}
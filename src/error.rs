@@ -9,11 +9,47 @@ use thiserror::Error;
 
 /// Error type for clap-version-flag
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum VersionError {
     /// Invalid hex color format
     #[error("Invalid hex color format: '{0}'. Expected format: #RRGGBB or #RGB")]
     InvalidHexColor(String),
 
+    /// Unknown color name or unsupported color syntax
+    #[error("Invalid color: '{0}'. Expected #RRGGBB, #RGB, rgb(), hsl() or a CSS color name")]
+    InvalidColor(String),
+
+    /// Invalid date or timestamp
+    #[error("Invalid date: '{0}'. Expected YYYY-MM-DD, an RFC 3339 date-time or Unix seconds")]
+    InvalidDate(String),
+
+    /// Version string that is not a valid semantic version
+    #[error("Invalid version: '{0}'. Expected MAJOR.MINOR.PATCH[-PRE][+BUILD]")]
+    InvalidVersion(String),
+
+    /// Text that is not a `"{name} v{version} by {author}"` version line
+    #[error("Invalid version line: '{0}'. Expected \"NAME vVERSION by AUTHOR\"")]
+    InvalidVersionLine(String),
+
+    /// Unusable color palette
+    #[error("Invalid palette: {0}")]
+    InvalidPalette(String),
+
+    /// Malformed configuration file
+    #[error("Invalid config: {0}")]
+    InvalidConfig(String),
+
+    /// The version flag clashes with an argument the command already has
+    #[error("Version flag conflict: {flag} is already used by argument '{arg}'. {hint}")]
+    FlagConflict {
+        /// The clashing id, short or long flag, e.g. `-V`
+        flag: String,
+        /// Id of the argument already using it
+        arg: String,
+        /// How to resolve the clash
+        hint: String,
+    },
+
     /// I/O error
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -25,3 +61,11 @@ impl VersionError {
         Self::InvalidHexColor(color.to_string())
     }
 }
+
+/// Lets infallible conversions, e.g. RGB tuples into a
+/// [`ColorSpec`](crate::ColorSpec), be used where a `VersionError` is expected
+impl From<std::convert::Infallible> for VersionError {
+    fn from(never: std::convert::Infallible) -> Self {
+        match never {}
+    }
+}
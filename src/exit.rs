@@ -0,0 +1,101 @@
+// Project: clap-version-flag
+// File: src/exit.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Pluggable exit strategy for version-triggered exits
+// License: MIT
+
+//! Pluggable exit strategy.
+//!
+//! Printing the version normally ends the process with `process::exit(0)`,
+//! which skips destructors and cannot be observed in tests. An
+//! [`ExitHandler`] installed with
+//! [`ColorfulVersion::with_exit_handler`](crate::ColorfulVersion::with_exit_handler)
+//! is called instead, so embedded, daemon and test contexts can flush logs,
+//! panic, or return control to the caller.
+
+use std::fmt;
+use std::process;
+use std::sync::Arc;
+
+/// Decides what happens after the version has been printed
+///
+/// Closures taking the exit code implement this trait too.
+///
+/// If the handler returns, [`check_and_exit`](crate::ColorfulVersion::check_and_exit)
+/// returns to its caller. [`print_and_exit`](crate::ColorfulVersion::print_and_exit)
+/// and [`parse_with_version`](crate::parse_with_version) cannot return
+/// normally and fall back to `process::exit` in that case.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, ExitHandler};
+///
+/// struct PanicOnExit;
+///
+/// impl ExitHandler for PanicOnExit {
+///     fn exit(&self, code: i32) {
+///         panic!("version requested, exit code {code}");
+///     }
+/// }
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_exit_handler(Box::new(PanicOnExit));
+/// ```
+pub trait ExitHandler: Send + Sync {
+    /// Called with the exit code once the version has been printed
+    fn exit(&self, code: i32);
+}
+
+impl<F: Fn(i32) + Send + Sync> ExitHandler for F {
+    fn exit(&self, code: i32) {
+        self(code);
+    }
+}
+
+/// The default exit strategy: terminates the process with `process::exit`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProcessExit;
+
+impl ExitHandler for ProcessExit {
+    fn exit(&self, code: i32) {
+        process::exit(code);
+    }
+}
+
+/// Shared handle to an installed [`ExitHandler`]
+#[derive(Clone)]
+pub(crate) struct SharedExitHandler(Arc<dyn ExitHandler>);
+
+impl SharedExitHandler {
+    pub(crate) fn new(handler: Box<dyn ExitHandler>) -> Self {
+        Self(Arc::from(handler))
+    }
+
+    pub(crate) fn exit(&self, code: i32) {
+        self.0.exit(code);
+    }
+}
+
+impl fmt::Debug for SharedExitHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ExitHandler")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    #[test]
+    fn test_closure_handler() {
+        let code = Arc::new(AtomicI32::new(-1));
+        let seen = Arc::clone(&code);
+        let handler = SharedExitHandler::new(Box::new(move |c| seen.store(c, Ordering::SeqCst)));
+
+        handler.clone().exit(3);
+        assert_eq!(code.load(Ordering::SeqCst), 3);
+        assert_eq!(format!("{handler:?}"), "ExitHandler");
+    }
+}
@@ -0,0 +1,229 @@
+// Project: clap-version-flag
+// File: src/expiry.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Expiring-build enforcement for time-limited builds
+// License: MIT
+
+//! Expiring builds.
+//!
+//! Time-limited beta builds can carry an expiry date set with
+//! [`ColorfulVersion::with_expiry`]. Past that date the build either warns on
+//! every run or refuses to run, with the message rendered in the version's
+//! colors.
+
+use crate::{ColorfulVersion, Role, VersionError, age, color};
+use std::time::SystemTime;
+
+/// What an expired build does
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExpiryAction {
+    /// Print a warning to stderr and keep running
+    Warn,
+    /// Print an error to stderr and exit with code 1
+    Refuse,
+}
+
+/// An expiry date together with the action taken past it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Expiry {
+    pub(crate) at: SystemTime,
+    pub(crate) action: ExpiryAction,
+}
+
+impl ColorfulVersion {
+    /// Makes the build expire at `date`
+    ///
+    /// `date` is `YYYY-MM-DD` (taken as UTC midnight), an RFC 3339 date-time
+    /// or Unix seconds. Expiry is enforced by [`ColorfulVersion::enforce_expiry`],
+    /// which every version check calls when no version output was requested:
+    /// the `check*` methods, [`handle_version_subcommand`] and the
+    /// `parse_with_version*` functions. So `--version` keeps working on
+    /// expired builds.
+    ///
+    /// [`handle_version_subcommand`]: ColorfulVersion::handle_version_subcommand
+    ///
+    /// # Errors
+    /// Returns [`VersionError::InvalidDate`] if `date` cannot be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, ExpiryAction};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "2.0.0-beta.1", "John Doe")
+    ///     .with_expiry("2000-01-01", ExpiryAction::Warn)
+    ///     .unwrap();
+    /// assert!(version.is_expired());
+    /// ```
+    pub fn with_expiry(self, date: &str, action: ExpiryAction) -> Result<Self, VersionError> {
+        let at =
+            age::parse_timestamp(date).ok_or_else(|| VersionError::InvalidDate(date.into()))?;
+        Ok(self.with_expiry_time(at, action))
+    }
+
+    /// Makes the build expire at the given instant
+    #[must_use]
+    pub fn with_expiry_time(mut self, at: SystemTime, action: ExpiryAction) -> Self {
+        self.expiry = Some(Expiry { at, action });
+        self
+    }
+
+    /// Returns whether the build has passed its expiry date
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expiry
+            .is_some_and(|expiry| SystemTime::now() >= expiry.at)
+    }
+
+    /// Returns the warning or refusal message, if the build has expired
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, ExpiryAction};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "2.0.0-beta.1", "John Doe")
+    ///     .with_expiry("2000-01-01", ExpiryAction::Refuse)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     version.expiry_message().unwrap(),
+    ///     "myapp v2.0.0-beta.1 by John Doe\nerror: this build expired on 2000-01-01 and can no longer be used"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn expiry_message(&self) -> Option<String> {
+        let expiry = self.expiry.filter(|_| self.is_expired())?;
+        Some(self.render_expiry(expiry, self.colorize()))
+    }
+
+    /// Warns or exits with code 1 if the build has expired
+    ///
+    /// The exit goes through the [`ExitHandler`](crate::ExitHandler).
+    pub fn enforce_expiry(&self) {
+        let Some(message) = self.expiry_message() else {
+            return;
+        };
+        eprintln!("{message}");
+        if matches!(
+            self.expiry,
+            Some(Expiry {
+                action: ExpiryAction::Refuse,
+                ..
+            })
+        ) {
+            self.exit(1);
+        }
+    }
+
+    /// Passes `requested` through, enforcing the expiry when it is `None`
+    ///
+    /// Every check for a version request goes through here, so a run that
+    /// does not ask for the version warns or refuses however it was parsed.
+    pub(crate) fn expiry_checked<T>(&self, requested: Option<T>) -> Option<T> {
+        if requested.is_none() {
+            self.enforce_expiry();
+        }
+        requested
+    }
+
+    fn render_expiry(&self, expiry: Expiry, colored: bool) -> String {
+        let date = age::format_date(expiry.at);
+        let (text, role) = match expiry.action {
+            ExpiryAction::Warn => (
+                format!("warning: this build expired on {date}, please update"),
+                Role::Warning,
+            ),
+            ExpiryAction::Refuse => (
+                format!("error: this build expired on {date} and can no longer be used"),
+                Role::Error,
+            ),
+        };
+        if !colored {
+            return format!("{}\n{text}", self.as_plain_string());
+        }
+        let depth = self.depth();
+        format!(
+            "{}\n{}",
+            self.colored_line(depth),
+            color::paint(&text, self.role(role), None, depth)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorDepth;
+    use std::time::Duration;
+
+    #[test]
+    fn test_not_expired() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_expiry_time(
+            SystemTime::now() + Duration::from_secs(3600),
+            ExpiryAction::Refuse,
+        );
+        assert!(!version.is_expired());
+        assert_eq!(version.expiry_message(), None);
+        // Not expired, so this returns instead of exiting
+        version.enforce_expiry();
+    }
+
+    #[test]
+    fn test_warn_message() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_expiry("2020-05-01T00:00:00Z", ExpiryAction::Warn)
+            .unwrap()
+            .with_color_depth(ColorDepth::TrueColor);
+        let expiry = version.expiry.unwrap();
+
+        assert_eq!(
+            version.render_expiry(expiry, false),
+            "app v1.0.0 by Jane\nwarning: this build expired on 2020-05-01, please update"
+        );
+        assert!(version.render_expiry(expiry, true).ends_with(
+            "\x1b[38;2;255;255;0mwarning: this build expired on 2020-05-01, please update\x1b[0m"
+        ));
+    }
+
+    #[test]
+    fn test_enforced_on_every_path() {
+        use crate::{ColorfulVersionExt, VersionOutcome, try_parse_with_version_from};
+        use clap::{Command, Parser};
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicI32, Ordering};
+
+        #[derive(Parser)]
+        struct Cli {}
+
+        let code = Arc::new(AtomicI32::new(0));
+        let recorded = Arc::clone(&code);
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_expiry("2000-01-01", ExpiryAction::Refuse)
+            .unwrap()
+            .with_exit_handler(Box::new(move |exit| recorded.store(exit, Ordering::SeqCst)));
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_subcommand(&version);
+
+        let matches = cmd.clone().try_get_matches_from(["app", "-V"]).unwrap();
+        assert!(version.check(&matches));
+        assert_eq!(code.load(Ordering::SeqCst), 0);
+
+        let matches = cmd.try_get_matches_from(["app"]).unwrap();
+        assert!(!version.check(&matches));
+        assert_eq!(code.swap(0, Ordering::SeqCst), 1);
+        version.handle_version_subcommand(&matches);
+        assert_eq!(code.swap(0, Ordering::SeqCst), 1);
+
+        let outcome: VersionOutcome<Cli> =
+            try_parse_with_version_from(Command::new("app"), &version, ["app"]).unwrap();
+        assert!(matches!(outcome, VersionOutcome::Parsed(_)));
+        assert_eq!(code.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_invalid_date() {
+        let result =
+            ColorfulVersion::new("app", "1.0.0", "Jane").with_expiry("soon", ExpiryAction::Warn);
+        assert!(matches!(result, Err(VersionError::InvalidDate(date)) if date == "soon"));
+    }
+}
@@ -0,0 +1,107 @@
+// Project: clap-version-flag
+// File: src/features.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Enabled Cargo features in the verbose output
+// License: MIT
+
+//! Enabled Cargo features.
+//!
+//! "Which build variant is this binary?" is answered by the Cargo features it
+//! was compiled with. Record them with
+//! [`colorful_version_with_features!`](crate::colorful_version_with_features),
+//! with [`build::emit_features`](crate::build::emit_features) and
+//! [`BuildInfo`](crate::BuildInfo), or by hand with
+//! [`ColorfulVersion::with_features`]; the verbose output then lists them as
+//! `features: json, tls`, each name painted in the [`Role::Success`] color.
+
+use crate::{ColorDepth, ColorfulVersion, Field, Role, Verbosity, color};
+
+impl ColorfulVersion {
+    /// Sets the enabled Cargo features shown in the verbose output
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_features(["json", "tls"]);
+    /// assert_eq!(
+    ///     version.as_verbose_plain_string(),
+    ///     "myapp v1.0.0 by John Doe\nfeatures: json, tls\ninstalled via: unknown"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_features(mut self, features: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.features = features.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns the enabled Cargo features
+    #[must_use]
+    pub fn features(&self) -> &[String] {
+        &self.features
+    }
+
+    /// Returns the "features" detail line, if any feature is recorded
+    pub(crate) fn features_field(&self) -> Option<Field> {
+        (!self.features.is_empty()).then(|| Field {
+            key: "features".into(),
+            value: self.features.join(", ").into(),
+            color: None,
+            level: Verbosity::Verbose,
+        })
+    }
+
+    /// Paints each feature name, leaving the separators uncolored
+    pub(crate) fn colored_features(&self, depth: ColorDepth) -> String {
+        let rgb = self.role(Role::Success);
+        self.features
+            .iter()
+            .map(|feature| color::paint(feature, rgb, None, depth))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BuildInfo;
+
+    #[test]
+    fn test_no_features_no_line() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        assert_eq!(version.features_field(), None);
+        assert!(!version.as_verbose_plain_string().contains("features"));
+    }
+
+    #[test]
+    fn test_colored_list() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_features(["a", "b"]);
+        assert_eq!(
+            version.colored_features(ColorDepth::TrueColor),
+            "\x1b[38;2;0;255;0ma\x1b[0m, \x1b[38;2;0;255;0mb\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_features_from_build_info() {
+        const INFO: BuildInfo = BuildInfo::new("app", "1.0", "Jo").with_features(Some("tls,json"));
+        let version = ColorfulVersion::from_build_info(&INFO);
+        assert_eq!(version.features(), ["json", "tls"]);
+    }
+
+    #[test]
+    fn test_macro_filters_disabled_features() {
+        let version = crate::colorful_version_with_features!(["ffi", "figlet",]);
+        let expected: Vec<&str> = [
+            ("ffi", cfg!(feature = "ffi")),
+            ("figlet", cfg!(feature = "figlet")),
+        ]
+        .into_iter()
+        .filter_map(|(feature, enabled)| enabled.then_some(feature))
+        .collect();
+        assert_eq!(version.features(), expected);
+    }
+}
@@ -0,0 +1,125 @@
+// Project: clap-version-flag
+// File: src/ffi.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: C functions returning the version information
+// License: MIT
+
+//! C interface.
+//!
+//! Non-Rust code embedding a Rust binary or library (a C host, a Python
+//! wrapper using `ctypes`) can read the same version information the version
+//! flag prints. The Rust side [`register`]s its version once at startup; the
+//! foreign side calls the functions declared in [`HEADER`]:
+//!
+//! ```c
+//! #include "clap_version_flag.h"
+//!
+//! const char *json = cvf_version_json();
+//! if (json != NULL) {
+//!     puts(json);
+//! }
+//! ```
+//!
+//! Requires the `ffi` feature.
+
+use crate::ColorfulVersion;
+use std::ffi::{CString, c_char};
+use std::ptr;
+use std::sync::OnceLock;
+
+/// The C header declaring the exported functions
+///
+/// Ship it with the library, e.g. by writing it out from a build script.
+pub const HEADER: &str = include_str!("../include/clap_version_flag.h");
+
+/// The strings handed out to C, rendered once at registration
+struct Exported {
+    plain: CString,
+    json: CString,
+}
+
+static EXPORTED: OnceLock<Exported> = OnceLock::new();
+
+/// Makes `version` the version returned by the C functions
+///
+/// Only the first registration takes effect; returns `false` if a version
+/// was already registered.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, ffi};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// assert!(ffi::register(&version));
+///
+/// let plain = unsafe { std::ffi::CStr::from_ptr(ffi::cvf_version_plain()) };
+/// assert_eq!(plain.to_str().unwrap(), "myapp v1.0.0 by John Doe");
+/// ```
+pub fn register(version: &ColorfulVersion) -> bool {
+    EXPORTED
+        .set(Exported {
+            plain: c_string(version.as_plain_string()),
+            json: c_string(version.to_json()),
+        })
+        .is_ok()
+}
+
+/// Converts `text` to a C string, dropping NUL bytes C cannot represent
+fn c_string(text: String) -> CString {
+    CString::new(text.replace('\0', "")).unwrap_or_default()
+}
+
+/// Returns the plain version line, or NULL if no version is registered
+#[unsafe(no_mangle)]
+pub extern "C" fn cvf_version_plain() -> *const c_char {
+    EXPORTED
+        .get()
+        .map_or(ptr::null(), |exported| exported.plain.as_ptr())
+}
+
+/// Returns the version as a JSON object, or NULL if no version is registered
+#[unsafe(no_mangle)]
+pub extern "C" fn cvf_version_json() -> *const c_char {
+    EXPORTED
+        .get()
+        .map_or(ptr::null(), |exported| exported.json.as_ptr())
+}
+
+/// Returns the schema version of [`cvf_version_json`]
+#[unsafe(no_mangle)]
+pub extern "C" fn cvf_schema_version() -> u32 {
+    ColorfulVersion::SCHEMA_VERSION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn test_header_declares_every_function() {
+        for function in [
+            "cvf_version_plain(void)",
+            "cvf_version_json(void)",
+            "cvf_schema_version(void)",
+        ] {
+            assert!(HEADER.contains(function), "{function} missing from header");
+        }
+    }
+
+    #[test]
+    fn test_register_once() {
+        let version = ColorfulVersion::new("app\0", "1.0.0", "Jane");
+        assert!(register(&version));
+        assert!(!register(&ColorfulVersion::new("other", "2.0.0", "Joe")));
+
+        let read = |pointer: *const c_char| unsafe { CStr::from_ptr(pointer) }.to_str().unwrap();
+        assert_eq!(read(cvf_version_plain()), "app v1.0.0 by Jane");
+        assert_eq!(
+            read(cvf_version_json()),
+            r#"{"schema_version":1,"name":"app\u0000","version":"1.0.0","author":"Jane","metadata":{}}"#
+        );
+        assert_eq!(cvf_schema_version(), 1);
+    }
+}
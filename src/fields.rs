@@ -0,0 +1,192 @@
+// Project: clap-version-flag
+// File: src/fields.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Selecting exactly which fields the version output shows
+// License: MIT
+
+//! Field selection.
+//!
+//! Scripts sometimes need an odd subset of the version output, e.g. just the
+//! name and the commit. [`ColorfulVersion::with_visible_fields`] keeps only
+//! the named fields, and
+//! [`ColorfulVersionExt::with_version_fields_flag`](crate::ColorfulVersionExt::with_version_fields_flag)
+//! lets end users pick them with `--version-fields name,version,commit`:
+//!
+//! ```text
+//! $ myapp --version --version-fields name,version,commit
+//! myapp v1.0.0
+//! commit: abc1234
+//! ```
+//!
+//! `name`, `version` and `author` select parts of the version line; any
+//! other name selects the detail line with that key, whatever its verbosity.
+//! Keys compare case-insensitively, with `-`, `_` and spaces interchangeable
+//! (`installed-via` selects "installed via"). Unknown names are ignored.
+
+use crate::{ColorDepth, ColorfulVersion, Verbosity};
+
+/// Field names that select parts of the version line
+const HEADLINE_FIELDS: [&str; 3] = ["name", "version", "author"];
+
+impl ColorfulVersion {
+    /// Shows only the named fields
+    ///
+    /// In the text and plain output the selection replaces the banner,
+    /// leaders, template and logo. The JSON output keeps the keys every
+    /// object holds, so it still matches the schema, and keeps only the
+    /// selected `metadata` keys.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.0.0", "John Doe")
+    ///     .extra("commit", "abc1234")
+    ///     .build()
+    ///     .with_visible_fields(["name", "version", "commit"]);
+    /// assert_eq!(
+    ///     version.render_plain(Verbosity::Normal),
+    ///     "myapp v1.0.0\ncommit: abc1234"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_visible_fields(
+        mut self,
+        fields: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.visible_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Returns the selected field names, if a selection is set
+    #[must_use]
+    pub fn visible_fields(&self) -> Option<&[String]> {
+        self.visible_fields.as_deref()
+    }
+
+    /// Renders only the selected fields, painted when `depth` is set
+    ///
+    /// The version line keeps its usual shape minus the unselected parts;
+    /// detail lines follow in the order they were selected.
+    pub(crate) fn render_selected(
+        &self,
+        selection: &[String],
+        depth: Option<ColorDepth>,
+    ) -> String {
+        let selected = |name: &str| selection.iter().any(|field| same_key(field, name));
+        let [name, version, author] = self.fitted_segments();
+        let palette = self.palette();
+        let parts = [
+            (
+                name.text.into_owned(),
+                &self.styles.name,
+                palette.name_fg,
+                Some(palette.name_bg),
+            ),
+            (
+                version.content(),
+                &self.styles.version,
+                palette.version_color,
+                self.version_background(),
+            ),
+            (
+                match self.separators {
+                    Some(_) => author.text.into_owned(),
+                    None => self.attributed(&author.text),
+                },
+                &self.styles.author,
+                palette.author_color,
+                self.author_background(),
+            ),
+        ];
+        let mut headline = String::new();
+        let shown = parts
+            .into_iter()
+            .zip(HEADLINE_FIELDS)
+            .filter(|(_, key)| selected(key));
+        for (index, ((text, style, fg, bg), key)) in shown.enumerate() {
+            match (index, key, &self.separators) {
+                (0, ..) => {}
+                (_, _, None) => headline.push(' '),
+                (_, "author", Some(_)) => {
+                    headline.push_str(&self.paint_separator(&self.author_separator(), depth));
+                }
+                _ => headline.push_str(&self.paint_separator(&self.name_separator(), depth)),
+            }
+            headline.push_str(&match depth {
+                Some(depth) if key == "name" => self.paint_name(&text, depth),
+                Some(depth) => self.paint_segment(&text, style, fg, bg, depth),
+                None => text,
+            });
+        }
+
+        let fields = self.detail_fields(Verbosity::Diagnostic);
+        let mut lines = Vec::new();
+        if !headline.is_empty() {
+            lines.push(headline);
+        }
+        for wanted in selection {
+            if HEADLINE_FIELDS.iter().any(|key| same_key(wanted, key)) {
+                continue;
+            }
+            if let Some(field) = fields.iter().find(|field| same_key(wanted, &field.key)) {
+                lines.push(self.detail_line(field, depth));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Compares field keys ignoring case and `-`/`_`/space differences
+pub(crate) fn same_key(a: &str, b: &str) -> bool {
+    let normalize = |key: &str| -> String {
+        key.trim()
+            .chars()
+            .map(|c| match c {
+                '-' | '_' => ' ',
+                c => c.to_ascii_lowercase(),
+            })
+            .collect()
+    };
+    normalize(a) == normalize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_key() {
+        assert!(same_key("installed-via", "installed via"));
+        assert!(same_key("Build_ID", "build-id"));
+        assert!(!same_key("build", "build-id"));
+    }
+
+    #[test]
+    fn test_verbose_field_selected_at_normal() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_install_method(crate::InstallMethod::Cargo)
+            .with_visible_fields(["installed-via", "version"]);
+        assert_eq!(
+            version.render_plain(Verbosity::Normal),
+            "v1.0\ninstalled via: cargo install"
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields_ignored() {
+        let version =
+            ColorfulVersion::new("app", "1.0", "Jo").with_visible_fields(["nope", "author"]);
+        assert_eq!(version.render_plain(Verbosity::Normal), "by Jo");
+    }
+
+    #[test]
+    fn test_colored_selection() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_visible_fields(["version"]);
+        assert_eq!(
+            version.render_selected(&["version".into()], Some(ColorDepth::TrueColor)),
+            "\x1b[38;2;255;255;0mv1.0\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,224 @@
+// Project: clap-version-flag
+// File: src/figlet.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Large ASCII-art rendering of the package name
+// License: MIT
+
+//! ASCII-art names.
+//!
+//! [`ColorfulVersion::with_ascii_art`] renders the package name in large
+//! letters, five rows high, followed by the version and author. The letters
+//! come from a small bundled font covering `A`–`Z` (lowercase is drawn as
+//! uppercase), `0`–`9`, space, `-`, `_` and `.`; other characters are drawn
+//! as `?`. When colored, the rows blend from the [`Role::Primary`] to the
+//! [`Role::Accent`] color.
+//!
+//! Requires the `figlet` feature.
+
+use crate::{ColorDepth, ColorfulVersion, Role, color};
+
+/// Number of rows of every glyph
+const HEIGHT: usize = 5;
+
+/// Glyphs as rows of `#` and spaces
+const GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    ('A', [" ## ", "#  #", "####", "#  #", "#  #"]),
+    ('B', ["### ", "#  #", "### ", "#  #", "### "]),
+    ('C', [" ###", "#   ", "#   ", "#   ", " ###"]),
+    ('D', ["### ", "#  #", "#  #", "#  #", "### "]),
+    ('E', ["####", "#   ", "### ", "#   ", "####"]),
+    ('F', ["####", "#   ", "### ", "#   ", "#   "]),
+    ('G', [" ###", "#   ", "# ##", "#  #", " ###"]),
+    ('H', ["#  #", "#  #", "####", "#  #", "#  #"]),
+    ('I', ["###", " # ", " # ", " # ", "###"]),
+    ('J', ["  ##", "   #", "   #", "#  #", " ## "]),
+    ('K', ["#  #", "# # ", "##  ", "# # ", "#  #"]),
+    ('L', ["#   ", "#   ", "#   ", "#   ", "####"]),
+    ('M', ["#   #", "## ##", "# # #", "#   #", "#   #"]),
+    ('N', ["#   #", "##  #", "# # #", "#  ##", "#   #"]),
+    ('O', [" ## ", "#  #", "#  #", "#  #", " ## "]),
+    ('P', ["### ", "#  #", "### ", "#   ", "#   "]),
+    ('Q', [" ## ", "#  #", "#  #", "# ##", " ###"]),
+    ('R', ["### ", "#  #", "### ", "# # ", "#  #"]),
+    ('S', [" ###", "#   ", " ## ", "   #", "### "]),
+    ('T', ["#####", "  #  ", "  #  ", "  #  ", "  #  "]),
+    ('U', ["#  #", "#  #", "#  #", "#  #", " ## "]),
+    ('V', ["#   #", "#   #", "#   #", " # # ", "  #  "]),
+    ('W', ["#   #", "#   #", "# # #", "## ##", "#   #"]),
+    ('X', ["#   #", " # # ", "  #  ", " # # ", "#   #"]),
+    ('Y', ["#   #", " # # ", "  #  ", "  #  ", "  #  "]),
+    ('Z', ["####", "   #", "  # ", " #  ", "####"]),
+    ('0', [" ## ", "# ##", "## #", "#  #", " ## "]),
+    ('1', [" # ", "## ", " # ", " # ", "###"]),
+    ('2', ["### ", "   #", " ## ", "#   ", "####"]),
+    ('3', ["### ", "   #", " ## ", "   #", "### "]),
+    ('4', ["#  #", "#  #", "####", "   #", "   #"]),
+    ('5', ["####", "#   ", "### ", "   #", "### "]),
+    ('6', [" ## ", "#   ", "### ", "#  #", " ## "]),
+    ('7', ["####", "   #", "  # ", " #  ", " #  "]),
+    ('8', [" ## ", "#  #", " ## ", "#  #", " ## "]),
+    ('9', [" ## ", "#  #", " ###", "   #", " ## "]),
+    (' ', ["  ", "  ", "  ", "  ", "  "]),
+    ('-', ["   ", "   ", "###", "   ", "   "]),
+    ('_', ["    ", "    ", "    ", "    ", "####"]),
+    ('.', [" ", " ", " ", " ", "#"]),
+    ('?', ["### ", "   #", " ## ", "    ", " #  "]),
+];
+
+/// Font of the ASCII-art name
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Font {
+    /// Letters drawn with `#`, safe for any terminal and log file
+    #[default]
+    Standard,
+    /// Letters drawn with full blocks (`█`)
+    Block,
+}
+
+impl Font {
+    /// Returns the character the glyphs are drawn with
+    fn ink(self) -> char {
+        match self {
+            Self::Standard => '#',
+            Self::Block => '█',
+        }
+    }
+
+    /// Renders `text` as rows of large letters
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Font;
+    ///
+    /// assert_eq!(
+    ///     Font::Standard.render("Hi"),
+    ///     ["#  # ###", "#  #  #", "####  #", "#  #  #", "#  # ###"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn render(self, text: &str) -> Vec<String> {
+        let glyphs: Vec<&[&str; HEIGHT]> = text.chars().map(glyph).collect();
+        (0..HEIGHT)
+            .map(|row| {
+                let line = glyphs
+                    .iter()
+                    .map(|glyph| glyph[row])
+                    .collect::<Vec<_>>()
+                    .join(" ")
+                    .replace('#', &self.ink().to_string());
+                line.trim_end().to_string()
+            })
+            .collect()
+    }
+}
+
+/// Returns the glyph of `c`, falling back to `?`
+fn glyph(c: char) -> &'static [&'static str; HEIGHT] {
+    let c = c.to_ascii_uppercase();
+    GLYPHS
+        .iter()
+        .find(|(key, _)| *key == c)
+        .or_else(|| GLYPHS.iter().find(|(key, _)| *key == '?'))
+        .map(|(_, rows)| rows)
+        .unwrap_or(&GLYPHS[0].1)
+}
+
+impl ColorfulVersion {
+    /// Renders the package name in large letters above the version
+    ///
+    /// Applies to the text output of the version flag and
+    /// [`print`](Self::print); [`as_plain_string`](Self::as_plain_string)
+    /// keeps the single line clap shows.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Font, VersionFormat};
+    ///
+    /// let version = ColorfulVersion::new("ok", "1.0.0", "John Doe").with_ascii_art(Font::Standard);
+    /// assert_eq!(
+    ///     version.render(VersionFormat::Plain),
+    ///     " ##  #  #\n#  # # #\n#  # ##\n#  # # #\n ##  #  #\nv1.0.0 by John Doe"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_ascii_art(mut self, font: Font) -> Self {
+        self.ascii_art = Some(font);
+        self
+    }
+
+    /// Renders the art rows followed by "v{version} by {author}"
+    pub(crate) fn render_ascii_art(&self, font: Font, depth: Option<ColorDepth>) -> String {
+        let [_, version, author] = self.fitted_segments();
+        let rows = font.render(&self.package_name);
+        let Some(depth) = depth else {
+            let mut out = rows.join("\n");
+            out.push_str(&format!(
+                "\n{} {}",
+                version.padded(),
+                self.attributed(&author.padded())
+            ));
+            return out;
+        };
+
+        let colors = color::gradient(
+            self.role(Role::Primary),
+            self.role(Role::Accent),
+            rows.len(),
+        );
+        let mut out: Vec<String> = rows
+            .iter()
+            .zip(colors)
+            .map(|(row, rgb)| color::paint(row, rgb, None, depth))
+            .collect();
+        out.push(format!(
+            "{}{}",
+            self.colored_version("", depth),
+            self.colored_author(depth)
+        ));
+        out.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grapheme;
+
+    fn width(rows: &[String]) -> usize {
+        rows.iter()
+            .map(|row| grapheme::width(row))
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_every_glyph_is_rectangular() {
+        for (c, rows) in GLYPHS {
+            let width = rows[0].len();
+            assert!(rows.iter().all(|row| row.len() == width), "glyph {c:?}");
+        }
+    }
+
+    #[test]
+    fn test_fallback_and_case() {
+        assert_eq!(Font::Standard.render("a"), Font::Standard.render("A"));
+        assert_eq!(Font::Standard.render("ß"), Font::Standard.render("?"));
+        assert_eq!(width(&Font::Block.render("MM")), 11);
+        assert_eq!(Font::Block.render("I")[0], "███");
+    }
+
+    #[test]
+    fn test_colored_rows_blend() {
+        let version = ColorfulVersion::new("i", "1.0", "Jo").with_ascii_art(Font::Standard);
+        let art = version.render_ascii_art(Font::Standard, Some(ColorDepth::TrueColor));
+        let lines: Vec<&str> = art.lines().collect();
+        assert_eq!(lines[0], "\x1b[38;2;170;0;255m###\x1b[0m");
+        assert_eq!(lines[4], "\x1b[38;2;255;255;0m###\x1b[0m");
+        assert_eq!(
+            lines[5],
+            "\x1b[38;2;255;255;0mv1.0\x1b[0m\x1b[38;2;0;255;255m by Jo\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,667 @@
+// Project: clap-version-flag
+// File: src/flag.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Configurable names and aliases for the version flag
+// License: MIT
+
+//! Configuration of the injected version argument.
+//!
+//! By default the flag is `-V`/`--version` with the id
+//! `clap_version_flag_version`. Apps that already use `-V` for something
+//! else (often "verbose") can remap it with a [`VersionFlagConfig`].
+//!
+//! Both forms print the same output unless a verbosity is set for one of
+//! them: with [`VersionFlagConfig::short_verbosity`] and
+//! [`VersionFlagConfig::long_verbosity`] the short and long flags become
+//! separate arguments, e.g. `-V` for the brief `myapp v1.2.3` and
+//! `--version` for the full line.
+
+use std::borrow::Cow;
+
+use crate::{PrintMode, VERSION_FLAG_ID, Verbosity, VersionError};
+use clap::builder::EnumValueParser;
+use clap::{Arg, ArgAction, ArgGroup, ArgMatches, Command};
+
+/// Names, aliases and help of the version flag
+///
+/// # Examples
+/// ```
+/// use clap::Command;
+/// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionFlagConfig};
+///
+/// // `-V` is taken by --verbose, so use `-v` and keep `--version`
+/// let cfg = VersionFlagConfig::new().short('v').alias("ver");
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// let cmd = Command::new("myapp").with_colorful_version_cfg(&version, &cfg);
+///
+/// assert!(cmd.clone().try_get_matches_from(["myapp", "-v"]).is_ok());
+/// assert!(cmd.try_get_matches_from(["myapp", "--ver"]).is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionFlagConfig {
+    id: Cow<'static, str>,
+    short: Option<char>,
+    long: Option<Cow<'static, str>>,
+    aliases: Vec<String>,
+    short_aliases: Vec<char>,
+    hidden: bool,
+    help: Option<String>,
+    short_verbosity: Option<Verbosity>,
+    long_verbosity: Option<Verbosity>,
+    mode_value: bool,
+}
+
+impl Default for VersionFlagConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VersionFlagConfig {
+    /// Creates the default `-V`/`--version` configuration
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: Cow::Borrowed(VERSION_FLAG_ID),
+            short: Some('V'),
+            long: Some(Cow::Borrowed("version")),
+            aliases: Vec::new(),
+            short_aliases: Vec::new(),
+            hidden: false,
+            help: None,
+            short_verbosity: None,
+            long_verbosity: None,
+            mode_value: false,
+        }
+    }
+
+    /// Sets the arg id used in [`clap::ArgMatches`]
+    ///
+    /// When changing the id, give the same configuration to the
+    /// [`ColorfulVersion`](crate::ColorfulVersion) with
+    /// [`with_flag_config`](crate::ColorfulVersion::with_flag_config) so
+    /// [`check_and_exit`](crate::ColorfulVersion::check_and_exit) finds it.
+    #[must_use]
+    pub fn id(mut self, id: impl Into<String>) -> Self {
+        self.id = Cow::Owned(id.into());
+        self
+    }
+
+    /// Sets the short flag (default: `V`)
+    #[must_use]
+    pub fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Removes the short flag
+    #[must_use]
+    pub fn no_short(mut self) -> Self {
+        self.short = None;
+        self
+    }
+
+    /// Sets the long flag (default: `version`)
+    #[must_use]
+    pub fn long(mut self, long: impl Into<String>) -> Self {
+        self.long = Some(Cow::Owned(long.into()));
+        self
+    }
+
+    /// Adds a visible long alias
+    #[must_use]
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Adds a visible short alias
+    #[must_use]
+    pub fn short_alias(mut self, alias: char) -> Self {
+        self.short_aliases.push(alias);
+        self
+    }
+
+    /// Hides the flag from `--help`
+    #[must_use]
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Overrides the help text
+    #[must_use]
+    pub fn help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Sets the output of the short flag and its short aliases
+    ///
+    /// Repeating the flag, where allowed, adds detail from there on.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::{CommandFactory, Parser};
+    /// use clap_version_flag::{
+    ///     ColorfulVersion, Verbosity, VersionFlagConfig, VersionOutcome, try_parse_with_version_from,
+    /// };
+    ///
+    /// #[derive(Parser)]
+    /// struct Cli {}
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe")
+    ///     .with_flag_config(VersionFlagConfig::new().short_verbosity(Verbosity::Brief))
+    ///     .with_color_choice(clap::ColorChoice::Never);
+    /// let output = |flag| match try_parse_with_version_from::<Cli, _, _>(
+    ///     Cli::command(),
+    ///     &version,
+    ///     ["myapp", flag],
+    /// ) {
+    ///     Ok(VersionOutcome::VersionRequested(output)) => output,
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(output("-V"), "myapp v1.2.3");
+    /// assert_eq!(output("--version"), "myapp v1.2.3 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn short_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.short_verbosity = Some(verbosity);
+        self
+    }
+
+    /// Sets the output of the long flag and its aliases
+    #[must_use]
+    pub fn long_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.long_verbosity = Some(verbosity);
+        self
+    }
+
+    /// Lets the flag take a [`PrintMode`] as value, e.g. `--version=short`
+    ///
+    /// The value must follow an `=`, so `myapp --version short` still treats
+    /// `short` as a separate argument; without one the flag prints the full
+    /// output. Applies to the flag of
+    /// [`with_colorful_version`](crate::ColorfulVersionExt::with_colorful_version);
+    /// the repeatable flag of
+    /// [`with_verbose_colorful_version`](crate::ColorfulVersionExt::with_verbose_colorful_version)
+    /// takes no value.
+    #[must_use]
+    pub fn mode_value(mut self, enabled: bool) -> Self {
+        self.mode_value = enabled;
+        self
+    }
+
+    /// Returns the arg id
+    #[must_use]
+    pub fn arg_id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns whether the short and long flags are separate arguments
+    fn is_split(&self) -> bool {
+        self.short_verbosity.is_some() || self.long_verbosity.is_some()
+    }
+
+    /// Returns the arg id of the short flag when split off
+    fn short_arg_id(&self) -> String {
+        format!("{}_short", self.id)
+    }
+
+    /// Returns the id of the group of the flag's arguments
+    fn group_id(&self) -> String {
+        format!("{}_group", self.id)
+    }
+
+    /// Adds the flag to `cmd` with the given action and default help
+    ///
+    /// The flag's arguments, two when split, form a group named after the
+    /// id, by which the companion flags find the flag to require. The hidden
+    /// `--version-color` option comes along, carrying the command's color
+    /// choice into the matches.
+    pub(crate) fn register(
+        &self,
+        cmd: Command,
+        action: ArgAction,
+        default_help: &'static str,
+    ) -> Command {
+        let color = crate::color_choice::color_arg();
+        let cmd = if cmd
+            .get_arguments()
+            .any(|arg| arg.get_id() == color.get_id())
+        {
+            cmd
+        } else {
+            cmd.arg(color)
+        };
+        if !self.is_split() {
+            return cmd
+                .arg(self.to_arg(action, default_help))
+                .group(ArgGroup::new(self.group_id()).arg(self.id.clone()));
+        }
+        let long = Self {
+            short: None,
+            short_aliases: Vec::new(),
+            ..self.clone()
+        };
+        let short = Self {
+            id: Cow::Owned(self.short_arg_id()),
+            long: None,
+            aliases: Vec::new(),
+            ..self.clone()
+        };
+        cmd.arg(long.to_arg(action.clone(), default_help))
+            .arg(short.to_arg(action, default_help))
+            .group(
+                ArgGroup::new(self.group_id())
+                    .args([self.id.clone(), short.id])
+                    .multiple(true),
+            )
+    }
+
+    /// Returns the id the companion flags of `cmd` require, if it has a flag
+    ///
+    /// That is the flag itself, or the group of the split flags, of the first
+    /// flag registered.
+    pub(crate) fn requirement(cmd: &Command) -> Option<String> {
+        cmd.get_groups().find_map(|group| {
+            let flag = group.get_id().as_str().strip_suffix("_group")?;
+            let args: Vec<&str> = group.get_args().map(|arg| arg.as_str()).collect();
+            match args[..] {
+                [only] if only == flag => Some(flag.to_string()),
+                [long, short] if long == flag && short == format!("{flag}_short") => {
+                    Some(group.get_id().to_string())
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Returns the verbosity requested through the flag, if it was given
+    pub(crate) fn requested_verbosity(&self, matches: &ArgMatches) -> Option<Verbosity> {
+        let given = |id: &str, base: Option<Verbosity>| {
+            let verbosity = crate::requested_verbosity(matches, id)?;
+            Some(match base {
+                Some(base) => base.raised(verbosity.steps()),
+                None => verbosity,
+            })
+        };
+        if !self.is_split() {
+            return given(&self.id, None);
+        }
+        let long = given(&self.id, self.long_verbosity);
+        let short = given(&self.short_arg_id(), self.short_verbosity);
+        long.max(short)
+    }
+
+    /// Returns whether the flag was given, split off or not
+    pub(crate) fn is_given(&self, matches: &ArgMatches) -> bool {
+        [self.id.to_string(), self.short_arg_id()]
+            .iter()
+            .any(|id| crate::requested_verbosity(matches, id).is_some())
+    }
+
+    /// Returns the print mode given as the flag's value, if any
+    pub(crate) fn requested_mode(&self, matches: &ArgMatches) -> Option<PrintMode> {
+        let given = |id: &str| matches.try_get_one::<PrintMode>(id).ok().flatten().copied();
+        if !self.is_split() {
+            return given(&self.id);
+        }
+        given(&self.id).or_else(|| given(&self.short_arg_id()))
+    }
+
+    /// Builds the clap argument with the given action and default help
+    pub(crate) fn to_arg(&self, action: ArgAction, default_help: &'static str) -> Arg {
+        let mut arg = match action {
+            ArgAction::SetTrue if self.mode_value => Arg::new(self.id.clone())
+                .action(ArgAction::Set)
+                .num_args(0..=1)
+                .require_equals(true)
+                .default_missing_value("full")
+                .value_name("MODE")
+                .value_parser(EnumValueParser::<PrintMode>::new()),
+            action => Arg::new(self.id.clone()).action(action),
+        }
+        .help(
+            self.help
+                .clone()
+                .unwrap_or_else(|| default_help.to_string()),
+        )
+        .hide(self.hidden)
+        .global(true);
+        if let Some(short) = self.short {
+            arg = arg.short(short);
+        }
+        if let Some(long) = &self.long {
+            arg = arg.long(long.clone());
+        }
+        arg.visible_aliases(self.aliases.clone())
+            .visible_short_aliases(self.short_aliases.clone())
+    }
+
+    /// Returns the first clash of `added` with the arguments of `cmd`
+    ///
+    /// `added` are the arguments the extension is about to register: the
+    /// flag, its companions and the hidden options coming along. They are
+    /// global, so the arguments of every subcommand are checked too, as is
+    /// clap's own `-h`/`--help`.
+    pub(crate) fn find_conflict(&self, cmd: &Command, added: &[&Arg]) -> Option<VersionError> {
+        added.iter().find_map(|new| {
+            let id = new.get_id().as_str();
+            let hints = if id == self.id || (self.is_split() && id == self.short_arg_id()) {
+                [ID_HINT, SHORT_HINT, LONG_HINT]
+            } else {
+                [COMPANION_HINT; 3]
+            };
+            let shorts: Vec<char> = new
+                .get_short()
+                .into_iter()
+                .chain(new.get_all_short_aliases().unwrap_or_default())
+                .collect();
+            let longs: Vec<&str> = new
+                .get_long()
+                .into_iter()
+                .chain(new.get_all_aliases().unwrap_or_default())
+                .collect();
+            clash(cmd, id, &shorts, &longs, hints)
+        })
+    }
+}
+
+/// Returns the first argument of `cmd` or its subcommands using `id`, one
+/// of `shorts` or one of `longs`, with the id, short and long hint
+fn clash(
+    cmd: &Command,
+    id: &str,
+    shorts: &[char],
+    longs: &[&str],
+    [id_hint, short_hint, long_hint]: [&str; 3],
+) -> Option<VersionError> {
+    let conflict = |flag: String, arg: &str, hint: &str| VersionError::FlagConflict {
+        flag,
+        arg: arg.to_string(),
+        hint: hint.to_string(),
+    };
+    if !cmd.is_disable_help_flag_set() {
+        if shorts.contains(&'h') {
+            return Some(conflict("-h".into(), "help", short_hint));
+        }
+        if longs.contains(&"help") {
+            return Some(conflict("--help".into(), "help", long_hint));
+        }
+    }
+    for arg in cmd.get_arguments() {
+        let arg_id = arg.get_id().as_str();
+        if arg_id == id {
+            return Some(conflict(format!("the id '{id}'"), arg_id, id_hint));
+        }
+        let mut arg_shorts = arg
+            .get_short()
+            .into_iter()
+            .chain(arg.get_all_short_aliases().unwrap_or_default());
+        if let Some(short) = arg_shorts.find(|short| shorts.contains(short)) {
+            return Some(conflict(format!("-{short}"), arg_id, short_hint));
+        }
+        let mut arg_longs = arg
+            .get_long()
+            .into_iter()
+            .chain(arg.get_all_aliases().unwrap_or_default());
+        if let Some(long) = arg_longs.find(|long| longs.contains(long)) {
+            return Some(conflict(format!("--{long}"), arg_id, long_hint));
+        }
+    }
+    cmd.get_subcommands().find_map(|subcommand| {
+        clash(
+            subcommand,
+            id,
+            shorts,
+            longs,
+            [id_hint, short_hint, long_hint],
+        )
+    })
+}
+
+// Remediation hints of `VersionError::FlagConflict`
+const ID_HINT: &str =
+    "Pass another id to VersionFlagConfig::id and ColorfulVersion::with_flag_config.";
+const SHORT_HINT: &str = "Remap it with VersionFlagConfig::short or remove it with ::no_short.";
+const LONG_HINT: &str = "Remap it with VersionFlagConfig::long.";
+const COMPANION_HINT: &str =
+    "The companion flags have fixed names; rename the app's argument or leave the companion out.";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Command;
+
+    #[test]
+    fn test_default_arg() {
+        let arg = VersionFlagConfig::new().to_arg(ArgAction::SetTrue, "Print version");
+        assert_eq!(arg.get_id(), VERSION_FLAG_ID);
+        assert_eq!(arg.get_short(), Some('V'));
+        assert_eq!(arg.get_long(), Some("version"));
+        assert!(!arg.is_hide_set());
+    }
+
+    #[test]
+    fn test_remapped_arg() {
+        let cfg = VersionFlagConfig::new()
+            .id("show_version")
+            .no_short()
+            .long("about-version")
+            .short_alias('B')
+            .hidden(true)
+            .help("Show it");
+        let cmd = Command::new("app").arg(cfg.to_arg(ArgAction::SetTrue, "unused"));
+
+        let matches = cmd.clone().try_get_matches_from(["app", "-B"]).unwrap();
+        assert!(matches.get_flag("show_version"));
+        assert!(cmd.clone().try_get_matches_from(["app", "-V"]).is_err());
+
+        let arg = cmd.get_arguments().next().unwrap();
+        assert!(arg.is_hide_set());
+        assert_eq!(
+            arg.get_help().map(ToString::to_string).as_deref(),
+            Some("Show it")
+        );
+    }
+
+    /// Checks the arguments `cfg` registers against `cmd`
+    fn conflict_of(cfg: &VersionFlagConfig, cmd: &Command) -> Option<VersionError> {
+        let flags = cfg.register(Command::new("probe"), ArgAction::SetTrue, "Print version");
+        let added: Vec<&Arg> = flags.get_arguments().collect();
+        cfg.find_conflict(cmd, &added)
+    }
+
+    #[test]
+    fn test_conflicts_found() {
+        let flag = |arg: Arg| conflict_of(&VersionFlagConfig::new(), &Command::new("app").arg(arg));
+        let conflict = |err: Option<VersionError>| match err {
+            Some(VersionError::FlagConflict { flag, arg, .. }) => Some((flag, arg)),
+            _ => None,
+        };
+        assert_eq!(
+            conflict(flag(Arg::new(VERSION_FLAG_ID))),
+            Some((
+                format!("the id '{VERSION_FLAG_ID}'"),
+                VERSION_FLAG_ID.to_string()
+            ))
+        );
+        assert_eq!(
+            conflict(flag(Arg::new("verbose").short_alias('V'))),
+            Some(("-V".to_string(), "verbose".to_string()))
+        );
+        assert_eq!(
+            conflict(flag(Arg::new("ver").alias("version"))),
+            Some(("--version".to_string(), "ver".to_string()))
+        );
+        assert!(flag(Arg::new("verbose").short('v').long("verbose")).is_none());
+    }
+
+    #[test]
+    fn test_conflicts_in_subcommands_and_help() {
+        let cmd = Command::new("app")
+            .subcommand(Command::new("run").arg(Arg::new("version").long("version")));
+        let err = conflict_of(&VersionFlagConfig::new(), &cmd).unwrap();
+        assert!(
+            err.to_string()
+                .contains("--version is already used by argument 'version'")
+        );
+
+        let help = VersionFlagConfig::new().short('h');
+        assert!(conflict_of(&help, &Command::new("app")).is_some());
+        let cmd = Command::new("app").disable_help_flag(true);
+        assert!(conflict_of(&help, &cmd).is_none());
+    }
+
+    #[test]
+    fn test_companion_conflicts() {
+        use crate::{ColorfulVersion, ColorfulVersionExt};
+
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        let with_all = |cmd: Command| {
+            cmd.with_colorful_version(&version)
+                .with_version_format_flag()
+                .with_dependencies_flag()
+                .with_version_fields_flag()
+                .with_bug_report_flag()
+        };
+        let flag = |cmd: Command| match cmd.try_with_version_flags(&version, with_all) {
+            Err(VersionError::FlagConflict { flag, arg, hint }) => {
+                assert_eq!(hint, COMPANION_HINT);
+                Some((flag, arg))
+            }
+            Err(err) => panic!("{err}"),
+            Ok(_) => None,
+        };
+        assert_eq!(
+            flag(Command::new("app").arg(Arg::new("format").long("version-format"))),
+            Some(("--version-format".to_string(), "format".to_string()))
+        );
+        let sub = Command::new("run").arg(Arg::new("deps").long("version-deps"));
+        assert_eq!(
+            flag(Command::new("app").subcommand(sub)),
+            Some(("--version-deps".to_string(), "deps".to_string()))
+        );
+        // the hidden option coming with the flag is checked as well
+        assert_eq!(
+            flag(Command::new("app").arg(Arg::new("color").long("version-color"))),
+            Some(("--version-color".to_string(), "color".to_string()))
+        );
+        assert_eq!(
+            flag(Command::new("app").arg(Arg::new("format").long("format"))),
+            None
+        );
+        let cmd = Command::new("app")
+            .try_with_version_flags(&version, with_all)
+            .unwrap();
+        assert!(
+            cmd.try_get_matches_from(["app", "-V", "--version-deps"])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_split_flags() {
+        let cfg = VersionFlagConfig::new().short_verbosity(Verbosity::Brief);
+        let cmd = cfg.register(Command::new("app"), ArgAction::Count, "Print version");
+        let requested = |args: &[&str]| {
+            let matches = cmd.clone().try_get_matches_from(args).unwrap();
+            cfg.requested_verbosity(&matches)
+        };
+        assert_eq!(requested(&["app", "-V"]), Some(Verbosity::Brief));
+        assert_eq!(requested(&["app", "-VV"]), Some(Verbosity::Normal));
+        assert_eq!(requested(&["app", "-VVV"]), Some(Verbosity::Verbose));
+        assert_eq!(requested(&["app", "--version"]), Some(Verbosity::Normal));
+        assert_eq!(
+            requested(&["app", "-V", "--version"]),
+            Some(Verbosity::Normal)
+        );
+        assert_eq!(requested(&["app"]), None);
+        assert!(conflict_of(&cfg, &cmd).is_some());
+    }
+
+    #[test]
+    fn test_companions_accept_either_split_flag() {
+        use crate::ColorfulVersionExt;
+
+        let version = crate::ColorfulVersion::new("app", "1.2.3", "Jo").with_flag_config(
+            VersionFlagConfig::new()
+                .short_verbosity(Verbosity::Brief)
+                .long_verbosity(Verbosity::Verbose),
+        );
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag();
+        assert!(
+            cmd.clone()
+                .try_get_matches_from(["app", "-V", "--version-format", "plain"])
+                .is_ok()
+        );
+        assert!(
+            cmd.clone()
+                .try_get_matches_from(["app", "--version", "--version-format", "plain"])
+                .is_ok()
+        );
+        assert!(
+            cmd.try_get_matches_from(["app", "--version-format", "plain"])
+                .is_err()
+        );
+        assert_eq!(version.render_plain(Verbosity::Brief), "app v1.2.3");
+    }
+
+    #[test]
+    fn test_companions_require_custom_flag() {
+        use crate::ColorfulVersionExt;
+
+        let version = crate::ColorfulVersion::new("app", "1.0", "Jo")
+            .with_flag_config(VersionFlagConfig::new().id("show_version").short('v'));
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag();
+        assert_eq!(
+            VersionFlagConfig::requirement(&cmd).as_deref(),
+            Some("show_version")
+        );
+        assert!(
+            cmd.clone()
+                .try_get_matches_from(["app", "-v", "--version-format", "plain"])
+                .is_ok()
+        );
+        assert!(
+            cmd.try_get_matches_from(["app", "--version-format", "plain"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_two_layers_coexist() {
+        use crate::ColorfulVersionExt;
+
+        let app = crate::ColorfulVersion::new("app", "1.0", "Jo")
+            .with_flag_config(VersionFlagConfig::new().short_verbosity(Verbosity::Brief));
+        let lib = crate::ColorfulVersion::new("lib", "2.0", "Al").with_flag_config(
+            VersionFlagConfig::new()
+                .id("lib_version")
+                .short('L')
+                .long("lib-version")
+                .short_verbosity(Verbosity::Brief),
+        );
+        let cmd = Command::new("app")
+            .with_colorful_version(&app)
+            .with_colorful_version(&lib);
+        let matches = cmd.clone().try_get_matches_from(["app", "-L"]).unwrap();
+        assert!(!app.check(&matches));
+        assert_eq!(
+            lib.flag.requested_verbosity(&matches),
+            Some(Verbosity::Brief)
+        );
+        let matches = cmd.try_get_matches_from(["app", "--version"]).unwrap();
+        assert_eq!(
+            app.flag.requested_verbosity(&matches),
+            Some(Verbosity::Normal)
+        );
+        assert_eq!(lib.flag.requested_verbosity(&matches), None);
+    }
+}
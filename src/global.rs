@@ -0,0 +1,68 @@
+// Project: clap-version-flag
+// File: src/global.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Process-wide version instance
+// License: MIT
+
+//! Process-wide version instance.
+//!
+//! Error handlers, panic hooks and log headers deep inside an application
+//! rarely have the [`ColorfulVersion`] at hand. Install it once at startup
+//! with [`init_global!`](crate::init_global) (or [`set_global`]) and fetch it
+//! anywhere with [`global`]:
+//!
+//! ```
+//! use clap_version_flag::{global, init_global};
+//!
+//! fn log_header() -> String {
+//!     global().map_or_else(String::new, |version| version.as_plain_string())
+//! }
+//!
+//! init_global!();
+//! assert!(log_header().starts_with(env!("CARGO_PKG_NAME")));
+//! ```
+
+use std::sync::OnceLock;
+
+use crate::ColorfulVersion;
+
+/// The installed version
+static GLOBAL: OnceLock<ColorfulVersion> = OnceLock::new();
+
+/// Installs `version` as the process-wide version, unless one is installed
+///
+/// The first installation wins; later calls drop their argument. Returns
+/// the installed version either way.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, set_global};
+///
+/// let first = set_global(ColorfulVersion::new("myapp", "1.0.0", "John Doe"));
+/// let second = set_global(ColorfulVersion::new("other", "2.0.0", "Jane Doe"));
+/// assert_eq!(first.package_name(), "myapp");
+/// assert_eq!(second.package_name(), "myapp");
+/// ```
+pub fn set_global(version: ColorfulVersion) -> &'static ColorfulVersion {
+    GLOBAL.get_or_init(|| version)
+}
+
+/// Returns the process-wide version, if one is installed
+#[must_use]
+pub fn global() -> Option<&'static ColorfulVersion> {
+    GLOBAL.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_installation_wins() {
+        let installed = set_global(ColorfulVersion::new("app", "1.0", "Jo"));
+        assert!(std::ptr::eq(global().unwrap(), installed));
+        let again = crate::init_global!(ColorfulVersion::new("other", "2.0", "Jo"));
+        assert!(std::ptr::eq(again, installed));
+    }
+}
@@ -0,0 +1,212 @@
+// Project: clap-version-flag
+// File: src/grapheme.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Lightweight grapheme cluster segmentation and display width
+// License: MIT
+
+//! Grapheme cluster segmentation.
+//!
+//! Anything that styles, counts or cuts text piece by piece must work on
+//! user-perceived characters, otherwise an emoji ZWJ sequence such as
+//! "👩‍💻" or a flag is split mid-sequence and renders as broken glyphs.
+//! This covers the clusters that occur in package and author names
+//! (combining marks, variation selectors, emoji modifiers and tags, ZWJ
+//! sequences, keycaps and regional-indicator flags) without pulling in the
+//! full Unicode segmentation tables.
+//!
+//! It is an approximation of UAX #29, not an implementation of it. Marks
+//! outside the listed blocks (Thai, Bengali and most other Indic and
+//! Southeast Asian scripts) split off their base character, conjoining
+//! Hangul jamo and Indic conjuncts are not merged into syllables, and
+//! `\r\n` is two clusters. Cutting such text can therefore separate a mark
+//! from its letter; the text itself is never altered.
+//!
+//! [`width`] approximates the terminal column count on top of it, for
+//! output that draws aligned borders. Its tables cover the common CJK,
+//! Hangul and emoji blocks rather than the full East Asian Width data:
+//! emoji in the older symbol blocks (such as "☕" or "⌚"), text symbols
+//! turned into emoji by a variation selector ("❤️") and East Asian
+//! ambiguous characters all count as one column, which terminals may draw
+//! two columns wide.
+
+/// Zero width joiner, gluing emoji into one glyph
+const ZWJ: char = '\u{200D}';
+
+/// Returns whether `c` attaches to the preceding character
+fn is_extend(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'   // combining diacritical marks
+        | '\u{0483}'..='\u{0489}' // Cyrillic combining marks
+        | '\u{0591}'..='\u{05BD}' // Hebrew points
+        | '\u{0610}'..='\u{061A}' // Arabic marks
+        | '\u{064B}'..='\u{065F}' // Arabic vowel signs
+        | '\u{0900}'..='\u{0903}' // Devanagari signs
+        | '\u{093A}'..='\u{094F}'
+        | '\u{1AB0}'..='\u{1AFF}'
+        | '\u{1DC0}'..='\u{1DFF}'
+        | '\u{200C}'              // zero width non-joiner
+        | '\u{20D0}'..='\u{20FF}' // combining marks for symbols, incl. keycap
+        | '\u{FE00}'..='\u{FE0F}' // variation selectors
+        | '\u{FE20}'..='\u{FE2F}'
+        | '\u{1F3FB}'..='\u{1F3FF}' // emoji skin tone modifiers
+        | '\u{E0020}'..='\u{E007F}' // emoji tag sequences
+        | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
+/// Returns whether `c` is a regional indicator (half of a flag)
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&c)
+}
+
+/// Splits `text` into grapheme clusters
+///
+/// The clusters concatenate back to `text`.
+pub(crate) fn graphemes(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let mut chars = rest.char_indices().peekable();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+        let mut previous = first;
+        let mut pending_flag = is_regional_indicator(first);
+
+        while let Some(&(index, c)) = chars.peek() {
+            let joins = is_extend(c)
+                || c == ZWJ
+                || previous == ZWJ
+                || (pending_flag && is_regional_indicator(c));
+            if !joins {
+                break;
+            }
+            pending_flag = false;
+            previous = c;
+            end = index + c.len_utf8();
+            chars.next();
+        }
+
+        let (cluster, tail) = rest.split_at(end);
+        rest = tail;
+        Some(cluster)
+    })
+}
+
+/// Returns the number of grapheme clusters in `text`
+pub(crate) fn count(text: &str) -> usize {
+    graphemes(text).count()
+}
+
+/// Returns the number of terminal columns `text` occupies
+///
+/// A cluster is two columns wide when it starts with an East Asian wide or
+/// fullwidth character or an emoji; invisible formatting characters, such
+/// as the directional isolates, take no column. See the
+/// [module documentation](self) for what the tables do not cover.
+pub(crate) fn width(text: &str) -> usize {
+    graphemes(text)
+        .map(|cluster| {
+            let first = cluster.chars().next().unwrap_or_default();
+            if is_zero_width(first) {
+                0
+            } else if is_wide(first) {
+                2
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// Returns whether `c` is an invisible formatting character
+fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200F}'   // zero width space, joiners, direction marks
+        | '\u{2028}'..='\u{202E}' // separators and directional embeddings
+        | '\u{2060}'..='\u{206F}' // word joiner, directional isolates
+        | '\u{FEFF}'              // zero width no-break space
+    ) || is_extend(c)
+}
+
+/// Returns whether `c` is East Asian wide or fullwidth, or an emoji
+fn is_wide(c: char) -> bool {
+    matches!(
+        c,
+        '\u{1100}'..='\u{115F}'     // Hangul Jamo
+        | '\u{2E80}'..='\u{303E}'   // CJK radicals, punctuation
+        | '\u{3041}'..='\u{33FF}'   // Hiragana, Katakana, CJK compatibility
+        | '\u{3400}'..='\u{4DBF}'   // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK unified ideographs
+        | '\u{A000}'..='\u{A4CF}'   // Yi
+        | '\u{AC00}'..='\u{D7A3}'   // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}'   // CJK compatibility ideographs
+        | '\u{FE30}'..='\u{FE4F}'   // CJK compatibility forms
+        | '\u{FF00}'..='\u{FF60}'   // fullwidth forms
+        | '\u{FFE0}'..='\u{FFE6}'
+        | '\u{1F1E6}'..='\u{1F1FF}' // regional indicators (flags)
+        | '\u{1F300}'..='\u{1F64F}' // pictographs, emoticons
+        | '\u{1F680}'..='\u{1F6FF}' // transport and map symbols
+        | '\u{1F900}'..='\u{1F9FF}' // supplemental pictographs
+        | '\u{1FA70}'..='\u{1FAFF}'
+        | '\u{20000}'..='\u{3FFFD}' // CJK extensions B and later
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_width() {
+        assert_eq!(width("myapp"), 5);
+        assert_eq!(width("日本語"), 6);
+        assert_eq!(width("한글 v1"), 7);
+        assert_eq!(width("👩\u{200D}💻 🇮🇩"), 5);
+        assert_eq!(width("e\u{301}"), 1);
+        assert_eq!(width("\u{2068}שלום\u{2069}"), 4);
+    }
+
+    fn split(text: &str) -> Vec<&str> {
+        graphemes(text).collect()
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(split("abc"), ["a", "b", "c"]);
+        assert_eq!(split(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_clusters() {
+        // e + combining acute accent
+        assert_eq!(split("e\u{301}x"), ["e\u{301}", "x"]);
+        // woman technologist: woman ZWJ laptop
+        assert_eq!(split("👩\u{200D}💻!"), ["👩\u{200D}💻", "!"]);
+        // family with skin tones
+        assert_eq!(count("👨🏽\u{200D}👩🏽\u{200D}👧🏽"), 1);
+        // flags pair up regional indicators
+        assert_eq!(split("🇮🇩🇯🇵"), ["🇮🇩", "🇯🇵"]);
+        // keycap and variation selector
+        assert_eq!(split("1\u{FE0F}\u{20E3}2"), ["1\u{FE0F}\u{20E3}", "2"]);
+    }
+
+    #[test]
+    fn test_documented_limits() {
+        // outside the covered blocks marks and jamo stay clusters of their own
+        assert_eq!(split("\u{0E01}\u{0E31}"), ["\u{0E01}", "\u{0E31}"]);
+        assert_eq!(count("\u{1100}\u{1161}"), 2);
+        assert_eq!(split("\r\n"), ["\r", "\n"]);
+        // symbols outside the covered emoji blocks count as narrow
+        assert_eq!(width("\u{2615}"), 1);
+        assert_eq!(width("\u{2764}\u{FE0F}"), 1);
+        assert_eq!(width("\u{00B1}"), 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = "my👩\u{200D}💻app 🇮🇩 v1";
+        assert_eq!(graphemes(text).collect::<String>(), text);
+    }
+}
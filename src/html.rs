@@ -0,0 +1,263 @@
+// Project: clap-version-flag
+// File: src/html.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: HTML renderer with inline CSS colors
+// License: MIT
+
+//! HTML output.
+//!
+//! [`HtmlRenderer`] draws the version output with the terminal renderer,
+//! then turns its escape codes into `<span style="color:#..">` markup inside
+//! a `<pre>`. The colors are therefore the resolved ones, styles, overrides
+//! and contrast adjustments included, so a dashboard or docs site shows the
+//! banner exactly as `--version` does. Inline styles need no stylesheet and
+//! survive copy and paste.
+
+use crate::color::{self, Piece, Rgb};
+use crate::{
+    AnsiRenderer, ColorDepth, ColorSpec, ColorfulVersion, Labels, Theme, Verbosity, VersionInfo,
+    VersionRenderer,
+};
+
+/// Renders the version information as HTML with inline colors
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{HtmlRenderer, VersionInfo, VersionRenderer};
+///
+/// let html = HtmlRenderer::new().render(&VersionInfo::new("myapp", "1.0.0", "John Doe"));
+/// assert_eq!(
+///     html,
+///     "<pre><span style=\"color:#FFFFFF;background-color:#AA00FF\">myapp</span>\
+///      <span style=\"color:#FFFF00\"> v1.0.0</span>\
+///      <span style=\"color:#00FFFF\"> by John Doe</span></pre>"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct HtmlRenderer {
+    ansi: AnsiRenderer,
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        Self::from(AnsiRenderer::new())
+    }
+}
+
+impl HtmlRenderer {
+    /// Creates a renderer drawing each version as configured
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the "v" and "by" labels
+    #[must_use]
+    pub fn with_labels(self, labels: Labels) -> Self {
+        Self::from(self.ansi.with_labels(labels))
+    }
+
+    /// Colors the output with `theme`
+    #[must_use]
+    pub fn with_theme(self, theme: Theme) -> Self {
+        Self::from(self.ansi.with_theme(theme))
+    }
+}
+
+impl From<AnsiRenderer> for HtmlRenderer {
+    /// Turns the output of `ansi`, drawn in truecolor, into HTML
+    fn from(ansi: AnsiRenderer) -> Self {
+        Self {
+            ansi: ansi.with_color_depth(ColorDepth::TrueColor),
+        }
+    }
+}
+
+impl From<&ColorfulVersion> for HtmlRenderer {
+    /// Draws everything with the presentation of `version`
+    fn from(version: &ColorfulVersion) -> Self {
+        Self::from(AnsiRenderer::from(version))
+    }
+}
+
+impl VersionRenderer for HtmlRenderer {
+    fn render(&self, info: &VersionInfo) -> String {
+        markup(&self.ansi.render(info))
+    }
+
+    fn render_version(&self, version: &ColorfulVersion, verbosity: Verbosity) -> String {
+        markup(&self.ansi.render_version(version, verbosity))
+    }
+}
+
+/// The colors and attributes set by the SGR sequences so far
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Style {
+    fg: Option<Rgb>,
+    bg: Option<Rgb>,
+    bold: bool,
+    dimmed: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+}
+
+impl Style {
+    /// Applies the parameters of one SGR sequence
+    fn apply(&mut self, params: &str) {
+        let mut params = params.split(';');
+        while let Some(param) = params.next() {
+            if param.is_empty() {
+                *self = Self::default();
+                continue;
+            }
+            match param.parse::<u8>() {
+                Ok(code @ (38 | 48)) => {
+                    let mut number = || params.next().and_then(|n| n.parse::<u8>().ok());
+                    let rgb = match number() {
+                        Some(5) => number().map(color::ansi256_rgb),
+                        Some(2) => match (number(), number(), number()) {
+                            (Some(r), Some(g), Some(b)) => Some((r, g, b)),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    if code == 38 {
+                        self.fg = rgb.or(self.fg);
+                    } else {
+                        self.bg = rgb.or(self.bg);
+                    }
+                }
+                Ok(0) => *self = Self::default(),
+                Ok(1) => self.bold = true,
+                Ok(2) => self.dimmed = true,
+                Ok(3) => self.italic = true,
+                Ok(4) => self.underline = true,
+                Ok(9) => self.strikethrough = true,
+                Ok(22) => (self.bold, self.dimmed) = (false, false),
+                Ok(23) => self.italic = false,
+                Ok(24) => self.underline = false,
+                Ok(29) => self.strikethrough = false,
+                Ok(code @ 30..=37) => self.fg = Some(color::ansi256_rgb(code - 30)),
+                Ok(39) => self.fg = None,
+                Ok(code @ 40..=47) => self.bg = Some(color::ansi256_rgb(code - 40)),
+                Ok(49) => self.bg = None,
+                Ok(code @ 90..=97) => self.fg = Some(color::ansi256_rgb(code - 90 + 8)),
+                Ok(code @ 100..=107) => self.bg = Some(color::ansi256_rgb(code - 100 + 8)),
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the inline CSS of the style, empty for unstyled text
+    fn css(&self) -> String {
+        let mut rules = Vec::new();
+        if let Some(fg) = self.fg {
+            rules.push(format!("color:{}", ColorSpec::from(fg)));
+        }
+        if let Some(bg) = self.bg {
+            rules.push(format!("background-color:{}", ColorSpec::from(bg)));
+        }
+        if self.bold {
+            rules.push("font-weight:bold".to_string());
+        }
+        if self.dimmed {
+            rules.push("opacity:0.6".to_string());
+        }
+        if self.italic {
+            rules.push("font-style:italic".to_string());
+        }
+        let lines: Vec<&str> = [
+            (self.underline, "underline"),
+            (self.strikethrough, "line-through"),
+        ]
+        .into_iter()
+        .filter_map(|(set, line)| set.then_some(line))
+        .collect();
+        if !lines.is_empty() {
+            rules.push(format!("text-decoration:{}", lines.join(" ")));
+        }
+        rules.join(";")
+    }
+}
+
+/// Turns rendered terminal output into a `<pre>` of escaped, styled spans
+///
+/// SGR sequences style the following text; other escape sequences
+/// (hyperlinks, ...) are dropped.
+fn markup(rendered: &str) -> String {
+    let mut out = String::from("<pre>");
+    let mut style = Style::default();
+    for piece in color::pieces(rendered) {
+        match piece {
+            Piece::Text(text) => match style.css() {
+                css if css.is_empty() => out.push_str(&escape(text)),
+                css => out.push_str(&format!("<span style=\"{css}\">{}</span>", escape(text))),
+            },
+            Piece::Sgr(params) => style.apply(params),
+            Piece::Control => {}
+        }
+    }
+    out.push_str("</pre>");
+    out
+}
+
+/// Escapes the characters HTML gives a meaning in text and attributes
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ComponentVersions, Style, Styles};
+
+    #[test]
+    fn test_escaping() {
+        let info = VersionInfo::new("a&b", "1.0", "Jo <jo@x.io>");
+        let html = HtmlRenderer::new().render(&info);
+        assert!(html.contains(">a&amp;b</span>"));
+        assert!(html.contains("> by Jo &lt;jo@x.io&gt;</span>"));
+    }
+
+    #[test]
+    fn test_follows_version_theme() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_theme(Theme::new().accent((1, 2, 3)))
+            .with_license("MIT")
+            .with_components(ComponentVersions::new().add_component_fn("server", || None));
+        let html = version.render_with(&HtmlRenderer::from(&version), Verbosity::Normal);
+        assert!(html.contains("<span style=\"color:#010203\"> v1.0</span>"));
+        assert!(html.contains("\n<span style=\"color:#010203\">license:</span> "));
+        assert!(html.ends_with("<span style=\"color:#FFFF00\">unavailable</span></pre>"));
+    }
+
+    #[test]
+    fn test_same_colors_as_terminal() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_styles(Styles::new().version(Style::new().bold().underline()))
+            .with_components(
+                ComponentVersions::new()
+                    .add_component("core", "2")
+                    .component_color("core", (1, 2, 3)),
+            )
+            .with_accessibility(crate::Accessibility::new().high_contrast(true));
+        let html = version.render_with(&HtmlRenderer::from(&version), Verbosity::Normal);
+        assert!(html.contains(
+            "<span style=\"color:#FFFF00;font-weight:bold;text-decoration:underline\">1.0</span>"
+        ));
+        assert!(html.contains("<span style=\"color:#010203\">2</span>"));
+        assert!(html.starts_with("<pre><span style=\"color:#FFFFFF;background-color:#000000\">"));
+    }
+}
@@ -0,0 +1,278 @@
+// Project: clap-version-flag
+// File: src/i18n.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Translatable "v" and "by" labels of the version line
+// License: MIT
+
+//! Localized labels.
+//!
+//! The version line reads "{name} v{version} by {author}". The `v` before
+//! the version and the `by` before the author are [`Labels`]; pick bundled
+//! translations with [`ColorfulVersion::with_locale`] or set your own with
+//! [`ColorfulVersion::with_labels`]:
+//!
+//! ```
+//! use clap_version_flag::{ColorfulVersion, Labels, Locale};
+//!
+//! let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_locale(Locale::De);
+//! assert_eq!(version.as_plain_string(), "myapp v1.0.0 von John Doe");
+//!
+//! let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_labels(Labels {
+//!     version_prefix: "version ".into(),
+//!     author_prefix: "—".into(),
+//! });
+//! assert_eq!(version.as_plain_string(), "myapp version 1.0.0 — John Doe");
+//! ```
+//!
+//! The labels apply to every rendered output. Parsing a version line with
+//! [`FromStr`](std::str::FromStr) still expects the English labels.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::ColorfulVersion;
+
+/// The words around the version and the author on the version line
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Labels {
+    /// Written right before the version number, e.g. `v`
+    pub version_prefix: String,
+    /// Written before the author, separated by a space, e.g. `by`; empty
+    /// for none
+    pub author_prefix: String,
+}
+
+impl Default for Labels {
+    fn default() -> Self {
+        Locale::En.labels()
+    }
+}
+
+/// Languages with bundled [`Labels`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Locale {
+    /// English: "v1.0 by"
+    #[default]
+    En,
+    /// German: "v1.0 von"
+    De,
+    /// French: "v1.0 par"
+    Fr,
+    /// Spanish: "v1.0 por"
+    Es,
+    /// Portuguese: "v1.0 por"
+    Pt,
+    /// Italian: "v1.0 di"
+    It,
+    /// Dutch: "v1.0 door"
+    Nl,
+    /// Swedish: "v1.0 av"
+    Sv,
+    /// Polish: "v1.0 autor:"
+    Pl,
+    /// Russian: "v1.0 от"
+    Ru,
+    /// Chinese: "v1.0 由"
+    Zh,
+}
+
+impl Locale {
+    /// Every bundled locale
+    pub const ALL: [Self; 11] = [
+        Self::En,
+        Self::De,
+        Self::Fr,
+        Self::Es,
+        Self::Pt,
+        Self::It,
+        Self::Nl,
+        Self::Sv,
+        Self::Pl,
+        Self::Ru,
+        Self::Zh,
+    ];
+
+    /// Returns the labels of this locale
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Locale;
+    ///
+    /// assert_eq!(Locale::Fr.labels().author_prefix, "par");
+    /// assert_eq!(Locale::Fr.labels().version_prefix, "v");
+    /// ```
+    #[must_use]
+    pub fn labels(self) -> Labels {
+        let author_prefix = match self {
+            Self::En => "by",
+            Self::De => "von",
+            Self::Fr => "par",
+            Self::Es | Self::Pt => "por",
+            Self::It => "di",
+            Self::Nl => "door",
+            Self::Sv => "av",
+            Self::Pl => "autor:",
+            Self::Ru => "от",
+            Self::Zh => "由",
+        };
+        Labels {
+            version_prefix: "v".to_string(),
+            author_prefix: author_prefix.to_string(),
+        }
+    }
+
+    /// Returns the ISO 639-1 code of the language, e.g. `de`
+    #[must_use]
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::De => "de",
+            Self::Fr => "fr",
+            Self::Es => "es",
+            Self::Pt => "pt",
+            Self::It => "it",
+            Self::Nl => "nl",
+            Self::Sv => "sv",
+            Self::Pl => "pl",
+            Self::Ru => "ru",
+            Self::Zh => "zh",
+        }
+    }
+
+    /// Finds the locale of a language tag or POSIX locale name
+    ///
+    /// Only the language is looked at, so `de`, `de-AT` and `de_DE.UTF-8`
+    /// all give [`Locale::De`]. Returns `None` for languages without bundled
+    /// labels.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Locale;
+    ///
+    /// assert_eq!(Locale::from_tag("pt_BR.UTF-8"), Some(Locale::Pt));
+    /// assert_eq!(Locale::from_tag("zh-Hans"), Some(Locale::Zh));
+    /// assert_eq!(Locale::from_tag("C"), None);
+    /// ```
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let language = tag.split(['-', '_', '.', '@']).next()?;
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(language))
+    }
+}
+
+impl ColorfulVersion {
+    /// Uses the bundled labels of `locale`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Locale};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_locale(Locale::Es);
+    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0 por John Doe");
+    /// ```
+    #[must_use]
+    pub fn with_locale(self, locale: Locale) -> Self {
+        self.with_labels(locale.labels())
+    }
+
+    /// Replaces the "v" and "by" labels of the version line
+    #[must_use]
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Returns the labels of the version line
+    #[must_use]
+    pub fn labels(&self) -> &Labels {
+        static ENGLISH: OnceLock<Labels> = OnceLock::new();
+        // a const-built version has no labels of its own until set
+        self.labels
+            .as_ref()
+            .unwrap_or_else(|| ENGLISH.get_or_init(|| Locale::En.labels()))
+    }
+
+    /// Returns the labels to change, starting from the English ones
+    pub(crate) fn labels_mut(&mut self) -> &mut Labels {
+        self.labels.get_or_insert_with(|| Locale::En.labels())
+    }
+
+    /// Returns the version with its prefix, e.g. `v1.0.0`
+    #[cfg(feature = "mangen")]
+    pub(crate) fn prefixed_version(&self) -> String {
+        format!("{}{}", self.labels().version_prefix, self.version)
+    }
+
+    /// Returns `author` behind the author prefix, e.g. `by John Doe`
+    pub(crate) fn attributed(&self, author: &str) -> String {
+        self.attribution(author).to_string()
+    }
+
+    /// Returns `author` behind the author prefix, for writing
+    pub(crate) fn attribution<T: fmt::Display>(&self, author: T) -> Attribution<'_, T> {
+        Attribution {
+            prefix: &self.labels().author_prefix,
+            author,
+        }
+    }
+}
+
+/// An author behind its prefix, written as `by John Doe`
+pub(crate) struct Attribution<'a, T> {
+    prefix: &'a str,
+    author: T,
+}
+
+impl<T: fmt::Display> fmt::Display for Attribution<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.prefix {
+            "" => write!(f, "{}", self.author),
+            prefix => write!(f, "{prefix} {}", self.author),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_locale_round_trips_its_code() {
+        for locale in Locale::ALL {
+            assert_eq!(Locale::from_tag(locale.code()), Some(locale));
+            assert!(!locale.labels().author_prefix.is_empty());
+        }
+        assert_eq!(Locale::from_tag("DE_ch"), Some(Locale::De));
+        assert_eq!(Locale::from_tag(""), None);
+    }
+
+    #[test]
+    fn test_localized_outputs() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_locale(Locale::Zh);
+        assert_eq!(version.as_plain_string(), "app v1.0 由 Jo");
+        assert_eq!(version.to_string(), "app v1.0 由 Jo");
+        let colored =
+            crate::color::strip_escapes(&version.render_text(crate::Verbosity::Normal, true));
+        assert_eq!(colored, "app v1.0 由 Jo");
+        let (_, rest) = version.native_version_parts();
+        assert!(crate::color::strip_escapes(&rest).ends_with("由 Jo"));
+    }
+
+    #[test]
+    fn test_custom_labels() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_labels(Labels {
+            version_prefix: "".into(),
+            author_prefix: "".into(),
+        });
+        assert_eq!(version.as_plain_string(), "app 1.0 Jo");
+        assert_eq!(
+            crate::color::strip_escapes(&version.render_text(crate::Verbosity::Normal, true)),
+            "app 1.0 Jo"
+        );
+        assert_eq!(version.labels().version_prefix, "");
+    }
+}
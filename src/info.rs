@@ -0,0 +1,204 @@
+// Project: clap-version-flag
+// File: src/info.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: The version information as plain data
+// License: MIT
+
+//! Version information as data.
+//!
+//! A [`VersionInfo`] holds what the version output says, without how it
+//! looks: the name, version and author, the detail lines shown at some
+//! verbosity and the component versions. Take one from a
+//! [`ColorfulVersion`] with [`ColorfulVersion::info`], or build it by hand,
+//! and hand it to a [`VersionRenderer`](crate::VersionRenderer).
+
+use crate::{ColorfulVersion, Field, Verbosity, fields};
+
+/// The name, version, author and details of an app
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, Verbosity, VersionInfo};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_license("MIT");
+/// let info = version.info(Verbosity::Normal);
+/// assert_eq!(info.name, "myapp");
+/// assert_eq!(info.fields, [("license".to_string(), "MIT".to_string())]);
+///
+/// let by_hand = VersionInfo::new("myapp", "1.0.0", "John Doe").with_field("license", "MIT");
+/// assert_eq!(info, by_hand);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct VersionInfo {
+    /// The package name, e.g. `myapp`
+    pub name: String,
+    /// The version number without prefix, e.g. `1.0.0`
+    pub version: String,
+    /// The author, or authors as configured
+    pub author: String,
+    /// Detail lines as key and value, in output order
+    pub fields: Vec<(String, String)>,
+    /// Component names with their versions, `None` for unavailable ones
+    pub components: Vec<(String, Option<String>)>,
+}
+
+impl VersionInfo {
+    /// Creates the information of a version line without details
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        version: impl Into<String>,
+        author: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            author: author.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a detail line
+    #[must_use]
+    pub fn with_field(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.fields.push((key.into(), value.into()));
+        self
+    }
+
+    /// Adds a component version; `None` marks it unavailable
+    #[must_use]
+    pub fn with_component(mut self, name: impl Into<String>, version: Option<String>) -> Self {
+        self.components.push((name.into(), version));
+        self
+    }
+
+    /// Returns the value of the detail line `key`, if present
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::VersionInfo;
+    ///
+    /// let info = VersionInfo::new("myapp", "1.0.0", "John Doe").with_field("commit", "abc1234");
+    /// assert_eq!(info.field("commit"), Some("abc1234"));
+    /// assert_eq!(info.field("license"), None);
+    /// ```
+    #[must_use]
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(candidate, _)| candidate == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl ColorfulVersion {
+    /// Returns the information shown at `verbosity`, without presentation
+    ///
+    /// The fields are the detail lines the text output would show at
+    /// `verbosity`; the field selection, banners and templates are ignored.
+    /// Component versions are looked up once.
+    #[must_use]
+    pub fn info(&self, verbosity: Verbosity) -> VersionInfo {
+        VersionInfo {
+            name: self.package_name.to_string(),
+            version: self.version.to_string(),
+            author: self.author.to_string(),
+            fields: self
+                .detail_fields(verbosity)
+                .into_iter()
+                .map(|field| (field.key.into_owned(), field.value.into_owned()))
+                .collect(),
+            components: self.components.versions(),
+        }
+    }
+
+    /// Returns the information of the JSON output
+    ///
+    /// That is every metadata field the field selection keeps, at any
+    /// verbosity, and the components.
+    pub(crate) fn json_info(&self) -> VersionInfo {
+        let selected = |key: &str| {
+            self.visible_fields
+                .as_ref()
+                .is_none_or(|selection| selection.iter().any(|field| fields::same_key(field, key)))
+        };
+        VersionInfo {
+            name: self.package_name.to_string(),
+            version: self.version.to_string(),
+            author: self.author.to_string(),
+            fields: self
+                .metadata
+                .iter()
+                .filter(|field| selected(&field.key))
+                .map(|field| (field.key.to_string(), field.value.to_string()))
+                .collect(),
+            components: self.components.versions(),
+        }
+    }
+
+    /// Returns this version's presentation showing `info` instead of its own
+    ///
+    /// The detail lines are exactly the fields of `info`, colored as this
+    /// version colors the line of the same key; the components keep their
+    /// colors by name.
+    pub(crate) fn presenting(mut self, info: &VersionInfo) -> Self {
+        let mut detailed = None;
+        let fields = info
+            .fields
+            .iter()
+            .map(|(key, value)| {
+                let color = match self.metadata.iter().find(|field| field.key == key.as_str()) {
+                    Some(field) => Some(field.color.unwrap_or_else(|| self.metadata_color(key))),
+                    None => detailed
+                        .get_or_insert_with(|| self.detail_fields(Verbosity::Verbose))
+                        .iter()
+                        .find(|field| field.key == key.as_str())
+                        .and_then(|field| field.color),
+                };
+                Field {
+                    key: key.clone().into(),
+                    value: value.clone().into(),
+                    color,
+                    level: Verbosity::Normal,
+                }
+            })
+            .collect();
+        self.presented_fields = Some(fields);
+        self.package_name = info.name.clone().into();
+        self.version = info.version.clone().into();
+        self.author = info.author.clone().into();
+        self.components = self.components.presenting(&info.components);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComponentVersions;
+
+    #[test]
+    fn test_info_follows_verbosity() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_install_method(crate::InstallMethod::Cargo)
+            .with_components(ComponentVersions::new().add_component_fn("server", || None));
+        let normal = version.info(Verbosity::Normal);
+        assert!(normal.fields.is_empty());
+        assert_eq!(normal.components, [("server".to_string(), None)]);
+        let verbose = version.info(Verbosity::Verbose);
+        assert_eq!(verbose.field("installed via"), Some("cargo install"));
+    }
+
+    #[test]
+    fn test_info_ignores_selection() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_license("MIT")
+            .with_visible_fields(["name"]);
+        assert_eq!(
+            version.info(Verbosity::Normal).field("license"),
+            Some("MIT")
+        );
+    }
+}
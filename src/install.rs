@@ -0,0 +1,228 @@
+// Project: clap-version-flag
+// File: src/install.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Install method detection for package-manager aware update prompts
+// License: MIT
+
+//! Detection of how the running binary was installed.
+//!
+//! Update prompts are only useful if they recommend a command that actually
+//! works for the user. A binary living in a Homebrew cellar should be upgraded
+//! with `brew`, one in `/usr/bin` belongs to the system package manager, and
+//! one in `~/.cargo/bin` came from `cargo install`.
+
+use std::env;
+use std::fmt;
+use std::path::Path;
+
+/// How the running binary was installed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InstallMethod {
+    /// Installed through Homebrew (macOS cellar or Linuxbrew)
+    Homebrew,
+    /// Installed through apt / dpkg into a system location
+    Apt,
+    /// Installed with `cargo install`
+    Cargo,
+    /// Installation method could not be determined
+    Unknown,
+}
+
+impl InstallMethod {
+    /// Detects the install method of the currently running executable
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::InstallMethod;
+    ///
+    /// let method = InstallMethod::detect();
+    /// println!("installed via: {method}");
+    /// ```
+    #[must_use]
+    pub fn detect() -> Self {
+        env::current_exe()
+            .ok()
+            .map(|exe| exe.canonicalize().unwrap_or(exe))
+            .map_or(Self::Unknown, |exe| Self::from_path(&exe))
+    }
+
+    /// Classifies an executable path into an install method
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::InstallMethod;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("/opt/homebrew/Cellar/myapp/1.0.0/bin/myapp");
+    /// assert_eq!(InstallMethod::from_path(path), InstallMethod::Homebrew);
+    /// ```
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        let path = path.to_string_lossy().replace('\\', "/");
+
+        if path.contains("/Cellar/")
+            || path.starts_with("/opt/homebrew/")
+            || path.starts_with("/home/linuxbrew/.linuxbrew/")
+        {
+            return Self::Homebrew;
+        }
+
+        let cargo_bin = env::var("CARGO_HOME")
+            .ok()
+            .map(|home| format!("{}/bin/", home.replace('\\', "/").trim_end_matches('/')));
+        if path.contains("/.cargo/bin/") || cargo_bin.is_some_and(|bin| path.starts_with(&bin)) {
+            return Self::Cargo;
+        }
+
+        if ["/usr/bin/", "/usr/sbin/", "/bin/", "/sbin/", "/usr/games/"]
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+        {
+            return Self::Apt;
+        }
+
+        Self::Unknown
+    }
+
+    /// Parses a packager-provided install method hint
+    ///
+    /// Packagers can set `CLAP_VERSION_FLAG_INSTALL_METHOD` while building so
+    /// the binary reports how it was distributed without relying on path
+    /// heuristics. Unrecognized values map to [`InstallMethod::Unknown`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::InstallMethod;
+    ///
+    /// assert_eq!(InstallMethod::from_hint("brew"), InstallMethod::Homebrew);
+    /// assert_eq!(InstallMethod::from_hint("Cargo"), InstallMethod::Cargo);
+    /// assert_eq!(InstallMethod::from_hint("flatpak"), InstallMethod::Unknown);
+    /// ```
+    #[must_use]
+    pub const fn from_hint(hint: &str) -> Self {
+        const HINTS: [(&str, InstallMethod); 9] = [
+            ("homebrew", InstallMethod::Homebrew),
+            ("brew", InstallMethod::Homebrew),
+            ("linuxbrew", InstallMethod::Homebrew),
+            ("apt", InstallMethod::Apt),
+            ("deb", InstallMethod::Apt),
+            ("dpkg", InstallMethod::Apt),
+            ("cargo", InstallMethod::Cargo),
+            ("cargo install", InstallMethod::Cargo),
+            ("cargo-install", InstallMethod::Cargo),
+        ];
+        // const, so the hint of colorful_version!() is read at compile time
+        let hint = hint.trim_ascii().as_bytes();
+        let mut index = 0;
+        while index < HINTS.len() {
+            let (name, method) = HINTS[index];
+            if hint.eq_ignore_ascii_case(name.as_bytes()) {
+                return method;
+            }
+            index += 1;
+        }
+        Self::Unknown
+    }
+
+    /// Returns the shell command that upgrades `package` for this install method
+    ///
+    /// Returns `None` when the install method is unknown.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::InstallMethod;
+    ///
+    /// assert_eq!(
+    ///     InstallMethod::Homebrew.upgrade_command("myapp").as_deref(),
+    ///     Some("brew upgrade myapp")
+    /// );
+    /// assert_eq!(InstallMethod::Unknown.upgrade_command("myapp"), None);
+    /// ```
+    #[must_use]
+    pub fn upgrade_command(&self, package: &str) -> Option<String> {
+        match self {
+            Self::Homebrew => Some(format!("brew upgrade {package}")),
+            Self::Apt => Some(format!(
+                "sudo apt update && sudo apt install --only-upgrade {package}"
+            )),
+            Self::Cargo => Some(format!("cargo install {package}")),
+            Self::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for InstallMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Homebrew => "homebrew",
+            Self::Apt => "apt",
+            Self::Cargo => "cargo install",
+            Self::Unknown => "unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path() {
+        let cases = [
+            (
+                "/usr/local/Cellar/myapp/1.0.0/bin/myapp",
+                InstallMethod::Homebrew,
+            ),
+            ("/opt/homebrew/bin/myapp", InstallMethod::Homebrew),
+            (
+                "/home/linuxbrew/.linuxbrew/bin/myapp",
+                InstallMethod::Homebrew,
+            ),
+            ("/home/user/.cargo/bin/myapp", InstallMethod::Cargo),
+            (
+                "C:\\Users\\user\\.cargo\\bin\\myapp.exe",
+                InstallMethod::Cargo,
+            ),
+            ("/usr/bin/myapp", InstallMethod::Apt),
+            (
+                "/home/user/projects/myapp/target/debug/myapp",
+                InstallMethod::Unknown,
+            ),
+        ];
+
+        for (path, expected) in cases {
+            assert_eq!(
+                InstallMethod::from_path(Path::new(path)),
+                expected,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_hint() {
+        assert_eq!(InstallMethod::from_hint(" apt "), InstallMethod::Apt);
+        assert_eq!(
+            InstallMethod::from_hint("cargo-install"),
+            InstallMethod::Cargo
+        );
+        assert_eq!(InstallMethod::from_hint(""), InstallMethod::Unknown);
+    }
+
+    #[test]
+    fn test_upgrade_command() {
+        assert_eq!(
+            InstallMethod::Cargo.upgrade_command("myapp").as_deref(),
+            Some("cargo install myapp")
+        );
+        assert!(
+            InstallMethod::Apt
+                .upgrade_command("myapp")
+                .unwrap()
+                .contains("--only-upgrade myapp")
+        );
+        assert_eq!(InstallMethod::Unknown.upgrade_command("myapp"), None);
+    }
+}
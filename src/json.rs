@@ -0,0 +1,143 @@
+// Project: clap-version-flag
+// File: src/json.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Minimal JSON writer for machine-readable version output
+// License: MIT
+
+//! A tiny JSON object writer.
+//!
+//! The machine-readable output only ever contains flat objects of strings and
+//! a few nested values, so a full serializer would be an unnecessary
+//! dependency for the default build.
+
+/// Version of every machine-readable output of this crate
+///
+/// Bumped only for breaking changes; adding keys keeps the version.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+/// Incrementally builds a JSON object
+pub(crate) struct JsonObject {
+    out: String,
+    empty: bool,
+}
+
+impl JsonObject {
+    pub(crate) fn new() -> Self {
+        Self {
+            out: String::from("{"),
+            empty: true,
+        }
+    }
+
+    fn key(&mut self, key: &str) {
+        if !self.empty {
+            self.out.push(',');
+        }
+        self.empty = false;
+        push_string(&mut self.out, key);
+        self.out.push(':');
+    }
+
+    /// Adds the `schema_version` field
+    pub(crate) fn schema_version(self) -> Self {
+        self.raw("schema_version", &SCHEMA_VERSION.to_string())
+    }
+
+    /// Adds a string field
+    pub(crate) fn string(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        push_string(&mut self.out, value);
+        self
+    }
+
+    /// Adds a string field, or `null` when the value is absent
+    pub(crate) fn optional_string(mut self, key: &str, value: Option<&str>) -> Self {
+        self.key(key);
+        match value {
+            Some(value) => push_string(&mut self.out, value),
+            None => self.out.push_str("null"),
+        }
+        self
+    }
+
+    /// Adds an array-of-strings field
+    pub(crate) fn string_array<'a>(
+        mut self,
+        key: &str,
+        values: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        self.key(key);
+        self.out.push('[');
+        for (index, value) in values.into_iter().enumerate() {
+            if index > 0 {
+                self.out.push(',');
+            }
+            push_string(&mut self.out, value);
+        }
+        self.out.push(']');
+        self
+    }
+
+    /// Adds a field whose value is already valid JSON
+    pub(crate) fn raw(mut self, key: &str, value: &str) -> Self {
+        self.key(key);
+        self.out.push_str(value);
+        self
+    }
+
+    pub(crate) fn finish(mut self) -> String {
+        self.out.push('}');
+        self.out
+    }
+}
+
+/// Appends `value` as a quoted, escaped JSON string
+pub(crate) fn push_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if u32::from(c) < 0x20 => out.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object() {
+        let json = JsonObject::new()
+            .string("name", "my\"app")
+            .raw("extra", "{}")
+            .finish();
+        assert_eq!(json, r#"{"name":"my\"app","extra":{}}"#);
+        assert_eq!(JsonObject::new().finish(), "{}");
+    }
+
+    #[test]
+    fn test_optional_and_array() {
+        let json = JsonObject::new()
+            .optional_string("a", None)
+            .optional_string("b", Some("x"))
+            .string_array("c", ["1", "2"])
+            .string_array("d", [])
+            .finish();
+        assert_eq!(json, r#"{"a":null,"b":"x","c":["1","2"],"d":[]}"#);
+    }
+
+    #[test]
+    fn test_escaping() {
+        let mut out = String::new();
+        push_string(&mut out, "a\\b\n\u{1}");
+        assert_eq!(out, r#""a\\b\n\u0001""#);
+    }
+}
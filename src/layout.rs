@@ -0,0 +1,587 @@
+// Project: clap-version-flag
+// File: src/layout.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Width and alignment constraints for the version line
+// License: MIT
+
+//! Width and alignment of the version line segments.
+//!
+//! When several binaries print their banners into the same dashboard or log,
+//! a [`Layout`] pads (and optionally truncates) the name, version and author
+//! segments to fixed columns so the lines line up. Widths are counted in
+//! terminal columns, so wide characters take two and combining marks none;
+//! emoji sequences are never cut apart and escape codes never count.
+//!
+//! A layout can also switch the extended (`-VV` and up) output to a
+//! two-column key/value listing with dotted leaders:
+//!
+//! ```text
+//! Name.............. myapp
+//! Version........... 1.2.0
+//! Author............ John Doe
+//! Installed via..... cargo install
+//! ```
+//!
+//! Finally, [`ColorfulVersion::with_template`] replaces the
+//! `{name} v{version} by {author}` shape of the version line altogether.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::{ColorDepth, ColorfulVersion, Field, Verbosity, color, grapheme};
+
+/// Fill characters added after the longest key of the two-column listing
+const MIN_LEADER: usize = 3;
+
+/// Horizontal alignment of a segment within its column, or of the output
+/// within the terminal
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Pads on the right (the default)
+    #[default]
+    Left,
+    /// Pads on the left
+    Right,
+    /// Splits the padding, the extra space going to the right
+    Center,
+}
+
+/// Width constraints of one segment
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{Align, FieldWidth};
+///
+/// // version right-aligned in an 8-char column
+/// let width = FieldWidth::new().min(8).align(Align::Right);
+/// assert_ne!(width, FieldWidth::new());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FieldWidth {
+    min: usize,
+    max: Option<usize>,
+    align: Align,
+}
+
+impl FieldWidth {
+    /// Creates a constraint that leaves the segment unchanged
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            min: 0,
+            max: None,
+            align: Align::Left,
+        }
+    }
+
+    /// Creates a fixed-width column: padded to and truncated at `width`
+    #[must_use]
+    pub fn column(width: usize) -> Self {
+        Self::new().min(width).max(width)
+    }
+
+    /// Pads the segment to at least `width` columns
+    #[must_use]
+    pub fn min(mut self, width: usize) -> Self {
+        self.min = width;
+        self
+    }
+
+    /// Truncates the segment to at most `width` columns, ending in `…`
+    #[must_use]
+    pub fn max(mut self, width: usize) -> Self {
+        self.max = Some(width);
+        self
+    }
+
+    /// Sets how the segment is aligned within its padding
+    #[must_use]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Truncates and pads `text` according to the constraints
+    ///
+    /// Widths are terminal columns, so wide and combining characters line
+    /// up; clusters are kept whole when truncating.
+    pub(crate) fn fit<'a>(&self, text: &'a str) -> Fitted<'a> {
+        self.fit_prefixed("", text)
+    }
+
+    /// Truncates and pads `prefix` followed by `text`, borrowing both unless
+    /// they are cut
+    pub(crate) fn fit_prefixed<'a>(&self, prefix: &'a str, text: &'a str) -> Fitted<'a> {
+        let mut len = grapheme::width(prefix) + grapheme::width(text);
+        let (prefix, text) = match self.max.filter(|&max| len > max) {
+            None => (prefix, Cow::Borrowed(text)),
+            Some(max) => {
+                // whole clusters, leaving a column for the ellipsis
+                let whole = format!("{prefix}{text}");
+                let mut kept = 0;
+                let shown: String = grapheme::graphemes(&whole)
+                    .take_while(|cluster| {
+                        kept += grapheme::width(cluster);
+                        kept < max
+                    })
+                    .collect();
+                len = grapheme::width(&shown);
+                let text = match max {
+                    0 => String::new(),
+                    _ => {
+                        len += 1;
+                        shown + "…"
+                    }
+                };
+                ("", Cow::Owned(text))
+            }
+        };
+
+        let padding = self.min.saturating_sub(len);
+        let left = match self.align {
+            Align::Left => 0,
+            Align::Right => padding,
+            Align::Center => padding / 2,
+        };
+        Fitted {
+            left: " ".repeat(left),
+            prefix,
+            text,
+            right: " ".repeat(padding - left),
+        }
+    }
+}
+
+/// A segment after applying its [`FieldWidth`]
+pub(crate) struct Fitted<'a> {
+    pub(crate) left: String,
+    /// The version prefix; empty for the other segments and once cut
+    pub(crate) prefix: &'a str,
+    pub(crate) text: Cow<'a, str>,
+    pub(crate) right: String,
+}
+
+impl Fitted<'_> {
+    /// Returns the padded segment
+    pub(crate) fn padded(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns the segment without its padding
+    pub(crate) fn content(&self) -> String {
+        format!("{}{}", self.prefix, self.text)
+    }
+
+    /// Returns the text behind `prefix`, unless the prefix was cut
+    pub(crate) fn unprefixed(&self, prefix: &str) -> Option<&str> {
+        if self.prefix == prefix {
+            Some(&self.text)
+        } else {
+            self.text.strip_prefix(prefix)
+        }
+    }
+}
+
+impl fmt::Display for Fitted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}{}", self.left, self.prefix, self.text, self.right)
+    }
+}
+
+/// Width constraints for the segments of the version line
+///
+/// The version segment includes its `v` prefix.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{Align, ColorfulVersion, FieldWidth, Layout};
+///
+/// let layout = Layout::new()
+///     .name(FieldWidth::new().min(8))
+///     .version(FieldWidth::new().min(8).align(Align::Right));
+/// let version = ColorfulVersion::new("myapp", "1.2.0", "John Doe").with_layout(layout);
+/// assert_eq!(version.as_plain_string(), "myapp      v1.2.0 by John Doe");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Layout {
+    pub(crate) name: FieldWidth,
+    pub(crate) version: FieldWidth,
+    pub(crate) author: FieldWidth,
+    pub(crate) leaders: Option<char>,
+    key_width: usize,
+}
+
+impl Layout {
+    /// Creates a layout without constraints
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            name: FieldWidth::new(),
+            version: FieldWidth::new(),
+            author: FieldWidth::new(),
+            leaders: None,
+            key_width: 0,
+        }
+    }
+
+    /// Constrains the package name
+    #[must_use]
+    pub fn name(mut self, width: FieldWidth) -> Self {
+        self.name = width;
+        self
+    }
+
+    /// Constrains the `v{version}` segment
+    #[must_use]
+    pub fn version(mut self, width: FieldWidth) -> Self {
+        self.version = width;
+        self
+    }
+
+    /// Constrains the author
+    #[must_use]
+    pub fn author(mut self, width: FieldWidth) -> Self {
+        self.author = width;
+        self
+    }
+
+    /// Renders the extended output as a key/value listing with `.` leaders
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Layout};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.0", "John Doe")
+    ///     .with_layout(Layout::new().dotted_leaders().key_width(18));
+    /// assert_eq!(
+    ///     version.as_verbose_plain_string(),
+    ///     "Name.............. myapp\n\
+    ///      Version........... 1.2.0\n\
+    ///      Author............ John Doe\n\
+    ///      Installed via..... unknown"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn dotted_leaders(self) -> Self {
+        self.leaders('.')
+    }
+
+    /// Renders the extended output as a key/value listing, filling the gap
+    /// between key and value with `fill`
+    #[must_use]
+    pub fn leaders(mut self, fill: char) -> Self {
+        self.leaders = Some(fill);
+        self
+    }
+
+    /// Sets the minimum width of the key column, leaders included
+    ///
+    /// The column always grows to fit the longest key plus three leaders.
+    #[must_use]
+    pub fn key_width(mut self, width: usize) -> Self {
+        self.key_width = width;
+        self
+    }
+}
+
+impl ColorfulVersion {
+    /// Sets width and alignment constraints for the version line
+    ///
+    /// Applies to the plain, colored and native clap outputs alike.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, FieldWidth, Layout};
+    ///
+    /// let version = ColorfulVersion::new("my-long-app-name", "1.0.0", "Jane")
+    ///     .with_layout(Layout::new().name(FieldWidth::column(8)));
+    /// assert_eq!(version.as_plain_string(), "my-long… v1.0.0 by Jane");
+    /// ```
+    #[must_use]
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Renders the name, version, author and `fields` as a two-column listing
+    pub(crate) fn render_leaders(&self, fields: Vec<Field>, colored: bool) -> String {
+        let fill = self.layout.leaders.unwrap_or('.');
+        let header = [
+            ("name", &self.package_name, self.palette().name_fg),
+            ("version", &self.version, self.palette().version_color),
+            ("author", &self.author, self.palette().author_color),
+        ]
+        .map(|(key, value, rgb)| Field {
+            key: key.into(),
+            value: self.bidi(value).into_owned().into(),
+            color: Some(rgb),
+            level: Verbosity::Normal,
+        });
+        let rows: Vec<Field> = header.into_iter().chain(fields).collect();
+
+        let longest = rows.iter().map(|row| grapheme::width(&row.key)).max();
+        let width = self.layout.key_width.max(longest.unwrap_or(0) + MIN_LEADER);
+        let depth = self.depth();
+
+        rows.iter()
+            .map(|row| {
+                let mut key = capitalize(&row.key);
+                let len = grapheme::width(&key);
+                key.extend(std::iter::repeat_n(fill, width.saturating_sub(len)));
+                if colored {
+                    format!(
+                        "{} {}",
+                        color::paint(&key, self.palette().version_color, None, depth),
+                        self.colored_field_value(row, depth)
+                    )
+                } else {
+                    format!("{key} {}", row.value)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Sets a template for the version line
+    ///
+    /// `{name}`, `{version}` and `{author}` are replaced by the (width
+    /// constrained) segments; `{version}` is the bare number, without the `v`
+    /// prefix. `{sep}` and `{author_sep}` are the
+    /// [separators](Self::with_separators), a space and " by " unless set,
+    /// in the separator color. Everything else is copied verbatim and left
+    /// uncolored. The native clap output of
+    /// [`with_native_colorful_version`](crate::ColorfulVersionExt::with_native_colorful_version)
+    /// keeps the default shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.0", "John Doe")
+    ///     .with_template("{name} {version} ({author})");
+    /// assert_eq!(version.as_plain_string(), "myapp 1.2.0 (John Doe)");
+    /// ```
+    #[must_use]
+    pub fn with_template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+
+    /// Renders the version line from `template`, painted when `depth` is set
+    pub(crate) fn render_template(&self, template: &str, depth: Option<ColorDepth>) -> String {
+        let mut out = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+            let separator = match &rest[1..end] {
+                "sep" => Some(self.name_separator()),
+                "author_sep" => Some(self.author_separator()),
+                _ => None,
+            };
+            if let Some(separator) = separator {
+                out.push_str(&self.paint_separator(&separator, depth));
+                rest = &rest[end + 1..];
+                continue;
+            }
+            let segment = match &rest[1..end] {
+                "name" => Some((
+                    self.isolated(self.layout.name.fit(&self.package_name)),
+                    &self.styles.name,
+                    self.palette().name_fg,
+                    Some(self.palette().name_bg),
+                )),
+                "version" => Some((
+                    self.layout.version.fit(&self.version),
+                    &self.styles.version,
+                    self.palette().version_color,
+                    self.version_background(),
+                )),
+                "author" => Some((
+                    self.isolated(self.layout.author.fit(&self.author)),
+                    &self.styles.author,
+                    self.palette().author_color,
+                    self.author_background(),
+                )),
+                _ => None,
+            };
+            match (segment, depth) {
+                (Some((fitted, style, fg, bg)), Some(depth)) => {
+                    let painted = match &rest[1..end] {
+                        "version" if fitted.left.is_empty() => {
+                            self.colored_number(&fitted.text, &fitted.right, depth)
+                        }
+                        "name" => self.paint_name(&fitted.padded(), depth),
+                        _ => self.paint_segment(&fitted.padded(), style, fg, bg, depth),
+                    };
+                    let link = (&rest[1..end] == "author")
+                        .then_some(self.author_link.as_deref())
+                        .flatten();
+                    match self.link_target(link) {
+                        Some(url) => out.push_str(&color::hyperlink(&painted, url)),
+                        None => out.push_str(&painted),
+                    }
+                }
+                (Some((fitted, ..)), None) => out.push_str(&fitted.padded()),
+                (None, _) => out.push_str(&rest[..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Returns the padded name, `v{version}` and author segments
+    pub(crate) fn fitted_segments(&self) -> [Fitted<'_>; 3] {
+        [
+            self.isolated(self.layout.name.fit(&self.package_name)),
+            self.layout
+                .version
+                .fit_prefixed(&self.labels().version_prefix, &self.version),
+            self.isolated(self.layout.author.fit(&self.author)),
+        ]
+    }
+
+    /// Isolates the fitted text, keeping the padding outside the isolate
+    fn isolated<'a>(&self, mut fitted: Fitted<'a>) -> Fitted<'a> {
+        if let Cow::Owned(isolated) = self.bidi(&fitted.text) {
+            fitted.text = Cow::Owned(isolated);
+        }
+        fitted
+    }
+}
+
+/// Uppercases the first character of a field key
+fn capitalize(key: &str) -> String {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fit(width: FieldWidth, text: &str) -> String {
+        width.fit(text).padded()
+    }
+
+    #[test]
+    fn test_alignment() {
+        assert_eq!(fit(FieldWidth::new().min(6), "ab"), "ab    ");
+        assert_eq!(
+            fit(FieldWidth::new().min(6).align(Align::Right), "ab"),
+            "    ab"
+        );
+        assert_eq!(
+            fit(FieldWidth::new().min(7).align(Align::Center), "ab"),
+            "  ab   "
+        );
+        assert_eq!(fit(FieldWidth::new().min(1), "abc"), "abc");
+    }
+
+    #[test]
+    fn test_truncation() {
+        assert_eq!(fit(FieldWidth::new().max(4), "abcdef"), "abc…");
+        assert_eq!(fit(FieldWidth::new().max(6), "abcdef"), "abcdef");
+        assert_eq!(fit(FieldWidth::new().max(0), "abc"), "");
+        assert_eq!(fit(FieldWidth::column(3), "é"), "é  ");
+        // clusters are kept whole and measured in terminal columns
+        assert_eq!(
+            fit(FieldWidth::column(3), "👩\u{200D}💻ab"),
+            "👩\u{200D}💻…"
+        );
+        assert_eq!(fit(FieldWidth::column(3), "a👩\u{200D}💻bc"), "a… ");
+        assert_eq!(fit(FieldWidth::new().min(4), "🇮🇩"), "🇮🇩  ");
+        assert_eq!(fit(FieldWidth::column(5), "日本語"), "日本…");
+        assert_eq!(
+            fit(FieldWidth::column(4), "e\u{301}\u{301}"),
+            "e\u{301}\u{301}   "
+        );
+    }
+
+    #[test]
+    fn test_aligned_banners() {
+        let layout = Layout::new()
+            .name(FieldWidth::column(10))
+            .version(FieldWidth::new().min(8).align(Align::Right));
+        let first = ColorfulVersion::new("myapp", "1.2.0", "Jane").with_layout(layout);
+        let second = ColorfulVersion::new("myapp-srv", "10.0.1", "Jane").with_layout(layout);
+
+        assert_eq!(first.as_plain_string(), "myapp        v1.2.0 by Jane");
+        assert_eq!(second.as_plain_string(), "myapp-srv   v10.0.1 by Jane");
+    }
+
+    #[test]
+    fn test_leaders() {
+        let version = crate::ColorfulVersionBuilder::new("myapp", "1.2.0", "Jane")
+            .license("MIT")
+            .build()
+            .with_install_method(crate::InstallMethod::Cargo)
+            .with_layout(Layout::new().leaders('_'));
+
+        assert_eq!(
+            version.as_verbose_plain_string(),
+            "Name____________ myapp\n\
+             Version_________ 1.2.0\n\
+             Author__________ Jane\n\
+             License_________ MIT\n\
+             Installed via___ cargo install"
+        );
+        // the normal output keeps the single line
+        assert_eq!(
+            version.render_plain(Verbosity::Normal),
+            "myapp v1.2.0 by Jane\nlicense: MIT"
+        );
+    }
+
+    #[test]
+    fn test_colored_leaders() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane")
+            .with_color_depth(crate::ColorDepth::TrueColor)
+            .with_layout(Layout::new().dotted_leaders());
+        let colored = version.render_leaders(Vec::new(), true);
+
+        assert!(
+            colored.starts_with("\x1b[38;2;255;255;0mName......\x1b[0m \x1b[38;2;255;255;255mapp")
+        );
+        assert_eq!(colored.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_template() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane")
+            .with_layout(Layout::new().version(FieldWidth::new().min(5).align(Align::Right)))
+            .with_template("[{name}] {version} {unknown} {author");
+
+        assert_eq!(version.as_plain_string(), "[app]   1.0 {unknown} {author");
+        assert_eq!(version.to_string(), version.as_plain_string());
+    }
+
+    #[test]
+    fn test_colored_template() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane").with_template("{version}/{name}");
+        assert_eq!(
+            version.colored_line(ColorDepth::TrueColor),
+            "\x1b[38;2;255;255;0m1.0\x1b[0m/\x1b[38;2;255;255;255;48;2;170;0;255mapp\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_colored_line_keeps_widths() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane")
+            .with_layout(Layout::new().version(FieldWidth::new().min(6).align(Align::Right)));
+        let colored = version.colored_line(crate::ColorDepth::TrueColor);
+        let stripped: String = colored.split('\x1b').fold(String::new(), |mut out, part| {
+            out.push_str(part.split_once('m').map_or(part, |(_, rest)| rest));
+            out
+        });
+        assert_eq!(stripped, version.as_plain_string());
+    }
+}
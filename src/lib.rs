@@ -41,51 +41,433 @@
 //! version.print();
 //! ```
 
+mod accessibility;
+mod age;
+mod alignment;
+mod authors;
+mod background;
+mod banner;
+mod bare;
+mod bidi;
+mod bug_report;
+pub mod build;
+mod build_info;
+mod builder;
+pub mod color;
+mod color_choice;
+#[cfg(feature = "completions")]
+mod completions;
+mod components;
+#[cfg(feature = "config")]
+mod config;
+mod contrast;
+mod deferred;
+mod dependencies;
+mod diagnostics;
+mod direct;
+mod early_exit;
+mod entitlement;
+mod env_overrides;
 mod error;
+mod exit;
+mod expiry;
+mod features;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod fields;
+#[cfg(feature = "figlet")]
+mod figlet;
+mod flag;
+mod global;
+mod grapheme;
+mod html;
+mod i18n;
+mod info;
+pub mod install;
+mod json;
+mod layout;
+mod license;
+mod link;
+mod logo;
 pub mod macros;
+#[cfg(feature = "mangen")]
+mod mangen;
+mod markdown;
+mod matches;
+mod metrics;
+mod minimal;
+mod palette;
+mod panic;
+mod parse;
+#[cfg(feature = "suite")]
+mod plugins;
+#[cfg(feature = "preview")]
+pub mod preview;
+mod rainbow;
+mod release;
+mod render;
+mod schema;
+mod segment_bg;
+mod segments;
+pub mod semver;
+mod separators;
+#[cfg(feature = "serde")]
+mod serialize;
+pub mod skew;
+mod startup;
+mod style;
+mod styled;
+mod subcommand;
+#[cfg(feature = "config")]
+mod suggest;
+#[cfg(feature = "suite")]
+pub mod suite;
+#[cfg(feature = "sysinfo")]
+mod sysinfo;
+mod theme;
+#[cfg(feature = "update-check")]
+mod update;
+mod user_agent;
+mod warnings;
+#[cfg(feature = "windows-console")]
+mod windows_console;
+mod wrappers;
 
+pub use accessibility::Accessibility;
+pub use age::AgeThresholds;
+pub use authors::{Author, AuthorDisplay};
+pub use background::Background;
+pub use banner::BannerStyle;
+pub use bare::PrintMode;
+pub use build_info::BuildInfo;
+pub use builder::ColorfulVersionBuilder;
+#[cfg(feature = "attribute")]
+pub use clap_version_flag_attr::clap_version;
+pub use color::{ColorDepth, ColorSpec};
+#[cfg(feature = "completions")]
+pub use completions::augment_completions;
+pub use components::ComponentVersions;
+pub use contrast::contrast_ratio;
+pub use early_exit::{exit_on_version_flag, exit_on_version_flag_for};
+pub use entitlement::{Entitlement, EntitlementStatus};
 pub use error::VersionError;
+pub use exit::{ExitHandler, ProcessExit};
+pub use expiry::ExpiryAction;
+#[cfg(feature = "figlet")]
+pub use figlet::Font;
+pub use flag::VersionFlagConfig;
+pub use global::{global, set_global};
+pub use html::HtmlRenderer;
+pub use i18n::{Labels, Locale};
+pub use info::VersionInfo;
+pub use install::InstallMethod;
+pub use layout::{Align, FieldWidth, Layout};
+pub use markdown::MarkdownRenderer;
+pub use matches::ArgMatchesExt;
+#[cfg(feature = "suite")]
+pub use plugins::{PluginDir, PluginSource};
+pub use render::{AnsiRenderer, JsonRenderer, PlainRenderer, VersionRenderer};
+pub use segment_bg::SegmentBackground;
+pub use skew::{SkewPolicy, SkewReport, SkewVerdict};
+pub use startup::{STARTUP_BUDGET, StartupCost, startup_cost};
+pub use style::{Style, Styles};
+#[cfg(feature = "suite")]
+pub use suite::{SuiteEntry, VersionSuite};
+#[cfg(feature = "sysinfo")]
+pub use sysinfo::SystemField;
+pub use theme::{Role, Theme};
+#[cfg(feature = "update-check")]
+pub use update::{UpdateChecker, UpdateSource};
+pub use warnings::{Warning, Warnings};
 
-use clap::{Arg, ArgAction, ArgMatches, Command, FromArgMatches};
-use colored::Colorize;
+use clap::builder::{EnumValueParser, PossibleValue};
+use clap::{Arg, ArgAction, ArgMatches, ColorChoice, Command, FromArgMatches, ValueEnum};
+use deferred::Deferred;
+use layout::Fitted;
+use std::borrow::Cow;
+use std::ffi::OsString;
 use std::fmt;
+use std::io::{self, Write};
 use std::process;
+use std::time::SystemTime;
+
+/// Arg id of the version flag registered by [`ColorfulVersionExt`]
+///
+/// Stays the same across releases. [`VersionFlagConfig::id`] registers the
+/// flag under another id, so an embedded library can add its own flag
+/// next to the app's.
+///
+/// The type of the flag's value depends on the configuration (a `bool`, a
+/// count for the repeatable flag, a [`PrintMode`] with
+/// [`mode_value`](VersionFlagConfig::mode_value)), so
+/// `ArgMatches::get_flag` panics for some of them. Query the flag through
+/// [`ArgMatchesExt`] instead, which handles every form.
+///
+/// # Examples
+/// ```
+/// use clap::Command;
+/// use clap_version_flag::{
+///     ArgMatchesExt, ColorfulVersion, ColorfulVersionExt, VERSION_FLAG_ID, VersionFlagConfig,
+/// };
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_flag_config(VersionFlagConfig::new().mode_value(true));
+/// let matches = Command::new("myapp")
+///     .with_colorful_version(&version)
+///     .get_matches_from(["myapp", "--version=short"]);
+/// assert!(matches.version_requested());
+/// assert!(matches.contains_id(VERSION_FLAG_ID));
+/// ```
+pub const VERSION_FLAG_ID: &str = "clap_version_flag_version";
+
+/// Companion `--version-format` flag id registered by [`ColorfulVersionExt`]
+const FORMAT_ARG_ID: &str = "clap_version_flag_format";
+
+/// Companion `--version-deps` flag id registered by [`ColorfulVersionExt`]
+const DEPS_ARG_ID: &str = "clap_version_flag_deps";
+
+/// Companion `--version-fields` option id registered by [`ColorfulVersionExt`]
+const FIELDS_ARG_ID: &str = "clap_version_flag_fields";
+
+/// Companion `--version-theme` option id registered by [`ColorfulVersionExt`]
+const THEME_ARG_ID: &str = "clap_version_flag_theme";
+
+/// Companion `--bug-report` flag id registered by [`ColorfulVersionExt`]
+const BUG_REPORT_ARG_ID: &str = "clap_version_flag_bug_report";
+
+/// Hidden `--version-schema` flag id registered by [`ColorfulVersionExt`]
+const SCHEMA_ARG_ID: &str = "clap_version_flag_schema";
+
+/// Hidden `--version-color` option id registered along with the version flag
+const COLOR_ARG_ID: &str = "clap_version_flag_color";
+
+/// Output format of the version information
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum VersionFormat {
+    /// Colored text when the terminal supports it
+    #[default]
+    Text,
+    /// Text without any escape codes
+    Plain,
+    /// A single-line JSON object for scripts and tooling
+    Json,
+}
+
+impl ValueEnum for VersionFormat {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Text, Self::Plain, Self::Json]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Text => PossibleValue::new("text").help("Colored text (default)"),
+            Self::Plain => PossibleValue::new("plain").help("Text without colors"),
+            Self::Json => PossibleValue::new("json").help("Machine-readable JSON"),
+        })
+    }
+}
+
+/// Level of detail requested for the version output
+///
+/// `-V` maps to [`Verbosity::Normal`], `-VV` to [`Verbosity::Verbose`] and
+/// `-VVV` to [`Verbosity::Diagnostic`] when the flag is registered with
+/// [`ColorfulVersionExt::with_verbose_colorful_version`].
+/// [`Verbosity::Brief`] is only used when configured, see
+/// [`VersionFlagConfig::short_verbosity`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Verbosity {
+    /// Just "name vX", without author, detail fields or decoration
+    Brief,
+    /// The single "name vX by author" line
+    Normal,
+    /// The version line followed by detail fields such as the install method
+    Verbose,
+    /// Everything in `Verbose` plus diagnostics such as the config snapshot
+    Diagnostic,
+}
+
+impl Verbosity {
+    /// Converts the number of times the version flag was given to a verbosity
+    ///
+    /// Returns `None` if the flag was not given at all.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Verbosity;
+    ///
+    /// assert_eq!(Verbosity::from_occurrences(0), None);
+    /// assert_eq!(Verbosity::from_occurrences(1), Some(Verbosity::Normal));
+    /// assert_eq!(Verbosity::from_occurrences(2), Some(Verbosity::Verbose));
+    /// assert_eq!(Verbosity::from_occurrences(3), Some(Verbosity::Diagnostic));
+    /// ```
+    #[must_use]
+    pub fn from_occurrences(count: u8) -> Option<Self> {
+        match count {
+            0 => None,
+            1 => Some(Self::Normal),
+            2 => Some(Self::Verbose),
+            _ => Some(Self::Diagnostic),
+        }
+    }
+
+    /// Returns how many levels `self` is above [`Verbosity::Normal`]
+    fn steps(self) -> usize {
+        match self {
+            Self::Brief | Self::Normal => 0,
+            Self::Verbose => 1,
+            Self::Diagnostic => 2,
+        }
+    }
+
+    /// Returns the verbosity `steps` levels above `self`, at most diagnostic
+    fn raised(self, steps: usize) -> Self {
+        const LEVELS: [Verbosity; 4] = [
+            Verbosity::Brief,
+            Verbosity::Normal,
+            Verbosity::Verbose,
+            Verbosity::Diagnostic,
+        ];
+        LEVELS[(self as usize + steps).min(LEVELS.len() - 1)]
+    }
+}
+
+/// Result of [`try_parse_with_version`]
+///
+/// Lets the caller decide how to finish when the version was requested,
+/// instead of the process exiting from inside the library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VersionOutcome<T> {
+    /// The arguments were parsed normally
+    Parsed(T),
+    /// The version flag was given; holds the rendered version output
+    VersionRequested(String),
+}
 
 /// Configuration for colorful version output
 #[derive(Clone, Debug)]
 pub struct ColorfulVersion {
-    package_name: String,
-    version: String,
-    author: String,
+    package_name: Cow<'static, str>,
+    version: Cow<'static, str>,
+    author: Cow<'static, str>,
     colors: Colors,
+    styles: Styles,
+    layout: Layout,
+    template: Option<String>,
+    banner: Option<BannerStyle>,
+    logo: Option<logo::Logo>,
+    #[cfg(feature = "figlet")]
+    ascii_art: Option<Font>,
+    #[cfg(feature = "sysinfo")]
+    system_info: Option<sysinfo::SystemInfo>,
+    color_depth: Option<ColorDepth>,
+    install_method: Option<InstallMethod>,
+    metadata: Deferred<Field>,
+    /// Detail lines shown instead of the configured ones, when presenting
+    /// a [`VersionInfo`]
+    presented_fields: Option<Vec<Field>>,
+    authors: Deferred<Author>,
+    author_display: AuthorDisplay,
+    author_emails: bool,
+    features: Vec<String>,
+    visible_fields: Option<Vec<String>>,
+    dependencies: Vec<(String, String)>,
+    components: ComponentVersions,
+    exit_handler: Option<exit::SharedExitHandler>,
+    flag: VersionFlagConfig,
+    print_mode: PrintMode,
+    build_time: Option<SystemTime>,
+    age_thresholds: AgeThresholds,
+    expiry: Option<expiry::Expiry>,
+    entitlement: Option<entitlement::EntitlementProvider>,
+    config_snapshot: Option<diagnostics::SnapshotProvider>,
+    redactions: Vec<String>,
+    author_link: Option<String>,
+    repo_link: Option<String>,
+    hyperlinks: Option<bool>,
+    accessibility: Option<Accessibility>,
+    color_choice: Option<ColorChoice>,
+    min_contrast: Option<f64>,
+    labels: Option<Labels>,
+    adaptive_theme: Option<(Theme, Theme)>,
+    background: Option<Background>,
+    env_prefix: Option<String>,
+    bidi_isolation: bool,
+    rainbow: bool,
+    name_palette: Option<Vec<(u8, u8, u8)>>,
+    hide_name: bool,
+    hide_author: bool,
+    separators: Option<separators::Separators>,
+    alignment: Align,
+    terminal_width: Option<usize>,
+    warnings: Warnings,
+    #[cfg(feature = "suite")]
+    plugins: Option<plugins::PluginScan>,
+    #[cfg(feature = "update-check")]
+    update_check: Option<update::UpdateCheck>,
+    #[cfg(feature = "update-check")]
+    update_checker: Option<UpdateChecker>,
 }
 
-#[derive(Clone, Debug)]
+/// A "key: value" line rendered below the version line
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    key: Cow<'static, str>,
+    value: Cow<'static, str>,
+    color: Option<(u8, u8, u8)>,
+    /// Lowest verbosity at which the field is shown
+    level: Verbosity,
+}
+
+#[derive(Clone, Copy, Debug)]
 struct Colors {
-    name_fg: (u8, u8, u8),       // RGB for name foreground
-    name_bg: (u8, u8, u8),       // RGB for name background
-    version_color: (u8, u8, u8), // RGB for version
-    author_color: (u8, u8, u8),  // RGB for author
+    name_fg: (u8, u8, u8),         // RGB for name foreground
+    name_bg: (u8, u8, u8),         // RGB for name background
+    version_color: (u8, u8, u8),   // RGB for version
+    author_color: (u8, u8, u8),    // RGB for author
+    license_color: (u8, u8, u8),   // RGB for license and copyright
+    success: (u8, u8, u8),         // RGB for healthy states
+    warning: (u8, u8, u8),         // RGB for states needing attention
+    error: (u8, u8, u8),           // RGB for failures
+    separator_color: (u8, u8, u8), // RGB for segment separators
+    version_bg: SegmentBackground,
+    author_bg: SegmentBackground,
+}
+
+impl Colors {
+    /// The classic look, white on `#AA00FF` with a yellow version
+    const CLASSIC: Self = Self {
+        // Default colors as specified in requirements:
+        // name: light #FFFFFF on #AA00FF
+        // version: light #FFFF00
+        // author: light #00FFFF
+        name_fg: (255, 255, 255),         // #FFFFFF
+        name_bg: (170, 0, 255),           // #AA00FF
+        version_color: (255, 255, 0),     // #FFFF00
+        author_color: (0, 255, 255),      // #00FFFF
+        license_color: (170, 170, 170),   // #AAAAAA
+        success: (0, 255, 0),             // #00FF00
+        warning: (255, 255, 0),           // #FFFF00
+        error: (255, 0, 0),               // #FF0000
+        separator_color: (170, 170, 170), // #AAAAAA
+        version_bg: SegmentBackground::None,
+        author_bg: SegmentBackground::None,
+    };
 }
 
 impl Default for Colors {
     fn default() -> Self {
-        Self {
-            // Default colors as specified in requirements:
-            // name: light #FFFFFF on #AA00FF
-            // version: light #FFFF00
-            // author: light #00FFFF
-            name_fg: (255, 255, 255),     // #FFFFFF
-            name_bg: (170, 0, 255),       // #AA00FF
-            version_color: (255, 255, 0), // #FFFF00
-            author_color: (0, 255, 255),  // #00FFFF
-        }
+        Self::CLASSIC
     }
 }
 
 impl ColorfulVersion {
     /// Creates a new ColorfulVersion with custom values
     ///
+    /// Copies the strings; [`ColorfulVersion::from_static`] borrows string
+    /// literals instead.
+    ///
     /// # Examples
     /// ```
     /// use clap_version_flag::ColorfulVersion;
@@ -99,47 +481,311 @@ impl ColorfulVersion {
         version: impl Into<String>,
         author: impl Into<String>,
     ) -> Self {
+        let author = author.into();
         Self {
-            package_name: package_name.into(),
-            version: version.into(),
-            author: author.into(),
-            colors: Colors::default(),
+            package_name: Cow::Owned(package_name.into()),
+            version: Cow::Owned(version.into()),
+            authors: vec![Author::parse(&author)].into(),
+            author: Cow::Owned(author),
+            ..Self::from_static("", "", "")
         }
     }
 
-    /// Sets custom hex colors for the version output
+    /// Creates a ColorfulVersion in a `const` or `static` context
     ///
-    /// # Arguments
-    /// * `name_fg` - Hex color for package name foreground (e.g., "#FFFFFF")
-    /// * `name_bg` - Hex color for package name background (e.g., "#AA00FF")
-    /// * `version` - Hex color for version text (e.g., "#FFFF00")
-    /// * `author` - Hex color for author text (e.g., "#00FFFF")
+    /// Nothing is allocated until the version is first used, so a
+    /// `static` needs no lazy initialization. The result is the same as
+    /// [`ColorfulVersion::new`] with these strings;
+    /// [`colorful_version!`](crate::colorful_version) is const as well and
+    /// takes the values from `Cargo.toml`.
     ///
-    /// # Errors
-    /// Returns `VersionError::InvalidHexColor` if any hex color is invalid
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// static VERSION: ColorfulVersion = ColorfulVersion::from_static("myapp", "1.0.0", "John Doe");
+    ///
+    /// assert_eq!(VERSION.as_plain_string(), "myapp v1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub const fn from_static(
+        package_name: &'static str,
+        version: &'static str,
+        author: &'static str,
+    ) -> Self {
+        Self::from_parts(
+            package_name,
+            version,
+            author,
+            Deferred::new(author, |author| vec![Author::parse_static(author)]),
+            Deferred::new("", |_| Vec::new()),
+            None,
+        )
+    }
+
+    /// Creates the version of [`colorful_version!`](crate::colorful_version)
+    /// from the `CARGO_PKG_*` values
+    ///
+    /// `authors` is the colon-separated `CARGO_PKG_AUTHORS` and `author` the
+    /// same list as the version line shows it. The list and the license line
+    /// are parsed on first use.
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn from_cargo(
+        package_name: &'static str,
+        version: &'static str,
+        authors: &'static str,
+        author: &'static str,
+        license: &'static str,
+        install_method_hint: Option<&'static str>,
+    ) -> Self {
+        let install_method = match install_method_hint {
+            Some(hint) => match InstallMethod::from_hint(hint) {
+                InstallMethod::Unknown => None,
+                method => Some(method),
+            },
+            None => None,
+        };
+        Self::from_parts(
+            package_name,
+            version,
+            author,
+            Deferred::new(authors, Author::parse_static_list),
+            Deferred::new(license, license::license_line),
+            install_method,
+        )
+    }
+
+    /// Creates a version with default settings around the given values
+    const fn from_parts(
+        package_name: &'static str,
+        version: &'static str,
+        author: &'static str,
+        authors: Deferred<Author>,
+        metadata: Deferred<Field>,
+        install_method: Option<InstallMethod>,
+    ) -> Self {
+        Self {
+            package_name: Cow::Borrowed(package_name),
+            version: Cow::Borrowed(version),
+            author: Cow::Borrowed(author),
+            authors,
+            author_display: AuthorDisplay::Joined,
+            author_emails: true,
+            colors: Colors::CLASSIC,
+            styles: Styles::new(),
+            layout: Layout::new(),
+            template: None,
+            banner: None,
+            logo: None,
+            #[cfg(feature = "figlet")]
+            ascii_art: None,
+            #[cfg(feature = "sysinfo")]
+            system_info: None,
+            color_depth: None,
+            install_method,
+            metadata,
+            presented_fields: None,
+            features: Vec::new(),
+            visible_fields: None,
+            dependencies: Vec::new(),
+            components: ComponentVersions::new(),
+            exit_handler: None,
+            flag: VersionFlagConfig::new(),
+            print_mode: PrintMode::Full,
+            build_time: None,
+            age_thresholds: AgeThresholds::DEFAULT,
+            expiry: None,
+            entitlement: None,
+            config_snapshot: None,
+            redactions: Vec::new(),
+            author_link: None,
+            repo_link: None,
+            hyperlinks: None,
+            accessibility: None,
+            color_choice: None,
+            min_contrast: None,
+            labels: None,
+            adaptive_theme: None,
+            background: None,
+            env_prefix: None,
+            bidi_isolation: true,
+            rainbow: false,
+            name_palette: None,
+            hide_name: false,
+            hide_author: false,
+            separators: None,
+            alignment: Align::Left,
+            terminal_width: None,
+            warnings: Warnings::new(),
+            #[cfg(feature = "suite")]
+            plugins: None,
+            #[cfg(feature = "update-check")]
+            update_check: None,
+            #[cfg(feature = "update-check")]
+            update_checker: None,
+        }
+    }
+
+    /// Creates a ColorfulVersion from embedded [`BuildInfo`]
+    ///
+    /// Description, homepage, repository, license, Rust version, commit and
+    /// build date become detail fields shown in verbose (`-VV`) output. A
+    /// parsable build date also adds the build age ("built: 42 days ago").
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{BuildInfo, ColorfulVersion};
+    ///
+    /// const INFO: BuildInfo = BuildInfo::new("myapp", "1.0.0", "John Doe")
+    ///     .with_commit(Some("4f2a9c1"));
+    ///
+    /// let version = ColorfulVersion::from_build_info(&INFO);
+    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0 by John Doe");
+    /// assert_eq!(version.metadata("commit"), Some("4f2a9c1"));
+    /// ```
+    #[must_use]
+    pub fn from_build_info(info: &BuildInfo) -> Self {
+        let mut version = Self::new(info.name, info.version, info.authors)
+            .with_authors(Author::parse_static_list(info.authors))
+            .with_install_method_hint(info.install_method);
+        let fields = [
+            ("description", info.description),
+            ("homepage", info.homepage),
+            ("repository", info.repository),
+            ("license", info.license),
+            ("rust version", info.rust_version),
+            ("commit", info.commit),
+            ("build date", info.build_date),
+            ("rustc", info.rustc),
+            ("target", info.target),
+            ("profile", info.profile),
+            ("opt level", info.opt_level),
+        ];
+        let fields = fields
+            .into_iter()
+            .map(|(key, value)| (key, value.map(Cow::Borrowed)))
+            .chain([("build-id", Some(Cow::Owned(info.build_id())))]);
+        for (key, value) in fields {
+            if let Some(value) = value {
+                version.metadata.push(Field {
+                    key: key.into(),
+                    value,
+                    color: None,
+                    level: Verbosity::Verbose,
+                });
+            }
+        }
+        version.build_time = info.build_date.and_then(age::parse_timestamp);
+        version
+            .with_features(info.feature_list())
+            .with_dependencies(info.dependency_list())
+    }
+
+    /// Starts a [`ColorfulVersionBuilder`] for a version with optional metadata
     ///
     /// # Examples
     /// ```
     /// use clap_version_flag::ColorfulVersion;
     ///
-    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
-    ///     .with_hex_colors("#FFFFFF", "#AA00FF", "#FFFF00", "#00FFFF")
-    ///     .expect("Invalid hex colors");
+    /// let version = ColorfulVersion::builder("myapp", "1.0.0", "John Doe")
+    ///     .description("Does useful things")
+    ///     .license("MIT")
+    ///     .build();
+    /// assert_eq!(version.license(), Some("MIT"));
     /// ```
+    #[must_use]
+    pub fn builder(
+        package_name: impl Into<String>,
+        version: impl Into<String>,
+        author: impl Into<String>,
+    ) -> ColorfulVersionBuilder {
+        ColorfulVersionBuilder::new(package_name, version, author)
+    }
+
+    /// Sets custom hex colors for the version output
+    ///
+    /// A shim over [`with_colors`](Self::with_colors) that still accepts hex
+    /// colors only, `#RRGGBB` or `#RGB` with an optional `#`, as it always
+    /// did; `with_colors` takes color names and `rgb()`/`hsl()` notation too.
+    ///
+    /// # Errors
+    /// Returns `VersionError::InvalidHexColor` if any hex color is invalid
+    #[deprecated(since = "1.1.0", note = "use `with_colors` instead")]
     pub fn with_hex_colors(
-        mut self,
+        self,
         name_fg: &str,
         name_bg: &str,
         version: &str,
         author: &str,
     ) -> Result<Self, VersionError> {
-        self.colors.name_fg = parse_hex(name_fg)?;
-        self.colors.name_bg = parse_hex(name_bg)?;
-        self.colors.version_color = parse_hex(version)?;
-        self.colors.author_color = parse_hex(author)?;
+        self.with_colors(
+            parse_hex(name_fg)?,
+            parse_hex(name_bg)?,
+            parse_hex(version)?,
+            parse_hex(author)?,
+        )
+    }
+
+    /// Sets the colors from anything convertible to a [`ColorSpec`]
+    ///
+    /// Accepts RGB tuples, [`ColorSpec`]s and everything
+    /// [`color::parse_color`] does, e.g. `"#AA00FF"`, `"rebeccapurple"`,
+    /// `"rgb(170, 0, 255)"` or `"hsl(280, 100%, 50%)"`.
+    ///
+    /// # Errors
+    /// Returns an error if any of the colors cannot be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_colors("white", "hsl(280, 100%, 50%)", (255, 215, 0), "#00FFFF")
+    ///     .expect("Invalid colors");
+    /// ```
+    pub fn with_colors(
+        mut self,
+        name_fg: impl TryInto<ColorSpec, Error = impl Into<VersionError>>,
+        name_bg: impl TryInto<ColorSpec, Error = impl Into<VersionError>>,
+        version: impl TryInto<ColorSpec, Error = impl Into<VersionError>>,
+        author: impl TryInto<ColorSpec, Error = impl Into<VersionError>>,
+    ) -> Result<Self, VersionError> {
+        fn rgb(
+            spec: impl TryInto<ColorSpec, Error = impl Into<VersionError>>,
+        ) -> Result<(u8, u8, u8), VersionError> {
+            spec.try_into().map(ColorSpec::rgb).map_err(Into::into)
+        }
+
+        self.colors.name_fg = rgb(name_fg)?;
+        self.colors.name_bg = rgb(name_bg)?;
+        self.colors.version_color = rgb(version)?;
+        self.colors.author_color = rgb(author)?;
         Ok(self)
     }
 
+    /// Sets text styles such as bold or underline for the segments of the line
+    ///
+    /// Colors set in a [`Style`] take precedence over the regular colors.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorDepth, ColorfulVersion, Style, Styles};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_color_depth(ColorDepth::TrueColor)
+    ///     .with_styles(Styles::new().name(Style::new().bold()));
+    /// let mut out = Vec::new();
+    /// version.write_colored_to(&mut out).unwrap();
+    ///
+    /// assert!(String::from_utf8(out).unwrap().starts_with("\x1b[1;38;2;255;255;255;48;2;170;0;255mmyapp"));
+    /// ```
+    #[must_use]
+    pub fn with_styles(mut self, styles: Styles) -> Self {
+        self.styles = styles;
+        self
+    }
+
     /// Sets custom RGB colors for the version output
     ///
     /// # Examples
@@ -164,111 +810,797 @@ impl ColorfulVersion {
         self
     }
 
-    /// Prints the colorful version to stdout and exits the process
+    /// Sets the color depth used for rendering
+    ///
+    /// By default the depth is detected from `COLORTERM`/`TERM` each time the
+    /// version is rendered. Terminals without truecolor support get the
+    /// configured RGB values mapped to the nearest xterm-256 or ANSI-16 color.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorDepth, ColorfulVersion};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_color_depth(ColorDepth::Ansi256);
+    /// ```
+    #[must_use]
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+        self.color_depth = Some(depth);
+        self
+    }
+
+    /// Sets how the binary was installed, shown as "installed via" in verbose output
+    ///
+    /// Without this, the install method is detected from the executable path
+    /// when verbose output is rendered.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, InstallMethod};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_install_method(InstallMethod::Cargo);
+    /// assert_eq!(version.install_method(), InstallMethod::Cargo);
+    /// ```
+    #[must_use]
+    pub fn with_install_method(mut self, method: InstallMethod) -> Self {
+        self.install_method = Some(method);
+        self
+    }
+
+    /// Applies a build-time install method hint provided by packagers
+    ///
+    /// The `colorful_version!()` macros pass
+    /// `option_env!("CLAP_VERSION_FLAG_INSTALL_METHOD")` here, so a packager
+    /// building with `CLAP_VERSION_FLAG_INSTALL_METHOD=homebrew` gets an exact
+    /// answer instead of path heuristics. Missing or unrecognized hints leave
+    /// the current setting untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, InstallMethod};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_install_method_hint(Some("apt"));
+    /// assert_eq!(version.install_method(), InstallMethod::Apt);
+    /// ```
+    #[must_use]
+    pub fn with_install_method_hint(mut self, hint: Option<&str>) -> Self {
+        match hint.map(InstallMethod::from_hint) {
+            Some(InstallMethod::Unknown) | None => {}
+            Some(method) => self.install_method = Some(method),
+        }
+        self
+    }
+
+    /// Sets what happens after a version request has been printed
+    ///
+    /// Defaults to [`ProcessExit`]. See [`ExitHandler`] for how returning
+    /// handlers are treated.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_exit_handler(Box::new(|code| eprintln!("exiting with {code}")));
+    /// let matches = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .get_matches_from(["myapp", "-V"]);
+    ///
+    /// version.check_and_exit(&matches); // prints, calls the handler and returns
+    /// ```
+    #[must_use]
+    pub fn with_exit_handler(mut self, handler: Box<dyn ExitHandler>) -> Self {
+        self.exit_handler = Some(exit::SharedExitHandler::new(handler));
+        self
+    }
+
+    /// Sets the names of the version flag registered for this version
+    ///
+    /// [`ColorfulVersionExt::with_colorful_version`] and
+    /// [`ColorfulVersionExt::with_verbose_colorful_version`] register the flag
+    /// from this configuration, and the `check*` methods look it up by its id.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionFlagConfig};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_flag_config(VersionFlagConfig::new().id("show_version").short('v'));
+    /// let matches = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .get_matches_from(["myapp", "-v"]);
+    ///
+    /// assert!(matches.get_flag("show_version"));
+    /// ```
+    #[must_use]
+    pub fn with_flag_config(mut self, config: VersionFlagConfig) -> Self {
+        self.flag = config;
+        self
+    }
+
+    /// Hands `code` to the installed exit handler, or exits the process
+    fn exit(&self, code: i32) {
+        match &self.exit_handler {
+            Some(handler) => handler.exit(code),
+            None => ProcessExit.exit(code),
+        }
+    }
+
+    /// Prints the colorful version to stdout and exits the process
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use clap_version_flag::colorful_version;
+    ///
+    /// let version = colorful_version!();
+    /// version.print_and_exit(); // Prints and exits with code 0
+    /// ```
+    pub fn print_and_exit(&self) -> ! {
+        self.print();
+        self.exit(0);
+        process::exit(0);
+    }
+
+    /// Prints the colorful version to stdout
+    /// Format: "{package_name} v{version} by {author}"
+    ///
+    /// Metadata fields set through [`ColorfulVersionBuilder`] are printed as
+    /// "key: value" lines below the version line.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// version.print(); // Prints colored output
+    /// ```
+    pub fn print(&self) {
+        self.print_text(Verbosity::Normal, self.color_choice.unwrap_or_default());
+    }
+
+    /// Writes the plain version output, followed by a newline, to `w`
+    ///
+    /// Unlike [`ColorfulVersion::print`], the destination is up to the
+    /// caller: stderr, a log file or an in-memory buffer in tests.
+    ///
+    /// # Errors
+    /// Returns any I/O error reported by the writer
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let mut buffer = Vec::new();
+    /// version.write_to(&mut buffer).unwrap();
+    /// assert_eq!(buffer, b"myapp v1.0.0 by John Doe\n");
+    /// ```
+    pub fn write_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "{}", self.render_text(Verbosity::Normal, false))
+    }
+
+    /// Writes the colored version output, followed by a newline, to `w`
+    ///
+    /// Escape codes are always written, since only the caller knows whether
+    /// the destination is a terminal. Use [`ColorfulVersion::write_to`] for
+    /// destinations without color support.
+    ///
+    /// # Errors
+    /// Returns any I/O error reported by the writer
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// version.write_colored_to(&mut std::io::stderr()).unwrap();
+    /// ```
+    pub fn write_colored_to(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, "{}", self.render_text(Verbosity::Normal, true))
+    }
+
+    /// Returns a plain text version string (for clap's version flag)
+    /// Format: "{package_name} v{version} by {author}"
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn as_plain_string(&self) -> String {
+        let mut out = String::new();
+        // writing to a String cannot fail
+        let _ = self.write_line(&mut out, None);
+        out
+    }
+
+    /// Returns a colored version string if terminal supports colors
+    /// Format: "{package_name} v{version} by {author}"
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let colored = version.to_colored_string();
+    /// // colored string contains ANSI color codes
+    /// ```
+    #[must_use]
+    pub fn to_colored_string(&self) -> String {
+        let mut out = String::new();
+        self.render_into(&mut out);
+        out
+    }
+
+    /// Returns the color depth to render with, detecting it if not configured
+    fn depth(&self) -> ColorDepth {
+        self.color_depth.unwrap_or_else(ColorDepth::detect)
+    }
+
+    /// Renders the colored version line, regardless of terminal support
+    fn colored_line(&self, depth: ColorDepth) -> String {
+        let mut out = String::new();
+        let _ = self.write_line(&mut out, Some(depth));
+        out
+    }
+
+    /// Writes the version line to `out`, painted when `depth` is set
+    ///
+    /// The one formatter of the line. The standard shape is written piece
+    /// by piece; templates, field selections and rainbows are rendered
+    /// into a string first.
+    fn write_line(&self, out: &mut impl fmt::Write, depth: Option<ColorDepth>) -> fmt::Result {
+        if let (true, Some(depth)) = (self.rainbow, depth) {
+            return out.write_str(&self.rainbow_line(depth, 0.0));
+        }
+        if let Some(template) = &self.template {
+            return out.write_str(&self.render_template(template, depth));
+        }
+        if let Some(shown) = self.shown_segments() {
+            return out.write_str(&self.render_selected(&shown, depth));
+        }
+        let [name, version, author] = self.fitted_segments();
+        let Some(depth) = depth else {
+            return write!(out, "{name} {version} {}", self.attribution(&author));
+        };
+        self.write_name(out, &name, depth)?;
+        self.write_version(out, " ", &version, depth)?;
+        self.write_author(out, &author, depth)
+    }
+
+    /// Renders "name vX", painted when `depth` is set
+    fn brief_line(&self, depth: Option<ColorDepth>) -> String {
+        if let Some(mut shown) = self.shown_segments() {
+            shown.retain(|segment| segment != "author");
+            return self.render_selected(&shown, depth);
+        }
+        let [name, version, _] = self.fitted_segments();
+        let Some(depth) = depth else {
+            return format!("{name} {version}");
+        };
+        let mut out = String::new();
+        let _ = self.write_name(&mut out, &name, depth);
+        let _ = self.write_version(&mut out, " ", &version, depth);
+        out
+    }
+
+    /// Renders `{leading}v{version}`, splitting prefix and number when styled apart
+    fn colored_version(&self, leading: &str, depth: ColorDepth) -> String {
+        let [_, version, _] = self.fitted_segments();
+        let mut out = String::new();
+        let _ = self.write_version(&mut out, leading, &version, depth);
+        out
+    }
+
+    /// Writes `{leading}v{version}`, splitting prefix and number when styled apart
+    fn write_version(
+        &self,
+        out: &mut impl fmt::Write,
+        leading: &str,
+        fitted: &Fitted<'_>,
+        depth: ColorDepth,
+    ) -> fmt::Result {
+        let color = self.palette().version_color;
+        let prefix = self.labels().version_prefix.as_str();
+        let split = self.styles.prefix != self.styles.version || self.styles.has_components();
+        let Some(number) = fitted.unprefixed(prefix).filter(|_| split) else {
+            return self.write_segment(
+                out,
+                format_args!("{leading}{fitted}"),
+                &self.styles.version,
+                color,
+                self.version_background(),
+                depth,
+            );
+        };
+        self.write_segment(
+            out,
+            format_args!("{leading}{}{prefix}", fitted.left),
+            &self.styles.prefix,
+            color,
+            self.version_background(),
+            depth,
+        )?;
+        self.write_number(out, number, &fitted.right, depth)
+    }
+
+    /// Renders the version number followed by `padding`, painting each
+    /// semantic version component with its own style when one is set
+    pub(crate) fn colored_number(&self, number: &str, padding: &str, depth: ColorDepth) -> String {
+        let mut out = String::new();
+        let _ = self.write_number(&mut out, number, padding, depth);
+        out
+    }
+
+    /// Writes the output of [`colored_number`](Self::colored_number) to `out`
+    fn write_number(
+        &self,
+        out: &mut impl fmt::Write,
+        number: &str,
+        padding: &str,
+        depth: ColorDepth,
+    ) -> fmt::Result {
+        let color = self.palette().version_color;
+        let bg = self.version_background();
+        let version = self.styles.version;
+        let semver = self
+            .semver()
+            .filter(|_| self.styles.has_components() && number == self.version);
+        let Some(semver) = semver else {
+            return self.write_segment(
+                out,
+                format_args!("{number}{padding}"),
+                &version,
+                color,
+                bg,
+                depth,
+            );
+        };
+
+        let part = |style: Option<Style>| style.unwrap_or(version);
+        let mut pieces = vec![
+            (semver.major.to_string(), part(self.styles.major)),
+            (".".to_string(), version),
+            (semver.minor.to_string(), part(self.styles.minor)),
+            (".".to_string(), version),
+            (semver.patch.to_string(), part(self.styles.patch)),
+        ];
+        if semver.is_prerelease() {
+            pieces.push(("-".to_string(), version));
+            pieces.push((semver.pre, part(self.styles.pre_release)));
+        }
+        if !semver.build.is_empty() {
+            pieces.push(("+".to_string(), version));
+            pieces.push((semver.build, part(self.styles.build)));
+        }
+        if !padding.is_empty() {
+            pieces.push((padding.to_string(), version));
+        }
+        // neighbours sharing a style are painted as one run
+        let mut runs: Vec<(String, Style)> = Vec::new();
+        for (text, style) in pieces {
+            match runs.last_mut() {
+                Some((run, last)) if *last == style => run.push_str(&text),
+                _ => runs.push((text, style)),
+            }
+        }
+        runs.iter()
+            .try_for_each(|(text, style)| self.write_segment(out, text, style, color, bg, depth))
+    }
+
+    /// Renders the " by {author}" segment, linking the author when configured
+    fn colored_author(&self, depth: ColorDepth) -> String {
+        let [.., author] = self.fitted_segments();
+        let mut out = String::new();
+        let _ = self.write_author(&mut out, &author, depth);
+        out
+    }
+
+    /// Writes the " by {author}" segment, linking the author when configured
+    fn write_author(
+        &self,
+        out: &mut impl fmt::Write,
+        author: &Fitted<'_>,
+        depth: ColorDepth,
+    ) -> fmt::Result {
+        let style = &self.styles.author;
+        let color = self.palette().author_color;
+        let bg = self.author_background();
+        match self.link_target(self.author_link.as_deref()) {
+            Some(url) => {
+                self.write_segment(
+                    out,
+                    format_args!(" {}", self.attribution("")),
+                    style,
+                    color,
+                    bg,
+                    depth,
+                )?;
+                let linked = self.paint_segment(&author.padded(), style, color, bg, depth);
+                out.write_str(&color::hyperlink(&linked, url))
+            }
+            None => self.write_segment(
+                out,
+                format_args!(" {}", self.attribution(author)),
+                style,
+                color,
+                bg,
+                depth,
+            ),
+        }
+    }
+
+    /// Paints one segment, letting its [`Style`] override the default colors
+    fn paint_segment(
+        &self,
+        text: &str,
+        style: &Style,
+        fg: (u8, u8, u8),
+        bg: Option<(u8, u8, u8)>,
+        depth: ColorDepth,
+    ) -> String {
+        let mut out = String::new();
+        let _ = self.write_segment(&mut out, text, style, fg, bg, depth);
+        out
+    }
+
+    /// Writes one painted segment to `out`, see [`paint_segment`](Self::paint_segment)
+    fn write_segment(
+        &self,
+        out: &mut impl fmt::Write,
+        text: impl fmt::Display,
+        style: &Style,
+        fg: (u8, u8, u8),
+        bg: Option<(u8, u8, u8)>,
+        depth: ColorDepth,
+    ) -> fmt::Result {
+        color::write_painted(
+            out,
+            text,
+            Some(style.get_fg().unwrap_or(fg)),
+            style.get_bg().or(bg),
+            &style.sgr_attributes(),
+            depth,
+        )
+    }
+
+    /// Version of the JSON schema produced by [`ColorfulVersion::to_json`]
+    ///
+    /// The same number is used by every machine-readable output of this
+    /// crate, e.g. [`BuildInfo::to_json`].
+    pub const SCHEMA_VERSION: u32 = json::SCHEMA_VERSION;
+
+    /// Returns the version information as a single-line JSON object
+    ///
+    /// The object always holds `schema_version` (number), `name`, `version`,
+    /// `author` (strings) and `metadata` (an object of strings, possibly
+    /// empty). Within one [`SCHEMA_VERSION`](Self::SCHEMA_VERSION) keys are
+    /// only added, never renamed, removed or retyped, so consumers should
+    /// ignore keys they do not know. A
+    /// [field selection](Self::with_visible_fields) keeps just the selected
+    /// `metadata` keys. [Components](Self::with_components) add a
+    /// `components` object, with `null` for unavailable versions.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// assert_eq!(
+    ///     version.to_json(),
+    ///     r#"{"schema_version":1,"name":"myapp","version":"1.0.0","author":"John Doe","metadata":{}}"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        JsonRenderer.render(&self.json_info())
+    }
+
+    /// Renders the version information in the given format
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, VersionFormat};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// assert_eq!(version.render(VersionFormat::Plain), "myapp v1.0.0 by John Doe");
+    /// assert!(version.render(VersionFormat::Json).starts_with('{'));
+    /// ```
+    #[must_use]
+    pub fn render(&self, format: VersionFormat) -> String {
+        self.render_with_verbosity(format, Verbosity::Normal)
+    }
+
+    /// Renders the version information in the given format and verbosity
+    fn render_with_verbosity(&self, format: VersionFormat, verbosity: Verbosity) -> String {
+        match format {
+            VersionFormat::Json => self.to_json(),
+            VersionFormat::Plain => self.render_text(verbosity, false),
+            VersionFormat::Text => self.render_text(verbosity, self.colorize()),
+        }
+    }
+
+    /// Returns the colored name and the colored "v{version} by {author}" rest
+    ///
+    /// clap renders its native version output as "{display_name} {version}",
+    /// so the line is split where clap inserts the space.
+    fn native_version_parts(&self) -> (String, String) {
+        if !self.colorize() {
+            let [name, version, author] = self.fitted_segments();
+            return (
+                name.padded(),
+                format!("{} {}", version.padded(), self.attributed(&author.padded())),
+            );
+        }
+
+        let depth = self.depth();
+        let [name, ..] = self.fitted_segments();
+        let name = self.paint_name(&name.padded(), depth);
+        let rest = format!(
+            "{}{}",
+            self.colored_version("", depth),
+            self.colored_author(depth)
+        );
+        (name, rest)
+    }
+
+    /// Returns the lines rendered below the version line at `verbosity`
+    fn detail_fields(&self, verbosity: Verbosity) -> Vec<Field> {
+        if let Some(fields) = &self.presented_fields {
+            return fields
+                .iter()
+                .map(|field| Field {
+                    value: self.bidi(&field.value).into_owned().into(),
+                    ..field.clone()
+                })
+                .collect();
+        }
+        let mut fields: Vec<Field> = self
+            .metadata
+            .iter()
+            .filter(|field| field.level <= verbosity)
+            .map(|field| Field {
+                value: self.bidi(&field.value).into_owned().into(),
+                color: Some(
+                    field
+                        .color
+                        .unwrap_or_else(|| self.metadata_color(&field.key)),
+                ),
+                ..field.clone()
+            })
+            .collect();
+        fields.extend(self.entitlement_field());
+        if verbosity >= Verbosity::Verbose {
+            fields.extend(self.features_field());
+            if let Some(age) = self.build_age() {
+                fields.push(self.age_field(age));
+            }
+            fields.push(Field {
+                key: "installed via".into(),
+                value: self.install_method().to_string().into(),
+                color: None,
+                level: Verbosity::Verbose,
+            });
+            #[cfg(feature = "suite")]
+            fields.extend(self.plugin_fields());
+            #[cfg(feature = "sysinfo")]
+            fields.extend(self.system_fields());
+        }
+        if verbosity >= Verbosity::Diagnostic {
+            fields.extend(self.snapshot_fields());
+        }
+        fields
+    }
+
+    /// Returns the color of a metadata field without a color of its own
+    fn metadata_color(&self, key: &str) -> (u8, u8, u8) {
+        if license::LICENSE_KEYS.contains(&key) {
+            self.palette().license_color
+        } else {
+            self.palette().author_color
+        }
+    }
+
+    /// Renders the version line followed by its detail lines
+    ///
+    /// `colored` emits escape codes unconditionally; callers decide whether
+    /// the destination supports them.
+    fn render_text(&self, verbosity: Verbosity, colored: bool) -> String {
+        if colored {
+            AnsiRenderer::new().render_version(self, verbosity)
+        } else {
+            PlainRenderer::new().render_version(self, verbosity)
+        }
+    }
+
+    /// Renders the text output, painted when `depth` is set
+    ///
+    /// What the built-in renderers draw a version with.
+    pub(crate) fn render_at(&self, verbosity: Verbosity, depth: Option<ColorDepth>) -> String {
+        self.aligned(self.render_unaligned(verbosity, depth))
+    }
+
+    /// Renders the output of [`render_at`](Self::render_at) before alignment
+    fn render_unaligned(&self, verbosity: Verbosity, depth: Option<ColorDepth>) -> String {
+        let colored = depth.is_some();
+        if colored {
+            self.check_contrast();
+        }
+        if let Some(selection) = &self.visible_fields {
+            return self.render_selected(selection, depth);
+        }
+        if verbosity == Verbosity::Brief {
+            return self.brief_line(depth);
+        }
+        let mut body = self.render_body(verbosity, colored, depth);
+        if let Some(components) = self.render_components(depth) {
+            body.push('\n');
+            body.push_str(&components);
+        }
+        match self.render_logo(depth) {
+            Some(logo) => format!("{logo}\n{body}"),
+            None => body,
+        }
+    }
+
+    /// Renders the output below the logo
+    fn render_body(
+        &self,
+        verbosity: Verbosity,
+        colored: bool,
+        depth: Option<ColorDepth>,
+    ) -> String {
+        let fields = self.detail_fields(verbosity);
+        if let Some(style) = self.banner {
+            return self.render_banner(style, &fields, colored);
+        }
+        if verbosity >= Verbosity::Verbose && self.layout.leaders.is_some() {
+            return self.render_leaders(fields, colored);
+        }
+        let mut out = match depth {
+            Some(depth) => self.colored_line(depth),
+            None => self.as_plain_string(),
+        };
+        #[cfg(feature = "figlet")]
+        if let Some(font) = self.ascii_art {
+            out = self.render_ascii_art(font, depth);
+        }
+        self.push_author_lines(&mut out, depth);
+        for field in &fields {
+            out.push('\n');
+            out.push_str(&self.detail_line(field, depth));
+        }
+        out
+    }
+
+    /// Renders a "key: value" detail line, painted when `depth` is set
+    fn detail_line(&self, field: &Field, depth: Option<ColorDepth>) -> String {
+        let Some(depth) = depth else {
+            return format!("{}: {}", field.key, field.value);
+        };
+        format!(
+            "{} {}",
+            color::paint(
+                &format!("{}:", field.key),
+                self.palette().version_color,
+                None,
+                depth
+            ),
+            self.colored_field_value(field, depth)
+        )
+    }
+
+    /// Paints a field's value, linking the repository and author when configured
+    fn colored_field_value(&self, field: &Field, depth: ColorDepth) -> String {
+        let value = match field.color {
+            Some(rgb) => color::paint(&field.value, rgb, None, depth),
+            None if field.key == "features" => self.colored_features(depth),
+            None => field.value.to_string(),
+        };
+        let link = match field.key.as_ref() {
+            "repository" => self.repo_link.as_deref(),
+            "author" => self.author_link.as_deref(),
+            _ => None,
+        };
+        match self.link_target(link) {
+            Some(url) => color::hyperlink(&value, url),
+            None => value,
+        }
+    }
+
+    /// Returns the plain verbose version output (the `-VV` form)
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, InstallMethod};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_install_method(InstallMethod::Cargo);
+    /// assert_eq!(
+    ///     version.as_verbose_plain_string(),
+    ///     "myapp v1.0.0 by John Doe\ninstalled via: cargo install"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn as_verbose_plain_string(&self) -> String {
+        self.render_text(Verbosity::Verbose, false)
+    }
+
+    /// Returns the colored verbose version output (the `-VV` form)
     ///
     /// # Examples
-    /// ```no_run
-    /// use clap_version_flag::colorful_version;
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
     ///
-    /// let version = colorful_version!();
-    /// version.print_and_exit(); // Prints and exits with code 0
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let colored = version.to_verbose_colored_string();
+    /// assert!(colored.contains("installed via"));
     /// ```
-    pub fn print_and_exit(&self) -> ! {
-        self.print();
-        process::exit(0);
+    #[must_use]
+    pub fn to_verbose_colored_string(&self) -> String {
+        self.render_text(Verbosity::Verbose, self.colorize())
     }
 
-    /// Prints the colorful version to stdout
-    /// Format: "{package_name} v{version} by {author}"
+    /// Prints the colorful verbose version to stdout
     ///
     /// # Examples
     /// ```
     /// use clap_version_flag::ColorfulVersion;
     ///
     /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
-    /// version.print(); // Prints colored output
+    /// version.print_verbose(); // Prints the version line plus detail fields
     /// ```
-    pub fn print(&self) {
-        let name = self
-            .package_name
-            .truecolor(
-                self.colors.name_fg.0,
-                self.colors.name_fg.1,
-                self.colors.name_fg.2,
-            )
-            .on_truecolor(
-                self.colors.name_bg.0,
-                self.colors.name_bg.1,
-                self.colors.name_bg.2,
-            );
-
-        let version_text = format!(" v{}", self.version).truecolor(
-            self.colors.version_color.0,
-            self.colors.version_color.1,
-            self.colors.version_color.2,
-        );
-
-        let author_text = format!(" by {}", self.author).truecolor(
-            self.colors.author_color.0,
-            self.colors.author_color.1,
-            self.colors.author_color.2,
-        );
+    pub fn print_verbose(&self) {
+        self.detect_accessibility();
+        color_choice::print_line(&self.for_stdout().to_verbose_colored_string());
+    }
 
-        println!("{name}{version_text}{author_text}");
+    /// Prints the version at the given verbosity
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// version.print_with_verbosity(Verbosity::Verbose);
+    /// ```
+    pub fn print_with_verbosity(&self, verbosity: Verbosity) {
+        self.print_text(verbosity, self.color_choice.unwrap_or_default());
     }
 
-    /// Returns a plain text version string (for clap's version flag)
-    /// Format: "{package_name} v{version} by {author}"
+    /// Prints just "name vX" to stdout, colored when supported
     ///
     /// # Examples
     /// ```
-    /// use clap_version_flag::ColorfulVersion;
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
     ///
     /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
-    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0 by John Doe");
+    /// version.print_short();
+    /// assert_eq!(version.render_plain(Verbosity::Brief), "myapp v1.0.0");
     /// ```
-    #[must_use]
-    pub fn as_plain_string(&self) -> String {
-        format!("{} v{} by {}", self.package_name, self.version, self.author)
+    pub fn print_short(&self) {
+        self.print_text(Verbosity::Brief, self.color_choice.unwrap_or_default());
     }
 
-    /// Returns a colored version string if terminal supports colors
-    /// Format: "{package_name} v{version} by {author}"
+    /// Returns the plain output at the given verbosity
     ///
     /// # Examples
     /// ```
-    /// use clap_version_flag::ColorfulVersion;
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
     ///
     /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
-    /// let colored = version.to_colored_string();
-    /// // colored string contains ANSI color codes
+    /// assert_eq!(version.render_plain(Verbosity::Normal), "myapp v1.0.0 by John Doe");
     /// ```
     #[must_use]
-    pub fn to_colored_string(&self) -> String {
-        format!(
-            "{}{}{}",
-            self.package_name
-                .truecolor(
-                    self.colors.name_fg.0,
-                    self.colors.name_fg.1,
-                    self.colors.name_fg.2
-                )
-                .on_truecolor(
-                    self.colors.name_bg.0,
-                    self.colors.name_bg.1,
-                    self.colors.name_bg.2
-                ),
-            format!(" v{}", self.version).truecolor(
-                self.colors.version_color.0,
-                self.colors.version_color.1,
-                self.colors.version_color.2
-            ),
-            format!(" by {}", self.author).truecolor(
-                self.colors.author_color.0,
-                self.colors.author_color.1,
-                self.colors.author_color.2
-            )
-        )
+    pub fn render_plain(&self, verbosity: Verbosity) -> String {
+        self.render_text(verbosity, false)
     }
 
     /// Checks if the version flag was used and handles it
@@ -289,9 +1621,127 @@ impl ColorfulVersion {
     /// version.check_and_exit(&matches);
     /// ```
     pub fn check_and_exit(&self, matches: &ArgMatches) {
-        if matches.get_flag("clap_version_flag_version") {
-            self.print_and_exit();
+        if self.check(matches) {
+            self.exit(0);
+        }
+    }
+
+    /// Prints the version if the version flag was used, without exiting
+    ///
+    /// Returns `true` if the version was printed, so the caller can return
+    /// from `main` and let destructors run.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let matches = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .get_matches_from(["myapp"]);
+    ///
+    /// assert!(!version.check(&matches));
+    /// ```
+    #[must_use = "the program should stop when the version was printed"]
+    pub fn check(&self, matches: &ArgMatches) -> bool {
+        match self.expiry_checked(self.for_stdout().requested_output(matches)) {
+            Some(output) => {
+                color_choice::print_line(&output);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Renders the requested version output, if the version flag was used
+    fn requested_output(&self, matches: &ArgMatches) -> Option<String> {
+        if requested_flag(matches, BUG_REPORT_ARG_ID) {
+            return Some(self.bug_report());
         }
+        if requested_flag(matches, SCHEMA_ARG_ID) {
+            return Some(self.json_schema());
+        }
+        let verbosity = self.flag.requested_verbosity(matches)?;
+        self.detect_accessibility();
+        let format = requested_format(matches);
+        if format != VersionFormat::Json
+            && self.requested_print_mode(matches) == Some(PrintMode::Bare)
+        {
+            return Some(self.version.to_string());
+        }
+        let followed = self.following_choice(color_choice::requested_color(matches));
+        let themed =
+            requested_theme(matches).map(|theme| followed.clone().into_owned().with_theme(theme));
+        let version = themed.as_ref().unwrap_or(&followed);
+        let mut output = match requested_fields(matches) {
+            Some(fields) => version
+                .clone()
+                .with_visible_fields(fields)
+                .render_with_verbosity(format, verbosity),
+            None => version.render_with_verbosity(format, verbosity),
+        };
+        if format != VersionFormat::Json && requested_flag(matches, DEPS_ARG_ID) {
+            let colored = format == VersionFormat::Text && version.colorize();
+            output.push('\n');
+            output.push_str(&version.render_dependencies(colored.then(|| version.depth())));
+        }
+        #[cfg(feature = "update-check")]
+        let output = followed.with_update_notice(output, format);
+        Some(output)
+    }
+
+    /// Returns an update-available message tailored to the install method
+    ///
+    /// When the install method is known, the message recommends the matching
+    /// package-manager command instead of a generic self-update.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, InstallMethod};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let message = version.update_message("1.1.0", InstallMethod::Homebrew);
+    /// assert_eq!(
+    ///     message,
+    ///     "A new version of myapp is available: 1.0.0 -> 1.1.0\nUpgrade with: brew upgrade myapp"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn update_message(&self, latest: &str, method: InstallMethod) -> String {
+        let mut message = format!(
+            "A new version of {} is available: {} -> {}",
+            self.package_name, self.version, latest
+        );
+        if let Some(command) = method.upgrade_command(&self.package_name) {
+            message.push_str("\nUpgrade with: ");
+            message.push_str(&command);
+        }
+        message
+    }
+
+    /// Returns [`update_message`](Self::update_message) colored with the theme
+    ///
+    /// The notice uses the [`Role::Warning`] color and the upgrade command
+    /// the [`Role::Accent`] color. Falls back to the plain message when colors
+    /// are disabled.
+    #[must_use]
+    pub fn to_colored_update_message(&self, latest: &str, method: InstallMethod) -> String {
+        if !self.colorize() {
+            return self.update_message(latest, method);
+        }
+        let depth = self.depth();
+        let message = self.update_message(latest, method);
+        let (notice, command) = match message.split_once("\nUpgrade with: ") {
+            Some((notice, command)) => (notice, Some(command)),
+            None => (message.as_str(), None),
+        };
+        let mut out = color::paint(notice, self.role(Role::Warning), None, depth);
+        if let Some(command) = command {
+            out.push_str("\nUpgrade with: ");
+            out.push_str(&color::paint(command, self.role(Role::Accent), None, depth));
+        }
+        out
     }
 
     /// Returns the package name
@@ -322,6 +1772,68 @@ impl ColorfulVersion {
         &self.version
     }
 
+    /// Returns the value of a metadata field such as `"homepage"`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.0.0", "John Doe")
+    ///     .homepage("https://example.com")
+    ///     .build();
+    /// assert_eq!(version.metadata("homepage"), Some("https://example.com"));
+    /// assert_eq!(version.metadata("license"), None);
+    /// ```
+    #[must_use]
+    pub fn metadata(&self, key: &str) -> Option<&str> {
+        self.metadata
+            .iter()
+            .find(|field| field.key == key)
+            .map(|field| field.value.as_ref())
+    }
+
+    /// Returns the description set through the builder
+    #[must_use]
+    pub fn description(&self) -> Option<&str> {
+        self.metadata("description")
+    }
+
+    /// Returns the homepage set through the builder
+    #[must_use]
+    pub fn homepage(&self) -> Option<&str> {
+        self.metadata("homepage")
+    }
+
+    /// Returns the repository set through the builder
+    #[must_use]
+    pub fn repository(&self) -> Option<&str> {
+        self.metadata("repository")
+    }
+
+    /// Returns the license set through the builder
+    #[must_use]
+    pub fn license(&self) -> Option<&str> {
+        self.metadata("license")
+    }
+
+    /// Returns how the binary was installed
+    ///
+    /// Uses the value set with [`ColorfulVersion::with_install_method`] or a
+    /// packager hint, and falls back to [`InstallMethod::detect`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, InstallMethod};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_install_method(InstallMethod::Homebrew);
+    /// assert_eq!(version.install_method(), InstallMethod::Homebrew);
+    /// ```
+    #[must_use]
+    pub fn install_method(&self) -> InstallMethod {
+        self.install_method.unwrap_or_else(InstallMethod::detect)
+    }
+
     /// Returns the author
     ///
     /// # Examples
@@ -340,11 +1852,7 @@ impl ColorfulVersion {
 /// Implement Display trait for ColorfulVersion
 impl fmt::Display for ColorfulVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} v{} by {}",
-            self.package_name, self.version, self.author
-        )
+        f.write_str(&self.as_plain_string())
     }
 }
 
@@ -361,7 +1869,7 @@ impl fmt::Display for ColorfulVersion {
 /// # use clap_version_flag::ColorfulVersion;
 /// // This is an internal function, example shown for completeness
 /// let version = ColorfulVersion::new("app", "1.0", "author")
-///     .with_hex_colors("#FFF", "#000", "#F00", "#0F0")
+///     .with_colors("#FFF", "#000", "#F00", "#0F0")
 ///     .unwrap();
 /// ```
 fn parse_hex(hex: &str) -> Result<(u8, u8, u8), VersionError> {
@@ -394,9 +1902,319 @@ fn parse_hex(hex: &str) -> Result<(u8, u8, u8), VersionError> {
 // NOTE: Main macros are now in src/macros.rs and re-exported
 // This ensures they're available to users while keeping code organized
 
-/// Extension trait for clap::Command to add colorful version flag
-pub trait ColorfulVersionExt {
-    /// Adds a version flag that will display colorful output when used
+/// Extension trait for clap::Command to add colorful version flag
+pub trait ColorfulVersionExt {
+    /// Adds a version flag that will display colorful output when used
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp").with_colorful_version(&version);
+    /// ```
+    fn with_colorful_version(self, version: &ColorfulVersion) -> Self;
+
+    /// Like [`ColorfulVersionExt::with_colorful_version`], but checks for clashes first
+    ///
+    /// clap panics on duplicate flags when it builds the command. This
+    /// returns [`VersionError::FlagConflict`] instead if the command, or any
+    /// subcommand, already has an argument with the flag's id, short or long
+    /// name or alias, or if the flag would take over `-h`/`--help`. The
+    /// error names both sides and how to remap the flag.
+    ///
+    /// # Errors
+    /// Returns [`VersionError::FlagConflict`] for the first clash found
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::{Arg, ArgAction, Command};
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionError};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp").arg(Arg::new("verbose").short('V').action(ArgAction::SetTrue));
+    ///
+    /// let err = cmd.try_with_colorful_version(&version).unwrap_err();
+    /// assert!(matches!(&err, VersionError::FlagConflict { flag, .. } if flag == "-V"));
+    /// assert!(err.to_string().contains("'verbose'"));
+    /// ```
+    fn try_with_colorful_version(self, version: &ColorfulVersion) -> Result<Self, VersionError>
+    where
+        Self: Sized;
+
+    /// Adds flags with `add`, checking every argument it adds for clashes first
+    ///
+    /// The checked form of any combination of the extension methods: each
+    /// argument `add` registers, the version flag as well as the companion
+    /// flags and hidden options, is checked like
+    /// [`try_with_colorful_version`](Self::try_with_colorful_version) checks
+    /// the version flag, instead of ending in clap's duplicate-flag panic.
+    ///
+    /// # Errors
+    /// Returns [`VersionError::FlagConflict`] for the first clash found
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::{Arg, Command};
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionError};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp").arg(Arg::new("report").long("bug-report"));
+    ///
+    /// let err = cmd
+    ///     .try_with_version_flags(&version, |cmd| {
+    ///         cmd.with_colorful_version(&version).with_bug_report_flag()
+    ///     })
+    ///     .unwrap_err();
+    /// assert!(matches!(&err, VersionError::FlagConflict { flag, .. } if flag == "--bug-report"));
+    /// ```
+    fn try_with_version_flags(
+        self,
+        version: &ColorfulVersion,
+        add: impl FnOnce(Self) -> Self,
+    ) -> Result<Self, VersionError>
+    where
+        Self: Sized;
+
+    /// Adds a version flag that can be repeated for more detail
+    ///
+    /// `-V` prints the version line and `-VV` adds verbose details such as
+    /// the install method.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp").with_verbose_colorful_version(&version);
+    /// let matches = cmd.try_get_matches_from(["myapp", "-VV"]).unwrap();
+    /// assert_eq!(matches.get_count("clap_version_flag_version"), 2);
+    /// ```
+    fn with_verbose_colorful_version(self, version: &ColorfulVersion) -> Self;
+
+    /// Hands the pre-rendered colorful version to clap's native version flag
+    ///
+    /// Instead of disabling clap's `-V`/`--version` and checking a custom flag,
+    /// this sets [`Command::version`] (and, if the app has not set one, a
+    /// colored [`Command::display_name`]) so clap itself prints the colored
+    /// line. Required arguments, error messages and `--help` keep working
+    /// exactly as without this crate, and no `check_and_exit` call is needed.
+    /// clap strips the escape codes when its color choice disables color.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::{Arg, Command, error::ErrorKind};
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .arg(Arg::new("input").required(true))
+    ///     .with_native_colorful_version(&version);
+    ///
+    /// let err = cmd.try_get_matches_from(["myapp", "--version"]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::DisplayVersion);
+    /// ```
+    fn with_native_colorful_version(self, version: &ColorfulVersion) -> Self;
+
+    /// Adds a version flag with custom names, aliases and help
+    ///
+    /// Like [`ColorfulVersionExt::with_colorful_version`], but the flag is
+    /// built from `config` instead of the version's own
+    /// [`VersionFlagConfig`]. If `config` changes the arg id, set it on the
+    /// version as well with [`ColorfulVersion::with_flag_config`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::{Arg, ArgAction, Command};
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionFlagConfig};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .arg(Arg::new("verbose").short('V').action(ArgAction::SetTrue))
+    ///     .with_colorful_version_cfg(&version, &VersionFlagConfig::new().no_short());
+    ///
+    /// let matches = cmd.try_get_matches_from(["myapp", "-V"]).unwrap();
+    /// assert!(matches.get_flag("verbose"));
+    /// ```
+    fn with_colorful_version_cfg(
+        self,
+        version: &ColorfulVersion,
+        config: &VersionFlagConfig,
+    ) -> Self;
+
+    /// Adds a `--version-format <text|plain|json>` companion to the version flag
+    ///
+    /// The flag is only accepted together with `--version`, so
+    /// `myapp --version --version-format json` prints machine-readable output while
+    /// the app's own arguments are unaffected. Call this after
+    /// [`ColorfulVersionExt::with_colorful_version`] or
+    /// [`ColorfulVersionExt::with_verbose_colorful_version`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, VersionFormat};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .with_version_format_flag();
+    ///
+    /// let matches = cmd
+    ///     .try_get_matches_from(["myapp", "--version", "--version-format", "json"])
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     matches.get_one::<VersionFormat>("clap_version_flag_format"),
+    ///     Some(&VersionFormat::Json)
+    /// );
+    /// ```
+    fn with_version_format_flag(self) -> Self;
+
+    /// Adds a `--version-deps` companion flag listing the direct dependencies
+    ///
+    /// `myapp --version --version-deps` prints the table of
+    /// [`ColorfulVersion::print_dependencies`] below the version output; the
+    /// JSON format is left unchanged. Like
+    /// [`ColorfulVersionExt::with_version_format_flag`], call it after adding
+    /// the version flag.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_dependencies([("clap", "4.5.53")]);
+    /// let cmd = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .with_dependencies_flag();
+    ///
+    /// assert!(cmd.clone().try_get_matches_from(["myapp", "--version-deps"]).is_err());
+    /// let matches = cmd.try_get_matches_from(["myapp", "--version", "--version-deps"]).unwrap();
+    /// assert!(matches.get_flag("clap_version_flag_deps"));
+    /// ```
+    fn with_dependencies_flag(self) -> Self;
+
+    /// Adds a global `--version-fields <FIELDS>` option for `--version`
+    ///
+    /// The comma-separated names select exactly which fields the output
+    /// shows, as with [`ColorfulVersion::with_visible_fields`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .with_version_fields_flag();
+    ///
+    /// let matches = cmd
+    ///     .try_get_matches_from(["myapp", "--version", "--version-fields", "name,commit"])
+    ///     .unwrap();
+    /// let fields: Vec<&String> = matches.get_many("clap_version_flag_fields").unwrap().collect();
+    /// assert_eq!(fields, ["name", "commit"]);
+    /// ```
+    fn with_version_fields_flag(self) -> Self;
+
+    /// Adds a global `--version-theme <THEME>` option for `--version`
+    ///
+    /// Renders the version in one of the [built-in themes](Theme::built_in)
+    /// by name. Like `--version-format`, an unknown name is rejected by clap
+    /// with the closest valid name as a suggestion.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .with_version_theme_flag();
+    ///
+    /// let matches = cmd
+    ///     .clone()
+    ///     .try_get_matches_from(["myapp", "--version", "--version-theme", "high-contrast"])
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     matches.get_one::<String>("clap_version_flag_theme").map(String::as_str),
+    ///     Some("high-contrast")
+    /// );
+    ///
+    /// let error = cmd
+    ///     .try_get_matches_from(["myapp", "--version", "--version-theme", "clasic"])
+    ///     .unwrap_err();
+    /// assert!(error.to_string().contains("a similar value exists: 'classic'"));
+    /// ```
+    fn with_version_theme_flag(self) -> Self;
+
+    /// Adds a `--bug-report` flag printing [`ColorfulVersion::bug_report`]
+    ///
+    /// The flag stands on its own: `myapp --bug-report` prints the Markdown
+    /// diagnostic block (version, commit, OS, arch, rustc, features and the
+    /// relevant environment) through [`ColorfulVersion::check`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .with_bug_report_flag();
+    ///
+    /// let matches = cmd.try_get_matches_from(["myapp", "--bug-report"]).unwrap();
+    /// assert!(matches.get_flag("clap_version_flag_bug_report"));
+    /// ```
+    fn with_bug_report_flag(self) -> Self;
+
+    /// Adds a hidden `--version-schema` flag printing [`ColorfulVersion::json_schema`]
+    ///
+    /// Like `--bug-report`, the flag stands on its own and is answered by
+    /// [`ColorfulVersion::check`]; it is left out of `--help`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp")
+    ///     .with_colorful_version(&version)
+    ///     .with_version_schema_flag();
+    ///
+    /// let matches = cmd.try_get_matches_from(["myapp", "--version-schema"]).unwrap();
+    /// assert!(matches.get_flag("clap_version_flag_schema"));
+    /// ```
+    fn with_version_schema_flag(self) -> Self;
+
+    /// Adds a `version` subcommand with `--json`, `--short` and `--verbose`
+    ///
+    /// Answer it with [`ColorfulVersion::handle_version_subcommand`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let cmd = Command::new("myapp").with_version_subcommand(&version);
+    ///
+    /// let matches = cmd.try_get_matches_from(["myapp", "version", "--short"]).unwrap();
+    /// assert_eq!(matches.subcommand_name(), Some("version"));
+    /// ```
+    fn with_version_subcommand(self, version: &ColorfulVersion) -> Self;
+
+    /// Shows the colored version line at the top of `--help`
+    ///
+    /// The line is set as clap's `before_help` section, ahead of any
+    /// `before_help` text the app already has. clap strips the escape codes
+    /// when its color choice disables color.
     ///
     /// # Examples
     /// ```
@@ -404,23 +2222,219 @@ pub trait ColorfulVersionExt {
     /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
     ///
     /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
-    /// let cmd = Command::new("myapp").with_colorful_version(&version);
+    /// let mut cmd = Command::new("myapp").with_colorful_help_header(&version);
+    ///
+    /// let help = cmd.render_help().to_string();
+    /// assert!(help.starts_with("myapp v1.0.0 by John Doe"));
     /// ```
-    fn with_colorful_version(self, version: &ColorfulVersion) -> Self;
+    fn with_colorful_help_header(self, version: &ColorfulVersion) -> Self;
 }
 
 impl ColorfulVersionExt for Command {
-    fn with_colorful_version(self, _version: &ColorfulVersion) -> Self {
+    fn with_colorful_version(self, version: &ColorfulVersion) -> Self {
+        self.with_colorful_version_cfg(version, &version.flag)
+    }
+
+    fn try_with_colorful_version(self, version: &ColorfulVersion) -> Result<Self, VersionError> {
+        self.try_with_version_flags(version, |cmd| cmd.with_colorful_version(version))
+    }
+
+    fn try_with_version_flags(
+        self,
+        version: &ColorfulVersion,
+        add: impl FnOnce(Self) -> Self,
+    ) -> Result<Self, VersionError> {
+        let existing = self.get_arguments().count();
+        let extended = add(self.clone());
+        let added: Vec<&Arg> = extended.get_arguments().skip(existing).collect();
+        match version.flag.find_conflict(&self, &added) {
+            Some(conflict) => Err(conflict),
+            None => Ok(extended),
+        }
+    }
+
+    fn with_verbose_colorful_version(self, version: &ColorfulVersion) -> Self {
+        version.flag.register(
+            self.disable_version_flag(true),
+            ArgAction::Count,
+            "Print version information (repeat for more detail)",
+        )
+    }
+
+    fn with_colorful_version_cfg(
+        self,
+        _version: &ColorfulVersion,
+        config: &VersionFlagConfig,
+    ) -> Self {
         // Disable clap's built-in version flag and add our custom one
-        self.disable_version_flag(true).arg(
-            Arg::new("clap_version_flag_version")
-                .short('V')
-                .long("version")
+        config.register(
+            self.disable_version_flag(true),
+            ArgAction::SetTrue,
+            "Print version information",
+        )
+    }
+
+    fn with_native_colorful_version(self, version: &ColorfulVersion) -> Self {
+        let (name, rest) = version.native_version_parts();
+        let cmd = if self.get_display_name().is_none() {
+            self.display_name(name)
+        } else {
+            self
+        };
+        cmd.version(rest)
+    }
+
+    fn with_version_format_flag(self) -> Self {
+        let format = Arg::new(FORMAT_ARG_ID)
+            .long("version-format")
+            .value_name("FORMAT")
+            .value_parser(EnumValueParser::<VersionFormat>::new())
+            .help("Output format for --version")
+            .global(true);
+        let required = version_requirement(&self);
+        self.arg(match required {
+            Some(id) => format.requires(id),
+            None => format,
+        })
+    }
+
+    fn with_dependencies_flag(self) -> Self {
+        let deps = Arg::new(DEPS_ARG_ID)
+            .long("version-deps")
+            .action(ArgAction::SetTrue)
+            .help("List dependency versions with --version")
+            .global(true);
+        let required = version_requirement(&self);
+        self.arg(match required {
+            Some(id) => deps.requires(id),
+            None => deps,
+        })
+    }
+
+    fn with_version_theme_flag(self) -> Self {
+        let names = Theme::built_in().map(|(name, _)| name);
+        let theme = Arg::new(THEME_ARG_ID)
+            .long("version-theme")
+            .value_name("THEME")
+            .value_parser(clap::builder::PossibleValuesParser::new(names))
+            .help("Theme for --version")
+            .global(true);
+        let required = version_requirement(&self);
+        self.arg(match required {
+            Some(id) => theme.requires(id),
+            None => theme,
+        })
+    }
+
+    fn with_version_fields_flag(self) -> Self {
+        let fields = Arg::new(FIELDS_ARG_ID)
+            .long("version-fields")
+            .value_name("FIELDS")
+            .value_delimiter(',')
+            .help("Fields to show with --version, e.g. name,version,commit")
+            .global(true);
+        let required = version_requirement(&self);
+        self.arg(match required {
+            Some(id) => fields.requires(id),
+            None => fields,
+        })
+    }
+
+    fn with_bug_report_flag(self) -> Self {
+        self.arg(
+            Arg::new(BUG_REPORT_ARG_ID)
+                .long("bug-report")
+                .action(ArgAction::SetTrue)
+                .help("Print diagnostic information for bug reports")
+                .global(true),
+        )
+    }
+
+    fn with_version_schema_flag(self) -> Self {
+        self.arg(
+            Arg::new(SCHEMA_ARG_ID)
+                .long("version-schema")
                 .action(ArgAction::SetTrue)
-                .help("Print version information")
+                .help("Print the JSON Schema of the --version-format json output")
+                .hide(true)
                 .global(true),
         )
     }
+
+    fn with_version_subcommand(self, _version: &ColorfulVersion) -> Self {
+        self.subcommand(subcommand::version_subcommand())
+    }
+
+    fn with_colorful_help_header(self, version: &ColorfulVersion) -> Self {
+        let header = version.to_colored_string();
+        let before_help = match self.get_before_help() {
+            Some(existing) => format!("{header}\n\n{}", existing.ansi()),
+            None => header,
+        };
+        self.before_help(before_help)
+    }
+}
+
+/// Returns the verbosity requested by the version flag, if it was given
+///
+/// Handles both the plain (`SetTrue`) and the repeatable (`Count`) flag.
+fn requested_verbosity(matches: &ArgMatches, id: &str) -> Option<Verbosity> {
+    if let Ok(mode) = matches.try_get_one::<PrintMode>(id) {
+        return mode.map(|_| Verbosity::Normal);
+    }
+    match matches.try_get_one::<u8>(id) {
+        Ok(count) => Verbosity::from_occurrences(count.copied().unwrap_or(0)),
+        Err(_) => matches
+            .try_get_one::<bool>(id)
+            .ok()
+            .flatten()
+            .copied()
+            .unwrap_or(false)
+            .then_some(Verbosity::Normal),
+    }
+}
+
+/// Returns the version flag the companion flags require, if registered
+fn version_requirement(cmd: &Command) -> Option<String> {
+    VersionFlagConfig::requirement(cmd)
+}
+
+/// Returns whether the `SetTrue` flag `id` was given, if it is registered
+fn requested_flag(matches: &ArgMatches, id: &str) -> bool {
+    matches
+        .try_get_one::<bool>(id)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or(false)
+}
+
+/// Returns the fields selected with the `--version-fields` companion option
+fn requested_fields(matches: &ArgMatches) -> Option<Vec<String>> {
+    matches
+        .try_get_many::<String>(FIELDS_ARG_ID)
+        .ok()
+        .flatten()
+        .map(|fields| fields.cloned().collect())
+}
+
+/// Returns the theme requested with the `--version-theme` companion option
+fn requested_theme(matches: &ArgMatches) -> Option<Theme> {
+    matches
+        .try_get_one::<String>(THEME_ARG_ID)
+        .ok()
+        .flatten()
+        .and_then(|name| Theme::named(name))
+}
+
+/// Returns the format requested with the `--version-format` companion flag
+fn requested_format(matches: &ArgMatches) -> VersionFormat {
+    matches
+        .try_get_one::<VersionFormat>(FORMAT_ARG_ID)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or_default()
 }
 
 /// Helper function to parse command-line arguments with version handling
@@ -450,18 +2464,146 @@ pub fn parse_with_version<T: FromArgMatches>(
     command: Command,
     version: &ColorfulVersion,
 ) -> Result<T, clap::Error> {
-    let command = command.with_colorful_version(version);
+    let version = version.following(&command);
+    let command = command.with_verbose_colorful_version(&version);
     let matches = command.get_matches();
 
     // Check if version flag was used
-    if matches.get_flag("clap_version_flag_version") {
-        version.print();
+    if let Some(verbosity) = version.expiry_checked(version.flag.requested_verbosity(&matches)) {
+        version.print_with_verbosity(verbosity);
+        version.exit(0);
         process::exit(0);
     }
 
     T::from_arg_matches(&matches)
 }
 
+/// Like [`parse_with_version`], but parses the given arguments and never exits
+///
+/// A version request prints the version and returns `Ok(None)`; parse
+/// errors, including `--help`, are returned for the caller to report. The
+/// command can be parsed again and again, e.g. for each line of a REPL or
+/// in tests.
+///
+/// # Examples
+/// ```
+/// use clap::{CommandFactory, Parser};
+/// use clap_version_flag::{ColorfulVersion, parse_with_version_from};
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     input: Option<String>,
+/// }
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// for line in ["myapp file.txt", "myapp --version"] {
+///     let args = line.split_whitespace();
+///     match parse_with_version_from::<Cli, _, _>(Cli::command(), &version, args) {
+///         Ok(Some(cli)) => assert_eq!(cli.input.as_deref(), Some("file.txt")),
+///         Ok(None) => {} // the version was printed
+///         Err(err) => panic!("{err}"),
+///     }
+/// }
+/// ```
+pub fn parse_with_version_from<T, I, A>(
+    command: Command,
+    version: &ColorfulVersion,
+    args: I,
+) -> Result<Option<T>, clap::Error>
+where
+    T: FromArgMatches,
+    I: IntoIterator<Item = A>,
+    A: Into<OsString> + Clone,
+{
+    match try_parse_with_version_from(command, &version.for_stdout(), args)? {
+        VersionOutcome::Parsed(parsed) => Ok(Some(parsed)),
+        VersionOutcome::VersionRequested(output) => {
+            color_choice::print_line(&output);
+            Ok(None)
+        }
+    }
+}
+
+/// Parses command-line arguments with version handling, without exiting
+///
+/// Unlike [`parse_with_version`], neither a version request nor a parse
+/// error terminates the process: the rendered version is returned as
+/// [`VersionOutcome::VersionRequested`] and errors are returned as-is. An
+/// expired build set to [`ExpiryAction::Refuse`] still hands code 1 to the
+/// [`ExitHandler`].
+///
+/// # Examples
+/// ```no_run
+/// use clap::{CommandFactory, Parser};
+/// use clap_version_flag::{ColorfulVersion, VersionOutcome, try_parse_with_version};
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     input: Option<String>,
+/// }
+///
+/// fn main() -> Result<(), clap::Error> {
+///     let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+///     let cli: Cli = match try_parse_with_version(Cli::command(), &version)? {
+///         VersionOutcome::Parsed(cli) => cli,
+///         VersionOutcome::VersionRequested(output) => {
+///             println!("{output}");
+///             return Ok(());
+///         }
+///     };
+///
+///     // Normal program execution continues here
+///     Ok(())
+/// }
+/// ```
+pub fn try_parse_with_version<T: FromArgMatches>(
+    command: Command,
+    version: &ColorfulVersion,
+) -> Result<VersionOutcome<T>, clap::Error> {
+    try_parse_with_version_from(command, version, std::env::args_os())
+}
+
+/// Like [`try_parse_with_version`], but parses the given arguments
+///
+/// Never exits, so it can be called repeatedly on the same command.
+///
+/// # Examples
+/// ```
+/// use clap::{CommandFactory, Parser};
+/// use clap_version_flag::{ColorfulVersion, VersionOutcome, try_parse_with_version_from};
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     input: Option<String>,
+/// }
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// let outcome: VersionOutcome<Cli> =
+///     try_parse_with_version_from(Cli::command(), &version, ["myapp", "-V"]).unwrap();
+///
+/// assert!(matches!(outcome, VersionOutcome::VersionRequested(_)));
+/// ```
+pub fn try_parse_with_version_from<T, I, A>(
+    command: Command,
+    version: &ColorfulVersion,
+    args: I,
+) -> Result<VersionOutcome<T>, clap::Error>
+where
+    T: FromArgMatches,
+    I: IntoIterator<Item = A>,
+    A: Into<OsString> + Clone,
+{
+    let version = version.following(&command);
+    let matches = command
+        .with_verbose_colorful_version(&version)
+        .try_get_matches_from(args)?;
+
+    match version.expiry_checked(version.requested_output(&matches)) {
+        Some(output) => Ok(VersionOutcome::VersionRequested(output)),
+        None => T::from_arg_matches(&matches).map(VersionOutcome::Parsed),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -557,7 +2699,9 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_custom_colors_hex() {
+        // the deprecated shim keeps working until it is removed
         let version = ColorfulVersion::new("test", "1.0.0", "author")
             .with_hex_colors("#FF0000", "#00FF00", "#0000FF", "#FFFF00")
             .unwrap();
@@ -566,12 +2710,446 @@ mod tests {
         assert_eq!(version.colors.name_bg, (0, 255, 0));
         assert_eq!(version.colors.version_color, (0, 0, 255));
         assert_eq!(version.colors.author_color, (255, 255, 0));
+
+        // unlike `with_colors`, it takes hex only
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        assert!(
+            version
+                .clone()
+                .with_colors("red", "#000", "#000", "#000")
+                .is_ok()
+        );
+        assert!(matches!(
+            version.with_hex_colors("red", "#000", "#000", "#000"),
+            Err(VersionError::InvalidHexColor(_))
+        ));
+    }
+
+    #[test]
+    fn test_color_depth() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        assert_eq!(version.color_depth, None);
+
+        let version = version.with_color_depth(ColorDepth::Ansi16);
+        assert_eq!(version.color_depth, Some(ColorDepth::Ansi16));
+    }
+
+    #[test]
+    fn test_requested_verbosity() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+
+        let cmd = Command::new("app").with_colorful_version(&version);
+        let matches = cmd.clone().try_get_matches_from(["app", "-V"]).unwrap();
+        assert_eq!(
+            requested_verbosity(&matches, VERSION_FLAG_ID),
+            Some(Verbosity::Normal)
+        );
+        let matches = cmd.try_get_matches_from(["app"]).unwrap();
+        assert_eq!(requested_verbosity(&matches, VERSION_FLAG_ID), None);
+
+        let cmd = Command::new("app").with_verbose_colorful_version(&version);
+        let matches = cmd.clone().try_get_matches_from(["app", "-VV"]).unwrap();
+        assert_eq!(
+            requested_verbosity(&matches, VERSION_FLAG_ID),
+            Some(Verbosity::Verbose)
+        );
+        let matches = cmd.try_get_matches_from(["app"]).unwrap();
+        assert_eq!(requested_verbosity(&matches, VERSION_FLAG_ID), None);
+    }
+
+    #[test]
+    fn test_custom_flag_id() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author")
+            .with_flag_config(VersionFlagConfig::new().id("version").short('v'));
+        let cmd = Command::new("app").with_verbose_colorful_version(&version);
+
+        let matches = cmd.clone().try_get_matches_from(["app", "-vv"]).unwrap();
+        assert_eq!(matches.get_count("version"), 2);
+        assert!(version.requested_output(&matches).is_some());
+        let matches = cmd.try_get_matches_from(["app"]).unwrap();
+        assert_eq!(version.requested_output(&matches), None);
+    }
+
+    #[test]
+    fn test_help_header_keeps_existing_before_help() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        let mut cmd = Command::new("app")
+            .before_help("Existing notice")
+            .with_colorful_help_header(&version);
+
+        let help = cmd.render_help().to_string();
+        assert!(help.starts_with("test v1.0.0 by author\n\nExisting notice"));
+    }
+
+    #[test]
+    fn test_styled_segments() {
+        let version = ColorfulVersion::new("app", "1.2", "Jane")
+            .with_color_depth(ColorDepth::Ansi16)
+            .with_styles(
+                Styles::new()
+                    .prefix(Style::new().dimmed())
+                    .version(Style::new().bold().fg((255, 0, 0)))
+                    .author(Style::new().strikethrough()),
+            );
+        assert_eq!(
+            version.colored_line(ColorDepth::Ansi16),
+            "\x1b[97;45mapp\x1b[0m\x1b[2;93m v\x1b[0m\x1b[1;91m1.2\x1b[0m\x1b[9;96m by Jane\x1b[0m"
+        );
+
+        // Without styles the line is painted exactly as before
+        let plain = ColorfulVersion::new("app", "1.2", "Jane");
+        assert_eq!(
+            plain.colored_line(ColorDepth::Ansi16),
+            "\x1b[97;45mapp\x1b[0m\x1b[93m v1.2\x1b[0m\x1b[96m by Jane\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_format_flag() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag();
+
+        let matches = cmd
+            .clone()
+            .try_get_matches_from(["app", "-V", "--version-format", "plain"])
+            .unwrap();
+        assert_eq!(requested_format(&matches), VersionFormat::Plain);
+
+        let matches = cmd.clone().try_get_matches_from(["app", "-V"]).unwrap();
+        assert_eq!(requested_format(&matches), VersionFormat::Text);
+
+        // --version-format is meaningless without --version
+        assert!(
+            cmd.try_get_matches_from(["app", "--version-format", "json"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_deps_flag() {
+        let version =
+            ColorfulVersion::new("test", "1.0.0", "author").with_dependencies([("clap", "4.5.53")]);
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag()
+            .with_dependencies_flag();
+
+        let output = |args: &[&str]| {
+            let matches = cmd.clone().try_get_matches_from(args).unwrap();
+            version.requested_output(&matches)
+        };
+        assert_eq!(
+            output(&["app", "-V", "--version-deps", "--version-format", "plain"]).unwrap(),
+            "test v1.0.0 by author\ndependencies:\n  clap   4.5.53"
+        );
+        assert_eq!(
+            output(&["app", "-V", "--version-deps", "--version-format", "json"]),
+            Some(version.to_json())
+        );
+        assert_eq!(output(&["app"]), None);
+    }
+
+    #[test]
+    fn test_format_typo_suggests_value() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag();
+        let error = cmd
+            .try_get_matches_from(["app", "-V", "--version-format", "jsn"])
+            .unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::InvalidValue);
+        assert!(
+            error
+                .render()
+                .to_string()
+                .contains("a similar value exists: 'json'")
+        );
+    }
+
+    #[test]
+    fn test_version_fields_flag() {
+        let version = ColorfulVersion::builder("test", "1.0.0", "author")
+            .extra("commit", "abc1234")
+            .build();
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_format_flag()
+            .with_version_fields_flag();
+
+        let output = |args: &[&str]| {
+            let matches = cmd.clone().try_get_matches_from(args).unwrap();
+            version.requested_output(&matches)
+        };
+        assert_eq!(
+            output(&[
+                "app",
+                "-V",
+                "--version-fields",
+                "version,commit",
+                "--version-format",
+                "plain"
+            ])
+            .unwrap(),
+            "v1.0.0\ncommit: abc1234"
+        );
+        assert_eq!(
+            output(&[
+                "app",
+                "-V",
+                "--version-fields",
+                "commit",
+                "--version-format",
+                "json"
+            ])
+            .unwrap(),
+            r#"{"schema_version":1,"name":"test","version":"1.0.0","author":"author","metadata":{"commit":"abc1234"}}"#
+        );
+        assert!(
+            output(&[
+                "app",
+                "-V",
+                "--version-fields",
+                "name",
+                "--version-format",
+                "json"
+            ])
+            .unwrap()
+            .ends_with(r#""metadata":{}}"#)
+        );
+        assert!(
+            cmd.clone()
+                .try_get_matches_from(["app", "--version-fields", "name"])
+                .is_err()
+        );
+    }
+
+    #[cfg(not(feature = "no-color"))]
+    #[test]
+    fn test_version_theme_flag() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author")
+            .with_color_choice(clap::ColorChoice::Always)
+            .with_color_depth(ColorDepth::TrueColor);
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_version_theme_flag();
+
+        let output = |args: &[&str]| {
+            let matches = cmd.clone().try_get_matches_from(args).unwrap();
+            version.requested_output(&matches).unwrap()
+        };
+        assert!(output(&["app", "-V"]).starts_with("\x1b[38;2;255;255;255;48;2;170;0;255m"));
+        assert!(
+            output(&["app", "-V", "--version-theme", "high-contrast"])
+                .starts_with("\x1b[38;2;255;255;255;48;2;0;0;0m")
+        );
+        assert!(
+            cmd.clone()
+                .try_get_matches_from(["app", "--version-theme", "classic"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_bug_report_flag() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        let cmd = Command::new("app")
+            .with_colorful_version(&version)
+            .with_bug_report_flag()
+            .subcommand(Command::new("run"));
+
+        let output = |args: &[&str]| {
+            let matches = cmd.clone().try_get_matches_from(args).unwrap();
+            version.requested_output(&matches)
+        };
+        assert_eq!(output(&["app", "--bug-report"]), Some(version.bug_report()));
+        assert_eq!(
+            output(&["app", "run", "--bug-report"]),
+            Some(version.bug_report())
+        );
+        assert_eq!(output(&["app"]), None);
+    }
+
+    #[derive(Debug, clap::Parser)]
+    struct TestCli {
+        input: Option<String>,
+    }
+
+    #[test]
+    fn test_try_parse_with_version() {
+        use clap::CommandFactory;
+
+        let version = ColorfulVersion::new("test", "1.0.0", "author")
+            .with_install_method(InstallMethod::Cargo);
+
+        let outcome = try_parse_with_version_from::<TestCli, _, _>(
+            TestCli::command(),
+            &version,
+            ["app", "in"],
+        )
+        .unwrap();
+        let VersionOutcome::Parsed(cli) = outcome else {
+            panic!("expected parsed arguments");
+        };
+        assert_eq!(cli.input.as_deref(), Some("in"));
+
+        let outcome = try_parse_with_version_from::<TestCli, _, _>(
+            TestCli::command(),
+            &version,
+            ["app", "-VV"],
+        )
+        .unwrap();
+        assert!(matches!(
+            outcome,
+            VersionOutcome::VersionRequested(output) if output.ends_with("installed via: cargo install")
+        ));
+
+        let result = try_parse_with_version_from::<TestCli, _, _>(
+            TestCli::command(),
+            &version,
+            ["app", "--bogus"],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_exit_handler_intercepts_exit() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicI32, Ordering};
+
+        let code = Arc::new(AtomicI32::new(-1));
+        let seen = Arc::clone(&code);
+        let version = ColorfulVersion::new("test", "1.0.0", "author")
+            .with_exit_handler(Box::new(move |c| seen.store(c, Ordering::SeqCst)));
+        let cmd = Command::new("app").with_colorful_version(&version);
+
+        let matches = cmd.clone().try_get_matches_from(["app"]).unwrap();
+        version.check_and_exit(&matches);
+        assert_eq!(code.load(Ordering::SeqCst), -1);
+
+        let matches = cmd.try_get_matches_from(["app", "-V"]).unwrap();
+        version.clone().check_and_exit(&matches);
+        assert_eq!(code.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_check_without_flag() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author");
+        let matches = Command::new("app")
+            .with_colorful_version(&version)
+            .try_get_matches_from(["app"])
+            .unwrap();
+        assert!(!version.check(&matches));
+        assert_eq!(version.requested_output(&matches), None);
+    }
+
+    #[test]
+    fn test_json_escaping() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane \"JD\" Doe");
+        assert_eq!(
+            version.to_json(),
+            r#"{"schema_version":1,"name":"app","version":"1.0.0","author":"Jane \"JD\" Doe","metadata":{}}"#
+        );
+    }
+
+    #[test]
+    fn test_metadata_rendering() {
+        let version = ColorfulVersion::builder("myapp", "1.0.0", "Jane")
+            .description("A tool")
+            .extra("channel", "beta")
+            .build()
+            .with_install_method(InstallMethod::Cargo);
+
+        assert_eq!(
+            version.render(VersionFormat::Plain),
+            "myapp v1.0.0 by Jane\ndescription: A tool\nchannel: beta"
+        );
+        assert_eq!(
+            version.render_with_verbosity(VersionFormat::Plain, Verbosity::Verbose),
+            "myapp v1.0.0 by Jane\ndescription: A tool\nchannel: beta\ninstalled via: cargo install"
+        );
+        assert_eq!(
+            version.to_json(),
+            r#"{"schema_version":1,"name":"myapp","version":"1.0.0","author":"Jane","metadata":{"description":"A tool","channel":"beta"}}"#
+        );
+        // The single version line is unaffected by metadata
+        assert_eq!(version.as_plain_string(), "myapp v1.0.0 by Jane");
+    }
+
+    #[test]
+    fn test_write_to_sinks() {
+        let version = ColorfulVersion::builder("myapp", "1.0.0", "Jane")
+            .license("MIT")
+            .build()
+            .with_color_depth(ColorDepth::TrueColor);
+
+        let mut plain = Vec::new();
+        version.write_to(&mut plain).unwrap();
+        assert_eq!(
+            String::from_utf8(plain).unwrap(),
+            "myapp v1.0.0 by Jane\nlicense: MIT\n"
+        );
+
+        let mut colored = Vec::new();
+        version.write_colored_to(&mut colored).unwrap();
+        let colored = String::from_utf8(colored).unwrap();
+        assert!(colored.starts_with("\x1b[38;2;255;255;255;48;2;170;0;255mmyapp\x1b[0m"));
+        assert!(colored.ends_with("MIT\x1b[0m\n"));
+    }
+
+    #[test]
+    fn test_build_info_fields_are_verbose() {
+        const INFO: BuildInfo = BuildInfo::new("myapp", "1.0.0", "Jane")
+            .with_license("MIT")
+            .with_commit(Some("abc1234"))
+            .with_target(Some("x86_64-unknown-linux-gnu"));
+        let version =
+            ColorfulVersion::from_build_info(&INFO).with_install_method(InstallMethod::Cargo);
+
+        assert_eq!(version.render(VersionFormat::Plain), "myapp v1.0.0 by Jane");
+        assert_eq!(
+            version.as_verbose_plain_string(),
+            "myapp v1.0.0 by Jane\nlicense: MIT\ncommit: abc1234\ntarget: x86_64-unknown-linux-gnu\nbuild-id: 539e66e\ninstalled via: cargo install"
+        );
+        assert_eq!(version.metadata("build-id"), Some("539e66e"));
+    }
+
+    #[test]
+    fn test_install_method_hint() {
+        let version = ColorfulVersion::new("test", "1.0.0", "author")
+            .with_install_method(InstallMethod::Cargo)
+            .with_install_method_hint(Some("not-a-packager"));
+        assert_eq!(version.install_method(), InstallMethod::Cargo);
+
+        let version = version.with_install_method_hint(Some("homebrew"));
+        assert_eq!(version.install_method(), InstallMethod::Homebrew);
+        assert!(
+            version
+                .as_verbose_plain_string()
+                .ends_with("installed via: homebrew")
+        );
+    }
+
+    #[test]
+    fn test_update_message() {
+        let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+
+        let cargo = version.update_message("2.0.0", InstallMethod::Cargo);
+        assert!(cargo.ends_with("Upgrade with: cargo install myapp"));
+
+        let unknown = version.update_message("2.0.0", InstallMethod::Unknown);
+        assert_eq!(
+            unknown,
+            "A new version of myapp is available: 1.0.0 -> 2.0.0"
+        );
     }
 
     #[test]
     fn test_short_hex() {
         let version = ColorfulVersion::new("test", "1.0.0", "author")
-            .with_hex_colors("#F00", "#0F0", "#00F", "#FF0")
+            .with_colors("#F00", "#0F0", "#00F", "#FF0")
             .unwrap();
 
         assert_eq!(version.colors.name_fg, (255, 0, 0));
@@ -579,4 +3157,24 @@ mod tests {
         assert_eq!(version.colors.version_color, (0, 0, 255));
         assert_eq!(version.colors.author_color, (255, 255, 0));
     }
+
+    #[test]
+    fn test_from_static_matches_new() {
+        static VERSION: ColorfulVersion =
+            ColorfulVersion::from_static("app", "1.0.0", "Jane <jane@example.com>");
+        let runtime = ColorfulVersion::new("app", "1.0.0", "Jane <jane@example.com>");
+        assert_eq!(VERSION.as_plain_string(), runtime.as_plain_string());
+        assert_eq!(VERSION.to_colored_string(), runtime.to_colored_string());
+        assert_eq!(VERSION.authors(), runtime.authors());
+        assert_eq!(VERSION.labels(), runtime.labels());
+        let hidden = VERSION.clone().with_author_emails(false);
+        assert_eq!(hidden.author(), "Jane");
+
+        // borrowed strings are copied, so they need not outlive the version
+        let name = String::from("app");
+        assert_eq!(
+            ColorfulVersion::new(&name, "1.0.0", "Jane").package_name(),
+            "app"
+        );
+    }
 }
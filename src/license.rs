@@ -0,0 +1,161 @@
+// Project: clap-version-flag
+// File: src/license.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: License and copyright lines below the version line
+// License: MIT
+
+//! License and copyright lines.
+//!
+//! [`ColorfulVersion::with_license`] and [`ColorfulVersion::with_copyright`]
+//! add the lines most `--version` outputs of GNU-style tools carry:
+//!
+//! ```text
+//! myapp v1.0.0 by Acme
+//! license: MIT
+//! copyright: © 2025 Acme Inc.
+//! ```
+//!
+//! [`colorful_version!`](crate::colorful_version) fills the license from
+//! `CARGO_PKG_LICENSE`. Both lines are painted in their own color slot, set
+//! with [`ColorfulVersion::with_license_color`].
+
+use std::borrow::Cow;
+
+use crate::{ColorfulVersion, Field, Verbosity};
+
+/// Metadata keys painted with the license color
+pub(crate) const LICENSE_KEYS: [&str; 2] = ["license", "copyright"];
+
+/// Returns the license line of `license`, none when it is empty
+///
+/// Parses the `CARGO_PKG_LICENSE` of [`colorful_version!`](crate::colorful_version)
+/// on first use, like [`ColorfulVersion::with_license`] would.
+pub(crate) fn license_line(license: &'static str) -> Vec<Field> {
+    if license.is_empty() {
+        return Vec::new();
+    }
+    vec![Field {
+        key: Cow::Borrowed("license"),
+        value: Cow::Borrowed(license),
+        color: None,
+        level: Verbosity::Normal,
+    }]
+}
+
+impl ColorfulVersion {
+    /// Sets the license expression shown below the version line
+    ///
+    /// An empty string removes the line, so an unset `CARGO_PKG_LICENSE`
+    /// adds nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "Acme")
+    ///     .with_license("MIT")
+    ///     .with_copyright("© 2025 Acme Inc.");
+    /// assert_eq!(
+    ///     version.render_plain(Verbosity::Normal),
+    ///     "myapp v1.0.0 by Acme\nlicense: MIT\ncopyright: © 2025 Acme Inc."
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_license(self, license: impl Into<String>) -> Self {
+        self.with_normal_field("license", Cow::Owned(license.into()))
+    }
+
+    /// Sets the copyright notice shown below the version line
+    ///
+    /// An empty string removes the line.
+    #[must_use]
+    pub fn with_copyright(self, copyright: impl Into<String>) -> Self {
+        self.with_normal_field("copyright", Cow::Owned(copyright.into()))
+    }
+
+    /// Returns the copyright notice, if set
+    #[must_use]
+    pub fn copyright(&self) -> Option<&str> {
+        self.metadata("copyright")
+    }
+
+    /// Sets the color of the license and copyright lines
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "Acme")
+    ///     .with_license("MIT")
+    ///     .with_license_color((128, 128, 128));
+    /// ```
+    #[must_use]
+    pub fn with_license_color(mut self, rgb: (u8, u8, u8)) -> Self {
+        self.colors.license_color = rgb;
+        self
+    }
+
+    /// Replaces the metadata field `key` in place, or appends it
+    fn with_normal_field(mut self, key: &'static str, value: Cow<'static, str>) -> Self {
+        let position = self.metadata.iter().position(|field| field.key == key);
+        if value.is_empty() {
+            if let Some(index) = position {
+                self.metadata.remove(index);
+            }
+            return self;
+        }
+        let field = Field {
+            key: key.into(),
+            value,
+            color: None,
+            level: Verbosity::Normal,
+        };
+        match position {
+            Some(index) => self.metadata[index] = field,
+            None => self.metadata.push(field),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorDepth;
+
+    #[test]
+    fn test_replaces_builder_license() {
+        let version = ColorfulVersion::builder("app", "1.0", "Jo")
+            .license("MIT")
+            .description("A tool")
+            .build()
+            .with_license("Apache-2.0");
+        assert_eq!(version.license(), Some("Apache-2.0"));
+        assert_eq!(
+            version.render_plain(Verbosity::Normal),
+            "app v1.0 by Jo\nlicense: Apache-2.0\ndescription: A tool"
+        );
+        assert_eq!(version.with_license("").license(), None);
+    }
+
+    #[test]
+    fn test_license_color_slot() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_copyright("© Jo")
+            .with_license_color((1, 2, 3));
+        let fields = version.detail_fields(Verbosity::Normal);
+        assert_eq!(fields[0].color, Some((1, 2, 3)));
+        assert!(
+            version
+                .detail_line(&fields[0], Some(ColorDepth::TrueColor))
+                .ends_with("\x1b[38;2;1;2;3m© Jo\x1b[0m")
+        );
+    }
+
+    #[test]
+    fn test_macro_reads_cargo_license() {
+        let version = crate::colorful_version!();
+        assert_eq!(version.license(), Some(env!("CARGO_PKG_LICENSE")));
+    }
+}
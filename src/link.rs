@@ -0,0 +1,115 @@
+// Project: clap-version-flag
+// File: src/link.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: OSC 8 terminal hyperlinks for the author and repository
+// License: MIT
+
+//! Terminal hyperlinks.
+//!
+//! Terminals such as iTerm2, kitty, WezTerm and recent gnome-terminal make
+//! text wrapped in OSC 8 escape sequences clickable. The author and the
+//! repository can link to a URL; other terminals and plain output show the
+//! text only.
+
+use crate::{ColorfulVersion, Field, Verbosity, color};
+
+impl ColorfulVersion {
+    /// Links the author segment to `url` (e.g. a profile or `mailto:` URL)
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorDepth, ColorfulVersion};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_author_link("https://github.com/johndoe")
+    ///     .with_hyperlinks(true)
+    ///     .with_color_depth(ColorDepth::TrueColor);
+    /// let mut out = Vec::new();
+    /// version.write_colored_to(&mut out).unwrap();
+    ///
+    /// assert!(String::from_utf8(out).unwrap().contains("\x1b]8;;https://github.com/johndoe\x1b\\"));
+    /// ```
+    #[must_use]
+    pub fn with_author_link(mut self, url: impl Into<String>) -> Self {
+        self.author_link = Some(url.into());
+        self
+    }
+
+    /// Links the repository line to `url`
+    ///
+    /// Adds a `repository` line showing `url` when none was set through the
+    /// builder; an existing line keeps its text and becomes the link.
+    #[must_use]
+    pub fn with_repo_link(mut self, url: impl Into<String>) -> Self {
+        let url = url.into();
+        if self.repository().is_none() {
+            self.metadata.push(Field {
+                key: "repository".into(),
+                value: url.clone().into(),
+                color: None,
+                level: Verbosity::Normal,
+            });
+        }
+        self.repo_link = Some(url);
+        self
+    }
+
+    /// Forces hyperlinks on or off instead of detecting terminal support
+    #[must_use]
+    pub fn with_hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = Some(enabled);
+        self
+    }
+
+    /// Returns the link target of a colored segment, if links are enabled
+    pub(crate) fn link_target<'a>(&self, url: Option<&'a str>) -> Option<&'a str> {
+        url.filter(|_| self.hyperlinks.unwrap_or_else(color::supports_hyperlinks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorDepth, ColorfulVersion};
+
+    #[test]
+    fn test_author_link() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_color_depth(ColorDepth::Ansi16)
+            .with_author_link("mailto:jane@example.com")
+            .with_hyperlinks(true);
+        assert!(version.colored_line(ColorDepth::Ansi16).ends_with(
+            "\x1b[96m by \x1b[0m\x1b]8;;mailto:jane@example.com\x1b\\\x1b[96mJane\x1b[0m\x1b]8;;\x1b\\"
+        ));
+
+        let unlinked = version.with_hyperlinks(false);
+        assert!(
+            unlinked
+                .colored_line(ColorDepth::Ansi16)
+                .ends_with("\x1b[96m by Jane\x1b[0m")
+        );
+    }
+
+    #[test]
+    fn test_repo_link() {
+        let version = ColorfulVersion::builder("app", "1.0.0", "Jane")
+            .repository("github.com/jane/app")
+            .build()
+            .with_repo_link("https://github.com/jane/app")
+            .with_hyperlinks(true);
+        assert_eq!(version.repository(), Some("github.com/jane/app"));
+        assert!(
+            version
+                .render_text(crate::Verbosity::Normal, true)
+                .contains("\x1b]8;;https://github.com/jane/app\x1b\\")
+        );
+        // Plain output never contains escape sequences
+        assert_eq!(
+            version.as_verbose_plain_string().lines().nth(1),
+            Some("repository: github.com/jane/app")
+        );
+
+        let added = ColorfulVersion::new("app", "1.0.0", "Jane").with_repo_link("https://x.dev");
+        assert_eq!(added.repository(), Some("https://x.dev"));
+    }
+}
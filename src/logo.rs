@@ -0,0 +1,196 @@
+// Project: clap-version-flag
+// File: src/logo.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Custom logos printed above the version line
+// License: MIT
+
+//! Custom logos.
+//!
+//! [`ColorfulVersion::with_logo`] prints hand-drawn ASCII or ANSI art above
+//! the version output, typically embedded with `include_str!`. Inside the
+//! logo, `{name}`, `{version}`, `{author}` and the keys of the builder
+//! metadata (`{license}`, `{homepage}`, ...) are replaced by their values;
+//! unknown placeholders are kept verbatim.
+//!
+//! When colored, each line is painted with the next color of the cycle set
+//! by [`ColorfulVersion::with_logo_colors`], by default the
+//! [`Role::Primary`], [`Role::Secondary`] and [`Role::Accent`] colors. Lines
+//! the logo already colors with its own escape codes are left untouched, and
+//! plain output strips those codes.
+
+use crate::{ColorDepth, ColorfulVersion, Role, color, color::Rgb};
+
+/// A logo and the colors its lines cycle through
+#[derive(Clone, Debug)]
+pub(crate) struct Logo {
+    art: String,
+    colors: Vec<Rgb>,
+}
+
+impl ColorfulVersion {
+    /// Prints `logo` above the version output
+    ///
+    /// Applies to the text output of the version flag and
+    /// [`print`](Self::print); [`as_plain_string`](Self::as_plain_string)
+    /// keeps the single line clap shows.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, VersionFormat};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_logo("  /\\  \n /  \\ {name}\n/____\\ {version}\n");
+    /// assert_eq!(
+    ///     version.render(VersionFormat::Plain),
+    ///     "  /\\\n /  \\ myapp\n/____\\ 1.0.0\nmyapp v1.0.0 by John Doe"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_logo(mut self, logo: impl Into<String>) -> Self {
+        let colors = self.logo.take().map(|logo| logo.colors).unwrap_or_default();
+        self.logo = Some(Logo {
+            art: logo.into(),
+            colors,
+        });
+        self
+    }
+
+    /// Sets the colors the logo lines cycle through
+    ///
+    /// The first line gets the first color, the second line the second,
+    /// starting over after the last. An empty cycle restores the default.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_logo("##\n## {version}\n##")
+    ///     .with_logo_colors([(255, 0, 0), (255, 128, 0), (255, 255, 0)]);
+    /// assert_eq!(version.logo_text().unwrap(), "##\n## 1.0.0\n##");
+    /// ```
+    #[must_use]
+    pub fn with_logo_colors(mut self, colors: impl IntoIterator<Item = Rgb>) -> Self {
+        let logo = self.logo.get_or_insert_with(|| Logo {
+            art: String::new(),
+            colors: Vec::new(),
+        });
+        logo.colors = colors.into_iter().collect();
+        self
+    }
+
+    /// Returns the logo with its placeholders substituted, uncolored
+    #[must_use]
+    pub fn logo_text(&self) -> Option<String> {
+        let logo = self.logo.as_ref().filter(|logo| !logo.art.is_empty())?;
+        let lines: Vec<String> = self
+            .substitute(&logo.art)
+            .lines()
+            .map(|line| color::strip_escapes(line).trim_end().to_string())
+            .collect();
+        Some(lines.join("\n"))
+    }
+
+    /// Renders the logo lines, painted when `depth` is set
+    pub(crate) fn render_logo(&self, depth: Option<ColorDepth>) -> Option<String> {
+        let Some(depth) = depth else {
+            return self.logo_text();
+        };
+        let logo = self.logo.as_ref().filter(|logo| !logo.art.is_empty())?;
+        let defaults = [Role::Primary, Role::Secondary, Role::Accent].map(|role| self.role(role));
+        let colors = if logo.colors.is_empty() {
+            &defaults[..]
+        } else {
+            &logo.colors[..]
+        };
+
+        let art = self.substitute(&logo.art);
+        let lines: Vec<String> = art
+            .lines()
+            .zip(colors.iter().cycle())
+            .map(|(line, &rgb)| {
+                let line = line.trim_end();
+                if line.contains('\x1b') || line.is_empty() {
+                    line.to_string()
+                } else {
+                    color::paint(line, rgb, None, depth)
+                }
+            })
+            .collect();
+        Some(lines.join("\n"))
+    }
+
+    /// Replaces the `{key}` placeholders of `art`
+    fn substitute(&self, art: &str) -> String {
+        let mut out = String::with_capacity(art.len());
+        let mut rest = art;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let Some(end) = rest.find('}') else {
+                break;
+            };
+            let value = match &rest[1..end] {
+                "name" => Some(self.package_name.as_ref()),
+                "version" => Some(self.version.as_ref()),
+                "author" => Some(self.author.as_ref()),
+                key => self.metadata(key),
+            };
+            match value {
+                Some(value) => out.push_str(value),
+                None => out.push_str(&rest[..=end]),
+            }
+            rest = &rest[end + 1..];
+        }
+        out.push_str(rest);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substitution() {
+        let version = ColorfulVersion::builder("app", "1.0", "Jo")
+            .license("MIT")
+            .build()
+            .with_logo("{name}-{license} {unknown} {version");
+        assert_eq!(version.logo_text().unwrap(), "app-MIT {unknown} {version");
+    }
+
+    #[test]
+    fn test_color_cycle() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_logo("a\nb\n\nc")
+            .with_logo_colors([(1, 2, 3), (4, 5, 6)]);
+        assert_eq!(
+            version.render_logo(Some(ColorDepth::TrueColor)).unwrap(),
+            "\x1b[38;2;1;2;3ma\x1b[0m\n\x1b[38;2;4;5;6mb\x1b[0m\n\n\x1b[38;2;4;5;6mc\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_ansi_art_kept_or_stripped() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_logo("\x1b[31m##\x1b[0m \x1b]8;;https://x\x1b\\link\x1b]8;;\x1b\\");
+        assert_eq!(
+            version.render_logo(Some(ColorDepth::TrueColor)).unwrap(),
+            "\x1b[31m##\x1b[0m \x1b]8;;https://x\x1b\\link\x1b]8;;\x1b\\"
+        );
+        assert_eq!(version.logo_text().unwrap(), "## link");
+    }
+
+    #[test]
+    fn test_colors_without_logo() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_logo_colors([(1, 2, 3)]);
+        assert_eq!(version.logo_text(), None);
+        let version = version.with_logo("x");
+        assert_eq!(
+            version.render_logo(Some(ColorDepth::TrueColor)).unwrap(),
+            "\x1b[38;2;1;2;3mx\x1b[0m"
+        );
+    }
+}
@@ -26,26 +26,63 @@
 /// // With custom hex colors
 /// let version = colorful_version!("#FFFFFF", "#AA00FF", "#FFFF00", "#00FFFF");
 /// ```
+///
+/// Colors given as string literals are validated at compile time:
+/// ```compile_fail
+/// use clap_version_flag::colorful_version;
+///
+/// let version = colorful_version!("#GGG", "#AA00FF", "#FFFF00", "#00FFFF");
+/// ```
+///
+/// Colors computed at runtime are validated when the macro runs and panic if
+/// invalid.
+///
+/// Without colors the macro is a constant expression, so the version can be
+/// a `static` with no lazy initialization. The author list and the license
+/// are parsed when first used:
+/// ```
+/// use clap_version_flag::{ColorfulVersion, colorful_version};
+///
+/// static VERSION: ColorfulVersion = colorful_version!();
+///
+/// assert_eq!(VERSION.as_plain_string(), colorful_version!().as_plain_string());
+/// ```
 #[macro_export]
 macro_rules! colorful_version {
     () => {
         // env!() expands at the CALLER's location, not here!
         // This means it will read from the caller's Cargo.toml
-        $crate::ColorfulVersion::new(
+        $crate::ColorfulVersion::from_cargo(
             env!("CARGO_PKG_NAME"),
             env!("CARGO_PKG_VERSION"),
             env!("CARGO_PKG_AUTHORS"),
+            const {
+                const AUTHORS: &str = env!("CARGO_PKG_AUTHORS");
+                const SHOWN: [u8; $crate::macros::cargo_authors_len(AUTHORS)] =
+                    $crate::macros::cargo_authors(AUTHORS);
+                match ::core::str::from_utf8(&SHOWN) {
+                    Ok(author) => author,
+                    Err(_) => AUTHORS,
+                }
+            },
+            env!("CARGO_PKG_LICENSE"),
+            option_env!("CLAP_VERSION_FLAG_INSTALL_METHOD"),
         )
     };
 
-    ($name_fg:expr, $name_bg:expr, $version:expr, $author:expr) => {
-        $crate::ColorfulVersion::new(
-            env!("CARGO_PKG_NAME"),
-            env!("CARGO_PKG_VERSION"),
-            env!("CARGO_PKG_AUTHORS"),
+    ($name_fg:literal, $name_bg:literal, $version:literal, $author:literal) => {
+        $crate::colorful_version!().with_rgb_colors(
+            $crate::hex!($name_fg),
+            $crate::hex!($name_bg),
+            $crate::hex!($version),
+            $crate::hex!($author),
         )
-        .with_hex_colors($name_fg, $name_bg, $version, $author)
-        .unwrap_or_else(|e| panic!("clap-version-flag: Invalid hex color format: {}", e))
+    };
+
+    ($name_fg:expr, $name_bg:expr, $version:expr, $author:expr) => {
+        $crate::colorful_version!()
+            .with_colors($name_fg, $name_bg, $version, $author)
+            .unwrap_or_else(|e| panic!("clap-version-flag: Invalid hex color format: {}", e))
     };
 }
 
@@ -76,10 +113,20 @@ macro_rules! colorful_version_full {
         $crate::ColorfulVersion::new($name, $version, $author)
     };
 
+    ($name:expr, $version:expr, $author:expr,
+     $name_fg:literal, $name_bg:literal, $version_color:literal, $author_color:literal) => {
+        $crate::ColorfulVersion::new($name, $version, $author).with_rgb_colors(
+            $crate::hex!($name_fg),
+            $crate::hex!($name_bg),
+            $crate::hex!($version_color),
+            $crate::hex!($author_color),
+        )
+    };
+
     ($name:expr, $version:expr, $author:expr,
      $name_fg:expr, $name_bg:expr, $version_color:expr, $author_color:expr) => {
         $crate::ColorfulVersion::new($name, $version, $author)
-            .with_hex_colors($name_fg, $name_bg, $version_color, $author_color)
+            .with_colors($name_fg, $name_bg, $version_color, $author_color)
             .expect("Invalid hex color format")
     };
 }
@@ -100,13 +147,276 @@ macro_rules! colorful_version_full {
 #[macro_export]
 macro_rules! colorful_version_rgb {
     ($name_fg:expr, $name_bg:expr, $version:expr, $author:expr) => {
-        $crate::ColorfulVersion::new(
+        $crate::colorful_version!().with_rgb_colors($name_fg, $name_bg, $version, $author)
+    };
+}
+
+/// Macro turning a hex color literal into an RGB tuple at compile time
+///
+/// Accepts `#RRGGBB` or `#RGB` (the `#` is optional), like the hex
+/// colors [`ColorfulVersion::with_colors`](crate::ColorfulVersion::with_colors) takes.
+/// The color is parsed by
+/// [`ColorSpec::from_hex_const`](crate::ColorSpec::from_hex_const) in a
+/// const block, so a typo is a compile error instead of a runtime panic.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, hex};
+///
+/// assert_eq!(hex!("#AA00FF"), (170, 0, 255));
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_rgb_colors(hex!("#FFF"), hex!("#AA00FF"), hex!("FFFF00"), hex!("#0FF"));
+/// ```
+///
+/// ```compile_fail
+/// use clap_version_flag::hex;
+///
+/// let purple = hex!("#AA00FG");
+/// ```
+#[macro_export]
+macro_rules! hex {
+    ($hex:expr) => {
+        const { $crate::ColorSpec::from_hex_const($hex).rgb() }
+    };
+}
+
+/// Macro creating a ColorfulVersion that records the enabled Cargo features
+///
+/// Takes the candidate feature names of the calling crate and keeps those
+/// enabled in the current build (`cfg!(feature = "...")` is evaluated in the
+/// caller), passing them to
+/// [`with_features`](crate::ColorfulVersion::with_features). The rest comes
+/// from [`colorful_version!`](crate::colorful_version). Names not declared in
+/// the caller's `Cargo.toml` trigger the `unexpected_cfgs` lint.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::colorful_version_with_features;
+///
+/// let version = colorful_version_with_features!(["tls", "json"]);
+/// // neither feature exists in this doctest
+/// assert!(version.features().is_empty());
+/// ```
+#[macro_export]
+macro_rules! colorful_version_with_features {
+    ([$($feature:literal),* $(,)?]) => {{
+        let mut enabled: ::std::vec::Vec<&'static str> = ::std::vec::Vec::new();
+        $(
+            if cfg!(feature = $feature) {
+                enabled.push($feature);
+            }
+        )*
+        $crate::colorful_version!().with_features(enabled)
+    }};
+}
+
+/// Macro installing the process-wide version returned by [`global`](crate::global())
+///
+/// Without arguments, installs [`colorful_version!`](crate::colorful_version);
+/// otherwise installs the given version. Expands to
+/// [`set_global`](crate::set_global), so the first installation wins and the
+/// installed `&'static ColorfulVersion` is returned.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{global, init_global};
+///
+/// let version = init_global!();
+/// assert_eq!(version.package_name(), env!("CARGO_PKG_NAME"));
+/// assert!(std::ptr::eq(global().unwrap(), version));
+/// ```
+#[macro_export]
+macro_rules! init_global {
+    () => {
+        $crate::set_global($crate::colorful_version!())
+    };
+    ($version:expr $(,)?) => {
+        $crate::set_global($version)
+    };
+}
+
+/// Macro generating a typed `BUILD_INFO` constant from the caller's build metadata
+///
+/// Expands to a [`BuildInfo`](crate::BuildInfo) constant filled from the
+/// caller's `Cargo.toml` (`CARGO_PKG_*`) and the optional
+/// `CLAP_VERSION_FLAG_*` build-time variables listed on
+/// [`BuildInfo`](crate::BuildInfo). Pass a visibility and name to generate a
+/// differently named constant.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, generate_build_info};
+///
+/// generate_build_info!();
+///
+/// let version = ColorfulVersion::from_build_info(&BUILD_INFO);
+/// assert_eq!(version.package_name(), env!("CARGO_PKG_NAME"));
+/// ```
+///
+/// ## Custom name and visibility
+/// ```
+/// use clap_version_flag::generate_build_info;
+///
+/// generate_build_info!(pub(crate) APP_INFO);
+/// assert_eq!(APP_INFO.version, env!("CARGO_PKG_VERSION"));
+/// ```
+#[macro_export]
+macro_rules! generate_build_info {
+    () => {
+        $crate::generate_build_info!(pub BUILD_INFO);
+    };
+
+    ($vis:vis $name:ident) => {
+        /// Build information embedded at compile time
+        $vis const $name: $crate::BuildInfo = $crate::BuildInfo::new(
             env!("CARGO_PKG_NAME"),
             env!("CARGO_PKG_VERSION"),
             env!("CARGO_PKG_AUTHORS"),
         )
-        .with_rgb_colors($name_fg, $name_bg, $version, $author)
+        .with_description(env!("CARGO_PKG_DESCRIPTION"))
+        .with_homepage(env!("CARGO_PKG_HOMEPAGE"))
+        .with_repository(env!("CARGO_PKG_REPOSITORY"))
+        .with_license(env!("CARGO_PKG_LICENSE"))
+        .with_rust_version(env!("CARGO_PKG_RUST_VERSION"))
+        .with_commit(option_env!("CLAP_VERSION_FLAG_COMMIT"))
+        .with_build_date(option_env!("CLAP_VERSION_FLAG_BUILD_DATE"))
+        .with_install_method(option_env!("CLAP_VERSION_FLAG_INSTALL_METHOD"))
+        .with_target(option_env!("CLAP_VERSION_FLAG_TARGET"))
+        .with_features(option_env!("CLAP_VERSION_FLAG_FEATURES"))
+        .with_rustc(option_env!("CLAP_VERSION_FLAG_RUSTC"))
+        .with_opt_level(option_env!("CLAP_VERSION_FLAG_OPT_LEVEL"))
+        .with_profile(option_env!("CLAP_VERSION_FLAG_PROFILE"))
+        .with_dependencies(option_env!("CLAP_VERSION_FLAG_DEPENDENCIES"));
+    };
+}
+
+/// Macro exporting the version as a C-ABI `clap_version_flag_info()` symbol
+///
+/// Meant for `cdylib` plugins: the host loads the library, looks up
+/// `clap_version_flag_info` and calls it to get the plugin's
+/// [`to_json`](crate::ColorfulVersion::to_json) payload as a NUL-terminated
+/// UTF-8 string. The string is built on the first call and stays valid until
+/// the library is unloaded; the host must not free it. Without arguments the
+/// version comes from [`colorful_version!`](crate::colorful_version).
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, export_version_info};
+///
+/// export_version_info!(ColorfulVersion::new("myplugin", "0.4.0", "Jane"));
+///
+/// // what the host application does after loading the library
+/// let info = unsafe { std::ffi::CStr::from_ptr(clap_version_flag_info()) };
+/// assert_eq!(
+///     info.to_str().unwrap(),
+///     r#"{"schema_version":1,"name":"myplugin","version":"0.4.0","author":"Jane","metadata":{}}"#
+/// );
+/// ```
+#[macro_export]
+macro_rules! export_version_info {
+    () => {
+        $crate::export_version_info!($crate::colorful_version!());
     };
+
+    ($version:expr) => {
+        /// Returns the version information as a JSON C string owned by this library
+        #[unsafe(no_mangle)]
+        pub extern "C" fn clap_version_flag_info() -> *const ::std::ffi::c_char {
+            static INFO: ::std::sync::OnceLock<::std::ffi::CString> = ::std::sync::OnceLock::new();
+            INFO.get_or_init(|| $crate::macros::json_cstring(&$version))
+                .as_ptr()
+        }
+    };
+}
+
+/// Returns the length of the author that [`cargo_authors`] writes
+#[doc(hidden)]
+#[must_use]
+pub const fn cargo_authors_len(authors: &str) -> usize {
+    write_cargo_authors(authors.as_bytes(), &mut [])
+}
+
+/// Writes `CARGO_PKG_AUTHORS` the way the version line shows it, for
+/// [`colorful_version!`] to build its author at compile time
+///
+/// The colon-separated entries become `Name <email>` joined with ", ", as
+/// [`Author::parse_list`](crate::Author::parse_list) and
+/// [`AuthorDisplay::Joined`](crate::AuthorDisplay::Joined) render them.
+#[doc(hidden)]
+#[must_use]
+pub const fn cargo_authors<const N: usize>(authors: &str) -> [u8; N] {
+    let mut out = [0; N];
+    write_cargo_authors(authors.as_bytes(), &mut out);
+    out
+}
+
+/// Writes the shown authors into `out` as far as they fit; returns the
+/// length they need
+const fn write_cargo_authors(authors: &[u8], out: &mut [u8]) -> usize {
+    let mut len = 0;
+    let mut rest = authors;
+    while !rest.is_empty() {
+        let mut end = 0;
+        while end < rest.len() && rest[end] != b':' {
+            end += 1;
+        }
+        let (entry, tail) = rest.split_at(end);
+        rest = match tail.split_first() {
+            Some((_, tail)) => tail,
+            None => tail,
+        };
+        let entry = entry.trim_ascii();
+        if entry.is_empty() {
+            continue;
+        }
+        if len > 0 {
+            len = put(out, len, b", ");
+        }
+        let (name, email) = split_author(entry);
+        len = put(out, len, name);
+        if !email.is_empty() {
+            len = put(out, len, b" <");
+            len = put(out, len, email);
+            len = put(out, len, b">");
+        }
+    }
+    len
+}
+
+/// Splits a trimmed `Name <email>` entry; the email is empty when missing
+const fn split_author(entry: &[u8]) -> (&[u8], &[u8]) {
+    if let Some((b'>', rest)) = entry.split_last() {
+        let mut open = rest.len();
+        while open > 0 {
+            open -= 1;
+            if rest[open] == b'<' {
+                let (name, email) = rest.split_at(open);
+                return (name.trim_ascii(), email.split_at(1).1.trim_ascii());
+            }
+        }
+    }
+    (entry, &[])
+}
+
+/// Copies `bytes` to `out` at `at` as far as they fit; returns the end
+const fn put(out: &mut [u8], at: usize, bytes: &[u8]) -> usize {
+    let mut index = 0;
+    while index < bytes.len() {
+        if at + index < out.len() {
+            out[at + index] = bytes[index];
+        }
+        index += 1;
+    }
+    at + bytes.len()
+}
+
+/// Renders `version` as JSON for [`export_version_info!`]
+#[doc(hidden)]
+#[must_use]
+pub fn json_cstring(version: &crate::ColorfulVersion) -> std::ffi::CString {
+    // the JSON writer escapes every control character, NUL included
+    std::ffi::CString::new(version.to_json()).unwrap_or_default()
 }
 
 /*
@@ -194,6 +504,15 @@ mod tests {
         assert_eq!(version.package_name(), "testapp");
     }
 
+    #[test]
+    fn test_cargo_authors_shown_like_parsed() {
+        const AUTHORS: &str = " Jane Doe < jane@x.io > :: John:<>:Al <al@x.io>";
+        const SHOWN: [u8; super::cargo_authors_len(AUTHORS)] = super::cargo_authors(AUTHORS);
+        let parsed = crate::ColorfulVersion::new("app", "1.0", "")
+            .with_authors(crate::Author::parse_list(AUTHORS));
+        assert_eq!(std::str::from_utf8(&SHOWN).unwrap(), parsed.author());
+    }
+
     #[test]
     fn test_colorful_version_rgb() {
         let version = colorful_version_rgb!((255, 0, 0), (0, 255, 0), (0, 0, 255), (255, 255, 0));
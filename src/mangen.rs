@@ -0,0 +1,122 @@
+// Project: clap-version-flag
+// File: src/mangen.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: roff VERSION and AUTHORS sections for man pages
+// License: MIT
+
+//! Man page sections.
+//!
+//! With the `mangen` feature, [`ColorfulVersion::roff_section`] renders the
+//! version metadata as roff, so a man page generated with `clap_mangen`
+//! carries the same information as `--version`, without escape codes:
+//!
+//! ```ignore
+//! let mut page = Vec::new();
+//! clap_mangen::Man::new(cmd).render(&mut page)?;
+//! page.extend_from_slice(version.roff_section().as_bytes());
+//! ```
+
+use crate::{ColorfulVersion, Verbosity};
+
+impl ColorfulVersion {
+    /// Returns roff `VERSION` and `AUTHORS` sections for a man page
+    ///
+    /// `VERSION` holds the plain version line followed by the detail lines
+    /// of the default output (commit, license, ...); `AUTHORS` lists every
+    /// author, one per line. Text is escaped for roff.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.0.0", "John Doe")
+    ///     .extra("commit", "4f2a9c1")
+    ///     .build();
+    /// assert_eq!(
+    ///     version.roff_section(),
+    ///     ".SH VERSION\nmyapp v1.0.0\n.br\ncommit: 4f2a9c1\n.SH AUTHORS\nJohn Doe\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn roff_section(&self) -> String {
+        let mut version = vec![format!("{} {}", self.package_name, self.prefixed_version())];
+        version.extend(
+            self.detail_fields(Verbosity::Normal)
+                .into_iter()
+                .map(|field| format!("{}: {}", field.key, field.value)),
+        );
+        let mut authors: Vec<String> = self.authors.iter().map(ToString::to_string).collect();
+        if authors.is_empty() && !self.author.is_empty() {
+            authors.push(self.author.to_string());
+        }
+
+        let mut out = String::new();
+        for (title, lines) in [("VERSION", version), ("AUTHORS", authors)] {
+            if lines.is_empty() {
+                continue;
+            }
+            out.push_str(".SH ");
+            out.push_str(title);
+            out.push('\n');
+            let lines: Vec<String> = lines.iter().map(|line| escape(line)).collect();
+            out.push_str(&lines.join("\n.br\n"));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Escapes `text` for use as a roff text line
+///
+/// Backslashes and hyphens are escaped, and a leading `.` or `'` is
+/// protected so the line is not read as a request.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    if text.starts_with(['.', '\'']) {
+        out.push_str("\\&");
+    }
+    for ch in text.chars() {
+        match ch {
+            '\\' => out.push_str("\\e"),
+            '-' => out.push_str("\\-"),
+            '\n' => out.push(' '),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Author;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a-b\\c"), "a\\-b\\ec");
+        assert_eq!(escape(".hidden"), "\\&.hidden");
+        assert_eq!(escape("'quote"), "\\&'quote");
+    }
+
+    #[test]
+    fn test_all_authors_listed() {
+        let version = ColorfulVersion::new("my-app", "1.0.0-rc.1", "Jo").with_authors([
+            Author::new("Jane Doe").with_email("jane@example.com"),
+            Author::new("Bob"),
+        ]);
+        assert_eq!(
+            version.roff_section(),
+            ".SH VERSION\nmy\\-app v1.0.0\\-rc.1\n.SH AUTHORS\nJane Doe <jane@example.com>\n.br\nBob\n"
+        );
+    }
+
+    #[test]
+    fn test_no_escape_codes_with_colors() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_color_depth(crate::ColorDepth::TrueColor)
+            .with_license("MIT");
+        assert!(!version.roff_section().contains('\x1b'));
+        assert!(version.roff_section().contains("\n.br\nlicense: MIT\n"));
+    }
+}
@@ -0,0 +1,167 @@
+// Project: clap-version-flag
+// File: src/markdown.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Markdown renderer for release notes and docs
+// License: MIT
+
+//! Markdown output.
+//!
+//! [`MarkdownRenderer`] writes the version line as Markdown, followed by a
+//! table of the detail lines, for generated docs and GitHub release bodies:
+//!
+//! ```text
+//! **myapp** `v1.2.3` — by John Doe
+//!
+//! | Field | Value |
+//! | --- | --- |
+//! | commit | abc1234 |
+//! ```
+
+use crate::{Labels, VersionInfo, VersionRenderer};
+
+/// Characters with a meaning in Markdown inline text
+const SPECIAL: [char; 8] = ['\\', '*', '_', '`', '[', ']', '<', '>'];
+
+/// Renders the version information as Markdown
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{MarkdownRenderer, VersionInfo, VersionRenderer};
+///
+/// let info = VersionInfo::new("myapp", "1.2.3", "John Doe").with_field("commit", "abc1234");
+/// assert_eq!(
+///     MarkdownRenderer::new().render(&info),
+///     "**myapp** `v1.2.3` — by John Doe\n\n| Field | Value |\n| --- | --- |\n| commit | abc1234 |"
+/// );
+/// assert_eq!(
+///     MarkdownRenderer::new().with_table(false).render(&info),
+///     "**myapp** `v1.2.3` — by John Doe"
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkdownRenderer {
+    labels: Labels,
+    table: bool,
+}
+
+impl Default for MarkdownRenderer {
+    fn default() -> Self {
+        Self {
+            labels: Labels::default(),
+            table: true,
+        }
+    }
+}
+
+impl MarkdownRenderer {
+    /// Creates a renderer with the English labels and the metadata table
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the "v" and "by" labels
+    #[must_use]
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Adds the tables of detail lines and components below the version line
+    ///
+    /// On by default; tables without rows are always left out.
+    #[must_use]
+    pub fn with_table(mut self, table: bool) -> Self {
+        self.table = table;
+        self
+    }
+}
+
+impl VersionRenderer for MarkdownRenderer {
+    fn render(&self, info: &VersionInfo) -> String {
+        let author = match self.labels.author_prefix.as_str() {
+            "" => escape(&info.author),
+            prefix => format!("{} {}", escape(prefix), escape(&info.author)),
+        };
+        let mut out = format!(
+            "**{}** `{}{}` — {author}",
+            escape(&info.name),
+            self.labels.version_prefix,
+            info.version
+        );
+        if !self.table {
+            return out;
+        }
+        let fields = info
+            .fields
+            .iter()
+            .map(|(key, value)| [key.as_str(), value.as_str()]);
+        push_table(&mut out, ["Field", "Value"], fields);
+        let components = info
+            .components
+            .iter()
+            .map(|(name, version)| [name.as_str(), version.as_deref().unwrap_or("unavailable")]);
+        push_table(&mut out, ["Component", "Version"], components);
+        out
+    }
+}
+
+/// Appends a two-column table after a blank line, unless it has no rows
+fn push_table<'a>(out: &mut String, header: [&str; 2], rows: impl Iterator<Item = [&'a str; 2]>) {
+    let mut rows = rows.peekable();
+    if rows.peek().is_none() {
+        return;
+    }
+    out.push_str(&format!(
+        "\n\n| {} | {} |\n| --- | --- |",
+        header[0], header[1]
+    ));
+    for [key, value] in rows {
+        out.push_str(&format!("\n| {} | {} |", cell(key), cell(value)));
+    }
+}
+
+/// Escapes `text` for Markdown inline text
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if SPECIAL.contains(&c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Escapes `text` for a table cell, which cannot hold `|` or line breaks
+fn cell(text: &str) -> String {
+    escape(text).replace('|', "\\|").replace('\n', "<br>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorfulVersion, ComponentVersions, Locale, Verbosity};
+
+    #[test]
+    fn test_escaping() {
+        let info = VersionInfo::new("my_app", "1.0", "Jo <jo@x.io>").with_field("note", "a|b\nc");
+        assert_eq!(
+            MarkdownRenderer::new().render(&info),
+            "**my\\_app** `v1.0` — by Jo \\<jo@x.io\\>\n\n| Field | Value |\n| --- | --- |\n| note | a\\|b<br>c |"
+        );
+    }
+
+    #[test]
+    fn test_components_table() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_locale(Locale::De)
+            .with_components(ComponentVersions::new().add_component_fn("server", || None));
+        let renderer = MarkdownRenderer::new().with_labels(version.labels().clone());
+        assert_eq!(
+            version.render_with(&renderer, Verbosity::Normal),
+            "**app** `v1.0` — von Jo\n\n| Component | Version |\n| --- | --- |\n| server | unavailable |"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+// Project: clap-version-flag
+// File: src/matches.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Querying parsed arguments for the version flag
+// License: MIT
+
+//! Version queries on [`ArgMatches`].
+//!
+//! Apps that parse with the builder API and handle the flag themselves ask
+//! [`ArgMatchesExt`] whether it was given, instead of looking up the arg id
+//! the flag happens to be registered under.
+
+use clap::ArgMatches;
+
+use crate::{Verbosity, VersionFlagConfig};
+
+/// Version flag queries on [`ArgMatches`]
+///
+/// # Examples
+/// ```
+/// use clap::Command;
+/// use clap_version_flag::{ArgMatchesExt, ColorfulVersion, ColorfulVersionExt};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// let cmd = Command::new("myapp").with_colorful_version(&version);
+///
+/// assert!(cmd.clone().get_matches_from(["myapp", "-V"]).version_requested());
+/// assert!(!cmd.get_matches_from(["myapp"]).version_requested());
+/// ```
+pub trait ArgMatchesExt {
+    /// Returns whether the version flag was given
+    ///
+    /// Covers the flag under [`VERSION_FLAG_ID`](crate::VERSION_FLAG_ID),
+    /// split or not. For a flag with its own [`id`](VersionFlagConfig::id),
+    /// use [`version_verbosity`](Self::version_verbosity) with its config.
+    fn version_requested(&self) -> bool;
+
+    /// Returns the verbosity the flag described by `flag` asks for, if given
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::{
+    ///     ArgMatchesExt, ColorfulVersion, ColorfulVersionExt, Verbosity, VersionFlagConfig,
+    /// };
+    ///
+    /// let flag = VersionFlagConfig::new().id("show_version");
+    /// let version =
+    ///     ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_flag_config(flag.clone());
+    /// let matches = Command::new("myapp")
+    ///     .with_verbose_colorful_version(&version)
+    ///     .get_matches_from(["myapp", "-VV"]);
+    ///
+    /// assert_eq!(matches.version_verbosity(&flag), Some(Verbosity::Verbose));
+    /// ```
+    fn version_verbosity(&self, flag: &VersionFlagConfig) -> Option<Verbosity>;
+}
+
+impl ArgMatchesExt for ArgMatches {
+    fn version_requested(&self) -> bool {
+        VersionFlagConfig::new().is_given(self)
+    }
+
+    fn version_verbosity(&self, flag: &VersionFlagConfig) -> Option<Verbosity> {
+        flag.requested_verbosity(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorfulVersion, ColorfulVersionExt};
+    use clap::Command;
+
+    #[test]
+    fn test_split_flags() {
+        let flag = VersionFlagConfig::new().short_verbosity(Verbosity::Brief);
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_flag_config(flag.clone());
+        let cmd = Command::new("app").with_colorful_version(&version);
+        let short = cmd.clone().get_matches_from(["app", "-V"]);
+        assert!(short.version_requested());
+        assert_eq!(short.version_verbosity(&flag), Some(Verbosity::Brief));
+        let long = cmd.get_matches_from(["app", "--version"]);
+        assert!(long.version_requested());
+        assert_eq!(long.version_verbosity(&flag), Some(Verbosity::Normal));
+    }
+}
@@ -0,0 +1,88 @@
+// Project: clap-version-flag
+// File: src/metrics.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Prometheus build_info metric
+// License: MIT
+
+//! Build-info metric.
+//!
+//! Services conventionally export a `<name>_build_info` gauge with the value
+//! 1 and the version as labels, so dashboards can show which build runs
+//! where. [`ColorfulVersion::prometheus_build_info`] renders it in the text
+//! exposition format, ready to append to a `/metrics` response.
+
+use crate::ColorfulVersion;
+
+impl ColorfulVersion {
+    /// Returns the `<name>_build_info` metric line
+    ///
+    /// The labels are `version` and, when set, `commit`. Characters Prometheus
+    /// does not allow in metric names become `_`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("my-app", "1.2.3", "John Doe")
+    ///     .extra("commit", "abc")
+    ///     .build();
+    /// assert_eq!(
+    ///     version.prometheus_build_info(),
+    ///     r#"my_app_build_info{version="1.2.3",commit="abc"} 1"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn prometheus_build_info(&self) -> String {
+        let mut labels = vec![("version", self.version())];
+        if let Some(commit) = self.metadata("commit") {
+            labels.push(("commit", commit));
+        }
+        let labels: Vec<String> = labels
+            .into_iter()
+            .map(|(key, value)| format!("{key}=\"{}\"", escape(value)))
+            .collect();
+        format!(
+            "{}_build_info{{{}}} 1",
+            metric_name(&self.package_name),
+            labels.join(",")
+        )
+    }
+}
+
+/// Replaces the characters a metric name cannot hold with `_`
+fn metric_name(name: &str) -> String {
+    let mut out: String = name
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | ':' => c,
+            _ => '_',
+        })
+        .collect();
+    if !out.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == ':') {
+        out.insert(0, '_');
+    }
+    out
+}
+
+/// Escapes a label value as the text exposition format requires
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_and_value_escaping() {
+        let version = ColorfulVersion::new("1app.x", "1.0 \"beta\"", "Jo");
+        assert_eq!(
+            version.prometheus_build_info(),
+            r#"_1app_x_build_info{version="1.0 \"beta\""} 1"#
+        );
+    }
+}
@@ -0,0 +1,199 @@
+// Project: clap-version-flag
+// File: src/minimal.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Allocation-free version output for crash paths
+// License: MIT
+
+//! Minimal output for crash paths.
+//!
+//! Signal handlers, panic hooks and out-of-memory handlers cannot safely run
+//! the full renderer: it allocates, reads the environment and takes the
+//! locks of the standard streams, any of which may deadlock or abort when
+//! the process is already failing. [`ColorfulVersion::print_minimal_unbuffered`]
+//! writes the plain version line with nothing but `write` calls on the
+//! standard error file descriptor, and [`ColorfulVersion::precompute`]
+//! renders the full plain line at startup so that
+//! [`ColorfulVersion::print_precomputed`] only has to copy bytes out.
+
+use crate::ColorfulVersion;
+use std::io::{self, Write};
+use std::sync::OnceLock;
+
+impl ColorfulVersion {
+    /// Writes "{name} v{version} by {author}" straight to standard error
+    ///
+    /// Performs no allocation and takes no lock: on Unix the line goes to
+    /// file descriptor 2 through `write(2)`, bypassing the `std::io::stderr`
+    /// lock, so it is safe to call from a signal handler, a panic hook or an
+    /// allocation error path. Elsewhere it writes to `std::io::stderr`.
+    /// Colors, templates, layout and the detail lines are all skipped.
+    ///
+    /// # Errors
+    /// Returns the error of the underlying write, e.g. when standard error
+    /// is closed.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// std::panic::set_hook(Box::new(move |_| {
+    ///     let _ = version.print_minimal_unbuffered();
+    /// }));
+    /// # let _ = std::panic::take_hook();
+    /// ```
+    pub fn print_minimal_unbuffered(&self) -> io::Result<()> {
+        write_stderr(&self.minimal_parts())
+    }
+
+    /// Returns the pieces of the minimal version line
+    fn minimal_parts(&self) -> [&[u8]; 9] {
+        let author_space: &[u8] = match self.labels().author_prefix.as_str() {
+            "" => b"",
+            _ => b" ",
+        };
+        [
+            self.package_name.as_bytes(),
+            b" ",
+            self.labels().version_prefix.as_bytes(),
+            self.version.as_bytes(),
+            b" ",
+            self.labels().author_prefix.as_bytes(),
+            author_space,
+            self.author.as_bytes(),
+            b"\n",
+        ]
+    }
+
+    /// Renders the plain version line once and keeps it for crash paths
+    ///
+    /// Call it at startup, while the full renderer is still safe to run; the
+    /// line then honors the template and layout, unlike
+    /// [`print_minimal_unbuffered`](Self::print_minimal_unbuffered).
+    /// Afterwards [`print_precomputed`](Self::print_precomputed) emits it
+    /// without any formatting. Only the first call takes effect; returns
+    /// `false` if a line was already precomputed.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// version.precompute();
+    /// assert_eq!(ColorfulVersion::precomputed(), Some(&b"myapp v1.0.0 by John Doe\n"[..]));
+    ///
+    /// std::panic::set_hook(Box::new(|_| {
+    ///     let _ = ColorfulVersion::print_precomputed();
+    /// }));
+    /// # let _ = std::panic::take_hook();
+    /// ```
+    pub fn precompute(&self) -> bool {
+        let mut line = self.as_plain_string().into_bytes();
+        line.push(b'\n');
+        PRECOMPUTED.set(line.into_boxed_slice()).is_ok()
+    }
+
+    /// Returns the line stored by [`precompute`](Self::precompute)
+    ///
+    /// Reading it is a single atomic load, safe in a signal handler; write
+    /// it with [`print_precomputed`](Self::print_precomputed).
+    #[must_use]
+    pub fn precomputed() -> Option<&'static [u8]> {
+        PRECOMPUTED.get().map(|line| &line[..])
+    }
+
+    /// Writes the precomputed line straight to standard error
+    ///
+    /// Like [`print_minimal_unbuffered`](Self::print_minimal_unbuffered) it
+    /// neither allocates nor locks, and it does no formatting at all: on
+    /// Unix the bytes go to file descriptor 2 through `write(2)`, so the call
+    /// is async-signal-safe once [`precompute`](Self::precompute) has run.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::NotFound`] if nothing was precomputed, or
+    /// the error of the underlying write.
+    pub fn print_precomputed() -> io::Result<()> {
+        let line = Self::precomputed().ok_or(io::ErrorKind::NotFound)?;
+        write_stderr(&[line])
+    }
+}
+
+/// The version line rendered by [`ColorfulVersion::precompute`]
+static PRECOMPUTED: OnceLock<Box<[u8]>> = OnceLock::new();
+
+/// Writes `parts` to standard error without allocating or locking
+fn write_stderr(parts: &[&[u8]]) -> io::Result<()> {
+    #[cfg(unix)]
+    return write_parts(&mut RawStderr, parts);
+    // outside Unix the standard handle is the lowest level available
+    // without platform bindings
+    #[cfg(not(unix))]
+    return write_parts(&mut io::stderr(), parts);
+}
+
+/// File descriptor 2, written with `write(2)` and never closed
+#[cfg(unix)]
+struct RawStderr;
+
+#[cfg(unix)]
+impl Write for RawStderr {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // SAFETY: `buf` is valid for reads of `buf.len()` bytes
+        let written = unsafe { libc::write(libc::STDERR_FILENO, buf.as_ptr().cast(), buf.len()) };
+        usize::try_from(written).map_err(|_| io::Error::last_os_error())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Writes `parts` one after the other
+fn write_parts(out: &mut impl Write, parts: &[&[u8]]) -> io::Result<()> {
+    for part in parts {
+        out.write_all(part)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimal_line_ignores_styling() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_template("{name}@{version}")
+            .with_color_depth(crate::ColorDepth::TrueColor);
+        let mut out = Vec::new();
+        write_parts(&mut out, &version.minimal_parts()).unwrap();
+        assert_eq!(out, b"app v1.0.0 by Jane\n");
+    }
+
+    #[test]
+    fn test_minimal_line_uses_labels() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_locale(crate::Locale::De);
+        let mut out = Vec::new();
+        write_parts(&mut out, &version.minimal_parts()).unwrap();
+        assert_eq!(out, "app v1.0.0 von Jane\n".as_bytes());
+    }
+
+    #[test]
+    fn test_print_minimal_unbuffered() {
+        ColorfulVersion::new("app", "1.0.0", "Jane")
+            .print_minimal_unbuffered()
+            .unwrap();
+    }
+
+    #[test]
+    fn test_precompute_once() {
+        // the static is shared by the whole test binary, so one test covers it
+        let version =
+            ColorfulVersion::new("app", "1.0.0", "Jane").with_template("{name}@{version}");
+        assert!(version.precompute());
+        assert!(!ColorfulVersion::new("other", "2.0.0", "Joe").precompute());
+        assert_eq!(ColorfulVersion::precomputed(), Some(&b"app@1.0.0\n"[..]));
+        ColorfulVersion::print_precomputed().unwrap();
+    }
+}
@@ -0,0 +1,110 @@
+// Project: clap-version-flag
+// File: src/palette.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Brand palettes cycled across the package name
+// License: MIT
+
+//! Name palettes.
+//!
+//! Brands often come with a handful of colors rather than one.
+//! [`ColorfulVersion::with_palette`] paints the characters of the package
+//! name with them in turn, wrapping around when the name is longer than the
+//! palette, while the badge keeps its background. Unlike a gradient, the
+//! colors are used exactly as given.
+
+use std::fmt;
+
+use crate::color::{self, Rgb};
+use crate::{ColorDepth, ColorfulVersion, VersionError, grapheme};
+
+impl ColorfulVersion {
+    /// Cycles `palette` across the characters of the package name
+    ///
+    /// Spaces keep the badge background and do not use up a color; the
+    /// [`Style`](crate::Style) of the name still adds its attributes.
+    ///
+    /// # Errors
+    /// Returns [`VersionError::InvalidPalette`] if `palette` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_palette(&[(255, 0, 0), (255, 255, 255), (0, 0, 255)])
+    ///     .unwrap();
+    /// assert_eq!(version.name_palette().map(<[_]>::len), Some(3));
+    ///
+    /// assert!(ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_palette(&[]).is_err());
+    /// ```
+    pub fn with_palette(mut self, palette: &[Rgb]) -> Result<Self, VersionError> {
+        if palette.is_empty() {
+            return Err(VersionError::InvalidPalette(
+                "a palette needs at least one color".to_string(),
+            ));
+        }
+        self.name_palette = Some(palette.to_vec());
+        Ok(self)
+    }
+
+    /// Returns the palette set with [`with_palette`](Self::with_palette)
+    #[must_use]
+    pub fn name_palette(&self) -> Option<&[Rgb]> {
+        self.name_palette.as_deref()
+    }
+
+    /// Paints the name badge, one palette color per character when set
+    pub(crate) fn paint_name(&self, text: &str, depth: ColorDepth) -> String {
+        let mut out = String::new();
+        // writing to a String cannot fail
+        let _ = self.write_name(&mut out, text, depth);
+        out
+    }
+
+    /// Writes the output of [`paint_name`](Self::paint_name) to `out`
+    pub(crate) fn write_name(
+        &self,
+        out: &mut impl fmt::Write,
+        text: impl fmt::Display,
+        depth: ColorDepth,
+    ) -> fmt::Result {
+        let style = &self.styles.name;
+        let bg = style.get_bg().or(Some(self.palette().name_bg));
+        let attributes = style.sgr_attributes();
+        let Some(palette) = &self.name_palette else {
+            let fg = style.get_fg().unwrap_or(self.palette().name_fg);
+            return color::write_painted(out, text, Some(fg), bg, &attributes, depth);
+        };
+        let mut colors = palette.iter().copied().cycle();
+        for cluster in grapheme::graphemes(&text.to_string()) {
+            let fg = if cluster.trim().is_empty() {
+                None
+            } else {
+                colors.next()
+            };
+            color::write_painted(out, cluster, fg, bg, &attributes, depth)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palette_wraps() {
+        let version = ColorfulVersion::new("a b c", "1.0", "Jo")
+            .with_palette(&[(1, 1, 1), (2, 2, 2)])
+            .unwrap();
+        let name = version.paint_name("a b c", ColorDepth::TrueColor);
+        let fg = |n| format!("38;2;{n};{n};{n}");
+        let colors: Vec<&str> = name.split("\x1b[0m").collect();
+        assert!(colors[0].contains(&fg(1)) && colors[0].ends_with('a'));
+        assert!(!colors[1].contains("38;2") && colors[1].contains("48;2;170;0;255"));
+        assert!(colors[2].contains(&fg(2)));
+        assert!(colors[4].contains(&fg(1)) && colors[4].ends_with('c'));
+        assert_eq!(color::strip_escapes(&name), "a b c");
+    }
+}
@@ -0,0 +1,136 @@
+// Project: clap-version-flag
+// File: src/panic.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Panic hook printing the version before the panic message
+// License: MIT
+
+//! Panic hook.
+//!
+//! Bug reports usually consist of whatever the user pasted from their
+//! terminal. [`ColorfulVersion::install_panic_hook`] makes sure that paste
+//! starts with the version:
+//!
+//! ```text
+//! myapp v1.4.2 by Jane Doe
+//! commit: 4f2a9c1
+//! thread 'main' panicked at src/main.rs:10:5:
+//! index out of bounds
+//! ```
+
+use std::io::{self, IsTerminal, Write};
+
+use crate::{ColorfulVersion, color};
+
+impl ColorfulVersion {
+    /// Prints the version line before every panic message
+    ///
+    /// Wraps the panic hook in place (usually the default one), which still
+    /// prints the message afterwards. The version line goes to stderr,
+    /// colored when stderr is a terminal that supports it (or colors are
+    /// forced with [`color::set_override`]), followed by the
+    /// `commit` metadata field if present. The text is rendered once, here,
+    /// so the hook itself does no rendering.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.0.0", "John Doe")
+    ///     .extra("commit", "4f2a9c1")
+    ///     .build();
+    /// version.install_panic_hook();
+    /// # let _ = std::panic::take_hook();
+    /// ```
+    pub fn install_panic_hook(&self) {
+        let colored = self.colorize()
+            && (self
+                .color_choice
+                .is_some_and(|choice| choice != clap::ColorChoice::Auto)
+                || color::color_override().is_some()
+                || io::stderr().is_terminal());
+        let banner = self.panic_banner(colored);
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = writeln!(io::stderr().lock(), "{banner}");
+            previous(info);
+        }));
+    }
+
+    /// Renders the version line plus the commit line, if any
+    fn panic_banner(&self, colored: bool) -> String {
+        let depth = colored.then(|| self.depth());
+        let mut banner = match depth {
+            Some(depth) => self.colored_line(depth),
+            None => self.as_plain_string(),
+        };
+        let commit = self
+            .detail_fields(crate::Verbosity::Diagnostic)
+            .into_iter()
+            .find(|field| field.key == "commit");
+        if let Some(field) = commit {
+            banner.push('\n');
+            banner.push_str(&self.detail_line(&field, depth));
+        }
+        banner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorDepth;
+
+    #[test]
+    fn test_banner_with_and_without_commit() {
+        let plain = ColorfulVersion::new("app", "1.0", "Jo");
+        assert_eq!(plain.panic_banner(false), "app v1.0 by Jo");
+
+        let version = ColorfulVersion::builder("app", "1.0", "Jo")
+            .description("not shown")
+            .extra("commit", "abc1234")
+            .build()
+            .with_color_depth(ColorDepth::TrueColor);
+        assert_eq!(
+            version.panic_banner(false),
+            "app v1.0 by Jo\ncommit: abc1234"
+        );
+        assert!(
+            version
+                .panic_banner(true)
+                .contains("\x1b[38;2;255;255;0mcommit:\x1b[0m")
+        );
+    }
+
+    /// Set in the child process [`test_hook_prints_banner_first`] spawns
+    const CHILD_ENV: &str = "CLAP_VERSION_FLAG_PANIC_HOOK_CHILD";
+
+    #[test]
+    fn test_hook_prints_banner_first() {
+        if std::env::var_os(CHILD_ENV).is_some() {
+            // a child process: hooks are process-wide, so only it installs one
+            std::panic::set_hook(Box::new(|_| eprintln!("previous hook")));
+            ColorfulVersion::builder("app", "1.0", "Jo")
+                .extra("commit", "abc1234")
+                .build()
+                .install_panic_hook();
+            assert!(std::panic::catch_unwind(|| panic!("boom")).is_err());
+            drop(std::panic::take_hook());
+            return;
+        }
+
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "panic::tests::test_hook_prints_banner_first"])
+            .args(["--nocapture", "--test-threads=1"])
+            .env(CHILD_ENV, "1")
+            .env("NO_COLOR", "1")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("app v1.0 by Jo\ncommit: abc1234\nprevious hook\n"),
+            "{stderr}"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+// Project: clap-version-flag
+// File: src/parse.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Parsing version lines back into a ColorfulVersion
+// License: MIT
+
+//! Parsing version lines.
+//!
+//! `"myapp v1.2.3 by Jane Doe".parse::<ColorfulVersion>()` reverses
+//! [`ColorfulVersion::as_plain_string`], which makes the plain output easy to
+//! round-trip in tests and tooling that scrapes version strings. Escape codes
+//! and bidi isolation marks are ignored, so colored output parses too; only
+//! the first line is read.
+
+use std::str::FromStr;
+
+use crate::{ColorfulVersion, VersionError, color};
+
+impl FromStr for ColorfulVersion {
+    type Err = VersionError;
+
+    /// Parses `"{name} v{version} by {author}"`; the `by` part is optional
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version: ColorfulVersion = "myapp v1.2.3 by Jane Doe".parse().unwrap();
+    /// assert_eq!(version.package_name(), "myapp");
+    /// assert_eq!(version.version(), "1.2.3");
+    /// assert_eq!(version.author(), "Jane Doe");
+    /// assert!("myapp 1.2.3".parse::<ColorfulVersion>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || VersionError::InvalidVersionLine(text.to_string());
+        let line: String = color::strip_escapes(text.lines().next().unwrap_or_default())
+            .chars()
+            .filter(|c| !('\u{2066}'..='\u{2069}').contains(c))
+            .collect();
+        let line = line.trim();
+
+        let start = line
+            .match_indices(" v")
+            .map(|(index, _)| index)
+            .find(|&index| line[index + 2..].starts_with(|c: char| c.is_ascii_digit()))
+            .ok_or_else(invalid)?;
+        let name = line[..start].trim();
+        let rest = &line[start + 2..];
+        let (version, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        let author = match rest.trim_start() {
+            "" | "by" => "",
+            rest => rest.strip_prefix("by ").ok_or_else(invalid)?.trim(),
+        };
+        if name.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self::new(name, version, author))
+    }
+}
+
+impl TryFrom<&str> for ColorfulVersion {
+    type Error = VersionError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorDepth;
+
+    fn parts(version: &ColorfulVersion) -> (&str, &str, &str) {
+        (version.package_name(), version.version(), version.author())
+    }
+
+    #[test]
+    fn test_round_trip_plain_and_colored() {
+        let original = ColorfulVersion::new("my app", "1.0.0-rc.1+build", "Jane <j@x.io>");
+        let plain: ColorfulVersion = original.as_plain_string().parse().unwrap();
+        assert_eq!(parts(&plain), parts(&original));
+
+        let colored = original.colored_line(ColorDepth::TrueColor);
+        let parsed = ColorfulVersion::try_from(colored.as_str()).unwrap();
+        assert_eq!(parts(&parsed), parts(&original));
+    }
+
+    #[test]
+    fn test_optional_author_and_extra_lines() {
+        let version: ColorfulVersion = "app v2 by \nlicense: MIT".parse().unwrap();
+        assert_eq!(parts(&version), ("app", "2", ""));
+        let version: ColorfulVersion = "tool v0.1.0".parse().unwrap();
+        assert_eq!(parts(&version), ("tool", "0.1.0", ""));
+    }
+
+    #[test]
+    fn test_rejects_other_shapes() {
+        for text in ["", "v1.0 by Jo", "app version 1.0", "app v1.0 (Jo)"] {
+            assert!(
+                matches!(
+                    text.parse::<ColorfulVersion>(),
+                    Err(VersionError::InvalidVersionLine(_))
+                ),
+                "{text}"
+            );
+        }
+    }
+}
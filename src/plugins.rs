@@ -0,0 +1,418 @@
+// Project: clap-version-flag
+// File: src/plugins.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Plugin version discovery from a plugins directory
+// License: MIT
+
+//! Plugin versions.
+//!
+//! Apps with plugins can list every installed plugin in their verbose
+//! version output. A [`PluginSource`] reports the plugins it knows of;
+//! [`PluginDir`], the source behind [`ColorfulVersion::with_plugins`], scans
+//! a directory when the version is rendered: a plugin declares its version
+//! in a sidecar `{plugin}.json` file (the same shape as
+//! [`ColorfulVersion::to_json`]), and executables without a sidecar are
+//! asked for `--version`, killed after the timeout. Sources, and the
+//! executables within a directory, are queried concurrently, so the slowest
+//! plugin bounds the wait rather than the sum of them.
+//!
+//! Requires the `suite` feature.
+
+use crate::suite::{self, SuiteEntry};
+use crate::{ColorfulVersion, Field, Role, Verbosity};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// A place plugins are discovered from
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, PluginSource, SuiteEntry};
+/// use std::time::Duration;
+///
+/// /// Plugins compiled into the binary
+/// struct Builtin;
+///
+/// impl PluginSource for Builtin {
+///     fn plugins(&self, _timeout: Duration) -> Vec<SuiteEntry> {
+///         let export = ColorfulVersion::new("export", "0.3.1", "Jane");
+///         vec![SuiteEntry::new("export", Ok(export))]
+///     }
+/// }
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_plugin_source(Builtin);
+/// assert_eq!(version.plugins()[0].binary, "export");
+/// ```
+pub trait PluginSource: Send + Sync {
+    /// Returns one entry per plugin, giving each at most `timeout` to answer
+    fn plugins(&self, timeout: Duration) -> Vec<SuiteEntry>;
+}
+
+/// A plugins directory of sidecar files and executables
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PluginDir {
+    dir: PathBuf,
+}
+
+impl PluginDir {
+    /// Creates a source scanning `dir`; a missing directory lists no plugins
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl PluginSource for PluginDir {
+    fn plugins(&self, timeout: Duration) -> Vec<SuiteEntry> {
+        scan(&self.dir, timeout)
+    }
+}
+
+/// Where plugins are discovered and how long each may take to answer
+#[derive(Clone)]
+pub(crate) struct PluginScan {
+    sources: Vec<Arc<dyn PluginSource>>,
+    timeout: Duration,
+}
+
+impl fmt::Debug for PluginScan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginScan")
+            .field("sources", &self.sources.len())
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl ColorfulVersion {
+    /// Lists the plugins found in `dir` in the verbose version output
+    ///
+    /// Each plugin adds a "plugin {name}: {version}" line from `-VV` on.
+    /// A missing directory lists no plugins. Shorthand for
+    /// [`with_plugin_source`](Self::with_plugin_source) with a [`PluginDir`].
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let dir = std::env::temp_dir().join("clap-version-flag-doc-plugins");
+    /// std::fs::create_dir_all(&dir).unwrap();
+    /// std::fs::write(dir.join("export.json"), r#"{"name":"export","version":"0.3.1"}"#).unwrap();
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_plugins(&dir);
+    /// let plugins = version.plugins();
+    /// assert_eq!(plugins[0].binary, "export");
+    /// assert_eq!(plugins[0].outcome.as_ref().unwrap().version(), "0.3.1");
+    /// ```
+    #[must_use]
+    pub fn with_plugins(self, dir: impl Into<PathBuf>) -> Self {
+        self.with_plugin_source(PluginDir::new(dir))
+    }
+
+    /// Adds a source of plugins to the verbose version output
+    ///
+    /// Sources are queried concurrently; a plugin reported by several
+    /// sources is listed once, from the last source added.
+    #[must_use]
+    pub fn with_plugin_source(mut self, source: impl PluginSource + 'static) -> Self {
+        self.plugins
+            .get_or_insert_with(|| PluginScan {
+                sources: Vec::new(),
+                timeout: Duration::from_secs(2),
+            })
+            .sources
+            .push(Arc::new(source));
+        self
+    }
+
+    /// Sets how long each plugin may take to answer (default: 2 seconds)
+    ///
+    /// Has no effect without a plugin source.
+    #[must_use]
+    pub fn with_plugin_timeout(mut self, timeout: Duration) -> Self {
+        if let Some(scan) = &mut self.plugins {
+            scan.timeout = timeout;
+        }
+        self
+    }
+
+    /// Queries every plugin source and returns one entry per plugin, by name
+    #[must_use]
+    pub fn plugins(&self) -> Vec<SuiteEntry> {
+        let Some(scan) = &self.plugins else {
+            return Vec::new();
+        };
+        let found: Vec<_> = thread::scope(|scope| {
+            let queries: Vec<_> = scan
+                .sources
+                .iter()
+                .map(|source| scope.spawn(|| source.plugins(scan.timeout)))
+                .collect();
+            queries
+                .into_iter()
+                .flat_map(|query| query.join().unwrap_or_default())
+                .collect()
+        });
+        let by_name: BTreeMap<_, _> = found
+            .into_iter()
+            .map(|entry| (entry.binary.clone(), entry))
+            .collect();
+        by_name.into_values().collect()
+    }
+
+    /// Returns one detail line per discovered plugin
+    pub(crate) fn plugin_fields(&self) -> Vec<Field> {
+        self.plugins()
+            .into_iter()
+            .map(|entry| {
+                let (value, role) = match entry.outcome {
+                    Ok(plugin) => (plugin.version().to_string(), Role::Secondary),
+                    Err(reason) => (reason, Role::Error),
+                };
+                Field {
+                    key: format!("plugin {}", entry.binary).into(),
+                    value: value.into(),
+                    color: Some(self.role(role)),
+                    level: Verbosity::Verbose,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Discovers the plugins in `dir`, sidecars first, running the executables
+/// concurrently
+fn scan(dir: &Path, timeout: Duration) -> Vec<SuiteEntry> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut plugins: BTreeMap<String, SuiteEntry> = BTreeMap::new();
+    let mut executables = Vec::new();
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            let outcome = read_sidecar(&path, stem);
+            plugins.insert(
+                stem.to_string(),
+                SuiteEntry::new(stem, outcome).with_path(path.clone()),
+            );
+        } else if is_executable(&path) {
+            executables.push((stem.to_string(), path));
+        }
+    }
+    executables.retain(|(name, _)| !plugins.contains_key(name));
+
+    let queried: Vec<_> = thread::scope(|scope| {
+        let queries: Vec<_> = executables
+            .into_iter()
+            .map(|(name, path)| {
+                scope.spawn(move || {
+                    let outcome = suite::query(&path, &["--version".into()], timeout)
+                        .map(|output| plugin_version(&name, &output));
+                    SuiteEntry::new(name, outcome).with_path(path)
+                })
+            })
+            .collect();
+        queries
+            .into_iter()
+            .filter_map(|query| query.join().ok())
+            .collect()
+    });
+    for entry in queried {
+        plugins.insert(entry.binary.clone(), entry);
+    }
+    plugins.into_values().collect()
+}
+
+/// Reads a sidecar JSON file declaring a plugin's version
+fn read_sidecar(path: &Path, name: &str) -> Result<ColorfulVersion, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    match serde_json::from_str::<serde_json::Value>(&contents) {
+        Ok(serde_json::Value::Object(_)) => Ok(suite::parse_output(name, &contents)),
+        _ => Err("invalid sidecar".to_string()),
+    }
+}
+
+/// Interprets `--version` output, dropping a leading plugin name
+fn plugin_version(name: &str, output: &str) -> ColorfulVersion {
+    let parsed = suite::parse_output(name, output);
+    let Some(version) = parsed.version().strip_prefix(name) else {
+        return parsed;
+    };
+    ColorfulVersion::new(
+        parsed.package_name(),
+        version.trim_start().trim_start_matches('v'),
+        parsed.author(),
+    )
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+        && path.extension().is_some_and(|extension| {
+            extension.eq_ignore_ascii_case(std::env::consts::EXE_EXTENSION)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "clap-version-flag-plugins-{}-{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_plugin_version() {
+        assert_eq!(plugin_version("fmt", "fmt v1.4.0\n").version(), "1.4.0");
+        assert_eq!(plugin_version("fmt", "2.0.0").version(), "2.0.0");
+        assert_eq!(
+            plugin_version("fmt", r#"{"name":"fmt","version":"3.1.0"}"#).version(),
+            "3.1.0"
+        );
+    }
+
+    #[test]
+    fn test_sidecars_and_fields() {
+        let dir = plugin_dir("sidecars");
+        std::fs::write(dir.join("lint.json"), r#"{"version":"0.2.0"}"#).unwrap();
+        std::fs::write(dir.join("broken.json"), "not json").unwrap();
+        std::fs::write(dir.join("README.txt"), "ignored").unwrap();
+
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_plugins(&dir);
+        let fields = version.plugin_fields();
+        let lines: Vec<_> = fields
+            .iter()
+            .map(|field| format!("{}: {}", field.key, field.value))
+            .collect();
+        assert_eq!(
+            lines,
+            ["plugin broken: invalid sidecar", "plugin lint: 0.2.0"]
+        );
+        assert_eq!(fields[0].color, Some((255, 0, 0)));
+
+        assert_eq!(
+            version.render(crate::VersionFormat::Plain),
+            "app v1.0.0 by Jane"
+        );
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_executable_plugin() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = plugin_dir("executables");
+        let script = dir.join("sync");
+        std::fs::write(&script, "#!/bin/sh\necho 'sync 1.7.2'\n").unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        // a sidecar takes precedence over running the executable
+        let shadowed = dir.join("fast");
+        std::fs::write(&shadowed, "#!/bin/sh\nexit 1\n").unwrap();
+        std::fs::set_permissions(&shadowed, std::fs::Permissions::from_mode(0o755)).unwrap();
+        std::fs::write(dir.join("fast.json"), r#"{"version":"9.0.0"}"#).unwrap();
+
+        let plugins = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_plugins(&dir)
+            .plugins();
+        let versions: Vec<_> = plugins
+            .iter()
+            .map(|entry| {
+                (
+                    entry.binary.as_str(),
+                    entry.outcome.as_ref().unwrap().version(),
+                )
+            })
+            .collect();
+        assert_eq!(versions, [("fast", "9.0.0"), ("sync", "1.7.2")]);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_executables_run_concurrently() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = plugin_dir("concurrent");
+        for name in ["one", "two", "three", "four"] {
+            let script = dir.join(name);
+            std::fs::write(
+                &script,
+                format!("#!/bin/sh\nsleep 0.4\necho '{name} 1.0.0'\n"),
+            )
+            .unwrap();
+            std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let started = std::time::Instant::now();
+        let plugins = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_plugins(&dir)
+            .plugins();
+        assert_eq!(plugins.len(), 4);
+        assert!(plugins.iter().all(|entry| entry.outcome.is_ok()));
+        // one after another they would take 1.6 seconds at least
+        assert!(started.elapsed() < Duration::from_millis(1400));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_sources_merge_by_name() {
+        struct Fixed(&'static str, &'static str);
+
+        impl PluginSource for Fixed {
+            fn plugins(&self, _timeout: Duration) -> Vec<SuiteEntry> {
+                let version = ColorfulVersion::new(self.0, self.1, "");
+                vec![SuiteEntry::new(self.0, Ok(version))]
+            }
+        }
+
+        let plugins = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_plugin_source(Fixed("sync", "1.0.0"))
+            .with_plugin_source(Fixed("export", "0.3.1"))
+            .with_plugin_source(Fixed("sync", "2.0.0"))
+            .plugins();
+        let versions: Vec<_> = plugins
+            .iter()
+            .map(|entry| {
+                (
+                    entry.binary.as_str(),
+                    entry.outcome.as_ref().unwrap().version(),
+                )
+            })
+            .collect();
+        assert_eq!(versions, [("export", "0.3.1"), ("sync", "2.0.0")]);
+    }
+
+    #[test]
+    fn test_missing_directory() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_plugins("/surely/no/such/plugins/dir")
+            .with_plugin_timeout(Duration::from_millis(10));
+        assert!(version.plugins().is_empty());
+    }
+}
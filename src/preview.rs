@@ -0,0 +1,131 @@
+// Project: clap-version-flag
+// File: src/preview.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Theme preview for picking a theme
+// License: MIT
+
+//! Theme preview.
+//!
+//! Picking a theme by editing code and rerunning `--version` gets old fast.
+//! [`run`] prints the version line once per built-in theme, names aligned,
+//! so they can be compared at a glance, e.g. from a hidden subcommand:
+//!
+//! ```no_run
+//! use clap_version_flag::{colorful_version, preview};
+//!
+//! if std::env::args().any(|arg| arg == "theme-preview") {
+//!     preview::run(&colorful_version!());
+//! }
+//! ```
+//!
+//! Requires the `preview` feature.
+
+use crate::{Accessibility, ColorfulVersion, Theme};
+
+/// Returns the built-in themes with their names
+///
+/// The same list as [`Theme::built_in`].
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{Theme, preview};
+///
+/// assert_eq!(preview::themes()[0], ("classic", Theme::default()));
+/// ```
+#[must_use]
+pub fn themes() -> [(&'static str, Theme); 5] {
+    Theme::built_in()
+}
+
+/// Returns the version line in its own theme, then in every built-in one
+///
+/// Each line starts with the theme name. Whether and how deep colors are
+/// drawn follows the version's color settings, as in
+/// [`ColorfulVersion::to_colored_string`]. Each built-in row is painted in
+/// its own theme alone: the environment overrides, an adaptive theme, high
+/// contrast and the minimum contrast apply to the current row only, since
+/// they would repaint every row alike.
+///
+/// # Examples
+/// ```
+/// use clap::ColorChoice;
+/// use clap_version_flag::{ColorfulVersion, preview};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_color_choice(ColorChoice::Never);
+/// assert_eq!(
+///     preview::render(&version),
+///     "current        myapp v1.0.0 by John Doe\n\
+///      classic        myapp v1.0.0 by John Doe\n\
+///      high-contrast  myapp v1.0.0 by John Doe\n\
+///      solarized      myapp v1.0.0 by John Doe\n\
+///      dracula        myapp v1.0.0 by John Doe\n\
+///      monochrome     myapp v1.0.0 by John Doe"
+/// );
+/// ```
+#[must_use]
+pub fn render(version: &ColorfulVersion) -> String {
+    let themes = themes();
+    let width = themes.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    let current = ("current", version.clone());
+    let rows = themes
+        .into_iter()
+        .map(|(name, theme)| (name, themed(version, theme)));
+    [current]
+        .into_iter()
+        .chain(rows)
+        .map(|(name, version)| format!("{name:<width$}  {}", version.to_colored_string()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns `version` painted in `theme` alone
+fn themed(version: &ColorfulVersion, theme: Theme) -> ColorfulVersion {
+    let mut row = version
+        .clone()
+        .with_theme(theme)
+        .with_accessibility(Accessibility::new());
+    row.env_prefix = None;
+    row.adaptive_theme = None;
+    row.min_contrast = None;
+    row
+}
+
+/// Prints [`render`] to stdout
+pub fn run(version: &ColorfulVersion) {
+    crate::color_choice::print_line(&render(version));
+}
+
+#[cfg(all(test, not(feature = "no-color")))]
+mod tests {
+    use super::*;
+    use crate::{ColorDepth, Role};
+    use clap::ColorChoice;
+
+    #[test]
+    fn test_each_theme_is_painted() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_color_choice(ColorChoice::Always)
+            .with_color_depth(ColorDepth::TrueColor);
+        let plain = version.clone().with_accessibility(Accessibility::new());
+        let overridden = version
+            .with_accessibility(Accessibility::new().high_contrast(true))
+            .with_adaptive_theme(Theme::dracula(), Theme::solarized())
+            .with_min_contrast(7.0);
+        for version in [plain, overridden] {
+            let preview = render(&version);
+            assert_eq!(preview.lines().count(), themes().len() + 1);
+            for ((_, theme), line) in themes().iter().zip(preview.lines().skip(1)) {
+                let expected = ColorfulVersion::new("app", "1.0", "Jo")
+                    .with_color_choice(ColorChoice::Always)
+                    .with_color_depth(ColorDepth::TrueColor)
+                    .with_accessibility(Accessibility::new())
+                    .with_theme(*theme);
+                let (r, g, b) = theme.role(Role::Primary);
+                assert!(line.contains(&format!("48;2;{r};{g};{b}m")), "{line:?}");
+                assert!(line.ends_with(&expected.to_colored_string()), "{line:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+// Project: clap-version-flag
+// File: src/rainbow.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Rainbow coloring and the animated splash
+// License: MIT
+
+//! Rainbow mode.
+//!
+//! [`ColorfulVersion::with_rainbow`] paints every character of the version
+//! line in its own hue, once around the color wheel from the first
+//! character to the last. [`ColorfulVersion::print_animated`] turns that
+//! into a short splash: the hues rotate along the line for a while before
+//! it settles. Animation needs a terminal and is skipped when stdout is not
+//! one, when colors are off or when the user asked for reduced motion.
+
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use crate::{ColorDepth, ColorfulVersion, color, grapheme};
+
+/// Time between two frames of the animation
+const FRAME: Duration = Duration::from_millis(50);
+
+/// Degrees the hues rotate per frame
+const HUE_STEP: f64 = 15.0;
+
+impl ColorfulVersion {
+    /// Colors each character of the version line with a rotating hue
+    ///
+    /// Replaces the segment colors of the colored line; spaces stay
+    /// uncolored and the plain output is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("app", "1.0", "Jo").with_rainbow();
+    /// assert!(version.is_rainbow());
+    /// assert_eq!(version.as_plain_string(), "app v1.0 by Jo");
+    /// ```
+    #[must_use]
+    pub fn with_rainbow(mut self) -> Self {
+        self.rainbow = true;
+        self
+    }
+
+    /// Returns whether the rainbow mode is on
+    #[must_use]
+    pub fn is_rainbow(&self) -> bool {
+        self.rainbow
+    }
+
+    /// Prints the version line with hues cycling along it for `duration`
+    ///
+    /// The line is redrawn in place and ends as
+    /// [`to_colored_string`](Self::to_colored_string) renders it. Falls back
+    /// to [`print`](Self::print) when stdout is not a terminal (or a legacy
+    /// Windows console), colors are off,
+    /// [`animations_enabled`](Self::animations_enabled) is false or the
+    /// output spans several lines.
+    pub fn print_animated(&self, duration: Duration) {
+        let line = self.as_plain_string();
+        #[cfg(all(windows, feature = "windows-console"))]
+        let legacy_console = crate::windows_console::is_legacy();
+        #[cfg(not(all(windows, feature = "windows-console")))]
+        let legacy_console = false;
+        if !self.colorize()
+            || !io::stdout().is_terminal()
+            || legacy_console
+            || !self.animations_enabled()
+            || line.contains('\n')
+        {
+            self.print();
+            return;
+        }
+        let depth = self.depth();
+        let mut stdout = io::stdout().lock();
+        let start = Instant::now();
+        let mut shift = 0.0;
+        while start.elapsed() < duration {
+            let _ = write!(stdout, "\r{}", self.rainbow_line(depth, shift));
+            let _ = stdout.flush();
+            std::thread::sleep(FRAME);
+            shift += HUE_STEP;
+        }
+        let _ = writeln!(stdout, "\r{}", self.to_colored_string());
+    }
+
+    /// Renders the plain line with one hue per character, starting at `shift` degrees
+    pub(crate) fn rainbow_line(&self, depth: ColorDepth, shift: f64) -> String {
+        let line = self.as_plain_string();
+        let step = 360.0 / grapheme::count(&line).max(1) as f64;
+        let mut out = String::with_capacity(line.len() * 20);
+        for (index, cluster) in grapheme::graphemes(&line).enumerate() {
+            if cluster.trim().is_empty() {
+                out.push_str(cluster);
+                continue;
+            }
+            let hue = shift + index as f64 * step;
+            let rgb = color::hsl_to_rgb(hue, 1.0, 0.5);
+            // writing to a String cannot fail
+            let _ = color::write_painted(&mut out, cluster, Some(rgb), None, &[], depth);
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hues_rotate() {
+        let version = ColorfulVersion::new("ab", "1", "c").with_rainbow();
+        let first = version.rainbow_line(ColorDepth::TrueColor, 0.0);
+        assert!(first.starts_with("\x1b[38;2;255;0;0ma\x1b[0m"));
+        assert!(first.contains(" \x1b["));
+        let next = version.rainbow_line(ColorDepth::TrueColor, HUE_STEP);
+        assert_ne!(first, next);
+        assert_eq!(color::strip_escapes(&first), version.as_plain_string());
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-color"))]
+    fn test_replaces_colored_line() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_rainbow()
+            .with_color_choice(clap::ColorChoice::Always)
+            .with_color_depth(ColorDepth::TrueColor);
+        assert_eq!(
+            version.to_colored_string(),
+            version.rainbow_line(ColorDepth::TrueColor, 0.0)
+        );
+        let mut buffer = String::new();
+        version.render_into(&mut buffer);
+        assert_eq!(buffer, version.to_colored_string());
+    }
+}
@@ -0,0 +1,84 @@
+// Project: clap-version-flag
+// File: src/release.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Release identifiers for error trackers
+// License: MIT
+
+//! Release identifiers.
+//!
+//! Error trackers group events by release, and Sentry, among others,
+//! expects it as `package@version+build`. [`ColorfulVersion::release_string`]
+//! builds that from the name, version and `commit` metadata, so the release
+//! reported at runtime matches the one uploaded from CI:
+//!
+//! ```ignore
+//! let _guard = sentry::init(sentry::ClientOptions {
+//!     release: version.sentry_release(),
+//!     ..Default::default()
+//! });
+//! ```
+
+use std::borrow::Cow;
+
+use crate::ColorfulVersion;
+
+impl ColorfulVersion {
+    /// Returns the release as `name@version+commit`
+    ///
+    /// The commit comes from the `commit` metadata and is left out when
+    /// unset. A version that already has build metadata gets the commit
+    /// appended to it, as in `1.0.0+linux.4f2a9c1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.2.3", "John Doe")
+    ///     .extra("commit", "4f2a9c1")
+    ///     .build();
+    /// assert_eq!(version.release_string(), "myapp@1.2.3+4f2a9c1");
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe");
+    /// assert_eq!(version.release_string(), "myapp@1.2.3");
+    /// ```
+    #[must_use]
+    pub fn release_string(&self) -> String {
+        let mut release = format!("{}@{}", self.package_name, self.version);
+        if let Some(commit) = self.metadata("commit").filter(|commit| !commit.is_empty()) {
+            release.push(if self.version.contains('+') { '.' } else { '+' });
+            release.push_str(commit);
+        }
+        release
+    }
+
+    /// Returns the release in the form `sentry::ClientOptions::release` takes
+    ///
+    /// The crate does not depend on `sentry`; the type matches the field, so
+    /// the value can be assigned directly.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe");
+    /// assert_eq!(version.sentry_release().as_deref(), Some("myapp@1.2.3"));
+    /// ```
+    #[must_use]
+    pub fn sentry_release(&self) -> Option<Cow<'static, str>> {
+        Some(Cow::Owned(self.release_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_joins_build_metadata() {
+        let version = ColorfulVersion::builder("app", "1.0.0+linux", "Jo")
+            .extra("commit", "abc1234")
+            .build();
+        assert_eq!(version.release_string(), "app@1.0.0+linux.abc1234");
+    }
+}
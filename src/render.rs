@@ -0,0 +1,362 @@
+// Project: clap-version-flag
+// File: src/render.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Pluggable renderers for VersionInfo
+// License: MIT
+
+//! Renderers.
+//!
+//! A [`VersionRenderer`] turns a [`VersionInfo`] into text. The crate ships
+//! [`AnsiRenderer`], [`PlainRenderer`], [`JsonRenderer`],
+//! [`MarkdownRenderer`](crate::MarkdownRenderer) and
+//! [`HtmlRenderer`](crate::HtmlRenderer); anything else, say a Slack
+//! message, is one `impl` away and reads the same data as the built-in
+//! output:
+//!
+//! ```
+//! use clap_version_flag::{ColorfulVersion, Verbosity, VersionInfo, VersionRenderer};
+//!
+//! struct Slack;
+//!
+//! impl VersionRenderer for Slack {
+//!     fn render(&self, info: &VersionInfo) -> String {
+//!         format!("*{}* {}", info.name, info.version)
+//!     }
+//! }
+//!
+//! let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+//! assert_eq!(version.render_with(&Slack, Verbosity::Normal), "*myapp* 1.0.0");
+//! ```
+//!
+//! The text output of [`ColorfulVersion::print`] and friends is drawn by
+//! [`AnsiRenderer`] and [`PlainRenderer`]: rendering a version with them
+//! gives exactly what `--version` prints, banners, templates and logos
+//! included. Bare [`VersionInfo`] is drawn with the same presentation.
+
+use crate::{ColorDepth, ColorfulVersion, Labels, Theme, Verbosity, VersionInfo, json};
+
+/// Turns version information into text
+pub trait VersionRenderer {
+    /// Renders `info`, without a trailing newline
+    fn render(&self, info: &VersionInfo) -> String;
+
+    /// Renders `version` at `verbosity`, without a trailing newline
+    ///
+    /// Renders the version's [`info`](ColorfulVersion::info) unless
+    /// overridden. The built-in renderers draw everything the version
+    /// configures instead, exactly as its text output does.
+    fn render_version(&self, version: &ColorfulVersion, verbosity: Verbosity) -> String {
+        self.render(&version.info(verbosity))
+    }
+}
+
+/// Returns the presentation to draw bare information with
+fn presentation(presentation: Option<&ColorfulVersion>) -> ColorfulVersion {
+    presentation
+        .cloned()
+        .unwrap_or_else(|| ColorfulVersion::new("", "", ""))
+}
+
+/// Renders uncolored text, as in the plain output
+///
+/// Bare information is drawn with the default presentation, or with the
+/// presentation of the version the renderer was made [from](From).
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{PlainRenderer, VersionInfo, VersionRenderer};
+///
+/// let info = VersionInfo::new("myapp", "1.0.0", "John Doe").with_field("license", "MIT");
+/// assert_eq!(
+///     PlainRenderer::new().render(&info),
+///     "myapp v1.0.0 by John Doe\nlicense: MIT"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PlainRenderer {
+    presentation: Option<Box<ColorfulVersion>>,
+}
+
+impl PlainRenderer {
+    /// Creates a renderer drawing each version as configured
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the "v" and "by" labels
+    #[must_use]
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        let presentation = presentation(self.presentation.as_deref()).with_labels(labels);
+        self.presentation = Some(Box::new(presentation));
+        self
+    }
+}
+
+impl From<&ColorfulVersion> for PlainRenderer {
+    /// Draws everything with the presentation of `version`
+    fn from(version: &ColorfulVersion) -> Self {
+        Self {
+            presentation: Some(Box::new(version.clone())),
+        }
+    }
+}
+
+impl VersionRenderer for PlainRenderer {
+    fn render(&self, info: &VersionInfo) -> String {
+        presentation(self.presentation.as_deref())
+            .presenting(info)
+            .render_at(Verbosity::Normal, None)
+    }
+
+    fn render_version(&self, version: &ColorfulVersion, verbosity: Verbosity) -> String {
+        match &self.presentation {
+            None => version.render_at(verbosity, None),
+            Some(presentation) => presentation
+                .as_ref()
+                .clone()
+                .presenting(&version.info(verbosity))
+                .render_at(verbosity, None),
+        }
+    }
+}
+
+/// Renders text colored with ANSI escape codes
+///
+/// The escape codes are emitted unconditionally; whether the destination
+/// shows them is up to the caller. The colors are the ones the version
+/// output resolves: its theme and [`Style`](crate::Style)s, the
+/// environment overrides, the minimum contrast and high contrast.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{AnsiRenderer, ColorDepth, VersionInfo, VersionRenderer};
+///
+/// let renderer = AnsiRenderer::new().with_color_depth(ColorDepth::TrueColor);
+/// let out = renderer.render(&VersionInfo::new("myapp", "1.0.0", "John Doe"));
+/// assert!(out.starts_with("\x1b[38;2;255;255;255;48;2;170;0;255mmyapp"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AnsiRenderer {
+    presentation: Option<Box<ColorfulVersion>>,
+    depth: Option<ColorDepth>,
+}
+
+impl AnsiRenderer {
+    /// Creates a renderer drawing each version as configured, at its color
+    /// depth
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the "v" and "by" labels
+    #[must_use]
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        let presentation = presentation(self.presentation.as_deref()).with_labels(labels);
+        self.presentation = Some(Box::new(presentation));
+        self
+    }
+
+    /// Colors the output with `theme`
+    #[must_use]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        let presentation = presentation(self.presentation.as_deref()).with_theme(theme);
+        self.presentation = Some(Box::new(presentation));
+        self
+    }
+
+    /// Forces truecolor, 256-color or 16-color output
+    #[must_use]
+    pub fn with_color_depth(mut self, depth: ColorDepth) -> Self {
+        self.depth = Some(depth);
+        self
+    }
+
+    /// Renders with `presentation` at the configured depth, or its own
+    fn draw(&self, presentation: &ColorfulVersion, verbosity: Verbosity) -> String {
+        let depth = self.depth.unwrap_or_else(|| presentation.depth());
+        presentation.render_at(verbosity, Some(depth))
+    }
+}
+
+impl From<&ColorfulVersion> for AnsiRenderer {
+    /// Draws everything with the presentation and color depth of `version`
+    fn from(version: &ColorfulVersion) -> Self {
+        Self {
+            presentation: Some(Box::new(version.clone())),
+            depth: None,
+        }
+    }
+}
+
+impl VersionRenderer for AnsiRenderer {
+    fn render(&self, info: &VersionInfo) -> String {
+        let presented = presentation(self.presentation.as_deref()).presenting(info);
+        self.draw(&presented, Verbosity::Normal)
+    }
+
+    fn render_version(&self, version: &ColorfulVersion, verbosity: Verbosity) -> String {
+        match &self.presentation {
+            None => self.draw(version, verbosity),
+            Some(presentation) => {
+                let presented = presentation
+                    .as_ref()
+                    .clone()
+                    .presenting(&version.info(verbosity));
+                self.draw(&presented, verbosity)
+            }
+        }
+    }
+}
+
+/// Renders the versioned JSON object of [`ColorfulVersion::to_json`]
+///
+/// The detail lines fill `metadata`; a `components` object, with `null`
+/// for unavailable versions, is added when there are components.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{JsonRenderer, VersionInfo, VersionRenderer};
+///
+/// let info = VersionInfo::new("myapp", "1.0.0", "John Doe").with_field("license", "MIT");
+/// assert_eq!(
+///     JsonRenderer.render(&info),
+///     r#"{"schema_version":1,"name":"myapp","version":"1.0.0","author":"John Doe","metadata":{"license":"MIT"}}"#
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JsonRenderer;
+
+impl VersionRenderer for JsonRenderer {
+    fn render(&self, info: &VersionInfo) -> String {
+        let metadata = info
+            .fields
+            .iter()
+            .fold(json::JsonObject::new(), |object, (key, value)| {
+                object.string(key, value)
+            });
+        let object = json::JsonObject::new()
+            .schema_version()
+            .string("name", &info.name)
+            .string("version", &info.version)
+            .string("author", &info.author)
+            .raw("metadata", &metadata.finish());
+        if info.components.is_empty() {
+            return object.finish();
+        }
+        let components = info
+            .components
+            .iter()
+            .fold(json::JsonObject::new(), |object, (name, version)| {
+                object.optional_string(name, version.as_deref())
+            });
+        object.raw("components", &components.finish()).finish()
+    }
+}
+
+impl ColorfulVersion {
+    /// Renders the information shown at `verbosity` with `renderer`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, PlainRenderer, Verbosity};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// assert_eq!(
+    ///     version.render_with(&PlainRenderer::new(), Verbosity::Normal),
+    ///     "myapp v1.0.0 by John Doe"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn render_with(&self, renderer: &impl VersionRenderer, verbosity: Verbosity) -> String {
+        renderer.render_version(self, verbosity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ComponentVersions;
+
+    fn version() -> ColorfulVersion {
+        ColorfulVersion::new("app", "1.0", "Jo")
+            .with_license("MIT")
+            .with_components(
+                ComponentVersions::new()
+                    .add_component("core", "2")
+                    .add_component_fn("server", || None),
+            )
+            .with_color_depth(ColorDepth::TrueColor)
+    }
+
+    #[test]
+    fn test_plain_matches_builtin_output() {
+        let version = version();
+        for verbosity in [Verbosity::Normal, Verbosity::Verbose] {
+            assert_eq!(
+                version.render_with(&PlainRenderer::new(), verbosity),
+                version.render_plain(verbosity)
+            );
+        }
+    }
+
+    #[test]
+    fn test_ansi_matches_builtin_output() {
+        let version = version()
+            .with_locale(crate::Locale::De)
+            .with_styles(crate::Styles::new().version(crate::Style::new().bold()))
+            .with_min_contrast(4.5)
+            .with_accessibility(crate::Accessibility::new().high_contrast(true));
+        for verbosity in [Verbosity::Normal, Verbosity::Verbose] {
+            let expected = version.render_text(verbosity, true);
+            assert_eq!(
+                version.render_with(&AnsiRenderer::new(), verbosity),
+                expected
+            );
+            assert_eq!(
+                version.render_with(&AnsiRenderer::from(&version), verbosity),
+                expected
+            );
+        }
+        assert_eq!(
+            AnsiRenderer::from(&version).render(&version.info(Verbosity::Normal)),
+            version.render_text(Verbosity::Normal, true)
+        );
+    }
+
+    #[test]
+    fn test_info_drawn_with_presentation() {
+        let info = VersionInfo::new("tool", "2.0", "Al")
+            .with_field("license", "MIT")
+            .with_component("core", None);
+        let version = version();
+        assert_eq!(
+            PlainRenderer::from(&version).render(&info),
+            "tool v2.0 by Al\nlicense: MIT\ncomponents:\n  core   unavailable"
+        );
+        let themed = AnsiRenderer::new()
+            .with_theme(Theme::new().accent((1, 2, 3)))
+            .with_color_depth(ColorDepth::TrueColor)
+            .render(&info);
+        assert!(
+            themed.contains("\x1b[38;2;1;2;3m v2.0\x1b[0m"),
+            "{themed:?}"
+        );
+    }
+
+    #[test]
+    fn test_json_components() {
+        let json = version().render_with(&JsonRenderer, Verbosity::Normal);
+        assert!(
+            json.ends_with(
+                r#""metadata":{"license":"MIT"},"components":{"core":"2","server":null}}"#
+            )
+        );
+        assert_eq!(
+            ColorfulVersion::new("app", "1.0", "Jo").render_with(&JsonRenderer, Verbosity::Normal),
+            ColorfulVersion::new("app", "1.0", "Jo").to_json()
+        );
+    }
+}
@@ -0,0 +1,70 @@
+// Project: clap-version-flag
+// File: src/schema.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: JSON Schema of the JSON version output
+// License: MIT
+
+//! JSON Schema of the version output.
+//!
+//! [`ColorfulVersion::json_schema`] describes the object printed by
+//! `--version --version-format json` as a JSON Schema (draft 2020-12), so
+//! downstream tooling can validate it instead of hard-coding its keys.
+//! New keys may appear within one schema version, so the schema allows
+//! properties it does not list.
+
+use crate::ColorfulVersion;
+use crate::json::{self, JsonObject};
+
+/// The JSON Schema dialect of [`ColorfulVersion::json_schema`]
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns a `{"type": ...}` schema
+fn typed(kind: &str) -> String {
+    JsonObject::new().string("type", kind).finish()
+}
+
+impl ColorfulVersion {
+    /// Returns the JSON Schema of [`to_json`](Self::to_json)
+    ///
+    /// `schema_version` is pinned to [`SCHEMA_VERSION`](Self::SCHEMA_VERSION);
+    /// the other required keys are typed, and unlisted keys are allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let schema = ColorfulVersion::new("myapp", "1.0.0", "John Doe").json_schema();
+    /// assert!(schema.starts_with(r#"{"$schema":"https://json-schema.org/draft/2020-12/schema""#));
+    /// assert!(schema.contains(r#""required":["schema_version","name","version","author","metadata"]"#));
+    /// ```
+    #[must_use]
+    pub fn json_schema(&self) -> String {
+        let schema_version = JsonObject::new()
+            .string("type", "integer")
+            .raw("const", &json::SCHEMA_VERSION.to_string())
+            .finish();
+        let metadata = JsonObject::new()
+            .string("type", "object")
+            .raw("additionalProperties", &typed("string"))
+            .finish();
+        let properties = JsonObject::new()
+            .raw("schema_version", &schema_version)
+            .raw("name", &typed("string"))
+            .raw("version", &typed("string"))
+            .raw("author", &typed("string"))
+            .raw("metadata", &metadata)
+            .finish();
+        JsonObject::new()
+            .string("$schema", DIALECT)
+            .string("title", &format!("{} version", self.package_name))
+            .string("type", "object")
+            .string_array(
+                "required",
+                ["schema_version", "name", "version", "author", "metadata"],
+            )
+            .raw("properties", &properties)
+            .raw("additionalProperties", "true")
+            .finish()
+    }
+}
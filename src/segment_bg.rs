@@ -0,0 +1,171 @@
+// Project: clap-version-flag
+// File: src/segment_bg.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Backgrounds of the version and author segments
+// License: MIT
+
+//! Segment backgrounds.
+//!
+//! The name is drawn as a badge on its own background; the version and
+//! author sit on the terminal background by default.
+//! [`ColorfulVersion::with_version_bg`] and
+//! [`ColorfulVersion::with_author_bg`] give them a background too, either a
+//! color of their own or the badge's, so the badge can stretch over the
+//! whole line.
+
+use crate::color::Rgb;
+use crate::{ColorSpec, ColorfulVersion, Colors};
+
+/// The background of the version or author segment
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, SegmentBackground};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_version_bg(SegmentBackground::Inherit)
+///     .with_author_bg((40, 40, 40));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SegmentBackground {
+    /// The terminal background (default)
+    #[default]
+    None,
+    /// The background of the name badge
+    Inherit,
+    /// A background of its own
+    Color(Rgb),
+}
+
+impl SegmentBackground {
+    /// Returns the color to paint, given the name badge background
+    pub(crate) const fn resolve(self, name_bg: Rgb) -> Option<Rgb> {
+        match self {
+            Self::None => None,
+            Self::Inherit => Some(name_bg),
+            Self::Color(rgb) => Some(rgb),
+        }
+    }
+}
+
+impl From<Rgb> for SegmentBackground {
+    fn from(rgb: Rgb) -> Self {
+        Self::Color(rgb)
+    }
+}
+
+impl From<ColorSpec> for SegmentBackground {
+    fn from(color: ColorSpec) -> Self {
+        Self::Color(color.rgb())
+    }
+}
+
+impl ColorfulVersion {
+    /// Sets the background of the version segment
+    ///
+    /// A [`Style`](crate::Style) background on the version still wins.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::ColorChoice;
+    /// use clap_version_flag::{ColorDepth, ColorfulVersion, SegmentBackground};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_version_bg(SegmentBackground::Inherit)
+    ///     .with_color_choice(ColorChoice::Never);
+    /// assert_eq!(version.to_colored_string(), "myapp v1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn with_version_bg(mut self, background: impl Into<SegmentBackground>) -> Self {
+        self.colors.version_bg = background.into();
+        self
+    }
+
+    /// Sets the background of the author segment
+    ///
+    /// A [`Style`](crate::Style) background on the author still wins.
+    #[must_use]
+    pub fn with_author_bg(mut self, background: impl Into<SegmentBackground>) -> Self {
+        self.colors.author_bg = background.into();
+        self
+    }
+
+    /// Returns the background to paint the version segment on
+    pub(crate) fn version_background(&self) -> Option<Rgb> {
+        let palette = self.palette();
+        palette.version_bg.resolve(palette.name_bg)
+    }
+
+    /// Returns the background to paint the author segment on
+    pub(crate) fn author_background(&self) -> Option<Rgb> {
+        let palette = self.palette();
+        palette.author_bg.resolve(palette.name_bg)
+    }
+}
+
+impl Colors {
+    /// Returns these colors with the segment backgrounds and the separator
+    /// color of `configured`
+    ///
+    /// A [`Theme`](crate::Theme) has no role for them, so colors converted
+    /// from one keep those the version was configured with.
+    pub(crate) fn with_unthemed_of(self, configured: &Colors) -> Self {
+        Self {
+            version_bg: configured.version_bg,
+            author_bg: configured.author_bg,
+            separator_color: configured.separator_color,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Accessibility, Background, ColorDepth, Theme};
+
+    #[test]
+    fn test_backgrounds_follow_badge() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_version_bg(SegmentBackground::Inherit)
+            .with_author_bg((1, 2, 3));
+        assert_eq!(version.version_background(), Some((170, 0, 255)));
+        assert_eq!(version.author_background(), Some((1, 2, 3)));
+        let line = version.colored_line(ColorDepth::TrueColor);
+        assert!(line.contains(";48;2;170;0;255m v1.0"));
+        assert!(line.contains(";48;2;1;2;3m by Jo"));
+
+        let themed = version.with_theme(Theme::new().primary((9, 9, 9)));
+        assert_eq!(themed.version_background(), Some((9, 9, 9)));
+        assert_eq!(themed.author_background(), Some((1, 2, 3)));
+        assert_eq!(
+            ColorfulVersion::new("app", "1.0", "Jo").version_background(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_backgrounds_kept_by_adaptive_theme() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_version_bg(SegmentBackground::Inherit)
+            .with_author_bg((1, 2, 3))
+            .with_adaptive_theme(Theme::new(), Theme::new().primary((9, 9, 9)))
+            .with_background(Background::Dark);
+        assert_eq!(version.version_background(), Some((9, 9, 9)));
+        assert_eq!(version.author_background(), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn test_backgrounds_kept_by_high_contrast() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_version_bg(SegmentBackground::Inherit)
+            .with_author_bg((1, 2, 3))
+            .with_accessibility(Accessibility::new().high_contrast(true));
+        assert_eq!(version.version_background(), Some((0, 0, 0)));
+        assert_eq!(version.author_background(), Some((1, 2, 3)));
+        let line = version.colored_line(ColorDepth::TrueColor);
+        assert!(line.contains(";48;2;1;2;3m by Jo"), "{line:?}");
+    }
+}
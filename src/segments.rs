@@ -0,0 +1,112 @@
+// Project: clap-version-flag
+// File: src/segments.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Leaving segments out of the version line
+// License: MIT
+
+//! Optional segments.
+//!
+//! The version line is `name v1.0.0 by author` unless told otherwise. Apps
+//! without a meaningful author call [`ColorfulVersion::hide_author`], apps
+//! that print their own banner [`ColorfulVersion::hide_name`], and
+//! [`ColorfulVersion::without_v_prefix`] drops the "v". The JSON output
+//! and [`ColorfulVersion::info`] keep every field.
+
+use crate::ColorfulVersion;
+
+impl ColorfulVersion {
+    /// Leaves the author segment out of the version line
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").hide_author();
+    /// assert_eq!(version.as_plain_string(), "myapp v1.0.0");
+    /// ```
+    #[must_use]
+    pub fn hide_author(mut self) -> Self {
+        self.hide_author = true;
+        self
+    }
+
+    /// Leaves the name segment out of the version line
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").hide_name();
+    /// assert_eq!(version.as_plain_string(), "v1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn hide_name(mut self) -> Self {
+        self.hide_name = true;
+        self
+    }
+
+    /// Shows the version number without the "v" prefix
+    ///
+    /// Clears the prefix of the current [labels](Self::labels); a locale
+    /// set afterwards brings its own back.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").without_v_prefix();
+    /// assert_eq!(version.as_plain_string(), "myapp 1.0.0 by John Doe");
+    /// ```
+    #[must_use]
+    pub fn without_v_prefix(mut self) -> Self {
+        self.labels_mut().version_prefix.clear();
+        self
+    }
+
+    /// Returns the segments still shown when some are hidden or the
+    /// [separators](Self::with_separators) are set, `None` otherwise
+    pub(crate) fn shown_segments(&self) -> Option<Vec<String>> {
+        if !self.hide_name && !self.hide_author && self.separators.is_none() {
+            return None;
+        }
+        let shown = [
+            ("name", !self.hide_name),
+            ("version", true),
+            ("author", !self.hide_author),
+        ];
+        Some(
+            shown
+                .into_iter()
+                .filter(|(_, shown)| *shown)
+                .map(|(key, _)| key.to_string())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AuthorDisplay, ColorDepth, Verbosity, color};
+
+    #[test]
+    fn test_hidden_everywhere() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_authors(["Jo", "Al"])
+            .with_author_display(AuthorDisplay::PerLine)
+            .hide_author()
+            .hide_name()
+            .with_license("MIT");
+        assert_eq!(version.as_plain_string(), "v1.0");
+        assert_eq!(
+            color::strip_escapes(&version.colored_line(ColorDepth::TrueColor)),
+            "v1.0"
+        );
+        assert_eq!(
+            version.render_plain(Verbosity::Normal),
+            "v1.0\nlicense: MIT"
+        );
+        assert_eq!(version.info(Verbosity::Normal).author, "Jo");
+    }
+}
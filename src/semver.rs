@@ -0,0 +1,335 @@
+// Project: clap-version-flag
+// File: src/semver.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Semantic version parsing and precedence
+// License: MIT
+
+//! Semantic versions.
+//!
+//! [`Version`] splits a [SemVer 2.0](https://semver.org) string into its
+//! major, minor and patch numbers, pre-release and build metadata, and
+//! orders versions by SemVer precedence. [`ColorfulVersion::semver`] parses
+//! the configured version, and [`Styles`](crate::Styles) can color each
+//! component of it on its own, e.g. a red pre-release and dimmed build
+//! metadata.
+
+use crate::{ColorfulVersion, VersionError};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed `MAJOR.MINOR.PATCH[-PRE][+BUILD]` version
+///
+/// Versions compare by SemVer precedence: numbers first, then a release
+/// sorts after its pre-releases, whose dot-separated identifiers compare
+/// numerically or lexically. Build metadata does not affect precedence and
+/// only breaks ties, so that ordering stays consistent with equality;
+/// [`cmp_precedence`](Self::cmp_precedence) compares by precedence alone.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::semver::Version;
+///
+/// let version: Version = "2.1.0-rc.1+sha.5114f85".parse().unwrap();
+/// assert_eq!((version.major, version.minor, version.patch), (2, 1, 0));
+/// assert_eq!(version.pre, "rc.1");
+/// assert_eq!(version.build, "sha.5114f85");
+///
+/// assert!(version < "2.1.0".parse().unwrap());
+/// assert!("1.0.0-alpha.10".parse::<Version>().unwrap() > "1.0.0-alpha.9".parse().unwrap());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Version {
+    /// Incremented for incompatible changes
+    pub major: u64,
+    /// Incremented for compatible additions
+    pub minor: u64,
+    /// Incremented for compatible fixes
+    pub patch: u64,
+    /// Pre-release identifiers without the leading `-`, empty for a release
+    pub pre: String,
+    /// Build metadata without the leading `+`, empty if absent
+    pub build: String,
+}
+
+impl Version {
+    /// Creates a release version without pre-release or build metadata
+    #[must_use]
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre: String::new(),
+            build: String::new(),
+        }
+    }
+
+    /// Parses a SemVer 2.0 string
+    ///
+    /// # Errors
+    /// Returns [`VersionError::InvalidVersion`] if `text` is not a valid
+    /// semantic version, e.g. `1.2`, `01.2.3` or `1.2.3-`.
+    pub fn parse(text: &str) -> Result<Self, VersionError> {
+        let invalid = || VersionError::InvalidVersion(text.to_string());
+        let (rest, build) = match text.split_once('+') {
+            Some((rest, build)) => (rest, identifiers(build, false).ok_or_else(invalid)?),
+            None => (text, ""),
+        };
+        let (core, pre) = match rest.split_once('-') {
+            Some((core, pre)) => (core, identifiers(pre, true).ok_or_else(invalid)?),
+            None => (rest, ""),
+        };
+
+        let mut numbers = core.split('.').map(number);
+        let (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) = (
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+        ) else {
+            return Err(invalid());
+        };
+        Ok(Self {
+            major,
+            minor,
+            patch,
+            pre: pre.to_string(),
+            build: build.to_string(),
+        })
+    }
+
+    /// Compares by SemVer precedence alone, ignoring build metadata
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::semver::Version;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a: Version = "1.0.0+build.1".parse().unwrap();
+    /// let b: Version = "1.0.0+build.2".parse().unwrap();
+    /// assert_eq!(a.cmp_precedence(&b), Ordering::Equal);
+    /// assert!(a < b);
+    /// ```
+    #[must_use]
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| compare_pre(&self.pre, &other.pre))
+    }
+
+    /// Returns whether this is a pre-release
+    #[must_use]
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+/// Parses a numeric identifier: digits without a leading zero
+fn number(text: &str) -> Option<u64> {
+    let valid = !text.is_empty()
+        && text.bytes().all(|b| b.is_ascii_digit())
+        && (text == "0" || !text.starts_with('0'));
+    valid.then(|| text.parse().ok()).flatten()
+}
+
+/// Validates dot-separated identifiers; numeric pre-release identifiers
+/// must not have leading zeros
+fn identifiers(text: &str, pre_release: bool) -> Option<&str> {
+    text.split('.')
+        .all(|identifier| {
+            !identifier.is_empty()
+                && identifier
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                && !(pre_release
+                    && identifier.bytes().all(|b| b.is_ascii_digit())
+                    && number(identifier).is_none())
+        })
+        .then_some(text)
+}
+
+/// Compares pre-release strings by SemVer precedence
+fn compare_pre(a: &str, b: &str) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+    loop {
+        let ordering = match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                // numeric identifiers sort before alphanumeric ones
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => x.cmp(y),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Version {
+    type Err = VersionError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre)?;
+        }
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build)?;
+        }
+        Ok(())
+    }
+}
+
+impl ColorfulVersion {
+    /// Returns the version parsed as a semantic version
+    ///
+    /// Returns `None` if the version string is not valid SemVer 2.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.4.0-beta.2", "John Doe");
+    /// let semver = version.semver().unwrap();
+    /// assert_eq!(semver.minor, 4);
+    /// assert!(semver.is_prerelease());
+    ///
+    /// assert!(ColorfulVersion::new("myapp", "2024.1", "John Doe").semver().is_none());
+    /// ```
+    #[must_use]
+    pub fn semver(&self) -> Option<Version> {
+        Version::parse(&self.version).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Option<Version> {
+        Version::parse(text).ok()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for text in [
+            "0.0.0",
+            "1.2.3",
+            "1.0.0-alpha.1",
+            "1.0.0+20130313144700",
+            "1.0.0-x-y.7z.92+exp.sha.5114f85",
+        ] {
+            assert_eq!(parse(text).unwrap().to_string(), text);
+        }
+        let version = parse("1.0.0-beta+exp.sha").unwrap();
+        assert_eq!(version.pre, "beta");
+        assert_eq!(version.build, "exp.sha");
+    }
+
+    #[test]
+    fn test_rejects_invalid() {
+        for text in [
+            "",
+            "1.2",
+            "1.2.3.4",
+            "v1.2.3",
+            "01.2.3",
+            "1.2.3-",
+            "1.2.3-01",
+            "1.2.3+",
+            "1.2.3-a..b",
+            "1.2.x",
+            "1.2.3-ß",
+        ] {
+            assert!(parse(text).is_none(), "{text} should be rejected");
+        }
+        assert!(matches!(
+            Version::parse("1.2"),
+            Err(VersionError::InvalidVersion(text)) if text == "1.2"
+        ));
+    }
+
+    #[test]
+    fn test_precedence() {
+        // the example chain from the SemVer specification
+        let chain = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "2.0.0",
+        ]
+        .map(|text| parse(text).unwrap());
+        assert!(chain.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(compare_pre("", ""), Ordering::Equal);
+        assert_ne!(parse("1.0.0+a"), parse("1.0.0+b"));
+        assert_eq!(
+            parse("1.0.0+a")
+                .unwrap()
+                .cmp_precedence(&parse("1.0.0+b").unwrap()),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_component_styles() {
+        let styles = crate::Styles::new()
+            .pre_release(crate::Style::new().fg((255, 0, 0)))
+            .build(crate::Style::new().dimmed());
+        let version = ColorfulVersion::new("app", "1.0.0-rc.1+sha", "Jane").with_styles(styles);
+        let line = version.colored_line(crate::ColorDepth::TrueColor);
+        assert_eq!(
+            line,
+            "\x1b[38;2;255;255;255;48;2;170;0;255mapp\x1b[0m\x1b[38;2;255;255;0m v\x1b[0m\
+             \x1b[38;2;255;255;0m1.0.0-\x1b[0m\x1b[38;2;255;0;0mrc.1\x1b[0m\x1b[38;2;255;255;0m+\x1b[0m\
+             \x1b[2;38;2;255;255;0msha\x1b[0m\x1b[38;2;0;255;255m by Jane\x1b[0m"
+        );
+
+        // versions that are not SemVer keep a single segment
+        let calver = ColorfulVersion::new("app", "2024.1", "Jane").with_styles(styles);
+        assert!(
+            calver
+                .colored_line(crate::ColorDepth::TrueColor)
+                .contains("\x1b[38;2;255;255;0m2024.1\x1b[0m")
+        );
+    }
+}
@@ -0,0 +1,146 @@
+// Project: clap-version-flag
+// File: src/separators.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Custom separators between the segments of the version line
+// License: MIT
+
+//! Segment separators.
+//!
+//! The segments are joined by a space and by " by ".
+//! [`ColorfulVersion::with_separators`] replaces both, e.g. with `" • "`
+//! and `" — "`, and templates place them with `{sep}` and `{author_sep}`.
+//! Either way they are painted in their own color, set with
+//! [`ColorfulVersion::with_separator_color`].
+
+use std::borrow::Cow;
+
+use crate::color::Rgb;
+use crate::{ColorDepth, ColorfulVersion, Style};
+
+/// The separators set with [`ColorfulVersion::with_separators`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Separators {
+    /// Between the name and the version
+    pub(crate) name: Cow<'static, str>,
+    /// Before the author, replacing " by "
+    pub(crate) author: Cow<'static, str>,
+}
+
+impl ColorfulVersion {
+    /// Joins the name and version with `name`, and the author with `author`
+    ///
+    /// `author` replaces the " by " label of the [locale](Self::with_locale)
+    /// as well as the spaces around it.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_separators(" • ", " — ");
+    /// assert_eq!(version.as_plain_string(), "myapp • v1.0.0 — John Doe");
+    /// ```
+    #[must_use]
+    pub fn with_separators(mut self, name: impl Into<String>, author: impl Into<String>) -> Self {
+        self.separators = Some(Separators {
+            name: Cow::Owned(name.into()),
+            author: Cow::Owned(author.into()),
+        });
+        self
+    }
+
+    /// Sets the color of the separators
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_separators(" | ", " | ")
+    ///     .with_separator_color((90, 90, 90));
+    /// ```
+    #[must_use]
+    pub fn with_separator_color(mut self, rgb: Rgb) -> Self {
+        self.colors.separator_color = rgb;
+        self
+    }
+
+    /// Returns the separator between the name and the version
+    pub(crate) fn name_separator(&self) -> Cow<'static, str> {
+        match &self.separators {
+            Some(separators) => separators.name.clone(),
+            None => Cow::Borrowed(" "),
+        }
+    }
+
+    /// Returns the separator before the author, including the "by" label
+    pub(crate) fn author_separator(&self) -> Cow<'static, str> {
+        match &self.separators {
+            Some(separators) => separators.author.clone(),
+            None if self.labels().author_prefix.is_empty() => Cow::Borrowed(" "),
+            None => Cow::Owned(format!(" {} ", self.labels().author_prefix)),
+        }
+    }
+
+    /// Paints a separator in the separator color when `depth` is set
+    pub(crate) fn paint_separator(&self, separator: &str, depth: Option<ColorDepth>) -> String {
+        match depth {
+            Some(depth) => self.paint_segment(
+                separator,
+                &Style::new(),
+                self.palette().separator_color,
+                None,
+                depth,
+            ),
+            None => separator.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Locale, color};
+
+    #[test]
+    fn test_separators() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        assert_eq!(version.author_separator(), " by ");
+        let version = version
+            .with_separators(" • ", " — ")
+            .with_separator_color((1, 2, 3));
+        let line = version.colored_line(ColorDepth::TrueColor);
+        assert!(line.contains("\x1b[38;2;1;2;3m • \x1b[0m"), "{line:?}");
+        assert_eq!(color::strip_escapes(&line), "app • v1.0 — Jo");
+        assert_eq!(version.clone().hide_name().as_plain_string(), "v1.0 — Jo");
+
+        let version = version.with_template("{name}{sep}{version}{author_sep}{author}");
+        assert_eq!(version.as_plain_string(), "app • 1.0 — Jo");
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_locale(Locale::De)
+            .with_template("{name}{sep}{version}{author_sep}{author}");
+        assert_eq!(version.as_plain_string(), "app 1.0 von Jo");
+    }
+
+    #[test]
+    fn test_separator_color_survives_themes() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        let themed = version.clone().with_theme(crate::Theme::default());
+        assert_eq!(
+            themed.colors.separator_color,
+            version.colors.separator_color
+        );
+
+        let version = version
+            .with_separators(" • ", " — ")
+            .with_separator_color((1, 2, 3))
+            .with_theme(crate::Theme::high_contrast())
+            .with_color_depth(ColorDepth::TrueColor);
+        assert_eq!(version.palette().separator_color, (1, 2, 3));
+        let brief = version.render_text(crate::Verbosity::Brief, true);
+        assert!(brief.contains("\x1b[38;2;1;2;3m • \x1b[0m"), "{brief:?}");
+        assert_eq!(color::strip_escapes(&brief), "app • v1.0");
+        assert_eq!(version.render_plain(crate::Verbosity::Brief), "app • v1.0");
+    }
+}
@@ -0,0 +1,398 @@
+// Project: clap-version-flag
+// File: src/serialize.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Serde support for versions, themes and colors
+// License: MIT
+
+//! Serde support.
+//!
+//! Applications that let users pick a theme can persist it in their own
+//! configuration files and reload it:
+//!
+//! - [`ColorSpec`] is a `"#RRGGBB"` string; anything
+//!   [`parse_color`](crate::color::parse_color) accepts deserializes.
+//! - [`Role`] is its snake-case name, e.g. `"on_primary"`.
+//! - [`Theme`] is a map from roles to colors; missing roles keep their
+//!   default color.
+//! - [`ColorfulVersion`] is the [`to_json`](ColorfulVersion::to_json) object
+//!   plus a `theme` key and a `colors` key holding the colors no role covers
+//!   (license, separator and the segment backgrounds). Only the name,
+//!   version, author, metadata and colors round-trip; metadata comes back at
+//!   [`Verbosity::Normal`], and handlers, providers and layout settings are
+//!   not serialized.
+//!
+//! Requires the `serde` feature.
+
+use std::fmt;
+
+use serde::de::{self, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::theme::ROLE_KEYS;
+use crate::{
+    ColorSpec, ColorfulVersion, Colors, Field, Role, SegmentBackground, Theme, Verbosity, json,
+};
+
+impl Serialize for ColorSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Role {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.key())
+    }
+}
+
+impl<'de> Deserialize<'de> for Role {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = String::deserialize(deserializer)?;
+        Self::from_key(&key).ok_or_else(|| de::Error::unknown_variant(&key, &ROLE_KEYS))
+    }
+}
+
+impl Serialize for Theme {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(Role::ALL.len()))?;
+        for role in Role::ALL {
+            map.serialize_entry(&role, &ColorSpec::from(self.role(role)))?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Theme {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ThemeVisitor;
+
+        impl<'de> Visitor<'de> for ThemeVisitor {
+            type Value = Theme;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map from theme roles to colors")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Theme, A::Error> {
+                let mut theme = Theme::default();
+                while let Some((role, color)) = map.next_entry::<Role, ColorSpec>()? {
+                    theme = theme.with_role(role, color);
+                }
+                Ok(theme)
+            }
+        }
+
+        deserializer.deserialize_map(ThemeVisitor)
+    }
+}
+
+/// Serializes metadata fields as an ordered map
+struct Metadata<'a>(&'a [Field]);
+
+impl Serialize for Metadata<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for field in self.0 {
+            map.serialize_entry(&field.key, &field.value)?;
+        }
+        map.end()
+    }
+}
+
+/// Deserializes metadata fields, keeping their order
+struct MetadataEntries(Vec<(String, String)>);
+
+impl<'de> Deserialize<'de> for MetadataEntries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = MetadataEntries;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of metadata strings")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<MetadataEntries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(MetadataEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+/// Serializes a segment background as `"none"`, `"inherit"` or a color
+struct Background(SegmentBackground);
+
+impl Serialize for Background {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            SegmentBackground::Inherit => serializer.serialize_str("inherit"),
+            SegmentBackground::Color(rgb) => ColorSpec::from(rgb).serialize(serializer),
+            _ => serializer.serialize_str("none"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Background {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Ok(Self(match text.as_str() {
+            "none" => SegmentBackground::None,
+            "inherit" => SegmentBackground::Inherit,
+            _ => SegmentBackground::Color(
+                text.parse::<ColorSpec>().map_err(de::Error::custom)?.rgb(),
+            ),
+        }))
+    }
+}
+
+/// Serializes the colors of a version that no theme role covers
+struct ExtraColors(Colors);
+
+impl Serialize for ExtraColors {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(4))?;
+        map.serialize_entry("license", &ColorSpec::from(self.0.license_color))?;
+        map.serialize_entry("separator", &ColorSpec::from(self.0.separator_color))?;
+        map.serialize_entry("version_bg", &Background(self.0.version_bg))?;
+        map.serialize_entry("author_bg", &Background(self.0.author_bg))?;
+        map.end()
+    }
+}
+
+/// Deserializes the `colors` key; missing colors keep the theme's
+#[derive(Default)]
+struct ExtraColorsPatch {
+    license: Option<ColorSpec>,
+    separator: Option<ColorSpec>,
+    version_bg: Option<Background>,
+    author_bg: Option<Background>,
+}
+
+impl ExtraColorsPatch {
+    /// Applies the given colors onto `colors`
+    fn apply(self, colors: &mut Colors) {
+        if let Some(license) = self.license {
+            colors.license_color = license.rgb();
+        }
+        if let Some(separator) = self.separator {
+            colors.separator_color = separator.rgb();
+        }
+        if let Some(Background(background)) = self.version_bg {
+            colors.version_bg = background;
+        }
+        if let Some(Background(background)) = self.author_bg {
+            colors.author_bg = background;
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtraColorsPatch {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PatchVisitor;
+
+        impl<'de> Visitor<'de> for PatchVisitor {
+            type Value = ExtraColorsPatch;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of colors")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<ExtraColorsPatch, A::Error> {
+                let mut patch = ExtraColorsPatch::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "license" => patch.license = Some(map.next_value()?),
+                        "separator" => patch.separator = Some(map.next_value()?),
+                        "version_bg" => patch.version_bg = Some(map.next_value()?),
+                        "author_bg" => patch.author_bg = Some(map.next_value()?),
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(patch)
+            }
+        }
+
+        deserializer.deserialize_map(PatchVisitor)
+    }
+}
+
+impl Serialize for ColorfulVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ColorfulVersion", 7)?;
+        state.serialize_field("schema_version", &json::SCHEMA_VERSION)?;
+        state.serialize_field("name", &self.package_name)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("author", &self.author)?;
+        state.serialize_field("metadata", &Metadata(&self.metadata))?;
+        state.serialize_field("theme", &Theme::from(self.colors))?;
+        state.serialize_field("colors", &ExtraColors(self.colors))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorfulVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct VersionVisitor;
+
+        impl<'de> Visitor<'de> for VersionVisitor {
+            type Value = ColorfulVersion;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a version object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ColorfulVersion, A::Error> {
+                let (mut name, mut version, mut author) = (None, None, None);
+                let mut metadata = MetadataEntries(Vec::new());
+                let mut theme = None;
+                let mut colors = ExtraColorsPatch::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
+                        "name" => name = Some(map.next_value::<String>()?),
+                        "version" => version = Some(map.next_value::<String>()?),
+                        "author" => author = Some(map.next_value::<String>()?),
+                        "metadata" => metadata = map.next_value()?,
+                        "theme" => theme = Some(map.next_value::<Theme>()?),
+                        "colors" => colors = map.next_value()?,
+                        // `schema_version` and keys added by later versions
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+                let version = version.ok_or_else(|| de::Error::missing_field("version"))?;
+                let mut result = ColorfulVersion::new(name, version, author.unwrap_or_default());
+                result.metadata = metadata
+                    .0
+                    .into_iter()
+                    .map(|(key, value)| Field {
+                        key: key.into(),
+                        value: value.into(),
+                        color: None,
+                        level: Verbosity::Normal,
+                    })
+                    .collect();
+                if let Some(theme) = theme {
+                    result = result.with_theme(theme);
+                }
+                colors.apply(&mut result.colors);
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_map(VersionVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_round_trip() {
+        let theme = Theme::new().primary((0, 95, 135)).warning((255, 165, 0));
+        let text = serde_json::to_string(&theme).unwrap();
+        assert!(text.starts_with(r##"{"primary":"#005F87","on_primary":"#FFFFFF""##));
+        assert_eq!(serde_json::from_str::<Theme>(&text).unwrap(), theme);
+    }
+
+    #[test]
+    fn test_theme_round_trip_covers_every_role() {
+        let theme = Theme::new()
+            .primary((1, 0, 0))
+            .on_primary((2, 0, 0))
+            .secondary((3, 0, 0))
+            .accent((4, 0, 0))
+            .success((5, 0, 0))
+            .warning((6, 0, 0))
+            .error((7, 0, 0));
+        let text = serde_json::to_string(&theme).unwrap();
+        for role in Role::ALL {
+            assert!(text.contains(&format!(r#""{}":"#, role.key())));
+        }
+        let loaded: Theme = serde_json::from_str(&text).unwrap();
+        for role in Role::ALL {
+            assert_eq!(loaded.role(role), theme.role(role), "{role:?}");
+        }
+        assert_eq!(loaded, theme);
+    }
+
+    #[test]
+    fn test_partial_theme_and_color_names() {
+        let theme: Theme = serde_json::from_str(r#"{"accent":"gold"}"#).unwrap();
+        assert_eq!(theme, Theme::new().accent((255, 215, 0)));
+
+        let error = serde_json::from_str::<Theme>(r##"{"brand":"#000000"}"##).unwrap_err();
+        assert!(error.to_string().contains("unknown variant `brand`"));
+        assert!(serde_json::from_str::<Theme>(r#"{"accent":"nope"}"#).is_err());
+    }
+
+    #[test]
+    fn test_version_round_trip() {
+        let version = ColorfulVersion::builder("app", "1.0", "Jo")
+            .description("A tool")
+            .build()
+            .with_theme(Theme::new().primary((1, 2, 3)));
+        let text = serde_json::to_string(&version).unwrap();
+        assert!(text.starts_with(&version.to_json()[..version.to_json().len() - 1]));
+
+        let loaded: ColorfulVersion = serde_json::from_str(&text).unwrap();
+        assert_eq!(loaded.to_json(), version.to_json());
+        assert_eq!(loaded.theme(), version.theme());
+        assert!(serde_json::from_str::<ColorfulVersion>(r#"{"name":"app"}"#).is_err());
+    }
+
+    #[test]
+    fn test_version_round_trip_keeps_every_color() {
+        let mut version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_theme(Theme::new().secondary((9, 9, 9)))
+            .with_version_bg(SegmentBackground::Inherit)
+            .with_author_bg((40, 40, 40));
+        version.colors.license_color = (1, 2, 3);
+        version.colors.separator_color = (4, 5, 6);
+        let text = serde_json::to_string(&version).unwrap();
+        assert!(text.ends_with(
+            r##""colors":{"license":"#010203","separator":"#040506","version_bg":"inherit","author_bg":"#282828"}}"##
+        ));
+
+        let loaded: ColorfulVersion = serde_json::from_str(&text).unwrap();
+        assert_eq!(loaded.theme(), version.theme());
+        assert_eq!(loaded.colors.license_color, (1, 2, 3));
+        assert_eq!(loaded.colors.separator_color, (4, 5, 6));
+        assert_eq!(loaded.colors.version_bg, SegmentBackground::Inherit);
+        assert_eq!(
+            loaded.colors.author_bg,
+            SegmentBackground::Color((40, 40, 40))
+        );
+
+        // without a `colors` key the theme decides them, as before
+        let loaded: ColorfulVersion = serde_json::from_str(
+            r##"{"name":"app","version":"1.0","theme":{"secondary":"#090909"}}"##,
+        )
+        .unwrap();
+        assert_eq!(loaded.colors.license_color, (9, 9, 9));
+        assert_eq!(loaded.colors.version_bg, SegmentBackground::None);
+    }
+}
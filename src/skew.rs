@@ -0,0 +1,282 @@
+// Project: clap-version-flag
+// File: src/skew.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Version skew policy between an application and its peers
+// License: MIT
+
+//! Version skew policies.
+//!
+//! Client/server tools usually tolerate some distance between the two sides,
+//! e.g. "the server may be one minor version ahead". A [`SkewPolicy`]
+//! describes that tolerance and [`ColorfulVersion::check_peer`] evaluates a
+//! peer version against it.
+
+use crate::{ColorDepth, ColorfulVersion, Role, Theme, color};
+use std::fmt;
+
+/// The outcome of evaluating a peer version against a [`SkewPolicy`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SkewVerdict {
+    /// Both sides run the same major and minor version
+    Ok,
+    /// The minor versions differ but stay within the allowed skew
+    Warn,
+    /// The versions differ in major version or by more than the allowed skew
+    Incompatible,
+}
+
+impl SkewVerdict {
+    /// Returns the color role the verdict is rendered with
+    #[must_use]
+    pub fn role(self) -> Role {
+        match self {
+            Self::Ok => Role::Success,
+            Self::Warn => Role::Warning,
+            Self::Incompatible => Role::Error,
+        }
+    }
+}
+
+/// How far a peer's minor version may drift from the local one
+///
+/// Major versions always have to match. For `0.x` versions the minor version
+/// is the breaking component, so it has to match too.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{SkewPolicy, SkewVerdict};
+///
+/// // "the server may be one minor version ahead"
+/// let policy = SkewPolicy::new().allow_ahead(1);
+/// assert_eq!(policy.evaluate("1.4.0", "1.4.2"), SkewVerdict::Ok);
+/// assert_eq!(policy.evaluate("1.4.0", "1.5.0"), SkewVerdict::Warn);
+/// assert_eq!(policy.evaluate("1.4.0", "1.6.0"), SkewVerdict::Incompatible);
+/// assert_eq!(policy.evaluate("1.4.0", "1.3.0"), SkewVerdict::Incompatible);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SkewPolicy {
+    max_ahead: u64,
+    max_behind: u64,
+}
+
+impl SkewPolicy {
+    /// Creates a policy that requires identical major and minor versions
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows the peer to be up to `minors` minor versions ahead
+    #[must_use]
+    pub fn allow_ahead(mut self, minors: u64) -> Self {
+        self.max_ahead = minors;
+        self
+    }
+
+    /// Allows the peer to be up to `minors` minor versions behind
+    #[must_use]
+    pub fn allow_behind(mut self, minors: u64) -> Self {
+        self.max_behind = minors;
+        self
+    }
+
+    /// Allows the peer to be up to `minors` minor versions ahead or behind
+    #[must_use]
+    pub fn allow_skew(self, minors: u64) -> Self {
+        self.allow_ahead(minors).allow_behind(minors)
+    }
+
+    /// Evaluates a peer version against the local version
+    #[must_use]
+    pub fn evaluate(&self, local: &str, peer: &str) -> SkewVerdict {
+        self.assess(local, peer).0
+    }
+
+    /// Evaluates a peer version and explains the verdict
+    fn assess(&self, local: &str, peer: &str) -> (SkewVerdict, String) {
+        let (Some(local), Some(peer)) = (parse_triple(local), parse_triple(peer)) else {
+            return (SkewVerdict::Incompatible, "unparsable version".into());
+        };
+
+        if local.0 != peer.0 || (local.0 == 0 && local.1 != peer.1) {
+            return (SkewVerdict::Incompatible, "different major version".into());
+        }
+
+        let (distance, direction, allowed) = if peer.1 >= local.1 {
+            (peer.1 - local.1, "ahead", self.max_ahead)
+        } else {
+            (local.1 - peer.1, "behind", self.max_behind)
+        };
+        let plural = if distance == 1 { "" } else { "s" };
+
+        match distance {
+            0 => (SkewVerdict::Ok, "compatible".into()),
+            d if d <= allowed => (
+                SkewVerdict::Warn,
+                format!("{d} minor version{plural} {direction}"),
+            ),
+            d => (
+                SkewVerdict::Incompatible,
+                format!("{d} minor version{plural} {direction}, at most {allowed} allowed"),
+            ),
+        }
+    }
+}
+
+/// A peer version check result, as returned by [`ColorfulVersion::check_peer`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SkewReport {
+    /// The verdict of the policy
+    pub verdict: SkewVerdict,
+    /// The peer's name, e.g. "server"
+    pub peer_name: String,
+    /// The peer's version
+    pub peer_version: String,
+    /// Human-readable explanation of the verdict
+    pub reason: String,
+}
+
+impl SkewReport {
+    /// Returns whether the peer can be used at all (`Ok` or `Warn`)
+    #[must_use]
+    pub fn is_compatible(&self) -> bool {
+        self.verdict != SkewVerdict::Incompatible
+    }
+
+    /// Returns the report colored by verdict with the default [`Theme`]
+    #[must_use]
+    pub fn to_colored_string(&self) -> String {
+        self.to_colored_string_with(&Theme::default())
+    }
+
+    /// Returns the report colored by verdict with the roles of `theme`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, SkewPolicy, Theme};
+    ///
+    /// let client = ColorfulVersion::new("myctl", "1.4.0", "John Doe")
+    ///     .with_theme(Theme::new().warning((255, 165, 0)));
+    /// let report = client.check_peer("server", "1.5.0", &SkewPolicy::new().allow_skew(1));
+    /// println!("{}", report.to_colored_string_with(&client.theme()));
+    /// ```
+    #[must_use]
+    pub fn to_colored_string_with(&self, theme: &Theme) -> String {
+        if !color::should_colorize() {
+            return self.to_string();
+        }
+        let rgb = theme.role(self.verdict.role());
+        color::paint(&self.to_string(), rgb, None, ColorDepth::detect())
+    }
+}
+
+impl fmt::Display for SkewReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} v{}: {}",
+            self.peer_name, self.peer_version, self.reason
+        )
+    }
+}
+
+impl ColorfulVersion {
+    /// Checks a peer's version against this version using `policy`
+    ///
+    /// Intended for handshakes, e.g. a client comparing itself with the
+    /// version reported by its server.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, SkewPolicy, SkewVerdict};
+    ///
+    /// let client = ColorfulVersion::new("myctl", "1.4.0", "John Doe");
+    /// let report = client.check_peer("server", "1.5.2", &SkewPolicy::new().allow_skew(1));
+    ///
+    /// assert_eq!(report.verdict, SkewVerdict::Warn);
+    /// assert_eq!(report.to_string(), "server v1.5.2: 1 minor version ahead");
+    /// ```
+    #[must_use]
+    pub fn check_peer(
+        &self,
+        peer_name: impl Into<String>,
+        peer_version: impl Into<String>,
+        policy: &SkewPolicy,
+    ) -> SkewReport {
+        let peer_version = peer_version.into();
+        let (verdict, reason) = policy.assess(&self.version, &peer_version);
+        SkewReport {
+            verdict,
+            peer_name: peer_name.into(),
+            peer_version,
+            reason,
+        }
+    }
+}
+
+/// Parses the `major.minor.patch` part of a version, ignoring a `v` prefix
+/// and any pre-release or build suffix. Missing components count as zero.
+fn parse_triple(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = core.split('.').map(str::parse::<u64>);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_triple() {
+        assert_eq!(parse_triple("1.2.3"), Some((1, 2, 3)));
+        assert_eq!(parse_triple("v2.0.1-beta.1+sha"), Some((2, 0, 1)));
+        assert_eq!(parse_triple("3"), Some((3, 0, 0)));
+        assert_eq!(parse_triple("abc"), None);
+    }
+
+    #[test]
+    fn test_zero_major_treats_minor_as_breaking() {
+        let policy = SkewPolicy::new().allow_skew(5);
+        assert_eq!(policy.evaluate("0.4.1", "0.4.9"), SkewVerdict::Ok);
+        assert_eq!(policy.evaluate("0.4.1", "0.5.0"), SkewVerdict::Incompatible);
+    }
+
+    #[test]
+    fn test_behind_and_unparsable() {
+        let policy = SkewPolicy::new().allow_behind(2);
+        assert_eq!(policy.evaluate("1.5.0", "1.3.0"), SkewVerdict::Warn);
+        assert_eq!(policy.evaluate("1.5.0", "1.2.0"), SkewVerdict::Incompatible);
+        assert_eq!(policy.evaluate("1.5.0", "2.5.0"), SkewVerdict::Incompatible);
+        assert_eq!(
+            policy.evaluate("1.5.0", "nightly"),
+            SkewVerdict::Incompatible
+        );
+    }
+
+    #[test]
+    fn test_verdict_roles() {
+        assert_eq!(SkewVerdict::Ok.role(), Role::Success);
+        assert_eq!(SkewVerdict::Warn.role(), Role::Warning);
+        assert_eq!(SkewVerdict::Incompatible.role(), Role::Error);
+    }
+
+    #[test]
+    fn test_report() {
+        let local = ColorfulVersion::new("myctl", "1.4.0", "Jane");
+        let report = local.check_peer("server", "1.7.0", &SkewPolicy::new().allow_ahead(1));
+
+        assert!(!report.is_compatible());
+        assert_eq!(
+            report.to_string(),
+            "server v1.7.0: 3 minor versions ahead, at most 1 allowed"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// Project: clap-version-flag
+// File: src/startup.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Startup overhead instrumentation
+// License: MIT
+
+//! Startup overhead.
+//!
+//! A version flag is paid for on every run, including the overwhelming
+//! majority that never ask for the version. The crate therefore guarantees:
+//!
+//! - Registering the flag with
+//!   [`with_colorful_version`](crate::ColorfulVersionExt::with_colorful_version)
+//!   costs one clap [`Arg`](clap::Arg), well under [`STARTUP_BUDGET`] in
+//!   release builds.
+//! - On the non-version path, i.e. [`ColorfulVersion::check`] and
+//!   [`ColorfulVersion::check_and_exit`] when the flag is absent (and no
+//!   [expiry](ColorfulVersion::with_expiry) is configured), handling the
+//!   arguments performs **zero heap allocations**, with or without the
+//!   `--version-format`, `--version-deps`, `--version-fields`,
+//!   `--version-theme` and `--bug-report` companions.
+//!
+//! `tests/startup_cost.rs` enforces the allocation bound with a counting
+//! global allocator, and the time budget with [`startup_cost`], which
+//! measures the time on the machine at hand.
+
+use std::time::{Duration, Instant};
+
+use clap::Command;
+
+use crate::{ColorfulVersion, ColorfulVersionExt};
+
+/// Documented upper bound of [`StartupCost::total`] in release builds
+pub const STARTUP_BUDGET: Duration = Duration::from_micros(10);
+
+/// Iterations averaged per measurement
+const ITERATIONS: u32 = 100;
+
+/// Time the version flag adds to a run that does not ask for the version
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StartupCost {
+    /// Registering the flag on a command
+    pub register: Duration,
+    /// Checking the parsed arguments for the flag
+    pub check: Duration,
+}
+
+impl StartupCost {
+    /// Returns the whole overhead
+    #[must_use]
+    pub fn total(&self) -> Duration {
+        self.register + self.check
+    }
+
+    /// Returns whether the overhead is within [`STARTUP_BUDGET`]
+    #[must_use]
+    pub fn within_budget(&self) -> bool {
+        self.total() <= STARTUP_BUDGET
+    }
+}
+
+/// Measures the overhead `version` adds to a run without `--version`
+///
+/// Each part is averaged over 100 iterations; the command building and
+/// argument parsing clap does anyway are excluded.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, startup_cost};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+/// let cost = startup_cost(&version);
+/// println!("version flag overhead: {:?}", cost.total());
+/// ```
+#[must_use]
+pub fn startup_cost(version: &ColorfulVersion) -> StartupCost {
+    let command = Command::new("app");
+    let mut register = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let command = command.clone();
+        let start = Instant::now();
+        let command = command.with_colorful_version(version);
+        register += start.elapsed();
+        drop(command);
+    }
+
+    let matches = command
+        .with_colorful_version(version)
+        .try_get_matches_from(["app"])
+        .unwrap_or_default();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        std::hint::black_box(version.requested_output(std::hint::black_box(&matches)));
+    }
+    StartupCost {
+        register: register / ITERATIONS,
+        check: start.elapsed() / ITERATIONS,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measures_both_parts() {
+        let cost = startup_cost(&ColorfulVersion::new("app", "1.0", "Jo"));
+        assert_eq!(cost.total(), cost.register + cost.check);
+    }
+}
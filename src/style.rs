@@ -0,0 +1,303 @@
+// Project: clap-version-flag
+// File: src/style.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Text styles (bold, italic, underline, ...) per output segment
+// License: MIT
+
+//! Text styles per segment.
+//!
+//! The version line is made of four segments: the name, the `v` prefix, the
+//! version number and the " by author" part. A semantic version number can
+//! further be styled per component. Besides the colors set with
+//! [`ColorfulVersion::with_rgb_colors`](crate::ColorfulVersion::with_rgb_colors),
+//! each segment can carry SGR attributes through [`Styles`].
+
+use crate::ColorSpec;
+
+/// Colors and attributes of one segment
+///
+/// Colors left unset fall back to the version's regular colors.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::Style;
+///
+/// let style = Style::new().bold().underline().fg((255, 128, 0));
+/// assert_ne!(style, Style::new());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    fg: Option<(u8, u8, u8)>,
+    bg: Option<(u8, u8, u8)>,
+    bold: bool,
+    dimmed: bool,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+}
+
+impl Style {
+    /// Creates a style without colors or attributes
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dimmed: false,
+            italic: false,
+            underline: false,
+            strikethrough: false,
+        }
+    }
+
+    /// Sets the foreground color
+    #[must_use]
+    pub fn fg(mut self, color: impl Into<ColorSpec>) -> Self {
+        self.fg = Some(color.into().rgb());
+        self
+    }
+
+    /// Sets the background color
+    #[must_use]
+    pub fn bg(mut self, color: impl Into<ColorSpec>) -> Self {
+        self.bg = Some(color.into().rgb());
+        self
+    }
+
+    /// Renders the segment in bold
+    #[must_use]
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Renders the segment dimmed
+    #[must_use]
+    pub fn dimmed(mut self) -> Self {
+        self.dimmed = true;
+        self
+    }
+
+    /// Renders the segment in italics
+    #[must_use]
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underlines the segment
+    #[must_use]
+    pub fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Strikes the segment through
+    #[must_use]
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Returns the foreground color, if set
+    pub(crate) fn get_fg(&self) -> Option<(u8, u8, u8)> {
+        self.fg
+    }
+
+    /// Returns the background color, if set
+    pub(crate) fn get_bg(&self) -> Option<(u8, u8, u8)> {
+        self.bg
+    }
+
+    /// Returns the enabled attributes, in SGR code order
+    pub(crate) fn attributes(&self) -> impl Iterator<Item = Attribute> {
+        [
+            (self.bold, Attribute::Bold),
+            (self.dimmed, Attribute::Dimmed),
+            (self.italic, Attribute::Italic),
+            (self.underline, Attribute::Underline),
+            (self.strikethrough, Attribute::Strikethrough),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, attribute)| enabled.then_some(attribute))
+    }
+
+    /// Returns the SGR codes of the enabled attributes
+    pub(crate) fn sgr_attributes(&self) -> Vec<u8> {
+        self.attributes().map(Attribute::sgr).collect()
+    }
+}
+
+/// A text attribute of a [`Style`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Attribute {
+    /// Bold or increased intensity
+    Bold,
+    /// Faint or decreased intensity
+    Dimmed,
+    /// Italic
+    Italic,
+    /// Single underline
+    Underline,
+    /// Crossed out
+    Strikethrough,
+}
+
+impl Attribute {
+    /// Returns the SGR code switching the attribute on
+    pub(crate) const fn sgr(self) -> u8 {
+        match self {
+            Self::Bold => 1,
+            Self::Dimmed => 2,
+            Self::Italic => 3,
+            Self::Underline => 4,
+            Self::Strikethrough => 9,
+        }
+    }
+}
+
+/// Styles of the four segments of the version line
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, Style, Styles};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_styles(
+///     Styles::new()
+///         .name(Style::new().bold())
+///         .prefix(Style::new().dimmed())
+///         .author(Style::new().italic()),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Styles {
+    pub(crate) name: Style,
+    pub(crate) prefix: Style,
+    pub(crate) version: Style,
+    pub(crate) author: Style,
+    pub(crate) major: Option<Style>,
+    pub(crate) minor: Option<Style>,
+    pub(crate) patch: Option<Style>,
+    pub(crate) pre_release: Option<Style>,
+    pub(crate) build: Option<Style>,
+}
+
+impl Styles {
+    /// Creates styles leaving every segment unstyled
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            name: Style::new(),
+            prefix: Style::new(),
+            version: Style::new(),
+            author: Style::new(),
+            major: None,
+            minor: None,
+            patch: None,
+            pre_release: None,
+            build: None,
+        }
+    }
+
+    /// Sets the style of the package name
+    #[must_use]
+    pub fn name(mut self, style: Style) -> Self {
+        self.name = style;
+        self
+    }
+
+    /// Sets the style of the `v` in front of the version
+    #[must_use]
+    pub fn prefix(mut self, style: Style) -> Self {
+        self.prefix = style;
+        self
+    }
+
+    /// Sets the style of the version number
+    #[must_use]
+    pub fn version(mut self, style: Style) -> Self {
+        self.version = style;
+        self
+    }
+
+    /// Sets the style of the " by author" segment
+    #[must_use]
+    pub fn author(mut self, style: Style) -> Self {
+        self.author = style;
+        self
+    }
+
+    /// Sets the style of the major number of a semantic version
+    ///
+    /// The component styles apply when the version is valid SemVer (see
+    /// [`ColorfulVersion::semver`](crate::ColorfulVersion::semver)); parts
+    /// without their own style keep the [`version`](Self::version) style,
+    /// as do the `.`, `-` and `+` separators.
+    #[must_use]
+    pub fn major(mut self, style: Style) -> Self {
+        self.major = Some(style);
+        self
+    }
+
+    /// Sets the style of the minor number of a semantic version
+    #[must_use]
+    pub fn minor(mut self, style: Style) -> Self {
+        self.minor = Some(style);
+        self
+    }
+
+    /// Sets the style of the patch number of a semantic version
+    #[must_use]
+    pub fn patch(mut self, style: Style) -> Self {
+        self.patch = Some(style);
+        self
+    }
+
+    /// Sets the style of the pre-release part (`rc.1` in `1.0.0-rc.1`)
+    #[must_use]
+    pub fn pre_release(mut self, style: Style) -> Self {
+        self.pre_release = Some(style);
+        self
+    }
+
+    /// Sets the style of the build metadata (`sha.5114f85` in `1.0.0+sha.5114f85`)
+    #[must_use]
+    pub fn build(mut self, style: Style) -> Self {
+        self.build = Some(style);
+        self
+    }
+
+    /// Returns whether any semantic version component has its own style
+    pub(crate) fn has_components(&self) -> bool {
+        [
+            self.major,
+            self.minor,
+            self.patch,
+            self.pre_release,
+            self.build,
+        ]
+        .iter()
+        .any(Option::is_some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sgr_attributes() {
+        assert!(Style::new().sgr_attributes().is_empty());
+        assert_eq!(
+            Style::new()
+                .strikethrough()
+                .bold()
+                .italic()
+                .sgr_attributes(),
+            [1, 3, 9]
+        );
+        assert_eq!(Style::new().dimmed().underline().sgr_attributes(), [2, 4]);
+    }
+}
@@ -0,0 +1,200 @@
+// Project: clap-version-flag
+// File: src/styled.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: The version line as a clap StyledStr
+// License: MIT
+
+//! clap styled strings.
+//!
+//! [`ColorfulVersion::to_styled_str`] returns the version line as a
+//! [`StyledStr`], styled with `anstyle`. Wherever clap accepts one (help
+//! headings, `before_help`, `after_help`, error messages) the line then
+//! passes through clap's own color handling: it is colored when clap colors
+//! the help and plain under `Command::color(ColorChoice::Never)`, whatever
+//! this crate's own terminal detection would decide.
+
+use std::fmt::Write as _;
+
+use anstyle::{Ansi256Color, Color, Effects, RgbColor};
+use clap::builder::StyledStr;
+
+use crate::color::{self, Rgb};
+use crate::style::Attribute;
+use crate::{ColorDepth, ColorfulVersion, Style};
+
+/// Converts a segment style with its default colors to an `anstyle` style
+fn anstyle(style: &Style, fg: Rgb, bg: Option<Rgb>, depth: ColorDepth) -> anstyle::Style {
+    let color = |rgb: Rgb| -> Color {
+        match depth {
+            ColorDepth::TrueColor => RgbColor(rgb.0, rgb.1, rgb.2).into(),
+            ColorDepth::Ansi256 => Ansi256Color(color::to_ansi256(rgb)).into(),
+            ColorDepth::Ansi16 => {
+                let index = Ansi256Color(color::to_ansi16(rgb));
+                index.into_ansi().map_or(index.into(), Color::from)
+            }
+        }
+    };
+    let effects = style
+        .attributes()
+        .fold(Effects::new(), |effects, attribute| {
+            effects
+                | match attribute {
+                    Attribute::Bold => Effects::BOLD,
+                    Attribute::Dimmed => Effects::DIMMED,
+                    Attribute::Italic => Effects::ITALIC,
+                    Attribute::Underline => Effects::UNDERLINE,
+                    Attribute::Strikethrough => Effects::STRIKETHROUGH,
+                }
+        });
+    anstyle::Style::new()
+        .fg_color(Some(color(style.get_fg().unwrap_or(fg))))
+        .bg_color(style.get_bg().or(bg).map(color))
+        .effects(effects)
+}
+
+impl ColorfulVersion {
+    /// Returns the version line as a [`StyledStr`] for clap's help output
+    ///
+    /// The segments carry the configured colors and styles, at the
+    /// configured [color depth](Self::with_color_depth); clap decides whether
+    /// they are shown. With a [template](Self::with_template) the rendered
+    /// template is passed through as is. Hyperlinks are left out.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap::Command;
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let mut cmd = Command::new("myapp").before_help(version.to_styled_str());
+    /// assert!(cmd.render_help().to_string().starts_with("myapp v1.0.0 by John Doe\n"));
+    /// ```
+    #[must_use]
+    pub fn to_styled_str(&self) -> StyledStr {
+        let depth = self.depth();
+        let mut out = StyledStr::new();
+        if let Some(template) = &self.template {
+            out.push_str(&self.render_template(template, Some(depth)));
+            return out;
+        }
+        if self.shown_segments().is_some() {
+            out.push_str(&self.colored_line(depth));
+            return out;
+        }
+        let palette = self.palette();
+        let [name, version, author] = self.fitted_segments();
+        let prefix = self.labels().version_prefix.as_str();
+        let (prefix, number) = match version.unprefixed(prefix) {
+            Some(number) => (prefix, number),
+            None => ("", version.text.as_ref()),
+        };
+        let segments = [
+            (
+                name.padded(),
+                anstyle(
+                    &self.styles.name,
+                    palette.name_fg,
+                    Some(palette.name_bg),
+                    depth,
+                ),
+            ),
+            (" ".to_string(), anstyle::Style::new()),
+            (
+                format!("{}{prefix}", version.left),
+                anstyle(
+                    &self.styles.prefix,
+                    palette.version_color,
+                    self.version_background(),
+                    depth,
+                ),
+            ),
+            (
+                format!("{number}{}", version.right),
+                anstyle(
+                    &self.styles.version,
+                    palette.version_color,
+                    self.version_background(),
+                    depth,
+                ),
+            ),
+            (" ".to_string(), anstyle::Style::new()),
+            (
+                self.attributed(&author.padded()),
+                anstyle(
+                    &self.styles.author,
+                    palette.author_color,
+                    self.author_background(),
+                    depth,
+                ),
+            ),
+        ];
+        for (text, style) in segments.iter().filter(|(text, _)| !text.is_empty()) {
+            let _ = write!(out, "{}{text}{}", style.render(), style.render_reset());
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::{ColorChoice, Command};
+
+    fn version() -> ColorfulVersion {
+        ColorfulVersion::new("app", "1.0", "Jo").with_color_depth(ColorDepth::TrueColor)
+    }
+
+    #[test]
+    fn test_styled_segments() {
+        let styled = version().to_styled_str();
+        assert_eq!(styled.to_string(), "app v1.0 by Jo");
+        let ansi = styled.ansi().to_string();
+        assert!(ansi.contains("\x1b[38;2;255;255;255m\x1b[48;2;170;0;255mapp"));
+        assert!(ansi.contains("\x1b[38;2;0;255;255mby Jo"));
+    }
+
+    #[test]
+    fn test_styles_and_depth() {
+        let styled = version()
+            .with_color_depth(ColorDepth::Ansi16)
+            .with_styles(crate::Styles::new().author(Style::new().bold()))
+            .to_styled_str();
+        let ansi = styled.ansi().to_string();
+        assert!(ansi.contains("\x1b[96m"), "{ansi:?}");
+        assert!(ansi.contains("\x1b[1m"), "{ansi:?}");
+    }
+
+    #[test]
+    fn test_every_attribute_maps_to_its_effect() {
+        let style = Style::new()
+            .bold()
+            .dimmed()
+            .italic()
+            .underline()
+            .strikethrough();
+        let effects = anstyle(&style, (0, 0, 0), None, ColorDepth::TrueColor).get_effects();
+        let expected = Effects::BOLD
+            | Effects::DIMMED
+            | Effects::ITALIC
+            | Effects::UNDERLINE
+            | Effects::STRIKETHROUGH;
+        assert_eq!(effects, expected);
+        let underline = anstyle(
+            &Style::new().underline(),
+            (0, 0, 0),
+            None,
+            ColorDepth::TrueColor,
+        );
+        assert_eq!(underline.get_effects(), Effects::UNDERLINE);
+    }
+
+    #[test]
+    fn test_help_keeps_styles() {
+        let cmd = Command::new("app").before_help(version().to_styled_str());
+        let plain = cmd.clone().render_help();
+        assert!(plain.to_string().starts_with("app v1.0 by Jo\n"));
+        let always = cmd.color(ColorChoice::Always).render_help();
+        assert!(always.ansi().to_string().contains("\x1b[38;2;"));
+    }
+}
@@ -0,0 +1,150 @@
+// Project: clap-version-flag
+// File: src/subcommand.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: `version` subcommand generator and handler
+// License: MIT
+
+//! A `myapp version` subcommand.
+//!
+//! Many CLIs (git, cargo, kubectl) accept `version` as a subcommand next to
+//! the `--version` flag. [`ColorfulVersionExt::with_version_subcommand`]
+//! injects it and [`ColorfulVersion::handle_version_subcommand`] answers it.
+//!
+//! [`ColorfulVersionExt::with_version_subcommand`]: crate::ColorfulVersionExt::with_version_subcommand
+
+use crate::{ColorfulVersion, Verbosity, VersionFormat};
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+/// Name of the injected subcommand
+pub(crate) const VERSION_SUBCOMMAND: &str = "version";
+
+/// Builds the `version` subcommand with its `--json`, `--short` and `--verbose` options
+pub(crate) fn version_subcommand() -> Command {
+    Command::new(VERSION_SUBCOMMAND)
+        .about("Print version information")
+        .disable_version_flag(true)
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("short")
+                .help("Print the version as JSON"),
+        )
+        .arg(
+            Arg::new("short")
+                .long("short")
+                .action(ArgAction::SetTrue)
+                .help("Print only the version number"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("short")
+                .help("Include details such as the install method"),
+        )
+}
+
+impl ColorfulVersion {
+    /// Prints the version and exits if the `version` subcommand was used
+    ///
+    /// The exit goes through the [`ExitHandler`](crate::ExitHandler), so
+    /// with a returning handler this returns to the caller. Runs without
+    /// the subcommand enforce the [expiry](Self::with_expiry).
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use clap::Command;
+    /// use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe");
+    /// let matches = Command::new("myapp")
+    ///     .with_version_subcommand(&version)
+    ///     .get_matches();
+    ///
+    /// version.handle_version_subcommand(&matches);
+    /// ```
+    pub fn handle_version_subcommand(&self, matches: &ArgMatches) {
+        if let Some(output) = self.expiry_checked(self.for_stdout().subcommand_output(matches)) {
+            crate::color_choice::print_line(&output);
+            self.exit(0);
+        }
+    }
+
+    /// Renders the answer to the `version` subcommand, if it was used
+    fn subcommand_output(&self, matches: &ArgMatches) -> Option<String> {
+        let (VERSION_SUBCOMMAND, sub) = matches.subcommand()? else {
+            return None;
+        };
+        self.detect_accessibility();
+        let output = if sub.get_flag("short") {
+            self.version().to_string()
+        } else {
+            let format = if sub.get_flag("json") {
+                VersionFormat::Json
+            } else {
+                VersionFormat::Text
+            };
+            let verbosity = if sub.get_flag("verbose") {
+                Verbosity::Verbose
+            } else {
+                Verbosity::Normal
+            };
+            self.render_with_verbosity(format, verbosity)
+        };
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ColorfulVersion, ColorfulVersionExt, InstallMethod};
+    use clap::Command;
+
+    fn output(args: &[&str]) -> Option<String> {
+        let version = ColorfulVersion::new("app", "1.2.3", "Jane")
+            .with_install_method(InstallMethod::Cargo)
+            .with_color_depth(crate::ColorDepth::TrueColor);
+        let matches = Command::new("app")
+            .subcommand(Command::new("run"))
+            .with_version_subcommand(&version)
+            .try_get_matches_from(args)
+            .unwrap();
+        version.subcommand_output(&matches)
+    }
+
+    #[test]
+    fn test_subcommand_options() {
+        assert_eq!(
+            output(&["app", "version", "--short"]).as_deref(),
+            Some("1.2.3")
+        );
+        assert!(
+            output(&["app", "version", "--json"])
+                .unwrap()
+                .starts_with(r#"{"schema_version":1,"name":"app""#)
+        );
+        assert!(
+            output(&["app", "version", "--verbose"])
+                .unwrap()
+                .contains("installed via")
+        );
+        assert!(output(&["app", "version"]).unwrap().contains("1.2.3"));
+    }
+
+    #[test]
+    fn test_other_subcommands_are_ignored() {
+        assert_eq!(output(&["app", "run"]), None);
+        assert_eq!(output(&["app"]), None);
+    }
+
+    #[test]
+    fn test_short_conflicts_with_json() {
+        let version = ColorfulVersion::new("app", "1.2.3", "Jane");
+        let result = Command::new("app")
+            .with_version_subcommand(&version)
+            .try_get_matches_from(["app", "version", "--short", "--json"]);
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,56 @@
+// Project: clap-version-flag
+// File: src/suggest.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: "Did you mean" suggestions for mistyped names
+// License: MIT
+
+//! "Did you mean" suggestions.
+//!
+//! A mistyped theme role or style flag in a configuration file is reported
+//! with the closest registered name, the way clap reports an unknown
+//! `--version-format` or `--version-theme` value.
+
+use strsim::levenshtein;
+
+/// Returns the candidate closest to `input`, if it is close enough
+///
+/// A candidate qualifies within one edit per three characters of `input`
+/// (at least one); ties go to the first candidate.
+pub(crate) fn did_you_mean<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = (input.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Formats `message`, appending a suggestion from `candidates` if any
+pub(crate) fn with_suggestion<'a>(
+    message: String,
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> String {
+    match did_you_mean(input, candidates) {
+        Some(suggestion) => format!("{message}; did you mean '{suggestion}'?"),
+        None => message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_did_you_mean() {
+        let roles = ["primary", "on_primary", "secondary", "accent"];
+        assert_eq!(did_you_mean("primery", roles), Some("primary"));
+        assert_eq!(did_you_mean("acent", roles), Some("accent"));
+        assert_eq!(did_you_mean("background", roles), None);
+    }
+}
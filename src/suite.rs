@@ -0,0 +1,356 @@
+// Project: clap-version-flag
+// File: src/suite.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Combined version listing for suites of binaries
+// License: MIT
+
+//! Version listing for suites that ship several binaries.
+//!
+//! [`VersionSuite`] locates sibling binaries (next to the running executable
+//! or on `PATH`), asks each of them for `--version --version-format json`, and renders
+//! one aligned table, similar to `kubectl version` showing client and server.
+//!
+//! Requires the `suite` feature.
+
+use crate::{ColorDepth, ColorfulVersion, color, grapheme};
+use std::env;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One row of a [`VersionSuite`] listing
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct SuiteEntry {
+    /// The binary name that was looked up
+    pub binary: String,
+    /// Where the binary was found, if anywhere
+    pub path: Option<PathBuf>,
+    /// The reported version, or why it could not be obtained
+    pub outcome: Result<ColorfulVersion, String>,
+}
+
+impl SuiteEntry {
+    /// Creates a row for `binary`, found nowhere in particular
+    #[must_use]
+    pub fn new(binary: impl Into<String>, outcome: Result<ColorfulVersion, String>) -> Self {
+        Self {
+            binary: binary.into(),
+            path: None,
+            outcome,
+        }
+    }
+
+    /// Sets where the binary was found
+    #[must_use]
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// A set of binaries whose versions are listed together
+///
+/// # Examples
+/// ```no_run
+/// use clap_version_flag::{ColorfulVersion, VersionSuite};
+///
+/// let version = ColorfulVersion::new("myapp", "1.2.0", "John Doe");
+/// VersionSuite::new(&version)
+///     .binary("myapp-server")
+///     .binary("myapp-agent")
+///     .print();
+/// ```
+#[derive(Clone, Debug)]
+pub struct VersionSuite {
+    current: ColorfulVersion,
+    binaries: Vec<String>,
+    args: Vec<String>,
+    timeout: Duration,
+}
+
+impl VersionSuite {
+    /// Creates a suite listing headed by the running binary's own version
+    #[must_use]
+    pub fn new(current: &ColorfulVersion) -> Self {
+        Self {
+            current: current.clone(),
+            binaries: Vec::new(),
+            args: vec!["--version".into(), "--version-format".into(), "json".into()],
+            timeout: Duration::from_secs(2),
+        }
+    }
+
+    /// Adds a sibling binary to the listing
+    #[must_use]
+    pub fn binary(mut self, name: impl Into<String>) -> Self {
+        self.binaries.push(name.into());
+        self
+    }
+
+    /// Sets the arguments used to query each binary
+    ///
+    /// Defaults to `--version --version-format json`. Output that is not JSON is used
+    /// verbatim (first line) as the version.
+    #[must_use]
+    pub fn query_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.args = args.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets how long each binary may take to answer (default: 2 seconds)
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Locates a binary next to the running executable or on `PATH`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::VersionSuite;
+    ///
+    /// assert!(VersionSuite::locate("surely-no-such-binary-exists").is_none());
+    /// ```
+    #[must_use]
+    pub fn locate(name: &str) -> Option<PathBuf> {
+        let file_name = format!("{name}{}", env::consts::EXE_SUFFIX);
+        let sibling_dir = env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf));
+        let path_dirs = env::var_os("PATH")
+            .map(|paths| env::split_paths(&paths).collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        sibling_dir
+            .into_iter()
+            .chain(path_dirs)
+            .map(|dir| dir.join(&file_name))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// Queries every binary and returns the rows, the running binary first
+    #[must_use]
+    pub fn collect(&self) -> Vec<SuiteEntry> {
+        let mut entries = vec![SuiteEntry {
+            binary: self.current.package_name().to_string(),
+            path: env::current_exe().ok(),
+            outcome: Ok(self.current.clone()),
+        }];
+
+        for binary in &self.binaries {
+            let path = Self::locate(binary);
+            let outcome = match &path {
+                Some(path) => query(path, &self.args, self.timeout)
+                    .map(|output| parse_output(binary, &output)),
+                None => Err("not found".to_string()),
+            };
+            entries.push(SuiteEntry {
+                binary: binary.clone(),
+                path,
+                outcome,
+            });
+        }
+        entries
+    }
+
+    /// Renders the listing as a plain aligned table
+    #[must_use]
+    pub fn as_plain_string(&self) -> String {
+        render_table(&self.collect(), None)
+    }
+
+    /// Renders the listing as a colored aligned table
+    #[must_use]
+    pub fn to_colored_string(&self) -> String {
+        if !color::should_colorize() {
+            return self.as_plain_string();
+        }
+        let depth = self.current.depth();
+        render_table(&self.collect(), Some((&self.current, depth)))
+    }
+
+    /// Prints the colored listing to stdout
+    pub fn print(&self) {
+        self.current.detect_accessibility();
+        crate::color_choice::print_line(&self.to_colored_string());
+    }
+}
+
+/// Runs `path args...` and returns its stdout, killing it after `timeout`
+pub(crate) fn query(path: &Path, args: &[String], timeout: Duration) -> Result<String, String> {
+    let mut child = Command::new(path)
+        .args(args)
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+
+    let mut stdout = child.stdout.take().ok_or("no stdout")?;
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait().map_err(|e| e.to_string())? {
+            Some(status) => break status,
+            None if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err("timed out".to_string());
+            }
+            None => thread::sleep(Duration::from_millis(10)),
+        }
+    };
+
+    if !status.success() {
+        return Err(format!("exited with {status}"));
+    }
+    reader
+        .join()
+        .map_err(|_| "failed to read output".to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Interprets a binary's version output, JSON first, first line otherwise
+pub(crate) fn parse_output(binary: &str, output: &str) -> ColorfulVersion {
+    if let Ok(serde_json::Value::Object(fields)) = serde_json::from_str(output.trim()) {
+        let field = |key: &str| {
+            fields
+                .get(key)
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_string()
+        };
+        let name = fields
+            .get("name")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(binary);
+        return ColorfulVersion::new(name, field("version"), field("author"));
+    }
+
+    let line = output.lines().next().unwrap_or_default().trim();
+    ColorfulVersion::new(binary, line, "")
+}
+
+fn render_table(entries: &[SuiteEntry], colors: Option<(&ColorfulVersion, ColorDepth)>) -> String {
+    let rows: Vec<[String; 3]> = entries
+        .iter()
+        .map(|entry| {
+            let (version, location) = match &entry.outcome {
+                Ok(info) => (
+                    info.version().to_string(),
+                    entry
+                        .path
+                        .as_ref()
+                        .map(|path| path.display().to_string())
+                        .unwrap_or_default(),
+                ),
+                Err(reason) => ("-".to_string(), reason.clone()),
+            };
+            [entry.binary.clone(), version, location]
+        })
+        .collect();
+
+    let header = ["COMPONENT", "VERSION", "PATH"].map(String::from);
+    let widths = [0, 1].map(|column| {
+        rows.iter()
+            .chain(std::iter::once(&header))
+            .map(|row| grapheme::width(&row[column]))
+            .max()
+            .unwrap_or(0)
+    });
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    for (index, row) in std::iter::once(&header).chain(&rows).enumerate() {
+        let name = pad(&row[0], widths[0]);
+        let version = pad(&row[1], widths[1]);
+        let line = match colors {
+            Some((theme, depth)) if index > 0 => format!(
+                "{}  {}  {}",
+                color::paint(&name, theme.palette().name_fg, None, depth),
+                color::paint(&version, theme.palette().version_color, None, depth),
+                color::paint(&row[2], theme.palette().author_color, None, depth)
+            ),
+            _ => format!("{name}  {version}  {}", row[2]),
+        };
+        lines.push(line.trim_end().to_string());
+    }
+    lines.join("\n")
+}
+
+/// Pads `text` with spaces to `width` grapheme clusters
+fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(grapheme::width(text));
+    format!("{text}{}", " ".repeat(padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_output() {
+        let parsed = parse_output(
+            "srv",
+            r#"{"name":"myapp-server","version":"1.2.0","author":"Jane"}"#,
+        );
+        assert_eq!(parsed.package_name(), "myapp-server");
+        assert_eq!(parsed.version(), "1.2.0");
+        assert_eq!(parsed.author(), "Jane");
+    }
+
+    #[test]
+    fn test_parse_plain_output() {
+        let parsed = parse_output("tool", "tool 0.9.1\nextra line\n");
+        assert_eq!(parsed.package_name(), "tool");
+        assert_eq!(parsed.version(), "tool 0.9.1");
+    }
+
+    #[test]
+    fn test_render_table_alignment() {
+        let entries = vec![
+            SuiteEntry {
+                binary: "myapp".into(),
+                path: Some(PathBuf::from("/usr/bin/myapp")),
+                outcome: Ok(ColorfulVersion::new("myapp", "1.2.3", "Jane")),
+            },
+            SuiteEntry {
+                binary: "myapp-server".into(),
+                path: None,
+                outcome: Err("not found".into()),
+            },
+        ];
+
+        assert_eq!(
+            render_table(&entries, None),
+            "COMPONENT     VERSION  PATH\n\
+             myapp         1.2.3    /usr/bin/myapp\n\
+             myapp-server  -        not found"
+        );
+    }
+
+    #[test]
+    fn test_missing_binary_entry() {
+        let version = ColorfulVersion::new("myapp", "1.0.0", "Jane");
+        let entries = VersionSuite::new(&version)
+            .binary("surely-no-such-binary-exists")
+            .collect();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].outcome.as_ref().unwrap().version(), "1.0.0");
+        assert_eq!(entries[1].outcome.as_ref().unwrap_err(), "not found");
+    }
+}
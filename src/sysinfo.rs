@@ -0,0 +1,257 @@
+// Project: clap-version-flag
+// File: src/sysinfo.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Runtime OS, kernel and architecture in the verbose output
+// License: MIT
+
+//! Runtime system information.
+//!
+//! With the `sysinfo` feature, [`ColorfulVersion::with_system_info`] adds the
+//! machine the binary runs on to the verbose output, the way
+//! `kubectl version` does:
+//!
+//! ```text
+//! myapp v1.4.2 by Jane Doe
+//! installed via: cargo install
+//! os: Ubuntu 24.04.1 LTS
+//! kernel: 6.8.0-45-generic
+//! arch: x86_64
+//! ```
+//!
+//! The values are looked up once per process, when the output is first
+//! rendered: the OS from `/etc/os-release` (Linux), `sw_vers` (macOS) or
+//! `ver` (Windows), the kernel from `/proc/sys/kernel/osrelease` or
+//! `uname -r`. When the OS lookup fails the line shows the platform name
+//! Rust was built for (e.g. `linux`); a failed kernel lookup leaves its line
+//! out, and `arch` is always known.
+
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+
+use crate::{ColorSpec, ColorfulVersion, Field, Verbosity};
+
+/// The values on the running machine, once looked up
+static DETECTED: OnceLock<[Option<String>; 3]> = OnceLock::new();
+
+/// A system information line of the verbose output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SystemField {
+    /// Operating system name and version (`os`)
+    Os,
+    /// Kernel release (`kernel`)
+    Kernel,
+    /// CPU architecture (`arch`)
+    Arch,
+}
+
+impl SystemField {
+    /// All fields, in output order
+    const ALL: [Self; 3] = [Self::Os, Self::Kernel, Self::Arch];
+
+    /// Returns the key of the detail line
+    fn key(self) -> &'static str {
+        match self {
+            Self::Os => "os",
+            Self::Kernel => "kernel",
+            Self::Arch => "arch",
+        }
+    }
+
+    /// Returns the value on the running machine, looked up once per process
+    fn value(self) -> Option<&'static str> {
+        DETECTED.get_or_init(|| Self::ALL.map(Self::detect))[self as usize].as_deref()
+    }
+
+    /// Looks up the value on the running machine
+    fn detect(self) -> Option<String> {
+        match self {
+            Self::Os => os_name(),
+            Self::Kernel => kernel_release(),
+            Self::Arch => Some(std::env::consts::ARCH.to_string()),
+        }
+    }
+}
+
+/// System information settings of a [`ColorfulVersion`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct SystemInfo {
+    colors: [Option<(u8, u8, u8)>; 3],
+}
+
+impl ColorfulVersion {
+    /// Adds the runtime OS, kernel and CPU architecture to the verbose output
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_system_info();
+    /// let output = version.as_verbose_plain_string();
+    /// assert!(output.contains(&format!("\narch: {}", std::env::consts::ARCH)));
+    /// ```
+    #[must_use]
+    pub fn with_system_info(mut self) -> Self {
+        self.system_info.get_or_insert_with(SystemInfo::default);
+        self
+    }
+
+    /// Sets the value color of one system information line
+    ///
+    /// Lines without a color use the author color. Enables the lines like
+    /// [`with_system_info`](Self::with_system_info).
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, SystemField};
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_system_info_color(SystemField::Os, (0, 180, 216))
+    ///     .with_system_info_color(SystemField::Arch, (255, 136, 0));
+    /// assert!(version.as_verbose_plain_string().contains("\narch: "));
+    /// ```
+    #[must_use]
+    pub fn with_system_info_color(
+        mut self,
+        field: SystemField,
+        color: impl Into<ColorSpec>,
+    ) -> Self {
+        let info = self.system_info.get_or_insert_with(SystemInfo::default);
+        info.colors[field as usize] = Some(color.into().rgb());
+        self
+    }
+
+    /// Returns the system information lines, if enabled
+    pub(crate) fn system_fields(&self) -> Vec<Field> {
+        let Some(info) = self.system_info else {
+            return Vec::new();
+        };
+        SystemField::ALL
+            .into_iter()
+            .filter_map(|field| {
+                Some(Field {
+                    key: field.key().into(),
+                    value: field.value()?.into(),
+                    color: Some(info.colors[field as usize].unwrap_or(self.palette().author_color)),
+                    level: Verbosity::Verbose,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the operating system name and version, else the platform name
+fn os_name() -> Option<String> {
+    match std::env::consts::OS {
+        "linux" => std::fs::read_to_string("/etc/os-release")
+            .ok()
+            .and_then(|contents| parse_os_release(&contents)),
+        "macos" => {
+            let name = run("sw_vers", &["-productName"])?;
+            Some(match run("sw_vers", &["-productVersion"]) {
+                Some(version) => format!("{name} {version}"),
+                None => name,
+            })
+        }
+        "windows" => run("cmd", &["/C", "ver"]),
+        _ => None,
+    }
+    .or_else(|| Some(std::env::consts::OS.to_string()))
+}
+
+/// Returns the kernel release
+fn kernel_release() -> Option<String> {
+    if cfg!(windows) {
+        return None;
+    }
+    std::fs::read_to_string("/proc/sys/kernel/osrelease")
+        .ok()
+        .map(|release| release.trim().to_string())
+        .filter(|release| !release.is_empty())
+        .or_else(|| run("uname", &["-r"]))
+}
+
+/// Extracts a display name from the contents of `/etc/os-release`
+///
+/// Prefers `PRETTY_NAME`, falling back to `NAME` plus `VERSION_ID`.
+fn parse_os_release(contents: &str) -> Option<String> {
+    let value = |key: &str| {
+        contents.lines().find_map(|line| {
+            let value = line.strip_prefix(key)?.strip_prefix('=')?;
+            let value = value.trim().trim_matches('"').trim_matches('\'');
+            (!value.is_empty()).then(|| value.to_string())
+        })
+    };
+    value("PRETTY_NAME").or_else(|| {
+        let name = value("NAME")?;
+        Some(match value("VERSION_ID") {
+            Some(version) => format!("{name} {version}"),
+            None => name,
+        })
+    })
+}
+
+/// Runs `program`, returning its trimmed, non-empty stdout
+fn run(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !stdout.is_empty()).then_some(stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorDepth;
+
+    #[test]
+    fn test_parse_os_release() {
+        let ubuntu = "NAME=\"Ubuntu\"\nVERSION_ID=\"24.04\"\nPRETTY_NAME=\"Ubuntu 24.04.1 LTS\"\n";
+        assert_eq!(parse_os_release(ubuntu).unwrap(), "Ubuntu 24.04.1 LTS");
+        assert_eq!(
+            parse_os_release("NAME=Alpine\nVERSION_ID=3.20\n").unwrap(),
+            "Alpine 3.20"
+        );
+        assert_eq!(parse_os_release("ID=nixos\n"), None);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        assert!(version.system_fields().is_empty());
+        assert!(!version.as_verbose_plain_string().contains("arch:"));
+    }
+
+    #[test]
+    fn test_fields_in_verbose_output() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo")
+            .with_system_info_color(SystemField::Arch, (1, 2, 3))
+            .with_color_depth(ColorDepth::TrueColor);
+        let keys: Vec<String> = version
+            .system_fields()
+            .into_iter()
+            .map(|field| field.key.into_owned())
+            .collect();
+        assert_eq!(keys.first().map(String::as_str), Some("os"));
+        assert_eq!(keys.last().map(String::as_str), Some("arch"));
+
+        let plain = version.as_verbose_plain_string();
+        assert!(plain.ends_with(&format!("\narch: {}", std::env::consts::ARCH)));
+        assert!(!version.as_plain_string().contains("arch:"));
+        // looked up once, then shared by every render
+        assert!(std::ptr::eq(
+            SystemField::Os.value().unwrap(),
+            SystemField::Os.value().unwrap()
+        ));
+        let colored = version.render_text(Verbosity::Verbose, true);
+        assert!(colored.contains(&format!(
+            "\x1b[38;2;1;2;3m{}\x1b[0m",
+            std::env::consts::ARCH
+        )));
+    }
+}
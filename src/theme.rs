@@ -0,0 +1,390 @@
+// Project: clap-version-flag
+// File: src/theme.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Themes built from semantic color roles
+// License: MIT
+
+//! Themes and semantic color roles.
+//!
+//! Every colored output of this crate picks its colors by [`Role`] rather
+//! than by raw RGB value: the version line uses the primary, secondary and
+//! accent roles, while build age, expiry, entitlement, skew and update lines
+//! use success, warning and error. Setting one [`Theme`] therefore restyles
+//! every feature consistently.
+
+use crate::{ColorSpec, ColorfulVersion, Colors, SegmentBackground};
+
+/// A semantic color role
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Role {
+    /// Brand color, the background of the name badge
+    Primary,
+    /// Text drawn on the primary color, the name itself
+    OnPrimary,
+    /// Secondary text: the author and detail values
+    Secondary,
+    /// Highlights: the version and detail keys
+    Accent,
+    /// Healthy states, e.g. a fresh build or an active subscription
+    Success,
+    /// States that need attention, e.g. an aging build
+    Warning,
+    /// Failures, e.g. an expired build or an incompatible peer
+    Error,
+}
+
+/// Snake-case keys of the roles, in [`Role::ALL`] order
+#[cfg(any(feature = "config", feature = "serde"))]
+pub(crate) const ROLE_KEYS: [&str; 7] = [
+    "primary",
+    "on_primary",
+    "secondary",
+    "accent",
+    "success",
+    "warning",
+    "error",
+];
+
+#[cfg(any(feature = "config", feature = "serde"))]
+impl Role {
+    /// Every role, in declaration order
+    pub(crate) const ALL: [Self; 7] = [
+        Self::Primary,
+        Self::OnPrimary,
+        Self::Secondary,
+        Self::Accent,
+        Self::Success,
+        Self::Warning,
+        Self::Error,
+    ];
+
+    /// Returns the snake-case key used in configuration files
+    pub(crate) fn key(self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::OnPrimary => "on_primary",
+            Self::Secondary => "secondary",
+            Self::Accent => "accent",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+
+    /// Returns the role with the snake-case `key`
+    pub(crate) fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|role| role.key() == key)
+    }
+}
+
+/// Colors for every [`Role`]
+///
+/// The default theme reproduces the crate's classic look: white on
+/// `#AA00FF`, a yellow version and a cyan author.
+///
+/// # Examples
+/// ```
+/// use clap_version_flag::{ColorfulVersion, Role, Theme};
+///
+/// let theme = Theme::new()
+///     .primary((0, 95, 135))
+///     .accent("gold".parse::<clap_version_flag::ColorSpec>().unwrap())
+///     .warning((255, 165, 0));
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_theme(theme);
+///
+/// assert_eq!(version.theme().role(Role::Primary), (0, 95, 135));
+/// assert_eq!(version.theme().role(Role::Accent), (255, 215, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Theme {
+    primary: (u8, u8, u8),
+    on_primary: (u8, u8, u8),
+    secondary: (u8, u8, u8),
+    accent: (u8, u8, u8),
+    success: (u8, u8, u8),
+    warning: (u8, u8, u8),
+    error: (u8, u8, u8),
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: (170, 0, 255),      // #AA00FF
+            on_primary: (255, 255, 255), // #FFFFFF
+            secondary: (0, 255, 255),    // #00FFFF
+            accent: (255, 255, 0),       // #FFFF00
+            success: (0, 255, 0),        // #00FF00
+            warning: (255, 255, 0),      // #FFFF00
+            error: (255, 0, 0),          // #FF0000
+        }
+    }
+}
+
+impl Theme {
+    /// Creates the default theme
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the built-in themes with their names, the default first
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Theme;
+    ///
+    /// assert_eq!(Theme::built_in()[0], ("classic", Theme::default()));
+    /// ```
+    #[must_use]
+    pub fn built_in() -> [(&'static str, Self); 5] {
+        [
+            ("classic", Self::default()),
+            ("high-contrast", Self::high_contrast()),
+            ("solarized", Self::solarized()),
+            ("dracula", Self::dracula()),
+            ("monochrome", Self::monochrome()),
+        ]
+    }
+
+    /// The Solarized palette: base3 text on blue, cyan and yellow
+    #[must_use]
+    pub fn solarized() -> Self {
+        Self::new()
+            .primary((38, 139, 210))
+            .on_primary((253, 246, 227))
+            .secondary((42, 161, 152))
+            .accent((181, 137, 0))
+            .success((133, 153, 0))
+            .warning((203, 75, 22))
+            .error((220, 50, 47))
+    }
+
+    /// The Dracula palette: dark text on purple, cyan and yellow
+    #[must_use]
+    pub fn dracula() -> Self {
+        Self::new()
+            .primary((189, 147, 249))
+            .on_primary((40, 42, 54))
+            .secondary((139, 233, 253))
+            .accent((241, 250, 140))
+            .success((80, 250, 123))
+            .warning((255, 184, 108))
+            .error((255, 85, 85))
+    }
+
+    /// Shades of gray only, for terminals where color carries no meaning
+    #[must_use]
+    pub fn monochrome() -> Self {
+        Self::new()
+            .primary((68, 68, 68))
+            .on_primary((255, 255, 255))
+            .secondary((170, 170, 170))
+            .accent((255, 255, 255))
+            .success((255, 255, 255))
+            .warning((204, 204, 204))
+            .error((255, 255, 255))
+    }
+
+    /// Returns the built-in theme called `name`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::Theme;
+    ///
+    /// assert_eq!(Theme::named("high-contrast"), Some(Theme::high_contrast()));
+    /// assert_eq!(Theme::named("nope"), None);
+    /// ```
+    #[must_use]
+    pub fn named(name: &str) -> Option<Self> {
+        Self::built_in()
+            .into_iter()
+            .find_map(|(candidate, theme)| (candidate == name).then_some(theme))
+    }
+
+    /// Sets the color of a role
+    #[must_use]
+    pub fn with_role(mut self, role: Role, color: impl Into<ColorSpec>) -> Self {
+        let rgb = color.into().rgb();
+        match role {
+            Role::Primary => self.primary = rgb,
+            Role::OnPrimary => self.on_primary = rgb,
+            Role::Secondary => self.secondary = rgb,
+            Role::Accent => self.accent = rgb,
+            Role::Success => self.success = rgb,
+            Role::Warning => self.warning = rgb,
+            Role::Error => self.error = rgb,
+        }
+        self
+    }
+
+    /// Sets the [`Role::Primary`] color
+    #[must_use]
+    pub fn primary(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::Primary, color)
+    }
+
+    /// Sets the [`Role::OnPrimary`] color
+    #[must_use]
+    pub fn on_primary(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::OnPrimary, color)
+    }
+
+    /// Sets the [`Role::Secondary`] color
+    #[must_use]
+    pub fn secondary(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::Secondary, color)
+    }
+
+    /// Sets the [`Role::Accent`] color
+    #[must_use]
+    pub fn accent(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::Accent, color)
+    }
+
+    /// Sets the [`Role::Success`] color
+    #[must_use]
+    pub fn success(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::Success, color)
+    }
+
+    /// Sets the [`Role::Warning`] color
+    #[must_use]
+    pub fn warning(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::Warning, color)
+    }
+
+    /// Sets the [`Role::Error`] color
+    #[must_use]
+    pub fn error(self, color: impl Into<ColorSpec>) -> Self {
+        self.with_role(Role::Error, color)
+    }
+
+    /// Returns the color of a role
+    #[must_use]
+    pub fn role(&self, role: Role) -> (u8, u8, u8) {
+        match role {
+            Role::Primary => self.primary,
+            Role::OnPrimary => self.on_primary,
+            Role::Secondary => self.secondary,
+            Role::Accent => self.accent,
+            Role::Success => self.success,
+            Role::Warning => self.warning,
+            Role::Error => self.error,
+        }
+    }
+}
+
+impl From<Theme> for Colors {
+    fn from(theme: Theme) -> Self {
+        Self {
+            name_fg: theme.on_primary,
+            name_bg: theme.primary,
+            version_color: theme.accent,
+            author_color: theme.secondary,
+            license_color: theme.secondary,
+            success: theme.success,
+            warning: theme.warning,
+            error: theme.error,
+            separator_color: (170, 170, 170), // #AAAAAA
+            version_bg: SegmentBackground::None,
+            author_bg: SegmentBackground::None,
+        }
+    }
+}
+
+impl From<Colors> for Theme {
+    fn from(colors: Colors) -> Self {
+        Self {
+            primary: colors.name_bg,
+            on_primary: colors.name_fg,
+            secondary: colors.author_color,
+            accent: colors.version_color,
+            success: colors.success,
+            warning: colors.warning,
+            error: colors.error,
+        }
+    }
+}
+
+impl ColorfulVersion {
+    /// Styles every output of this version with `theme`
+    ///
+    /// [`with_rgb_colors`](Self::with_rgb_colors) and friends keep working as
+    /// shortcuts for the on-primary, primary, accent and secondary roles.
+    #[must_use]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.colors = Colors::from(theme).with_unthemed_of(&self.colors);
+        self
+    }
+
+    /// Returns the theme in effect
+    ///
+    /// This is [`Theme::high_contrast`] while the
+    /// [accessibility settings](Self::accessibility) ask for high contrast.
+    #[must_use]
+    pub fn theme(&self) -> Theme {
+        self.palette().into()
+    }
+
+    /// Returns the color of a role in the theme in effect
+    pub(crate) fn role(&self, role: Role) -> (u8, u8, u8) {
+        self.theme().role(role)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "config", feature = "serde"))]
+    #[test]
+    fn test_role_keys() {
+        assert_eq!(Role::ALL.map(Role::key), ROLE_KEYS);
+        for role in Role::ALL {
+            assert_eq!(Role::from_key(role.key()), Some(role));
+        }
+        assert_eq!(Role::from_key("brand"), None);
+    }
+
+    #[test]
+    fn test_default_matches_classic_colors() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane");
+        assert_eq!(version.theme(), Theme::default());
+        assert_eq!(version.colors.name_bg, Theme::default().role(Role::Primary));
+        assert_eq!(version.colors.author_color, (0, 255, 255));
+    }
+
+    #[test]
+    fn test_shortcuts_update_roles() {
+        let version = ColorfulVersion::new("app", "1.0", "Jane").with_rgb_colors(
+            (1, 1, 1),
+            (2, 2, 2),
+            (3, 3, 3),
+            (4, 4, 4),
+        );
+        let theme = version.theme();
+        assert_eq!(theme.role(Role::OnPrimary), (1, 1, 1));
+        assert_eq!(theme.role(Role::Primary), (2, 2, 2));
+        assert_eq!(theme.role(Role::Accent), (3, 3, 3));
+        assert_eq!(theme.role(Role::Secondary), (4, 4, 4));
+        assert_eq!(theme.role(Role::Error), (255, 0, 0));
+    }
+
+    #[test]
+    fn test_status_roles_follow_theme() {
+        let theme = Theme::new()
+            .success((1, 0, 0))
+            .warning((2, 0, 0))
+            .error((3, 0, 0));
+        let version = ColorfulVersion::new("app", "1.0", "Jane").with_theme(theme);
+
+        let stale = version.age_field(std::time::Duration::from_secs(400 * 86_400));
+        assert_eq!(stale.color, Some((3, 0, 0)));
+        assert_eq!(
+            version.age_field(std::time::Duration::ZERO).color,
+            Some((1, 0, 0))
+        );
+    }
+}
@@ -0,0 +1,542 @@
+// Project: clap-version-flag
+// File: src/update.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Update check against crates.io for the version flag
+// License: MIT
+
+//! Update check.
+//!
+//! With the `update-check` feature, [`ColorfulVersion::with_update_check`]
+//! makes the version flag look up the latest release and append an
+//! "(update available: 2.1.0)" line when it is newer than the running
+//! version. The lookup runs `curl` on a background thread and is abandoned
+//! after the [timeout](ColorfulVersion::with_update_timeout), so the flag
+//! never waits longer than that. Results are cached for a day, so most runs
+//! answer from the cache without touching the network.
+//!
+//! The `curl` binary must be on the `PATH`; it ships with Windows 10 and
+//! later and with macOS, but minimal container images often lack it. A
+//! missing `curl`, like any other failed lookup, adds no notice and is
+//! recorded in the [warnings](ColorfulVersion::warnings).
+//!
+//! An [`UpdateChecker`] starts the lookup earlier, e.g. at the top of
+//! `main`: by the time the version is printed it has often resolved, so
+//! the flag rarely has to wait at all.
+
+use crate::semver::Version;
+use crate::{ColorfulVersion, Role, VersionFormat, Warning, color};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Default time the version flag waits for the lookup
+const DEFAULT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// How long a cached lookup stays valid
+const CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Where the latest released version is looked up
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum UpdateSource {
+    /// The package's entry on crates.io
+    CratesIo,
+    /// A crates.io-compatible API (`{url}/{package}`), e.g. a mirror
+    Registry(String),
+}
+
+impl UpdateSource {
+    /// Returns the API URL describing `package`
+    fn url(&self, package: &str) -> String {
+        match self {
+            Self::CratesIo => format!("https://crates.io/api/v1/crates/{package}"),
+            Self::Registry(base) => format!("{}/{package}", base.trim_end_matches('/')),
+        }
+    }
+}
+
+/// Update check settings of a [`ColorfulVersion`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UpdateCheck {
+    source: UpdateSource,
+    timeout: Duration,
+    cache_file: Option<PathBuf>,
+}
+
+/// A lookup of the latest release running in the background
+///
+/// The lookup answers from the cache when it is fresh; otherwise `curl`
+/// runs on a thread of its own and the result is cached for later runs.
+/// Clones share the same lookup.
+///
+/// The thread is detached, so waiting on the checker may give up while it
+/// still runs. curl's own `--max-time` is set to the same timeout, which
+/// bounds the thread's lifetime to about that long; when the process exits
+/// first, the lookup ends with it and the cache is simply not written.
+///
+/// # Examples
+/// ```no_run
+/// use clap_version_flag::{ColorfulVersion, UpdateChecker, UpdateSource};
+///
+/// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+///     .with_update_check(UpdateSource::CratesIo);
+/// let checker = UpdateChecker::spawn_background(&version);
+/// // ... parse arguments, load the config ...
+/// let version = version.with_update_checker(checker);
+/// version.print();
+/// ```
+#[derive(Clone, Debug)]
+pub struct UpdateChecker {
+    state: Arc<(Mutex<Lookup>, Condvar)>,
+}
+
+/// Progress of a background lookup
+#[derive(Debug)]
+enum Lookup {
+    Pending,
+    /// The latest version, or why the lookup failed
+    Done(Result<String, String>),
+}
+
+impl UpdateChecker {
+    /// Starts looking up the latest release of `version`
+    ///
+    /// Without [`ColorfulVersion::with_update_check`] there is nothing to
+    /// look up and the checker resolves to `None` right away.
+    #[must_use]
+    pub fn spawn_background(version: &ColorfulVersion) -> Self {
+        let Some(check) = &version.update_check else {
+            return Self::resolved(Err("no update check is configured".to_string()));
+        };
+        let cache = check
+            .cache_file
+            .clone()
+            .or_else(|| default_cache_file(&version.package_name));
+        if let Some(latest) = cache.as_deref().and_then(read_cache) {
+            return Self::resolved(Ok(latest));
+        }
+
+        let checker = Self {
+            state: Arc::new((Mutex::new(Lookup::Pending), Condvar::new())),
+        };
+        let state = Arc::clone(&checker.state);
+        let url = check.source.url(&version.package_name);
+        let timeout = check.timeout;
+        std::thread::spawn(move || {
+            let latest = fetch(&url, timeout);
+            if let (Ok(latest), Some(cache)) = (&latest, &cache) {
+                write_cache(cache, latest);
+            }
+            let (lookup, done) = &*state;
+            *lookup.lock().unwrap_or_else(PoisonError::into_inner) = Lookup::Done(latest);
+            done.notify_all();
+        });
+        checker
+    }
+
+    /// Returns a checker that has already resolved to `latest`
+    fn resolved(latest: Result<String, String>) -> Self {
+        Self {
+            state: Arc::new((Mutex::new(Lookup::Done(latest)), Condvar::new())),
+        }
+    }
+
+    /// Locks the progress, which a panicking lookup cannot leave invalid
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lookup> {
+        self.state.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Returns whether the lookup has finished
+    #[must_use]
+    pub fn is_resolved(&self) -> bool {
+        matches!(*self.lock(), Lookup::Done(_))
+    }
+
+    /// Returns the latest version if the lookup has finished, without waiting
+    #[must_use]
+    pub fn latest(&self) -> Option<String> {
+        match &*self.lock() {
+            Lookup::Done(latest) => latest.clone().ok(),
+            Lookup::Pending => None,
+        }
+    }
+
+    /// Waits at most `timeout` for the lookup and returns the latest version
+    #[must_use]
+    pub fn wait(&self, timeout: Duration) -> Option<String> {
+        self.wait_for(timeout).ok()
+    }
+
+    /// Waits at most `timeout`, explaining why no version is known
+    fn wait_for(&self, timeout: Duration) -> Result<String, String> {
+        let deadline = Instant::now() + timeout;
+        let mut lookup = self.lock();
+        loop {
+            match &*lookup {
+                Lookup::Done(latest) => return latest.clone(),
+                Lookup::Pending => {}
+            }
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return Err(format!("timed out after {timeout:?}"));
+            }
+            lookup = self
+                .state
+                .1
+                .wait_timeout(lookup, left)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+impl ColorfulVersion {
+    /// Checks `source` for a newer release when the version flag is used
+    ///
+    /// A newer release adds an "(update available: {latest})" line below the
+    /// text output, in the [`Role::Warning`] color. JSON output is unchanged.
+    /// Network errors, timeouts and unparsable responses add no notice; they
+    /// are recorded in the [warnings](Self::warnings) instead.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use clap_version_flag::{ColorfulVersion, UpdateSource};
+    /// use std::time::Duration;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+    ///     .with_update_check(UpdateSource::CratesIo)
+    ///     .with_update_timeout(Duration::from_millis(300));
+    /// assert!(version.latest_version().is_some());
+    /// ```
+    #[must_use]
+    pub fn with_update_check(mut self, source: UpdateSource) -> Self {
+        self.update_check = Some(UpdateCheck {
+            source,
+            timeout: DEFAULT_TIMEOUT,
+            cache_file: None,
+        });
+        self
+    }
+
+    /// Sets how long the version flag waits for the lookup (default 500 ms)
+    ///
+    /// Has no effect without [`with_update_check`](Self::with_update_check).
+    #[must_use]
+    pub fn with_update_timeout(mut self, timeout: Duration) -> Self {
+        if let Some(check) = &mut self.update_check {
+            check.timeout = timeout;
+        }
+        self
+    }
+
+    /// Sets the cache file of the update check
+    ///
+    /// Defaults to `clap-version-flag/{package}-latest` in the user's cache
+    /// directory (`$XDG_CACHE_HOME`, `~/.cache` or `%LOCALAPPDATA%`). Has no
+    /// effect without [`with_update_check`](Self::with_update_check).
+    #[must_use]
+    pub fn with_update_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        if let Some(check) = &mut self.update_check {
+            check.cache_file = Some(path.into());
+        }
+        self
+    }
+
+    /// Uses the lookup started by `checker` instead of starting one
+    ///
+    /// A lookup still running when the version is printed is waited for up
+    /// to the [timeout](Self::with_update_timeout). Has no effect without
+    /// [`with_update_check`](Self::with_update_check).
+    #[must_use]
+    pub fn with_update_checker(mut self, checker: UpdateChecker) -> Self {
+        self.update_checker = Some(checker);
+        self
+    }
+
+    /// Returns the latest released version, if an update check is configured
+    ///
+    /// Answers from the cache while it is fresh; otherwise waits at most the
+    /// configured timeout for the lookup.
+    #[must_use]
+    pub fn latest_version(&self) -> Option<String> {
+        let timeout = self.update_check.as_ref()?.timeout;
+        let outcome = match &self.update_checker {
+            Some(checker) => checker.wait_for(timeout),
+            None => UpdateChecker::spawn_background(self).wait_for(timeout),
+        };
+        outcome
+            .map_err(|reason| self.warnings.push(Warning::UpdateCheckSkipped { reason }))
+            .ok()
+    }
+
+    /// Returns the update notice if a newer release exists
+    fn update_notice(&self, colored: bool) -> Option<String> {
+        let latest = self.latest_version()?;
+        if !is_newer(&latest, &self.version) {
+            return None;
+        }
+        let text = format!("(update available: {latest})");
+        Some(if colored {
+            color::paint(&text, self.role(Role::Warning), None, self.depth())
+        } else {
+            text
+        })
+    }
+
+    /// Appends the update notice to text output of the version flag
+    pub(crate) fn with_update_notice(&self, mut output: String, format: VersionFormat) -> String {
+        let colored = match format {
+            VersionFormat::Json => return output,
+            VersionFormat::Plain => false,
+            VersionFormat::Text => self.colorize(),
+        };
+        if let Some(notice) = self.update_notice(colored) {
+            output.push('\n');
+            output.push_str(&notice);
+        }
+        output
+    }
+}
+
+/// Returns whether `latest` is a newer release than `current`
+///
+/// Versions compare by SemVer precedence, so a release is newer than its
+/// pre-releases and build metadata is ignored; versions that are not SemVer
+/// never count as newer.
+fn is_newer(latest: &str, current: &str) -> bool {
+    match (Version::parse(latest), Version::parse(current)) {
+        (Ok(latest), Ok(current)) => latest.cmp_precedence(&current).is_gt(),
+        _ => false,
+    }
+}
+
+/// Queries `url` with curl, giving up after `timeout`
+fn fetch(url: &str, timeout: Duration) -> Result<String, String> {
+    let seconds = format!("{:.3}", timeout.as_secs_f64().max(0.001));
+    let output = Command::new("curl")
+        .args(["--silent", "--fail", "--location", "--max-time", &seconds])
+        .args([
+            "--user-agent",
+            concat!("clap-version-flag/", env!("CARGO_PKG_VERSION")),
+        ])
+        .arg(url)
+        .stdin(Stdio::null())
+        .stderr(Stdio::null())
+        .output()
+        .map_err(|error| match error.kind() {
+            io::ErrorKind::NotFound => "curl is not installed".to_string(),
+            _ => format!("could not run curl: {error}"),
+        })?;
+    if !output.status.success() {
+        return Err(format!("curl failed ({})", output.status));
+    }
+    parse_latest(&String::from_utf8_lossy(&output.stdout))
+        .ok_or_else(|| "the response names no version".to_string())
+}
+
+/// Extracts the latest stable version from a crates.io crate response
+fn parse_latest(body: &str) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_str(body).ok()?;
+    let krate = body.get("crate")?;
+    ["max_stable_version", "newest_version", "max_version"]
+        .iter()
+        .find_map(|key| {
+            krate
+                .get(key)?
+                .as_str()
+                .filter(|version| !version.is_empty())
+        })
+        .map(str::to_string)
+}
+
+/// Returns the default cache file for `package`
+fn default_cache_file(package: &str) -> Option<PathBuf> {
+    let env = |name| std::env::var_os(name).filter(|value| !value.is_empty());
+    let base = env("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env("LOCALAPPDATA").map(PathBuf::from))
+        .or_else(|| env("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+    Some(
+        base.join("clap-version-flag")
+            .join(format!("{package}-latest")),
+    )
+}
+
+/// Reads a fresh cached version (`{unix seconds} {version}`)
+fn read_cache(path: &Path) -> Option<String> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    let (stamp, latest) = contents.trim().split_once(' ')?;
+    let written = SystemTime::UNIX_EPOCH + Duration::from_secs(stamp.parse().ok()?);
+    let age = SystemTime::now().duration_since(written).ok()?;
+    (age < CACHE_TTL).then(|| latest.to_string())
+}
+
+/// Caches `latest`, ignoring failures: the cache is only an optimization
+///
+/// The entry is written to a temporary file next to the cache and renamed
+/// over it, so a concurrent run never reads a half-written entry.
+fn write_cache(path: &Path, latest: &str) {
+    let Ok(now) = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let mut temporary = path.as_os_str().to_owned();
+    temporary.push(format!(".{}.tmp", std::process::id()));
+    let temporary = PathBuf::from(temporary);
+    let written = std::fs::write(&temporary, format!("{} {latest}\n", now.as_secs()))
+        .and_then(|()| std::fs::rename(&temporary, path));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&temporary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "clap-version-flag-update-{}-{name}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("2.1.0", "2.0.9"));
+        assert!(is_newer("1.0.0", "1.0.0-beta.1"));
+        assert!(!is_newer("1.0.0", "1.0.0"));
+        assert!(!is_newer("1.0.0-rc.1", "1.0.0"));
+        assert!(!is_newer("0.9.0", "1.0.0"));
+        assert!(!is_newer("garbage", "1.0.0"));
+        // build metadata is not a newer release
+        assert!(!is_newer("1.0.0+build.2", "1.0.0+build.1"));
+        assert!(!is_newer("1.0.0+sha.b", "1.0.0"));
+    }
+
+    #[test]
+    fn test_parse_latest() {
+        let body =
+            r#"{"crate":{"id":"myapp","max_stable_version":"2.1.0","max_version":"2.2.0-rc.1"}}"#;
+        assert_eq!(parse_latest(body).as_deref(), Some("2.1.0"));
+        let body = r#"{"crate":{"max_stable_version":"","max_version":"0.1.0-alpha"}}"#;
+        assert_eq!(parse_latest(body).as_deref(), Some("0.1.0-alpha"));
+        assert_eq!(parse_latest("{}"), None);
+        assert_eq!(parse_latest("not json"), None);
+    }
+
+    #[test]
+    fn test_parse_latest_reads_the_crate_object() {
+        // keys in any order, and the same keys nested elsewhere are ignored
+        let body = r#"{
+            "versions": [{"num": "9.0.0", "max_stable_version": "9.0.0"}],
+            "crate": {
+                "links": {"max_stable_version": "/bogus"},
+                "max_version": "2.2.0-rc.1",
+                "max_stable_version": "2.1.0"
+            }
+        }"#;
+        assert_eq!(parse_latest(body).as_deref(), Some("2.1.0"));
+    }
+
+    #[test]
+    fn test_source_url() {
+        assert_eq!(
+            UpdateSource::CratesIo.url("myapp"),
+            "https://crates.io/api/v1/crates/myapp"
+        );
+        assert_eq!(
+            UpdateSource::Registry("https://mirror.example/api/".into()).url("myapp"),
+            "https://mirror.example/api/myapp"
+        );
+    }
+
+    #[test]
+    fn test_cache_round_trip_and_expiry() {
+        let path = cache_file("round-trip");
+        write_cache(&path, "3.0.0");
+        assert_eq!(read_cache(&path).as_deref(), Some("3.0.0"));
+        // the temporary file is renamed into place, not left behind
+        let mut temporary = path.clone().into_os_string();
+        temporary.push(format!(".{}.tmp", std::process::id()));
+        assert!(!Path::new(&temporary).exists());
+
+        std::fs::write(&path, "1000 3.0.0\n").unwrap();
+        assert_eq!(read_cache(&path), None);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_notice_from_cache() {
+        let path = cache_file("notice");
+        write_cache(&path, "2.1.0");
+        let version = ColorfulVersion::new("app", "2.0.0", "Jane")
+            .with_color_depth(crate::ColorDepth::TrueColor)
+            .with_update_check(UpdateSource::CratesIo)
+            .with_update_cache(&path);
+
+        assert_eq!(
+            version.with_update_notice("app v2.0.0 by Jane".into(), VersionFormat::Plain),
+            "app v2.0.0 by Jane\n(update available: 2.1.0)"
+        );
+        assert_eq!(
+            version.with_update_notice("{}".into(), VersionFormat::Json),
+            "{}"
+        );
+        assert_eq!(
+            version.update_notice(true).unwrap(),
+            "\x1b[38;2;255;255;0m(update available: 2.1.0)\x1b[0m"
+        );
+
+        write_cache(&path, "2.0.0");
+        assert_eq!(version.update_notice(false), None);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_checker_outlives_cache() {
+        let path = cache_file("checker");
+        write_cache(&path, "2.1.0");
+        let version = ColorfulVersion::new("app", "2.0.0", "Jane")
+            .with_update_check(UpdateSource::CratesIo)
+            .with_update_cache(&path);
+        let checker = UpdateChecker::spawn_background(&version);
+        let _ = std::fs::remove_file(path);
+        assert!(checker.is_resolved());
+        assert_eq!(checker.latest().as_deref(), Some("2.1.0"));
+
+        let version = version.with_update_checker(checker.clone());
+        assert_eq!(
+            version.update_notice(false).as_deref(),
+            Some("(update available: 2.1.0)")
+        );
+        let unchecked = ColorfulVersion::new("app", "2.0.0", "Jane");
+        assert_eq!(UpdateChecker::spawn_background(&unchecked).latest(), None);
+    }
+
+    #[test]
+    fn test_pending_lookup_times_out() {
+        let checker = UpdateChecker {
+            state: Arc::new((Mutex::new(Lookup::Pending), Condvar::new())),
+        };
+        assert!(!checker.is_resolved());
+        assert_eq!(
+            checker.wait_for(Duration::from_millis(5)),
+            Err("timed out after 5ms".to_string())
+        );
+    }
+
+    #[test]
+    fn test_settings_require_check() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+            .with_update_timeout(Duration::from_millis(1))
+            .with_update_cache("unused");
+        assert_eq!(version.update_check, None);
+        assert_eq!(version.latest_version(), None);
+    }
+}
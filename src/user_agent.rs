@@ -0,0 +1,151 @@
+// Project: clap-version-flag
+// File: src/user_agent.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: HTTP User-Agent strings
+// License: MIT
+
+//! User-Agent strings.
+//!
+//! A CLI that calls an API should say who it is, in a form server logs and
+//! rate limiters can parse: `myapp/1.2.3 (+https://github.com/jane/myapp)`.
+//! [`ColorfulVersion::user_agent`] builds it from the configured name,
+//! version and repository, and
+//! [`ColorfulVersion::user_agent_with_platform`] adds the OS and
+//! architecture to the comment.
+
+use crate::ColorfulVersion;
+
+impl ColorfulVersion {
+    /// Returns `name/version`, followed by `(+url)` when a repository or
+    /// homepage is set
+    ///
+    /// Characters a product token cannot hold, such as spaces or `/`,
+    /// become `-` in the name and version. Comments and the URL lose `(`,
+    /// `)`, `\` and non-ASCII characters, and control characters are
+    /// dropped everywhere, so the result is always a valid header value
+    /// (RFC 9110). A URL without a scheme gets `https://`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.2.3", "John Doe")
+    ///     .repository("github.com/jane/myapp")
+    ///     .build();
+    /// assert_eq!(version.user_agent(), "myapp/1.2.3 (+https://github.com/jane/myapp)");
+    ///
+    /// let version = ColorfulVersion::new("my app", "1.2.3", "John Doe");
+    /// assert_eq!(version.user_agent(), "my-app/1.2.3");
+    /// ```
+    #[must_use]
+    pub fn user_agent(&self) -> String {
+        self.user_agent_with(&[])
+    }
+
+    /// Returns the [`user_agent`](Self::user_agent) with the OS and
+    /// architecture in the comment, e.g. `myapp/1.2.3 (linux; x86_64)`
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.3", "John Doe");
+    /// let platform = format!("({}; {})", std::env::consts::OS, std::env::consts::ARCH);
+    /// assert_eq!(version.user_agent_with_platform(), format!("myapp/1.2.3 {platform}"));
+    /// ```
+    #[must_use]
+    pub fn user_agent_with_platform(&self) -> String {
+        self.user_agent_with(&[std::env::consts::OS, std::env::consts::ARCH])
+    }
+
+    /// Returns the User-Agent with `comments` before the URL
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.2.3", "John Doe")
+    ///     .homepage("https://myapp.dev")
+    ///     .build();
+    /// assert_eq!(
+    ///     version.user_agent_with(&["ci"]),
+    ///     "myapp/1.2.3 (ci; +https://myapp.dev)"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn user_agent_with(&self, comments: &[&str]) -> String {
+        let mut out = format!("{}/{}", token(&self.package_name), token(&self.version));
+        let url = self.repository().or_else(|| self.homepage()).map(|url| {
+            if url.contains("://") {
+                format!("+{url}")
+            } else {
+                format!("+https://{url}")
+            }
+        });
+        let comment: Vec<String> = comments
+            .iter()
+            .copied()
+            .chain(url.as_deref())
+            .map(comment_text)
+            .filter(|comment| !comment.is_empty())
+            .collect();
+        if !comment.is_empty() {
+            out.push_str(&format!(" ({})", comment.join("; ")));
+        }
+        out
+    }
+}
+
+/// Returns `text` as an RFC 9110 token: control characters are dropped
+/// and other characters outside `tchar` become `-`
+fn token(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control())
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c) {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect()
+}
+
+/// Returns `text` with only the characters of an RFC 9110 comment: visible
+/// ASCII and spaces, without the parentheses and the backslash
+fn comment_text(text: &str) -> String {
+    text.chars()
+        .filter(|c| (c.is_ascii_graphic() || *c == ' ') && !matches!(c, '(' | ')' | '\\'))
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_before_homepage() {
+        let version = ColorfulVersion::builder("app", "1.0", "Jo")
+            .homepage("https://app.dev")
+            .repository("https://git.dev/app")
+            .build();
+        assert_eq!(
+            version.user_agent_with(&["", "linux"]),
+            "app/1.0 (linux; +https://git.dev/app)"
+        );
+    }
+
+    #[test]
+    fn test_components_cannot_break_the_header() {
+        let version = ColorfulVersion::builder("my/app (x)\r\nX-Evil: 1", "1.0\n (beta)", "Jo")
+            .repository("https://git.dev/app)\r\nSet-Cookie: a=b")
+            .build();
+        assert_eq!(
+            version.user_agent_with(&["ci) (linux\t", "\u{1b}[0m"]),
+            "my-app--x-X-Evil--1/1.0--beta- (ci linux; [0m; +https://git.dev/appSet-Cookie: a=b)"
+        );
+    }
+}
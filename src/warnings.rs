@@ -0,0 +1,244 @@
+// Project: clap-version-flag
+// File: src/warnings.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Non-fatal problems collected while rendering
+// License: MIT
+
+//! Warnings.
+//!
+//! Rendering never fails: an environment override that is not a color keeps
+//! the configured color, an update check that times out adds no notice, and
+//! an unreadable color pair is still drawn. Each of these is recorded as a
+//! [`Warning`] in the version's [`Warnings`] sink, so an application can
+//! report them instead of leaving users guessing:
+//!
+//! ```
+//! use clap_version_flag::ColorfulVersion;
+//!
+//! let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe")
+//!     .with_rgb_colors((250, 250, 250), (255, 255, 255), (255, 255, 0), (0, 255, 255));
+//! version.write_colored_to(&mut Vec::new()).unwrap();
+//! for warning in version.warnings().take() {
+//!     eprintln!("warning: {warning}");
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use crate::{ColorSpec, ColorfulVersion, color::Rgb};
+
+/// A non-fatal problem encountered while rendering
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// An environment color override was not a color and was ignored
+    InvalidColorOverride {
+        /// The variable, e.g. `MYAPP_VERSION_NAME_BG`
+        variable: String,
+        /// Its value
+        value: String,
+    },
+    /// The update check was skipped or gave no answer
+    UpdateCheckSkipped {
+        /// Why, e.g. "timed out after 500ms"
+        reason: String,
+    },
+    /// The name is hard to read on its badge
+    LowContrast {
+        /// Text color
+        foreground: Rgb,
+        /// Badge color
+        background: Rgb,
+        /// WCAG contrast ratio, from 1 to 21
+        ratio: f64,
+    },
+    /// The name color was changed to meet the minimum contrast
+    ContrastAdjusted {
+        /// Configured text color
+        from: Rgb,
+        /// Text color used instead
+        to: Rgb,
+        /// WCAG contrast ratio reached, from 1 to 21
+        ratio: f64,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidColorOverride { variable, value } => write!(
+                f,
+                "ignored {variable}={value:?}: not a color, kept the configured color"
+            ),
+            Self::UpdateCheckSkipped { reason } => write!(f, "update check skipped: {reason}"),
+            Self::LowContrast {
+                foreground,
+                background,
+                ratio,
+            } => write!(
+                f,
+                "low contrast ({ratio:.1}:1) between the name color {} and its background {}",
+                ColorSpec::from(*foreground),
+                ColorSpec::from(*background)
+            ),
+            Self::ContrastAdjusted { from, to, ratio } => write!(
+                f,
+                "adjusted the name color {} to {} for {ratio:.1}:1 contrast",
+                ColorSpec::from(*from),
+                ColorSpec::from(*to)
+            ),
+        }
+    }
+}
+
+/// A shared sink of [`Warning`]s
+///
+/// Clones share the same warnings, so one sink can collect the warnings of
+/// several versions (see [`ColorfulVersion::with_warnings`]). A warning
+/// already in the sink is not recorded twice.
+#[derive(Debug, Default)]
+pub struct Warnings {
+    /// Created on first use, so that an empty sink is `const`
+    inner: OnceLock<Arc<Mutex<Vec<Warning>>>>,
+}
+
+impl Clone for Warnings {
+    fn clone(&self) -> Self {
+        Self {
+            inner: OnceLock::from(Arc::clone(self.shared())),
+        }
+    }
+}
+
+impl Warnings {
+    /// Creates an empty sink
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: OnceLock::new(),
+        }
+    }
+
+    /// Returns the warnings shared by all clones of this sink
+    fn shared(&self) -> &Arc<Mutex<Vec<Warning>>> {
+        self.inner.get_or_init(Arc::default)
+    }
+
+    /// Records `warning` unless it is already in the sink
+    pub(crate) fn push(&self, warning: Warning) {
+        let mut warnings = self.shared().lock().unwrap_or_else(PoisonError::into_inner);
+        if !warnings.contains(&warning) {
+            warnings.push(warning);
+        }
+    }
+
+    /// Returns the recorded warnings, leaving them in the sink
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<Warning> {
+        self.shared()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Removes and returns the recorded warnings
+    #[must_use]
+    pub fn take(&self) -> Vec<Warning> {
+        std::mem::take(&mut *self.shared().lock().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Returns whether no warning is recorded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.shared()
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .is_empty()
+    }
+
+    /// Prints and removes the recorded warnings, one `warning: ...` line
+    /// each, to stderr
+    pub fn print(&self) {
+        for warning in self.take() {
+            eprintln!("warning: {warning}");
+        }
+    }
+}
+
+impl ColorfulVersion {
+    /// Collects warnings in `sink` instead of a sink of this version's own
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::{ColorfulVersion, Warnings};
+    ///
+    /// let sink = Warnings::new();
+    /// let version = ColorfulVersion::new("myapp", "1.0.0", "John Doe").with_warnings(sink.clone());
+    /// assert!(sink.is_empty());
+    /// ```
+    #[must_use]
+    pub fn with_warnings(mut self, sink: Warnings) -> Self {
+        self.warnings = sink;
+        self
+    }
+
+    /// Returns the sink collecting this version's warnings
+    #[must_use]
+    pub fn warnings(&self) -> &Warnings {
+        &self.warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Verbosity;
+
+    #[test]
+    fn test_low_contrast_recorded_once() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_rgb_colors(
+            (255, 255, 255),
+            (255, 255, 0),
+            (0, 0, 0),
+            (0, 0, 0),
+        );
+        assert!(version.warnings().is_empty());
+        version.write_colored_to(&mut Vec::new()).unwrap();
+        version.write_colored_to(&mut Vec::new()).unwrap();
+        let warnings = version.warnings().take();
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0]
+                .to_string()
+                .starts_with("low contrast (1.1:1) between the name color #FFFFFF")
+        );
+        assert!(version.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_plain_output_skips_contrast() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_rgb_colors(
+            (1, 1, 1),
+            (1, 1, 1),
+            (0, 0, 0),
+            (0, 0, 0),
+        );
+        let _ = version.render_plain(Verbosity::Normal);
+        assert!(version.warnings().is_empty());
+    }
+
+    #[test]
+    fn test_shared_sink() {
+        let sink = Warnings::new();
+        let version = ColorfulVersion::new("app", "1.0", "Jo").with_warnings(sink.clone());
+        version.warnings().push(Warning::UpdateCheckSkipped {
+            reason: "offline".into(),
+        });
+        assert_eq!(
+            sink.to_vec()[0].to_string(),
+            "update check skipped: offline"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+// Project: clap-version-flag
+// File: src/windows_console.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Legacy Windows console support for stdout output
+// License: MIT
+
+//! Legacy Windows consoles.
+//!
+//! Windows 10 and later consoles understand escape codes once virtual
+//! terminal processing is switched on; older consoles (and `conhost` with the
+//! legacy mode ticked) print them as garbage. With the `windows-console`
+//! feature, every method printing to stdout
+//! ([`print`](crate::ColorfulVersion::print),
+//! [`check`](crate::ColorfulVersion::check), the verbose, dependency, suite
+//! and preview output, ...) checks stdout at runtime:
+//!
+//! - not a console (redirected, or not Windows): printed as usual;
+//! - virtual terminal processing already on, or switched on successfully:
+//!   printed as usual;
+//! - otherwise: written through the console API, each run of text with the
+//!   console text attribute nearest to its colors. Other escape sequences,
+//!   such as hyperlinks, are dropped, and animations fall back to the
+//!   final line.
+
+// Only the parsing is compiled (and tested) on other platforms
+#![cfg_attr(not(windows), allow(dead_code))]
+
+use crate::color::{self, Piece};
+
+/// Console attribute bits of the 16 basic ANSI colors, by ANSI index
+///
+/// ANSI orders the color bits red, green, blue; the console API orders them
+/// blue, green, red, with bit 3 for the bright variants.
+const CONSOLE_COLORS: [u16; 16] = [0, 4, 2, 6, 1, 5, 3, 7, 8, 12, 10, 14, 9, 13, 11, 15];
+
+/// Colors of a run of text, as ANSI indices; `None` keeps the console default
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Style {
+    fg: Option<u8>,
+    bg: Option<u8>,
+}
+
+impl Style {
+    /// Returns the console text attributes, filling gaps from `default`
+    fn attributes(self, default: u16) -> u16 {
+        let fg = self
+            .fg
+            .map_or(default & 0x0F, |index| CONSOLE_COLORS[usize::from(index)]);
+        let bg = self.bg.map_or(default & 0xF0, |index| {
+            CONSOLE_COLORS[usize::from(index)] << 4
+        });
+        (default & !0xFF) | fg | bg
+    }
+
+    /// Applies the parameters of an SGR sequence
+    ///
+    /// 256-color and RGB colors map to the nearest of the 16 colors.
+    fn apply(&mut self, params: &str) {
+        let mut params = params.split(';');
+        while let Some(param) = params.next() {
+            if param.is_empty() {
+                *self = Self::default();
+                continue;
+            }
+            match param.parse::<u8>() {
+                Ok(code @ (38 | 48)) => {
+                    let mut number = || params.next().and_then(|n| n.parse::<u8>().ok());
+                    let rgb = match number() {
+                        Some(5) => number().map(color::ansi256_rgb),
+                        Some(2) => match (number(), number(), number()) {
+                            (Some(r), Some(g), Some(b)) => Some((r, g, b)),
+                            _ => None,
+                        },
+                        _ => None,
+                    };
+                    let index = rgb.map(color::to_ansi16);
+                    if code == 38 {
+                        self.fg = index.or(self.fg);
+                    } else {
+                        self.bg = index.or(self.bg);
+                    }
+                }
+                Ok(0) => *self = Self::default(),
+                Ok(code @ 30..=37) => self.fg = Some(code - 30),
+                Ok(39) => self.fg = None,
+                Ok(code @ 40..=47) => self.bg = Some(code - 40),
+                Ok(49) => self.bg = None,
+                Ok(code @ 90..=97) => self.fg = Some(code - 90 + 8),
+                Ok(code @ 100..=107) => self.bg = Some(code - 100 + 8),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Splits rendered output into runs of text with their colors
+///
+/// SGR sequences set the colors of the following run; other escape
+/// sequences (hyperlinks, ...) are dropped.
+fn segments(rendered: &str) -> Vec<(String, Style)> {
+    let mut style = Style::default();
+    color::pieces(rendered)
+        .filter_map(|piece| match piece {
+            Piece::Text(text) => Some((text.to_string(), style)),
+            Piece::Sgr(params) => {
+                style.apply(params);
+                None
+            }
+            Piece::Control => None,
+        })
+        .collect()
+}
+
+/// Returns whether stdout is a console that does not understand escape codes
+///
+/// Switches on virtual terminal processing where possible.
+#[cfg(windows)]
+pub(crate) fn is_legacy() -> bool {
+    sys::Console::stdout().is_some_and(|console| !console.enable_virtual_terminal())
+}
+
+/// Writes `text` plus a newline through the console API on legacy consoles
+///
+/// Returns `false`, writing nothing, when stdout is not a console or
+/// understands escape codes.
+#[cfg(windows)]
+pub(crate) fn print_line(text: &str) -> bool {
+    use std::io::Write;
+
+    let Some(console) = sys::Console::stdout() else {
+        return false;
+    };
+    if console.enable_virtual_terminal() {
+        return false;
+    }
+    let default = console.attributes();
+    let mut stdout = std::io::stdout().lock();
+    for (text, style) in segments(text) {
+        let _ = stdout.flush();
+        console.set_attributes(style.attributes(default));
+        let _ = stdout.write_all(text.as_bytes());
+    }
+    let _ = stdout.flush();
+    console.set_attributes(default);
+    let _ = writeln!(stdout);
+    true
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::ffi::c_void;
+
+    type Handle = *mut c_void;
+
+    const STD_OUTPUT_HANDLE: u32 = -11i32 as u32;
+    const ENABLE_VIRTUAL_TERMINAL_PROCESSING: u32 = 0x0004;
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Coord {
+        x: i16,
+        y: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SmallRect {
+        left: i16,
+        top: i16,
+        right: i16,
+        bottom: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    #[allow(dead_code)]
+    struct ScreenBufferInfo {
+        size: Coord,
+        cursor_position: Coord,
+        attributes: u16,
+        window: SmallRect,
+        maximum_window_size: Coord,
+    }
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetStdHandle(handle: u32) -> Handle;
+        fn GetConsoleMode(console: Handle, mode: *mut u32) -> i32;
+        fn SetConsoleMode(console: Handle, mode: u32) -> i32;
+        fn GetConsoleScreenBufferInfo(console: Handle, info: *mut ScreenBufferInfo) -> i32;
+        fn SetConsoleTextAttribute(console: Handle, attributes: u16) -> i32;
+    }
+
+    /// The console attached to stdout
+    pub(super) struct Console {
+        handle: Handle,
+        mode: u32,
+    }
+
+    impl Console {
+        /// Returns the console stdout writes to, if it is one
+        pub(super) fn stdout() -> Option<Self> {
+            // SAFETY: plain Win32 calls; `mode` outlives the call
+            unsafe {
+                let handle = GetStdHandle(STD_OUTPUT_HANDLE);
+                let mut mode = 0;
+                if handle.is_null() || GetConsoleMode(handle, &mut mode) == 0 {
+                    return None;
+                }
+                Some(Self { handle, mode })
+            }
+        }
+
+        /// Switches on virtual terminal processing, returning whether it is on
+        pub(super) fn enable_virtual_terminal(&self) -> bool {
+            if self.mode & ENABLE_VIRTUAL_TERMINAL_PROCESSING != 0 {
+                return true;
+            }
+            // SAFETY: the handle was validated by GetConsoleMode
+            unsafe {
+                SetConsoleMode(self.handle, self.mode | ENABLE_VIRTUAL_TERMINAL_PROCESSING) != 0
+            }
+        }
+
+        /// Returns the current text attributes (light gray on black if unknown)
+        pub(super) fn attributes(&self) -> u16 {
+            let mut info = ScreenBufferInfo::default();
+            // SAFETY: `info` is a correctly laid out, writable buffer
+            if unsafe { GetConsoleScreenBufferInfo(self.handle, &mut info) } == 0 {
+                return 0x07;
+            }
+            info.attributes
+        }
+
+        /// Sets the text attributes of subsequent output
+        pub(super) fn set_attributes(&self, attributes: u16) {
+            // SAFETY: the handle was validated by GetConsoleMode
+            unsafe {
+                SetConsoleTextAttribute(self.handle, attributes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorDepth, ColorfulVersion, Verbosity};
+
+    #[test]
+    fn test_segments_of_rendered_output() {
+        let version = ColorfulVersion::new("app", "1.0", "Jo");
+        let ansi16 = version.clone().with_color_depth(ColorDepth::Ansi16);
+        let ansi16 = segments(&ansi16.render_text(Verbosity::Normal, true));
+        let text: String = ansi16.iter().map(|(text, _)| text.as_str()).collect();
+        assert_eq!(text, "app v1.0 by Jo");
+        assert!(ansi16.iter().all(|(_, style)| style.fg.is_some()));
+        assert!(ansi16[0].1.bg.is_some());
+
+        // deeper colors map to the same console colors
+        for depth in [ColorDepth::Ansi256, ColorDepth::TrueColor] {
+            let rendered = version
+                .clone()
+                .with_color_depth(depth)
+                .render_text(Verbosity::Normal, true);
+            assert_eq!(segments(&rendered), ansi16, "{depth:?}");
+        }
+    }
+
+    #[test]
+    fn test_sgr_parameters() {
+        let parts =
+            segments("\x1b[91;44mhi\x1b[0m plain \x1b]8;;https://x\x1b\\link\x1b]8;;\x1b\\");
+        assert_eq!(
+            parts,
+            [
+                (
+                    "hi".to_string(),
+                    Style {
+                        fg: Some(9),
+                        bg: Some(4)
+                    }
+                ),
+                (" plain ".to_string(), Style::default()),
+                ("link".to_string(), Style::default()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extended_colors() {
+        let mut style = Style::default();
+        style.apply("1;38;2;255;0;0;48;5;21");
+        assert_eq!(
+            style,
+            Style {
+                fg: Some(9),
+                bg: Some(4)
+            }
+        );
+        style.apply("38;2;1");
+        assert_eq!(style.fg, Some(9));
+    }
+
+    #[test]
+    fn test_console_attributes() {
+        let bright_red_on_blue = Style {
+            fg: Some(9),
+            bg: Some(4),
+        };
+        assert_eq!(bright_red_on_blue.attributes(0x07), 0x0C | 0x10);
+        assert_eq!(Style::default().attributes(0x1E), 0x1E);
+        let yellow = Style {
+            fg: Some(3),
+            bg: None,
+        };
+        assert_eq!(yellow.attributes(0x8107), 0x8106);
+    }
+}
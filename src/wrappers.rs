@@ -0,0 +1,304 @@
+// Project: clap-version-flag
+// File: src/wrappers.rs
+// Author: Hadi Cahyadi <cumulus13@gmail.com>
+// Date: 2026-10-14
+// Description: Version stanzas for Python and Node.js wrapper packages
+// License: MIT
+
+//! Wrapper package versions.
+//!
+//! Projects that ship a Python or npm package around the Rust binary need
+//! the wrapper's version locked to the binary's. These generators produce
+//! the wrapper's version declaration from the configured version, typically
+//! from a build script or an `xtask`, so the two can no longer drift apart.
+
+use crate::{ColorfulVersion, VersionError, json};
+
+/// Parses a pre-release number, `None` unless all digits
+fn numeric(text: &str) -> Option<u64> {
+    text.bytes()
+        .all(|b| b.is_ascii_digit())
+        .then(|| text.parse().ok())
+        .flatten()
+}
+
+impl ColorfulVersion {
+    /// Returns the version in PEP 440 form, as Python packaging expects
+    ///
+    /// SemVer pre-releases `alpha.N`, `beta.N` and `rc.N` become `aN`, `bN`
+    /// and `rcN`, with the spellings PEP 440 normalizes (`a`, `b`, `c`,
+    /// `pre`, `preview`) and a number attached to the label (`rc1`) accepted
+    /// as well; a following `dev.N` becomes `.devN`, e.g. `rc1.dev2`. Other
+    /// pre-releases become `.devN`, further identifiers are dropped, and
+    /// build metadata becomes a local version label. Versions that are not
+    /// SemVer are returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let pep440 = |version| ColorfulVersion::new("myapp", version, "John Doe").pep440_version();
+    /// assert_eq!(pep440("1.2.3"), "1.2.3");
+    /// assert_eq!(pep440("1.2.3-rc.1"), "1.2.3rc1");
+    /// assert_eq!(pep440("2.0.0-beta+sha.5114f85"), "2.0.0b0+sha.5114f85");
+    /// ```
+    #[must_use]
+    pub fn pep440_version(&self) -> String {
+        let Some(semver) = self.semver() else {
+            return self.version.to_string();
+        };
+        let mut out = format!("{}.{}.{}", semver.major, semver.minor, semver.patch);
+        if semver.is_prerelease() {
+            let mut identifiers = semver.pre.split('.').peekable();
+            let first = identifiers.next().unwrap_or_default();
+            let label = first.trim_end_matches(|c: char| c.is_ascii_digit());
+            let mut number = numeric(&first[label.len()..]);
+            if number.is_none() {
+                number = identifiers
+                    .next_if(|next| numeric(next).is_some())
+                    .and_then(numeric);
+            }
+            let number = number.unwrap_or(0);
+            match label.to_ascii_lowercase().as_str() {
+                "alpha" | "a" => out.push_str(&format!("a{number}")),
+                "beta" | "b" => out.push_str(&format!("b{number}")),
+                "rc" | "c" | "pre" | "preview" => out.push_str(&format!("rc{number}")),
+                _ => {
+                    out.push_str(&format!(".dev{number}"));
+                    identifiers.by_ref().for_each(drop);
+                }
+            }
+            if identifiers.next() == Some("dev") {
+                let dev = identifiers.next().and_then(numeric).unwrap_or(0);
+                out.push_str(&format!(".dev{dev}"));
+            }
+        }
+        if !semver.build.is_empty() {
+            out.push('+');
+            out.push_str(&semver.build.replace('-', "."));
+        }
+        out
+    }
+
+    /// Returns a Python module declaring `__version__` and `__author__`
+    ///
+    /// Write it to e.g. `python/myapp/_version.py` and import it from the
+    /// package's `__init__.py`.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.2.0-rc.2", "John Doe");
+    /// assert_eq!(
+    ///     version.to_python_version_file(),
+    ///     "# Generated from myapp 1.2.0-rc.2 by clap-version-flag; do not edit.\n\
+    ///      __version__ = \"1.2.0rc2\"\n\
+    ///      __author__ = \"John Doe\"\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_python_version_file(&self) -> String {
+        let mut out = format!(
+            "# Generated from {} {} by clap-version-flag; do not edit.\n",
+            self.package_name.replace('\n', " "),
+            self.version.replace('\n', " ")
+        );
+        // a JSON string literal is also a valid Python string literal
+        for (name, value) in [
+            ("__version__", self.pep440_version()),
+            ("__author__", self.author.to_string()),
+        ] {
+            out.push_str(name);
+            out.push_str(" = ");
+            json::push_string(&mut out, &value);
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Returns a minimal `package.json` for an npm wrapper package
+    ///
+    /// Holds the name, version and author, followed by the description,
+    /// license, homepage and repository when they are set.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::builder("myapp", "1.2.0", "John Doe")
+    ///     .license("MIT")
+    ///     .build();
+    /// assert_eq!(
+    ///     version.to_package_json(),
+    ///     "{\n  \"name\": \"myapp\",\n  \"version\": \"1.2.0\",\n  \"author\": \"John Doe\",\n  \"license\": \"MIT\"\n}\n"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_package_json(&self) -> String {
+        let mut fields = vec![
+            ("name", self.package_name.as_ref()),
+            ("version", self.version.as_ref()),
+            ("author", self.author.as_ref()),
+        ];
+        for key in ["description", "license", "homepage", "repository"] {
+            if let Some(value) = self.metadata(key) {
+                fields.push((key, value));
+            }
+        }
+
+        let mut out = String::from("{\n");
+        for (index, (key, value)) in fields.iter().enumerate() {
+            out.push_str("  ");
+            json::push_string(&mut out, key);
+            out.push_str(": ");
+            json::push_string(&mut out, value);
+            out.push_str(if index + 1 < fields.len() {
+                ",\n"
+            } else {
+                "\n"
+            });
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Sets the top-level `"version"` of an existing `package.json`
+    ///
+    /// Only the version string is replaced, so formatting, key order and
+    /// every other field are preserved.
+    ///
+    /// # Errors
+    /// Returns [`VersionError::InvalidConfig`] if `contents` has no
+    /// top-level `"version"` string.
+    ///
+    /// # Examples
+    /// ```
+    /// use clap_version_flag::ColorfulVersion;
+    ///
+    /// let version = ColorfulVersion::new("myapp", "1.3.0", "John Doe");
+    /// let updated = version
+    ///     .update_package_json("{\n  \"name\": \"myapp\",\n  \"version\": \"1.2.0\"\n}\n")
+    ///     .unwrap();
+    /// assert_eq!(updated, "{\n  \"name\": \"myapp\",\n  \"version\": \"1.3.0\"\n}\n");
+    /// ```
+    pub fn update_package_json(&self, contents: &str) -> Result<String, VersionError> {
+        let (start, end) = top_level_string(contents, "version").ok_or_else(|| {
+            VersionError::InvalidConfig("package.json has no top-level \"version\" string".into())
+        })?;
+        let mut value = String::new();
+        json::push_string(&mut value, &self.version);
+        Ok(format!("{}{value}{}", &contents[..start], &contents[end..]))
+    }
+}
+
+/// Finds the byte span (quotes included) of the string value of `key` in
+/// the outermost JSON object of `text`
+fn top_level_string(text: &str, key: &str) -> Option<(usize, usize)> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut index = 0;
+    // the string that ended last at depth 1, awaiting a ':' to be a key
+    let mut last_key: Option<&str> = None;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            b'"' => {
+                let end = string_end(bytes, index)?;
+                if depth == 1 {
+                    last_key = Some(&text[index + 1..end - 1]);
+                }
+                index = end;
+                continue;
+            }
+            b':' if depth == 1 && last_key == Some(key) => {
+                let value =
+                    index + 1 + text[index + 1..].len() - text[index + 1..].trim_start().len();
+                return (bytes.get(value) == Some(&b'"'))
+                    .then(|| string_end(bytes, value).map(|end| (value, end)))
+                    .flatten();
+            }
+            b',' => last_key = None,
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Returns the index just past the closing quote of the string at `start`
+fn string_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return Some(index + 1),
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pep440_mapping() {
+        let pep440 = |version| ColorfulVersion::new("app", version, "Jane").pep440_version();
+        assert_eq!(pep440("1.0.0-alpha.3"), "1.0.0a3");
+        assert_eq!(pep440("1.0.0-nightly.20261014"), "1.0.0.dev20261014");
+        assert_eq!(pep440("1.0.0+build-7"), "1.0.0+build.7");
+        assert_eq!(pep440("2024.10"), "2024.10");
+    }
+
+    #[test]
+    fn test_pep440_vectors() {
+        // the pre-release and developmental release forms of PEP 440
+        let pep440 = |version| ColorfulVersion::new("app", version, "Jane").pep440_version();
+        for (semver, expected) in [
+            ("1.0.0-a.1", "1.0.0a1"),
+            ("1.0.0-alpha1", "1.0.0a1"),
+            ("1.0.0-b2", "1.0.0b2"),
+            ("1.0.0-beta.2.1", "1.0.0b2"),
+            ("1.0.0-c.1", "1.0.0rc1"),
+            ("1.0.0-pre.1", "1.0.0rc1"),
+            ("1.0.0-preview.2", "1.0.0rc2"),
+            ("1.0.0-RC.3", "1.0.0rc3"),
+            ("1.0.0-dev", "1.0.0.dev0"),
+            ("1.0.0-dev.4", "1.0.0.dev4"),
+            ("1.0.0-rc.1.dev.2", "1.0.0rc1.dev2"),
+            ("1.0.0-alpha.dev", "1.0.0a0.dev0"),
+            ("1.0.0-nightly.5.dev.1", "1.0.0.dev5"),
+        ] {
+            assert_eq!(pep440(semver), expected, "{semver}");
+        }
+    }
+
+    #[test]
+    fn test_python_escaping() {
+        let version = ColorfulVersion::new("app", "1.0.0", "Jane \"JD\" Doe");
+        assert!(
+            version
+                .to_python_version_file()
+                .ends_with("__author__ = \"Jane \\\"JD\\\" Doe\"\n")
+        );
+    }
+
+    #[test]
+    fn test_update_ignores_nested_versions() {
+        let version = ColorfulVersion::new("app", "2.0.0", "Jane");
+        let contents =
+            r#"{"engines": {"version": "18"}, "x": ["version"], "version" : "1.0.0", "y": 1}"#;
+        assert_eq!(
+            version.update_package_json(contents).unwrap(),
+            r#"{"engines": {"version": "18"}, "x": ["version"], "version" : "2.0.0", "y": 1}"#
+        );
+        assert!(matches!(
+            version.update_package_json(r#"{"name": "app", "engines": {"version": "18"}}"#),
+            Err(VersionError::InvalidConfig(_))
+        ));
+        assert!(version.update_package_json(r#"{"version": 1}"#).is_err());
+    }
+}
@@ -0,0 +1,97 @@
+// tests/attribute.rs
+// Tests for the #[clap_version] attribute
+
+#![cfg(feature = "attribute")]
+
+mod testkit;
+
+use clap_version_flag::{Theme, clap_version};
+use predicates::prelude::*;
+use testkit::example;
+
+#[clap_version]
+fn run() -> u8 {
+    7
+}
+
+#[clap_version(theme = "high-contrast")]
+fn themed(value: u8) -> u8 {
+    value * 2
+}
+
+#[clap_version(theme = "classic")]
+fn classic() -> &'static str {
+    "classic"
+}
+
+#[clap_version(theme = "high-contrast")]
+fn high_contrast() -> &'static str {
+    "high-contrast"
+}
+
+#[clap_version(theme = "solarized")]
+fn solarized() -> &'static str {
+    "solarized"
+}
+
+#[clap_version(theme = "dracula")]
+fn dracula() -> &'static str {
+    "dracula"
+}
+
+#[clap_version(theme = "monochrome")]
+fn monochrome() -> &'static str {
+    "monochrome"
+}
+
+#[test]
+fn test_body_runs_without_version_flag() {
+    assert_eq!(run(), 7);
+    assert_eq!(themed(3), 6);
+}
+
+#[test]
+fn test_every_built_in_theme_accepted() {
+    let accepted = [
+        classic(),
+        high_contrast(),
+        solarized(),
+        dracula(),
+        monochrome(),
+    ];
+    let built_in = Theme::built_in().map(|(name, _)| name);
+    assert_eq!(accepted, built_in);
+}
+
+fn headline() -> String {
+    format!("{} v{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+#[test]
+fn test_flags_before_version_flag() {
+    for args in [
+        &["--verbose", "--version"][..],
+        &["-v", "-V"],
+        &["file", "--version"],
+    ] {
+        example("attribute_app")
+            .args(args)
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(headline()));
+    }
+    example("attribute_app")
+        .args(["--quiet", "--version=short"])
+        .assert()
+        .success()
+        .stdout(concat!(env!("CARGO_PKG_VERSION"), "\n"));
+}
+
+#[test]
+fn test_main_runs_without_version_flag() {
+    example("attribute_app")
+        .args(["--verbose", "--", "-V"])
+        .assert()
+        .success()
+        .stdout("running\n");
+}
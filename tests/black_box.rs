@@ -0,0 +1,99 @@
+// tests/black_box.rs
+// Real-process tests of the exit paths the in-process tests cannot reach
+
+mod testkit;
+
+use predicates::prelude::*;
+use testkit::{FIXTURE_LINE, fixture};
+
+fn version_output() -> String {
+    format!("{FIXTURE_LINE}\ncommit: abc1234\n")
+}
+
+#[test]
+fn test_long_flag_prints_and_exits_zero() {
+    fixture()
+        .arg("--version")
+        .assert()
+        .code(0)
+        .stdout(version_output())
+        .stderr("");
+}
+
+#[test]
+fn test_short_flag_prints_and_exits_zero() {
+    fixture()
+        .arg("-V")
+        .assert()
+        .code(0)
+        .stdout(version_output());
+}
+
+#[test]
+fn test_without_flag_runs_the_app() {
+    fixture().assert().code(0).stdout("running\n");
+}
+
+#[test]
+fn test_piped_output_has_no_escape_codes() {
+    fixture()
+        .arg("--version")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b[").not());
+}
+
+#[test]
+fn test_no_color_disables_escape_codes() {
+    fixture()
+        .arg("--version")
+        .env("NO_COLOR", "1")
+        .assert()
+        .success()
+        .stdout(version_output());
+}
+
+#[test]
+#[cfg(not(feature = "no-color"))]
+fn test_clicolor_force_colors_piped_output() {
+    fixture()
+        .arg("--version")
+        .env("CLICOLOR_FORCE", "1")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\x1b["))
+        .stdout(predicate::str::contains("fixture"));
+}
+
+#[test]
+fn test_json_format() {
+    fixture()
+        .args(["--version", "--version-format", "json"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::starts_with(
+            r#"{"schema_version":1,"name":"fixture","version":"1.2.3""#,
+        ));
+}
+
+#[test]
+fn test_usage_error_exits_two() {
+    fixture()
+        .arg("--bogus")
+        .assert()
+        .code(2)
+        .stdout("")
+        .stderr(predicate::str::contains("unexpected argument '--bogus'"));
+}
+
+#[test]
+fn test_panic_hook_prints_version_to_stderr() {
+    fixture()
+        .arg("--panic")
+        .env("RUST_BACKTRACE", "0")
+        .assert()
+        .code(101)
+        .stdout("")
+        .stderr(predicate::str::starts_with(version_output()))
+        .stderr(predicate::str::contains("fixture panic"));
+}
@@ -0,0 +1,30 @@
+// tests/color_override.rs
+// Color overrides shared with the `colored` crate
+
+use clap_version_flag::{ColorfulVersion, color};
+
+#[test]
+fn test_override_is_shared_with_colored() {
+    let version = ColorfulVersion::new("app", "1.0.0", "Jane");
+
+    color::set_override(true);
+    assert_eq!(color::color_override(), Some(true));
+    assert_eq!(color::colors_enabled(), !cfg!(feature = "no-color"),);
+    assert_eq!(
+        version.to_colored_string().contains('\x1b'),
+        !cfg!(feature = "no-color")
+    );
+
+    // An override set directly on `colored` is honored as well
+    colored::control::set_override(false);
+    assert!(!color::colors_enabled());
+    assert_eq!(version.to_colored_string(), "app v1.0.0 by Jane");
+
+    // and reported, there being no second record to fall out of step
+    colored::control::unset_override();
+    colored::control::set_override(true);
+    assert_eq!(color::color_override(), Some(true));
+
+    color::unset_override();
+    assert_eq!(color::color_override(), None);
+}
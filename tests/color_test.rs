@@ -10,7 +10,7 @@ fn test_default_colors_correct() {
     let version = colorful_version!();
 
     // Test with custom colors to verify parsing works
-    let result = version.with_hex_colors("#FFFFFF", "#AA00FF", "#FFFF00", "#00FFFF");
+    let result = version.with_colors("#FFFFFF", "#AA00FF", "#FFFF00", "#00FFFF");
     assert!(result.is_ok(), "Should accept default color values");
 }
 
@@ -21,7 +21,7 @@ fn test_hex_color_validation() {
 
     for hex in valid_cases {
         let result = ColorfulVersion::new("test", "1.0.0", "author")
-            .with_hex_colors(hex, "#000", "#000", "#000");
+            .with_colors(hex, "#000", "#000", "#000");
         assert!(result.is_ok(), "Should accept: {}", hex);
     }
 
@@ -30,7 +30,7 @@ fn test_hex_color_validation() {
 
     for hex in invalid_cases {
         let result = ColorfulVersion::new("test", "1.0.0", "author")
-            .with_hex_colors(hex, "#000", "#000", "#000");
+            .with_colors(hex, "#000", "#000", "#000");
         assert!(result.is_err(), "Should reject: {}", hex);
     }
 }
@@ -79,7 +79,7 @@ fn test_command_extension() {
     let cmd = Command::new("testapp").with_colorful_version(&version);
 
     // Command should have our version flag
-    let result = cmd.try_get_matches_from(&["testapp", "--version"]);
+    let result = cmd.try_get_matches_from(["testapp", "--version"]);
     assert!(result.is_ok());
 
     if let Ok(matches) = result {
@@ -93,7 +93,7 @@ fn test_command_extension_disables_default_version() {
     let cmd = Command::new("testapp").with_colorful_version(&version);
 
     // The extension should disable clap's built-in version flag
-    let result = cmd.try_get_matches_from(&["testapp", "-V"]);
+    let result = cmd.try_get_matches_from(["testapp", "-V"]);
     assert!(result.is_ok(), "Our custom -V flag should work");
 }
 
@@ -174,7 +174,7 @@ fn test_cargo_env_output_format() {
 fn test_short_hex_expansion() {
     // Test that 3-digit hex codes are expanded correctly
     let version = ColorfulVersion::new("test", "1.0.0", "author")
-        .with_hex_colors("#FFF", "#000", "#F00", "#0F0")
+        .with_colors("#FFF", "#000", "#F00", "#0F0")
         .unwrap();
 
     // Just verify it was created without error
@@ -185,7 +185,7 @@ fn test_short_hex_expansion() {
 fn test_hex_without_hash() {
     // Test that hex codes without # are accepted
     let version = ColorfulVersion::new("test", "1.0.0", "author")
-        .with_hex_colors("FFFFFF", "000000", "FF0000", "00FF00")
+        .with_colors("FFFFFF", "000000", "FF0000", "00FF00")
         .unwrap();
 
     assert_eq!(version.package_name(), "test");
@@ -195,7 +195,7 @@ fn test_hex_without_hash() {
 fn test_mixed_case_hex() {
     // Test that mixed case hex codes work
     let version = ColorfulVersion::new("test", "1.0.0", "author")
-        .with_hex_colors("#FfFfFf", "#AaBbCc", "#123456", "#abcdef")
+        .with_colors("#FfFfFf", "#AaBbCc", "#123456", "#abcdef")
         .unwrap();
 
     assert_eq!(version.package_name(), "test");
@@ -232,21 +232,21 @@ fn test_invalid_hex_lengths() {
     // Too short
     assert!(
         ColorfulVersion::new("t", "1", "a")
-            .with_hex_colors("#FF", "#000000", "#000000", "#000000")
+            .with_colors("#FF", "#000000", "#000000", "#000000")
             .is_err()
     );
 
     // Too long
     assert!(
         ColorfulVersion::new("t", "1", "a")
-            .with_hex_colors("#FFFFFFF", "#000000", "#000000", "#000000")
+            .with_colors("#FFFFFFF", "#000000", "#000000", "#000000")
             .is_err()
     );
 
     // Invalid length (4 digits)
     assert!(
         ColorfulVersion::new("t", "1", "a")
-            .with_hex_colors("#FFFF", "#000000", "#000000", "#000000")
+            .with_colors("#FFFF", "#000000", "#000000", "#000000")
             .is_err()
     );
 }
@@ -256,13 +256,13 @@ fn test_invalid_hex_characters() {
     // Contains non-hex characters
     assert!(
         ColorfulVersion::new("t", "1", "a")
-            .with_hex_colors("#GGGGGG", "#000000", "#000000", "#000000")
+            .with_colors("#GGGGGG", "#000000", "#000000", "#000000")
             .is_err()
     );
 
     assert!(
         ColorfulVersion::new("t", "1", "a")
-            .with_hex_colors("#ZZZZZZ", "#000000", "#000000", "#000000")
+            .with_colors("#ZZZZZZ", "#000000", "#000000", "#000000")
             .is_err()
     );
 }
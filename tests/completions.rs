@@ -0,0 +1,74 @@
+// tests/completions.rs
+// Completion metadata of the injected flags, read the way clap_complete's
+// generators read it: from the built command, per (sub)command
+
+#![cfg(feature = "completions")]
+
+use clap::Command;
+use clap_version_flag::{ColorfulVersion, ColorfulVersionExt, augment_completions};
+
+/// Returns the visible long options of `cmd`, as offered after `--`
+fn long_options(cmd: &Command) -> Vec<String> {
+    cmd.get_arguments()
+        .filter(|arg| !arg.is_hide_set())
+        .filter_map(|arg| arg.get_long())
+        .map(|long| format!("--{long}"))
+        .collect()
+}
+
+/// Returns the values offered after `--<long>` in `cmd`
+fn values_of(cmd: &Command, long: &str) -> Vec<String> {
+    cmd.get_arguments()
+        .find(|arg| arg.get_long() == Some(long))
+        .unwrap_or_else(|| panic!("--{long} missing"))
+        .get_possible_values()
+        .iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| value.get_name().to_string())
+        .collect()
+}
+
+/// The finished command, built like the generators build it
+fn command() -> Command {
+    let version = ColorfulVersion::new("app", "1.0", "Jo");
+    let mut cmd = augment_completions(
+        Command::new("app")
+            .subcommand(Command::new("serve"))
+            .with_colorful_version(&version)
+            .with_version_format_flag()
+            .with_version_fields_flag(),
+    );
+    cmd.build();
+    cmd
+}
+
+#[test]
+fn test_flags_completed_on_root_and_subcommands() {
+    let cmd = command();
+    let serve = cmd.find_subcommand("serve").unwrap();
+    for cmd in [&cmd, serve] {
+        let options = long_options(cmd);
+        for long in ["--version", "--version-format", "--version-fields"] {
+            assert!(options.iter().any(|option| option == long), "{options:?}");
+        }
+    }
+}
+
+#[test]
+fn test_values_completed() {
+    let cmd = command();
+    let serve = cmd.find_subcommand("serve").unwrap();
+    for cmd in [&cmd, serve] {
+        assert_eq!(values_of(cmd, "version-format"), ["text", "plain", "json"]);
+        let fields = values_of(cmd, "version-fields");
+        assert!(fields.iter().any(|field| field == "commit"), "{fields:?}");
+        assert!(fields.iter().any(|field| field == "installed-via"));
+    }
+}
+
+#[test]
+fn test_unlisted_fields_still_parse() {
+    let parsed =
+        command().try_get_matches_from(["app", "serve", "-V", "--version-fields", "my_key"]);
+    assert!(parsed.is_ok());
+}
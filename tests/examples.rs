@@ -0,0 +1,90 @@
+// tests/examples.rs
+// Runs the examples-extra examples as black boxes
+
+#![cfg(feature = "examples-extra")]
+
+mod testkit;
+
+use predicates::prelude::*;
+use testkit::example;
+
+fn headline() -> String {
+    format!(
+        "{} v{} by {}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_AUTHORS")
+    )
+}
+
+#[test]
+fn test_themes() {
+    example("themes")
+        .args(["--theme", "ocean", "--version"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(headline()));
+    example("themes")
+        .args(["--theme", "mono"])
+        .assert()
+        .success()
+        .stdout("theme mono: primary #FFFFFF\n");
+}
+
+#[test]
+fn test_json_output() {
+    let output = example("json_output")
+        .args(["--version", "--version-format", "json"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(json["name"], env!("CARGO_PKG_NAME"));
+    assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn test_update_check_against_stub_registry() {
+    example("update_check")
+        .arg("--version")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("(update available: 99.0.0)\n"));
+    example("update_check")
+        .assert()
+        .success()
+        .stdout("latest release: 99.0.0\n");
+}
+
+#[test]
+fn test_self_update_dry_run() {
+    example("self_update_dry_run")
+        .args(["2.0.0", "--via", "homebrew"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with(format!(
+            "dry run: would run `brew upgrade {}`\n",
+            env!("CARGO_PKG_NAME")
+        )));
+    example("self_update_dry_run").assert().failure();
+}
+
+#[test]
+fn test_diagnostics() {
+    example("diagnostics")
+        .arg("-VVV")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "config.new_ui: on\nconfig.api_token: [redacted]\n",
+        ));
+    example("diagnostics")
+        .env("NO_COLOR", "1")
+        .arg("--bug-report")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("### Version information\n"))
+        .stdout(predicate::str::contains("\nNO_COLOR=1\n"));
+}
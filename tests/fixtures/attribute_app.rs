@@ -0,0 +1,10 @@
+// tests/fixtures/attribute_app.rs
+// Fixture binary whose main is wrapped in #[clap_version], spawned by the
+// black-box tests in tests/attribute.rs
+
+use clap_version_flag::clap_version;
+
+#[clap_version]
+fn main() {
+    println!("running");
+}
@@ -0,0 +1,28 @@
+// tests/fixtures/version_app.rs
+// Fixture binary spawned by the black-box tests in tests/black_box.rs
+
+use clap::{Arg, ArgAction, Command};
+use clap_version_flag::{ColorfulVersion, ColorfulVersionExt};
+
+fn main() {
+    let version = ColorfulVersion::builder("fixture", "1.2.3", "Fixture Author")
+        .extra("commit", "abc1234")
+        .build();
+    let matches = Command::new("fixture")
+        .with_colorful_version(&version)
+        .with_version_format_flag()
+        .arg(
+            Arg::new("panic")
+                .long("panic")
+                .action(ArgAction::SetTrue)
+                .help("Panic after installing the panic hook"),
+        )
+        .get_matches();
+    version.check_and_exit(&matches);
+
+    if matches.get_flag("panic") {
+        version.install_panic_hook();
+        panic!("fixture panic");
+    }
+    println!("running");
+}
@@ -2,7 +2,7 @@
 // Integration tests for clap-version-flag with real clap usage
 
 use clap::{CommandFactory, FromArgMatches, Parser};
-use clap_version_flag::{ColorfulVersionExt, colorful_version};
+use clap_version_flag::{ColorfulVersionExt, colorful_version, parse_with_version_from};
 
 #[derive(Parser, Debug)]
 #[command(name = "integration-test-app")]
@@ -21,7 +21,7 @@ fn test_version_flag_triggers_detection() {
     let cmd = TestApp::command().with_colorful_version(&version);
 
     // This should succeed (flag is present)
-    let matches = cmd.try_get_matches_from(&["test", "--version"]).unwrap();
+    let matches = cmd.try_get_matches_from(["test", "--version"]).unwrap();
     assert!(matches.get_flag("clap_version_flag_version"));
 }
 
@@ -31,7 +31,7 @@ fn test_short_version_flag() {
     let cmd = TestApp::command().with_colorful_version(&version);
 
     // Test short form -V
-    let matches = cmd.try_get_matches_from(&["test", "-V"]).unwrap();
+    let matches = cmd.try_get_matches_from(["test", "-V"]).unwrap();
     assert!(matches.get_flag("clap_version_flag_version"));
 }
 
@@ -42,7 +42,7 @@ fn test_normal_parsing_works() {
     // With proper args, should work
     let cmd = TestApp::command().with_colorful_version(&version);
     let matches = cmd
-        .try_get_matches_from(&["test", "--value", "hello"])
+        .try_get_matches_from(["test", "--value", "hello"])
         .unwrap();
     assert!(!matches.get_flag("clap_version_flag_version"));
 
@@ -59,7 +59,7 @@ fn test_multiple_args_parsing() {
     let cmd = TestApp::command().with_colorful_version(&version);
 
     let matches = cmd
-        .try_get_matches_from(&["test", "--value", "hello", "--number", "42"])
+        .try_get_matches_from(["test", "--value", "hello", "--number", "42"])
         .unwrap();
 
     assert_eq!(
@@ -80,7 +80,7 @@ fn test_parse_with_version_helper() {
     // Just verify the function signature works with proper args
     let cmd = TestApp::command().with_colorful_version(&version);
     let matches = cmd
-        .try_get_matches_from(&["test", "--value", "test"])
+        .try_get_matches_from(["test", "--value", "test"])
         .unwrap();
 
     // Manually verify it works without calling parse_with_version
@@ -88,15 +88,32 @@ fn test_parse_with_version_helper() {
     assert!(!matches.get_flag("clap_version_flag_version"));
 }
 
+#[test]
+fn test_parse_with_version_from_reparses() {
+    let version = colorful_version!();
+    let cmd = TestApp::command();
+
+    for _ in 0..2 {
+        let parsed: Option<TestApp> =
+            parse_with_version_from(cmd.clone(), &version, ["test", "-n", "3"]).unwrap();
+        assert_eq!(parsed.unwrap().number, Some(3));
+        let parsed: Option<TestApp> =
+            parse_with_version_from(cmd.clone(), &version, ["test", "--version"]).unwrap();
+        assert!(parsed.is_none());
+    }
+    let err = parse_with_version_from::<TestApp, _, _>(cmd, &version, ["test", "--bogus"]);
+    assert!(err.is_err());
+}
+
 #[test]
 fn test_check_and_exit_does_not_exit_without_flag() {
     let version = colorful_version!();
     let cmd = TestApp::command().with_colorful_version(&version);
     let matches = cmd
-        .try_get_matches_from(&["test", "--value", "hello"])
+        .try_get_matches_from(["test", "--value", "hello"])
         .unwrap();
 
-    // This should not exit (we can't test the exit case without subprocess)
+    // This should not exit (tests/black_box.rs covers the exit case in a subprocess)
     // But we can verify the flag is false
     assert!(!matches.get_flag("clap_version_flag_version"));
 }
@@ -177,13 +194,13 @@ fn test_version_flag_is_global() {
     // Should work at root level
     let matches = cmd
         .clone()
-        .try_get_matches_from(&["app", "--version"])
+        .try_get_matches_from(["app", "--version"])
         .unwrap();
     assert!(matches.get_flag("clap_version_flag_version"));
 
     // Should also work in subcommands (global flag)
     let matches = cmd
-        .try_get_matches_from(&["app", "sub1", "--version"])
+        .try_get_matches_from(["app", "sub1", "--version"])
         .unwrap();
     assert!(matches.get_flag("clap_version_flag_version"));
 }
@@ -201,12 +218,12 @@ struct MinimalApp {
 //     // Normal usage
 //     let matches = cmd
 //         .clone()
-//         .try_get_matches_from(&["app", "file.txt"])
+//         .try_get_matches_from(["app", "file.txt"])
 //         .unwrap();
 //     assert!(!matches.get_flag("clap_version_flag_version"));
 
 //     // Version flag
-//     let matches = cmd.try_get_matches_from(&["app", "--version"]).unwrap();
+//     let matches = cmd.try_get_matches_from(["app", "--version"]).unwrap();
 //     assert!(matches.get_flag("clap_version_flag_version"));
 // }
 
@@ -221,7 +238,7 @@ fn test_with_default_values() {
     let version = colorful_version!();
     let cmd = AppWithDefaults::command().with_colorful_version(&version);
 
-    let matches = cmd.try_get_matches_from(&["app"]).unwrap();
+    let matches = cmd.try_get_matches_from(["app"]).unwrap();
     assert!(!matches.get_flag("clap_version_flag_version"));
     assert_eq!(
         matches.get_one::<String>("value").map(|s| s.as_str()),
@@ -235,7 +252,7 @@ fn test_error_handling_with_invalid_args() {
     let cmd = TestApp::command().with_colorful_version(&version);
 
     // Should fail with invalid argument
-    let result = cmd.try_get_matches_from(&["test", "--invalid-arg"]);
+    let result = cmd.try_get_matches_from(["test", "--invalid-arg"]);
     assert!(result.is_err());
 }
 
@@ -245,7 +262,7 @@ fn test_help_flag_still_works() {
     let cmd = TestApp::command().with_colorful_version(&version);
 
     // Help should still work
-    let result = cmd.try_get_matches_from(&["test", "--help"]);
+    let result = cmd.try_get_matches_from(["test", "--help"]);
     // Help causes an error with kind DisplayHelp
     assert!(result.is_err());
     if let Err(e) = result {
@@ -260,7 +277,7 @@ fn test_version_precedence() {
     let cmd = TestApp::command().with_colorful_version(&version);
 
     let matches = cmd
-        .try_get_matches_from(&["test", "--value", "hello", "--version"])
+        .try_get_matches_from(["test", "--value", "hello", "--version"])
         .unwrap();
     assert!(matches.get_flag("clap_version_flag_version"));
 }
@@ -278,12 +295,12 @@ fn test_version_precedence() {
 //     // Version flag should work
 //     let matches = cmd
 //         .clone()
-//         .try_get_matches_from(&["app", "--version"])
+//         .try_get_matches_from(["app", "--version"])
 //         .unwrap();
 //     assert!(matches.get_flag("clap_version_flag_version"));
 
 //     // Normal usage should still require input
-//     let result = cmd.try_get_matches_from(&["app"]);
+//     let result = cmd.try_get_matches_from(["app"]);
 //     assert!(result.is_err()); // Should fail due to missing required arg
 // }
 
@@ -292,7 +309,7 @@ fn test_from_arg_matches_compatibility() {
     let version = colorful_version!();
     let cmd = TestApp::command().with_colorful_version(&version);
     let matches = cmd
-        .try_get_matches_from(&["test", "--value", "test"])
+        .try_get_matches_from(["test", "--value", "test"])
         .unwrap();
 
     // Should be able to construct from matches using FromArgMatches trait
@@ -303,3 +320,43 @@ fn test_from_arg_matches_compatibility() {
         assert_eq!(app.value, Some("test".to_string()));
     }
 }
+
+#[test]
+fn test_native_version_bypasses_required_args() {
+    use clap::error::ErrorKind;
+    use clap_version_flag::ColorfulVersion;
+
+    #[derive(Parser)]
+    #[command(name = "native-app")]
+    struct RequiredApp {
+        #[arg(short, long)]
+        name: String,
+    }
+
+    let version = ColorfulVersion::new("native-app", "3.1.4", "Jane Doe");
+    let cmd = RequiredApp::command().with_native_colorful_version(&version);
+
+    let err = cmd
+        .clone()
+        .try_get_matches_from(["native-app", "-V"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayVersion);
+    assert_eq!(err.to_string(), "native-app v3.1.4 by Jane Doe\n");
+
+    // Missing required args are still reported normally
+    let err = cmd.try_get_matches_from(["native-app"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn test_native_version_keeps_custom_display_name() {
+    use clap_version_flag::ColorfulVersion;
+
+    let version = ColorfulVersion::new("pkg-name", "1.0.0", "Jane Doe");
+    let cmd = clap::Command::new("app")
+        .display_name("My App")
+        .with_native_colorful_version(&version);
+
+    assert_eq!(cmd.get_display_name(), Some("My App"));
+    assert_eq!(cmd.get_version(), Some("v1.0.0 by Jane Doe"));
+}
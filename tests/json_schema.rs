@@ -0,0 +1,178 @@
+// tests/json_schema.rs
+// Compatibility contract for the machine-readable (JSON) outputs
+//
+// Every key listed here is part of schema version 1 and must keep its name
+// and type. New keys may be added freely; removing or retyping one of these
+// requires bumping the schema version and adding a new contract.
+
+use clap_version_flag::{BuildInfo, ColorfulVersion, VersionFormat};
+use serde_json::Value;
+
+#[derive(Clone, Copy, Debug)]
+enum Kind {
+    Number,
+    String,
+    NullableString,
+    StringArray,
+    StringMap,
+}
+
+const VERSION_V1: &[(&str, Kind)] = &[
+    ("schema_version", Kind::Number),
+    ("name", Kind::String),
+    ("version", Kind::String),
+    ("author", Kind::String),
+    ("metadata", Kind::StringMap),
+];
+
+const BUILD_INFO_V1: &[(&str, Kind)] = &[
+    ("schema_version", Kind::Number),
+    ("name", Kind::String),
+    ("version", Kind::String),
+    ("authors", Kind::StringArray),
+    ("description", Kind::NullableString),
+    ("homepage", Kind::NullableString),
+    ("repository", Kind::NullableString),
+    ("license", Kind::NullableString),
+    ("rust_version", Kind::NullableString),
+    ("commit", Kind::NullableString),
+    ("build_date", Kind::NullableString),
+    ("install_method", Kind::NullableString),
+    ("target", Kind::NullableString),
+    ("features", Kind::StringArray),
+    ("build_id", Kind::String),
+    ("rustc", Kind::NullableString),
+    ("opt_level", Kind::NullableString),
+    ("profile", Kind::NullableString),
+    ("dependencies", Kind::StringMap),
+];
+
+fn matches_kind(value: &Value, kind: Kind) -> bool {
+    match kind {
+        Kind::Number => value.is_u64(),
+        Kind::String => value.is_string(),
+        Kind::NullableString => value.is_string() || value.is_null(),
+        Kind::StringArray => value
+            .as_array()
+            .is_some_and(|items| items.iter().all(Value::is_string)),
+        Kind::StringMap => value
+            .as_object()
+            .is_some_and(|map| map.values().all(Value::is_string)),
+    }
+}
+
+fn assert_contract(json: &str, contract: &[(&str, Kind)]) {
+    let parsed: Value = serde_json::from_str(json).expect("output is valid JSON");
+    let object = parsed.as_object().expect("output is a JSON object");
+    for (key, kind) in contract {
+        let value = object
+            .get(*key)
+            .unwrap_or_else(|| panic!("schema v1 key `{key}` is missing from {json}"));
+        assert!(
+            matches_kind(value, *kind),
+            "schema v1 key `{key}` should be {kind:?}, got {value}"
+        );
+    }
+    assert_eq!(object["schema_version"], 1);
+}
+
+#[test]
+fn test_schema_version_is_shared() {
+    assert_eq!(ColorfulVersion::SCHEMA_VERSION, 1);
+    assert_eq!(BuildInfo::SCHEMA_VERSION, ColorfulVersion::SCHEMA_VERSION);
+}
+
+#[test]
+fn test_version_output_contract() {
+    let bare = ColorfulVersion::new("myapp", "1.0.0", "Jane");
+    assert_contract(&bare.to_json(), VERSION_V1);
+
+    let detailed = ColorfulVersion::builder("myapp", "1.0.0", "Jane")
+        .description("A tool")
+        .license("MIT")
+        .extra("channel", "beta")
+        .build();
+    assert_contract(&detailed.render(VersionFormat::Json), VERSION_V1);
+}
+
+#[test]
+fn test_build_info_contract() {
+    const MINIMAL: BuildInfo = BuildInfo::new("myapp", "1.0.0", "Jane");
+    const FULL: BuildInfo = BuildInfo::new("myapp", "1.0.0", "Jane:John")
+        .with_description("A tool")
+        .with_homepage("https://example.com")
+        .with_repository("https://example.com/repo")
+        .with_license("MIT")
+        .with_rust_version("1.85")
+        .with_commit(Some("4f2a9c1"))
+        .with_build_date(Some("2026-10-14"))
+        .with_install_method(Some("cargo"))
+        .with_target(Some("x86_64-unknown-linux-gnu"))
+        .with_features(Some("json,tls"))
+        .with_rustc(Some("rustc 1.85.0 (4d91de4e4 2025-02-17)"))
+        .with_opt_level(Some("3"))
+        .with_profile(Some("release"))
+        .with_dependencies(Some("clap 4.5.53,colored 2.1.0"));
+
+    assert_contract(&MINIMAL.to_json(), BUILD_INFO_V1);
+    assert_contract(&FULL.to_json(), BUILD_INFO_V1);
+}
+
+#[test]
+fn test_build_info_version_output_contract() {
+    const INFO: BuildInfo = BuildInfo::new("myapp", "1.0.0", "Jane").with_commit(Some("4f2a9c1"));
+    let version = ColorfulVersion::from_build_info(&INFO);
+    assert_contract(&version.to_json(), VERSION_V1);
+}
+
+#[test]
+fn test_published_schema_matches_contract() {
+    let version = ColorfulVersion::new("myapp", "1.0.0", "Jane");
+    let schema: Value = serde_json::from_str(&version.json_schema()).expect("schema is valid JSON");
+    let required: Vec<&str> = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|key| key.as_str().unwrap())
+        .collect();
+    let keys: Vec<&str> = VERSION_V1.iter().map(|(key, _)| *key).collect();
+    assert_eq!(required, keys);
+    for (key, kind) in VERSION_V1 {
+        let expected = match kind {
+            Kind::Number => "integer",
+            Kind::String => "string",
+            Kind::StringMap => "object",
+            Kind::NullableString | Kind::StringArray => unreachable!(),
+        };
+        assert_eq!(
+            schema["properties"][key]["type"], expected,
+            "type of `{key}`"
+        );
+    }
+    assert_eq!(
+        schema["properties"]["schema_version"]["const"],
+        ColorfulVersion::SCHEMA_VERSION
+    );
+    assert_eq!(schema["additionalProperties"], true);
+}
+
+#[test]
+fn test_schema_flag() {
+    use clap::Command;
+    use clap_version_flag::{ColorfulVersionExt, VersionOutcome, try_parse_with_version_from};
+
+    let version = ColorfulVersion::new("myapp", "1.0.0", "Jane");
+    let cmd = Command::new("myapp").with_version_schema_flag();
+    assert!(
+        !cmd.clone()
+            .render_help()
+            .to_string()
+            .contains("--version-schema")
+    );
+    let outcome: VersionOutcome<()> =
+        try_parse_with_version_from(cmd, &version, ["myapp", "--version-schema"]).unwrap();
+    assert_eq!(
+        outcome,
+        VersionOutcome::VersionRequested(version.json_schema())
+    );
+}
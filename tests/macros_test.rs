@@ -2,7 +2,10 @@
 // Tests for macros in clap-version-flag
 
 use clap::Parser;
-use clap_version_flag::{colorful_version, colorful_version_full, colorful_version_rgb};
+use clap_version_flag::{
+    Author, ColorfulVersion, Verbosity, colorful_version, colorful_version_full,
+    colorful_version_rgb,
+};
 
 #[test]
 fn test_colorful_version_macro() {
@@ -99,8 +102,9 @@ fn test_macro_with_hex_without_hash() {
 #[test]
 #[should_panic(expected = "Invalid hex color format")]
 fn test_macro_with_invalid_hex_panics() {
-    // This should panic with invalid hex
-    let _version = colorful_version!("INVALID", "#000000", "#000000", "#000000");
+    // Literal colors are checked at compile time; runtime values still panic
+    let invalid = String::from("INVALID");
+    let _version = colorful_version!(invalid.as_str(), "#000000", "#000000", "#000000");
 }
 
 #[test]
@@ -202,3 +206,46 @@ fn test_all_macros_produce_valid_output() {
     assert!(v2.to_string().contains(" by "));
     assert!(v3.to_string().contains(" by "));
 }
+
+mod exported {
+    clap_version_flag::export_version_info!();
+}
+
+#[test]
+fn test_export_version_info_macro() {
+    let info = unsafe { std::ffi::CStr::from_ptr(exported::clap_version_flag_info()) };
+    let json: serde_json::Value = serde_json::from_str(info.to_str().unwrap()).unwrap();
+    assert_eq!(json["name"], env!("CARGO_PKG_NAME"));
+    assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+    // the pointer stays the same across calls
+    assert_eq!(
+        exported::clap_version_flag_info(),
+        exported::clap_version_flag_info()
+    );
+}
+
+static VERSION: ColorfulVersion = colorful_version!();
+
+#[test]
+fn test_static_colorful_version_macro() {
+    let runtime = ColorfulVersion::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), "")
+        .with_authors(Author::parse_list(env!("CARGO_PKG_AUTHORS")))
+        .with_license(env!("CARGO_PKG_LICENSE"));
+    assert_eq!(VERSION.author(), runtime.author());
+    assert_eq!(VERSION.authors(), runtime.authors());
+    assert_eq!(VERSION.license(), Some(env!("CARGO_PKG_LICENSE")));
+    assert_eq!(VERSION.to_colored_string(), runtime.to_colored_string());
+    assert_eq!(
+        VERSION.render_plain(Verbosity::Verbose),
+        runtime.render_plain(Verbosity::Verbose)
+    );
+    assert_eq!(VERSION.to_json(), runtime.to_json());
+
+    let names = VERSION.clone().with_author_emails(false);
+    let expected = runtime
+        .authors()
+        .iter()
+        .map(Author::name)
+        .collect::<Vec<_>>();
+    assert_eq!(names.author(), expected.join(", "));
+}
@@ -0,0 +1,150 @@
+// tests/startup_cost.rs
+// Enforces the allocation guarantees of the non-version path, of
+// rendering into a reused buffer and of building from string literals,
+// and the startup time budget
+
+use clap::{ArgMatches, ColorChoice, Command};
+use clap_version_flag::{
+    ColorDepth, ColorfulVersion, ColorfulVersionExt, Locale, colorful_version, startup_cost,
+};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts the allocations of the current thread
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn count() {
+    let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count();
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count();
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count();
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Returns how many allocations `f` performs on this thread
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+fn matches(cmd: Command, args: &[&str]) -> ArgMatches {
+    cmd.try_get_matches_from(args).unwrap()
+}
+
+#[test]
+fn test_counter_sees_allocations() {
+    assert!(allocations_during(|| drop(std::hint::black_box(vec![1u8]))) > 0);
+}
+
+#[test]
+fn test_check_without_flag_allocates_nothing() {
+    let version = colorful_version!();
+    let matches = matches(
+        Command::new("app").with_colorful_version(&version),
+        &["app"],
+    );
+    let count = allocations_during(|| {
+        assert!(!version.check(&matches));
+        version.check_and_exit(&matches);
+    });
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn test_companions_allocate_nothing() {
+    let version = ColorfulVersion::new("app", "1.0.0", "Jane").with_dependencies([("clap", "4")]);
+    let cmd = Command::new("app")
+        .with_verbose_colorful_version(&version)
+        .with_version_format_flag()
+        .with_dependencies_flag()
+        .with_version_fields_flag()
+        .with_version_theme_flag()
+        .with_bug_report_flag();
+    let matches = matches(cmd, &["app"]);
+    assert_eq!(allocations_during(|| assert!(!version.check(&matches))), 0);
+}
+
+#[test]
+fn test_render_into_reused_buffer_allocates_nothing() {
+    let version = ColorfulVersion::new("app", "1.0.0", "Jane")
+        .with_color_choice(ColorChoice::Always)
+        .with_color_depth(ColorDepth::TrueColor);
+    let translated = version.clone().with_locale(Locale::De);
+    for version in [version, translated] {
+        let mut buffer = String::with_capacity(256);
+        version.render_into(&mut buffer);
+        let count = allocations_during(|| {
+            buffer.clear();
+            version.render_into(&mut buffer);
+        });
+        assert_eq!(count, 0);
+        assert_eq!(buffer, version.to_colored_string());
+    }
+}
+
+#[test]
+fn test_startup_cost_reports_overhead() {
+    let cost = startup_cost(&colorful_version!());
+    assert_eq!(cost.total(), cost.register + cost.check);
+}
+
+// the budget holds for release builds only; CI runs this with `--release`
+#[cfg(not(debug_assertions))]
+#[test]
+fn test_startup_cost_within_budget() {
+    use clap_version_flag::STARTUP_BUDGET;
+
+    // the best of a few runs, so a busy test machine does not fail the bound
+    let version = colorful_version!();
+    let best = (0..5)
+        .map(|_| startup_cost(&version))
+        .min_by_key(|cost| cost.total())
+        .unwrap();
+    assert!(
+        best.within_budget(),
+        "{:?} exceeds {STARTUP_BUDGET:?}",
+        best.total()
+    );
+}
+
+#[test]
+fn test_literals_are_not_copied() {
+    // the strings are borrowed; the author list and license are parsed on use
+    assert_eq!(
+        allocations_during(|| drop(std::hint::black_box(colorful_version!()))),
+        0
+    );
+    let count = allocations_during(|| {
+        drop(std::hint::black_box(ColorfulVersion::from_static(
+            "app",
+            "1.0.0",
+            "Jane <jane@example.com>",
+        )))
+    });
+    assert_eq!(count, 0);
+}
@@ -0,0 +1,99 @@
+// tests/testkit/mod.rs
+// Black-box harness: runs the fixture binary and the examples as real processes
+
+#![allow(dead_code)]
+
+use assert_cmd::Command;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Color-related variables cleared before every run
+const COLOR_VARS: [&str; 4] = ["NO_COLOR", "CLICOLOR", "CLICOLOR_FORCE", "TERM"];
+
+/// The package features, so examples are built like the test binary
+const FEATURES: [(&str, bool); 16] = [
+    ("no-color", cfg!(feature = "no-color")),
+    ("derive", cfg!(feature = "derive")),
+    ("suite", cfg!(feature = "suite")),
+    ("serde", cfg!(feature = "serde")),
+    ("config", cfg!(feature = "config")),
+    ("update-check", cfg!(feature = "update-check")),
+    ("ffi", cfg!(feature = "ffi")),
+    ("figlet", cfg!(feature = "figlet")),
+    ("sysinfo", cfg!(feature = "sysinfo")),
+    ("mangen", cfg!(feature = "mangen")),
+    ("completions", cfg!(feature = "completions")),
+    ("preview", cfg!(feature = "preview")),
+    ("attribute", cfg!(feature = "attribute")),
+    ("windows-console", cfg!(feature = "windows-console")),
+    ("anstream", cfg!(feature = "anstream")),
+    ("examples-extra", cfg!(feature = "examples-extra")),
+];
+
+/// Examples built by this test binary so far
+static BUILT: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Returns the path of the example `name`, building it first
+///
+/// Runs `cargo build --example` once per example and test binary, with the
+/// features and profile of the test binary, so a single integration test
+/// run on a clean tree finds its binaries (`tests/fixtures/` binaries are
+/// examples too) in `target/<profile>/examples`.
+pub fn example_path(name: &str) -> PathBuf {
+    let mut profile_dir = std::env::current_exe().unwrap();
+    profile_dir.pop();
+    if profile_dir.ends_with("deps") {
+        profile_dir.pop();
+    }
+
+    let mut built = BUILT
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if !built.iter().any(|example| example == name) {
+        let features: Vec<&str> = FEATURES
+            .iter()
+            .filter_map(|&(feature, enabled)| enabled.then_some(feature))
+            .collect();
+        let mut build =
+            std::process::Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+        build
+            .args(["build", "--quiet", "--example", name])
+            .arg("--manifest-path")
+            .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+            .arg("--target-dir")
+            .arg(profile_dir.parent().unwrap())
+            .arg("--features")
+            .arg(features.join(","));
+        if !cfg!(debug_assertions) {
+            build.arg("--release");
+        }
+        let status = build.status().unwrap();
+        assert!(status.success(), "building example {name} failed");
+        built.push(name.to_string());
+    }
+
+    let mut path = profile_dir;
+    path.push("examples");
+    path.push(format!("{name}{}", std::env::consts::EXE_SUFFIX));
+    path
+}
+
+/// Returns a command running the example `name` with a clean color environment
+///
+/// Output is piped, so colors are off unless the test forces them with
+/// `CLICOLOR_FORCE=1`.
+pub fn example(name: &str) -> Command {
+    let mut cmd = Command::new(example_path(name));
+    for var in COLOR_VARS {
+        cmd.env_remove(var);
+    }
+    cmd
+}
+
+/// Returns a command running the `tests/fixtures/version_app.rs` fixture
+pub fn fixture() -> Command {
+    example("version_app")
+}
+
+/// The version line printed by the fixture
+pub const FIXTURE_LINE: &str = "fixture v1.2.3 by Fixture Author";